@@ -20,12 +20,13 @@
 
 use grandpa_primitives::AuthorityId as GrandpaId;
 use kitchensink_runtime::{
-	constants::currency::*, wasm_binary_unwrap, AuthorityDiscoveryConfig, BabeConfig,
-	BalancesConfig, Block, CouncilConfig, DemocracyConfig, ElectionsConfig, GrandpaConfig,
-	ImOnlineConfig, IndicesConfig, MaxNominations, NominationPoolsConfig, SessionConfig,
-	SessionKeys, SocietyConfig, StakerStatus, StakingConfig, SudoConfig, SystemConfig,
-	TechnicalCommitteeConfig,
+	constants::currency::*, wasm_binary_unwrap, AssetId, AuthorityDiscoveryConfig, BabeConfig,
+	BalancesConfig, Block, CouncilConfig, DEXConfig, DemocracyConfig, ElectionsConfig,
+	GrandpaConfig, ImOnlineConfig, IndicesConfig, MaxNominations, NominationPoolsConfig, Royalties,
+	Runtime, SessionConfig, SessionKeys, SocietyConfig, StakerStatus, StakingConfig, SudoConfig,
+	SystemConfig, TechnicalCommitteeConfig,
 };
+use pallet_dex::{ConfigHelper, TokenKind};
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 use sc_chain_spec::{ChainSpecExtension, Properties};
 use sc_service::ChainType;
@@ -294,10 +295,54 @@ pub fn testnet_genesis(
 	const ENDOWMENT: Balance = 10_000_000 * DOLLARS;
 	const STASH: Balance = ENDOWMENT / 1000;
 
+	// Assets traded by the DEX pools seeded for local testing, endowed to every account.
+	const DEX_ASSETS: [AssetId; 2] = [1, 2];
+	// The pools are funded by the first endowed account. Liquidity tokens are minted to the
+	// pallet account, which has to exist for them to be created. The deposits of the receipts of
+	// concentrated liquidity positions are reserved from the positions account.
+	let dex_provider = endowed_accounts[0].clone();
+	let dex_account = <Runtime as ConfigHelper>::pallet_account();
+	let dex_positions_account = <Runtime as ConfigHelper>::positions_account();
+	// Royalties for plays are paid into the royalties account in amounts below the existential
+	// deposit, which it has to exist to accept.
+	let royalties_account = Royalties::account_id();
+	let dex_pools = vec![
+		(
+			TokenKind::Native,
+			TokenKind::Asset(DEX_ASSETS[0]),
+			1_001,
+			10_000 * DOLLARS,
+			20_000 * DOLLARS,
+		),
+		(
+			TokenKind::Native,
+			TokenKind::Asset(DEX_ASSETS[1]),
+			1_002,
+			10_000 * DOLLARS,
+			50_000 * DOLLARS,
+		),
+		(
+			TokenKind::Asset(DEX_ASSETS[0]),
+			TokenKind::Asset(DEX_ASSETS[1]),
+			1_003,
+			20_000 * DOLLARS,
+			50_000 * DOLLARS,
+		),
+	];
+
 	GenesisConfig {
 		system: SystemConfig { code: wasm_binary_unwrap().to_vec() },
 		balances: BalancesConfig {
-			balances: endowed_accounts.iter().cloned().map(|x| (x, ENDOWMENT)).collect(),
+			balances: endowed_accounts
+				.iter()
+				.cloned()
+				.map(|x| (x, ENDOWMENT))
+				.chain([
+					(dex_account, DOLLARS),
+					(dex_positions_account, 1_000 * DOLLARS),
+					(royalties_account, DOLLARS),
+				])
+				.collect(),
 		},
 		indices: IndicesConfig { indices: vec![] },
 		session: SessionConfig {
@@ -360,7 +405,14 @@ pub fn testnet_genesis(
 		vesting: Default::default(),
 		assets: pallet_assets::GenesisConfig {
 			// This asset is used by the NIS pallet as counterpart currency.
-			assets: vec![(9, get_account_id_from_seed::<sr25519::Public>("Alice"), true, 1)],
+			assets: vec![(9, get_account_id_from_seed::<sr25519::Public>("Alice"), true, 1)]
+				.into_iter()
+				.chain(DEX_ASSETS.iter().map(|id| (*id, dex_provider.clone(), true, 1)))
+				.collect(),
+			accounts: DEX_ASSETS
+				.iter()
+				.flat_map(|id| endowed_accounts.iter().map(|x| (*id, x.clone(), ENDOWMENT)))
+				.collect(),
 			..Default::default()
 		},
 		transaction_storage: Default::default(),
@@ -372,6 +424,14 @@ pub fn testnet_genesis(
 			min_join_bond: 1 * DOLLARS,
 			..Default::default()
 		},
+		dex: DEXConfig {
+			pools: dex_pools
+				.into_iter()
+				.map(|(token_a, token_b, liquidity_token_id, amount_a, amount_b)| {
+					(token_a, token_b, liquidity_token_id, amount_a, amount_b, dex_provider.clone())
+				})
+				.collect(),
+		},
 	}
 }
 
@@ -34,7 +34,7 @@
 use std::sync::Arc;
 
 use jsonrpsee::RpcModule;
-use node_primitives::{AccountId, Balance, Block, BlockNumber, Hash, Index};
+use node_primitives::{AccountId, Balance, Block, BlockNumber, Hash, Index, Signature};
 use sc_client_api::AuxStore;
 use sc_consensus_babe::{BabeConfiguration, Epoch};
 use sc_consensus_epochs::SharedEpochChanges;
@@ -110,6 +110,10 @@ where
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: mmr_rpc::MmrRuntimeApi<Block, <Block as sp_runtime::traits::Block>::Hash, BlockNumber>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_dex_rpc::DexRuntimeApi<Block, AccountId, u32, Balance, BlockNumber>,
+	C::Api: pallet_audio_storage_rpc::AudioStorageRuntimeApi<Block, AccountId, BlockNumber>,
+	C::Api:
+		pallet_uniques_rpc::UniquesRuntimeApi<Block, AccountId, u32, u32, BlockNumber, Signature>,
 	C::Api: BabeApi<Block>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
@@ -118,7 +122,10 @@ where
 	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashFor<Block>>,
 {
 	use mmr_rpc::{Mmr, MmrApiServer};
+	use pallet_audio_storage_rpc::{AudioStorage, AudioStorageApiServer};
+	use pallet_dex_rpc::{Dex, DexApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+	use pallet_uniques_rpc::{Uniques, UniquesApiServer};
 	use sc_consensus_babe_rpc::{Babe, BabeApiServer};
 	use sc_finality_grandpa_rpc::{Grandpa, GrandpaApiServer};
 	use sc_rpc::dev::{Dev, DevApiServer};
@@ -150,6 +157,11 @@ where
 	// These RPCs should use an asynchronous caller instead.
 	io.merge(Mmr::new(client.clone()).into_rpc())?;
 	io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	io.merge(Dex::new(client.clone()).into_rpc())?;
+	io.merge(
+		AudioStorage::new(client.clone(), subscription_executor.clone(), deny_unsafe).into_rpc(),
+	)?;
+	io.merge(Uniques::new(client.clone()).into_rpc())?;
 	io.merge(
 		Babe::new(
 			client.clone(),
@@ -0,0 +1,305 @@
+//! Chain extensions available to contracts.
+
+use crate::{Balance, BlockNumber, Runtime, RuntimeOrigin, System, DEX};
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::{GetDispatchInfo, UnfilteredDispatchable},
+	traits::PalletInfoAccess,
+	RuntimeDebug,
+};
+use frame_system::RawOrigin;
+use pallet_contracts::chain_extension::{
+	BufInBufOutState, ChainExtension, Environment, Ext, InitState, RegisteredChainExtension,
+	Result, RetVal,
+};
+use pallet_dex::{SwapPathOf, TokenKind, TokenKindOf};
+use sp_runtime::{DispatchError, ModuleError};
+
+type DexError = pallet_dex::Error<Runtime>;
+type DexCall = pallet_dex::Call<Runtime>;
+type Token = TokenKindOf<Runtime>;
+type Env<'a, 'b, E> = Environment<'a, 'b, E, BufInBufOutState>;
+
+/// Status code returned to contracts by [`DexExtension`].
+///
+/// Contracts should treat any code they don't know as [`DexStatus::Failed`].
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum DexStatus {
+	/// The call succeeded and its result, if any, was written to the output buffer.
+	Success = 0,
+	/// There is no pool between the given tokens.
+	PoolNotFound = 1,
+	/// The requested oracle window is empty.
+	InvalidOracleWindow = 2,
+	/// The pool has no observation old enough to cover the requested window.
+	OracleWindowUnavailable = 3,
+	/// The swap path is too short, too long or goes through a token twice.
+	InvalidPath = 4,
+	/// An amount is zero.
+	ZeroAmount = 5,
+	/// The trade would be executed beyond the given limit.
+	SlippageExceeded = 6,
+	/// The pool does not hold enough of the bought token.
+	InsufficientLiquidity = 7,
+	/// The contract does not hold enough of the sold token or of the liquidity token.
+	InsufficientBalance = 8,
+	/// A token could not be moved.
+	TransferFailed = 9,
+	/// A token of the trade is a PSP22 contract, which can't be traded from a contract.
+	ContractToken = 10,
+	/// Any other error.
+	Failed = 255,
+}
+
+impl From<DexError> for DexStatus {
+	fn from(error: DexError) -> Self {
+		use pallet_dex::Error::*;
+
+		match error {
+			ExchangeDoesNotExist | IdenticalTokens => DexStatus::PoolNotFound,
+			InvalidOracleWindow => DexStatus::InvalidOracleWindow,
+			OracleWindowUnavailable => DexStatus::OracleWindowUnavailable,
+			InvalidPath => DexStatus::InvalidPath,
+			TradeAmountIsZero |
+			TokenAmountIsZero |
+			CurrencyAmountIsZero |
+			MaxTokensIsZero |
+			MinLiquidityIsZero |
+			LiquidityAmountIsZero |
+			MinCurrencyIsZero |
+			MinTokensIsZero => DexStatus::ZeroAmount,
+			InsufficientOutputAmount |
+			MaxTokensTooLow |
+			MinLiquidityTooHigh |
+			MinCurrencyTooHigh |
+			MinTokensTooHigh |
+			MinBoughtTokensTooHigh |
+			MaxSoldTokensTooLow => DexStatus::SlippageExceeded,
+			NotEnoughLiquidity => DexStatus::InsufficientLiquidity,
+			BalanceTooLow | ProviderLiquidityTooLow => DexStatus::InsufficientBalance,
+			TokenTransferFailed => DexStatus::TransferFailed,
+			_ => DexStatus::Failed,
+		}
+	}
+}
+
+impl From<DispatchError> for DexStatus {
+	fn from(error: DispatchError) -> Self {
+		match error {
+			DispatchError::Module(ModuleError { index, error, .. })
+				if index as usize == <DEX as PalletInfoAccess>::index() =>
+				DexError::decode(&mut &error[..]).map_or(DexStatus::Failed, Into::into),
+			_ => DexStatus::Failed,
+		}
+	}
+}
+
+/// Chain extension giving contracts access to the DEX.
+///
+/// Every function reads its SCALE encoded arguments from the input buffer and returns a
+/// [`DexStatus`]. Trades are made on behalf of the calling contract, with its own tokens:
+///
+/// - `0x0001`: `(token_in, token_out, window)`, writes the `TwapPrice` of `token_in` in `token_out`
+///   over the last `window` blocks.
+/// - `0x0002`: `(path, amount_in)`, writes the `Quote` of selling exactly `amount_in`.
+/// - `0x0003`: `(path, amount_out)`, writes the `Quote` of buying exactly `amount_out`.
+/// - `0x0004`: `(path, amount_in, min_amount_out)`, sells exactly `amount_in` and writes the
+///   `Quote` it was executed at.
+/// - `0x0005`: `(path, amount_out, max_amount_in)`, buys exactly `amount_out` and writes the
+///   `Quote` it was executed at.
+/// - `0x0006`: `(token_a, token_b, amount_a, min_liquidity, max_amount_b)`, adds liquidity.
+/// - `0x0007`: `(token_a, token_b, liquidity, min_amount_a, min_amount_b)`, removes liquidity.
+///
+/// Trades of PSP22 contract tokens are refused with [`DexStatus::ContractToken`]: moving them calls
+/// their contract from within the calling one, on a new call stack that escapes the reentrancy and
+/// depth limits of `pallet-contracts`.
+///
+/// Quotes are charged the benchmarked weight of the swap of every hop of their path, whatever its
+/// kind of pool. Trades dispatch the matching DEX call, so they are charged its weight too,
+/// refunded down to what it actually used.
+#[derive(Default)]
+pub struct DexExtension;
+
+impl ChainExtension<Runtime> for DexExtension {
+	fn call<E: Ext<T = Runtime>>(&mut self, env: Environment<E, InitState>) -> Result<RetVal> {
+		let func_id = env.func_id();
+		let mut env = env.buf_in_buf_out();
+		let status = match func_id {
+			0x0001 => twap(&mut env)?,
+			0x0002 => quote(&mut env, false)?,
+			0x0003 => quote(&mut env, true)?,
+			0x0004 => swap(&mut env, false)?,
+			0x0005 => swap(&mut env, true)?,
+			0x0006 => add_liquidity(&mut env)?,
+			0x0007 => remove_liquidity(&mut env)?,
+			_ => return Err(DispatchError::Other("Unknown DEX chain extension function")),
+		};
+		Ok(RetVal::Converging(status as u32))
+	}
+}
+
+impl RegisteredChainExtension<Runtime> for DexExtension {
+	const ID: u16 = 0x0d3c;
+}
+
+fn twap<E: Ext<T = Runtime>>(env: &mut Env<E>) -> Result<DexStatus> {
+	let (token_in, token_out, window) = env.read_as::<(Token, Token, BlockNumber)>()?;
+	// The exchange, the concentrated or stable pool its spot price is read from, and its
+	// observations.
+	charge_reads(env, 5)?;
+	match DEX::twap(&token_in, &token_out, window) {
+		Ok(price) => write(env, price),
+		Err(error) => Ok(error.into()),
+	}
+}
+
+fn quote<E: Ext<T = Runtime>>(env: &mut Env<E>, exact_out: bool) -> Result<DexStatus> {
+	let (path, amount) = env.read_as::<(SwapPathOf<Runtime>, Balance)>()?;
+	charge_quote(env, path.len())?;
+	let quote = if exact_out {
+		DEX::quote_exact_out(path.into_inner(), amount)
+	} else {
+		DEX::quote_exact_in(path.into_inner(), amount)
+	};
+	match quote {
+		Ok(quote) => write(env, quote),
+		Err(error) => Ok(error.into()),
+	}
+}
+
+/// Sells exactly `amount` for at least `limit` or, if `exact_out`, buys exactly `amount` for at
+/// most `limit`.
+fn swap<E: Ext<T = Runtime>>(env: &mut Env<E>, exact_out: bool) -> Result<DexStatus> {
+	let (path, amount, limit) = env.read_as::<(SwapPathOf<Runtime>, Balance, Balance)>()?;
+	charge_quote(env, path.len())?;
+	if has_contract_token(&path) {
+		return Ok(DexStatus::ContractToken)
+	}
+	let (quote, call) = if exact_out {
+		let quote = DEX::quote_exact_out(path.to_vec(), amount);
+		let call = DexCall::swap_exact_out_along_path {
+			path,
+			bought_token_amount: amount,
+			max_sold_tokens: limit,
+		};
+		(quote, call)
+	} else {
+		let quote = DEX::quote_exact_in(path.to_vec(), amount);
+		let call = DexCall::swap_exact_in_along_path {
+			path,
+			sold_token_amount: amount,
+			min_bought_tokens: limit,
+		};
+		(quote, call)
+	};
+	let quote = match quote {
+		Ok(quote) => quote,
+		Err(error) => return Ok(error.into()),
+	};
+	match dispatch(env, call)? {
+		Ok(()) => write(env, quote),
+		Err(error) => Ok(error.into()),
+	}
+}
+
+fn add_liquidity<E: Ext<T = Runtime>>(env: &mut Env<E>) -> Result<DexStatus> {
+	let (token_a, token_b, amount_a, min_liquidity, max_amount_b) =
+		env.read_as::<(Token, Token, Balance, Balance, Balance)>()?;
+	if has_contract_token(&[token_a.clone(), token_b.clone()]) {
+		return Ok(DexStatus::ContractToken)
+	}
+	let call = DexCall::add_liquidity {
+		token_a,
+		token_b,
+		currency_amount: amount_a,
+		min_liquidity,
+		max_tokens: max_amount_b,
+		deadline: System::block_number(),
+	};
+	Ok(dispatch(env, call)?.map_or_else(Into::into, |()| DexStatus::Success))
+}
+
+fn remove_liquidity<E: Ext<T = Runtime>>(env: &mut Env<E>) -> Result<DexStatus> {
+	let (token_a, token_b, liquidity, min_amount_a, min_amount_b) =
+		env.read_as::<(Token, Token, Balance, Balance, Balance)>()?;
+	if has_contract_token(&[token_a.clone(), token_b.clone()]) {
+		return Ok(DexStatus::ContractToken)
+	}
+	let call = DexCall::remove_liquidity {
+		token_a,
+		token_b,
+		liquidity_amount: liquidity,
+		min_currency: min_amount_a,
+		min_tokens: min_amount_b,
+		deadline: System::block_number(),
+	};
+	Ok(dispatch(env, call)?.map_or_else(Into::into, |()| DexStatus::Success))
+}
+
+/// Whether any of `tokens` is a PSP22 contract.
+fn has_contract_token(tokens: &[Token]) -> bool {
+	tokens.iter().any(|token| matches!(token, TokenKind::Contract(_)))
+}
+
+/// Charges the weight of reading `reads` storage items.
+fn charge_reads<E: Ext<T = Runtime>>(env: &mut Env<E>, reads: usize) -> Result<()> {
+	let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(reads as u64);
+	env.charge_weight(weight).map(|_| ())
+}
+
+/// Charges the weight of quoting a swap along a path of `tokens` tokens.
+fn charge_quote<E: Ext<T = Runtime>>(env: &mut Env<E>, tokens: usize) -> Result<()> {
+	let weight = pallet_dex::quote_weight::<Runtime>(tokens.saturating_sub(1) as u64);
+	env.charge_weight(weight).map(|_| ())
+}
+
+/// Writes `value` to the output buffer of the contract.
+fn write<E: Ext<T = Runtime>>(env: &mut Env<E>, value: impl Encode) -> Result<DexStatus> {
+	env.write(&value.encode(), false, None)?;
+	Ok(DexStatus::Success)
+}
+
+/// Dispatches `call` on behalf of the calling contract.
+///
+/// The call is charged its full weight upfront and refunded down to its actual weight. Only
+/// running out of gas is an error: a failing call is returned for its status to be reported.
+fn dispatch<E: Ext<T = Runtime>>(
+	env: &mut Env<E>,
+	call: DexCall,
+) -> Result<sp_std::result::Result<(), DispatchError>> {
+	let info = call.get_dispatch_info();
+	let charged = env.charge_weight(info.weight)?;
+	let origin: RuntimeOrigin = RawOrigin::Signed(env.ext().address().clone()).into();
+	let result = call.dispatch_bypass_filter(origin);
+	let post_info = match &result {
+		Ok(post_info) => *post_info,
+		Err(error) => error.post_info,
+	};
+	env.adjust_weight(charged, post_info.calc_actual_weight(&info));
+	Ok(result.map(|_| ()).map_err(|error| error.error))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn dex_errors_map_to_status_codes() {
+		let status = |error: DexError| DexStatus::from(DispatchError::from(error));
+
+		assert_eq!(status(DexError::ExchangeDoesNotExist), DexStatus::PoolNotFound);
+		assert_eq!(status(DexError::MaxSoldTokensTooLow), DexStatus::SlippageExceeded);
+		assert_eq!(status(DexError::NotEnoughLiquidity), DexStatus::InsufficientLiquidity);
+		assert_eq!(status(DexError::Overflow), DexStatus::Failed);
+		assert_eq!(DexStatus::from(DispatchError::BadOrigin), DexStatus::Failed);
+	}
+
+	#[test]
+	fn contract_tokens_are_detected() {
+		let contract = TokenKind::Contract(sp_runtime::AccountId32::new([1; 32]));
+
+		assert!(!has_contract_token(&[TokenKind::Native, TokenKind::Asset(1)]));
+		assert!(has_contract_token(&[TokenKind::Native, contract.clone()]));
+		assert!(has_contract_token(&[contract, TokenKind::Asset(1)]));
+	}
+}
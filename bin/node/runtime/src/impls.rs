@@ -18,18 +18,18 @@
 //! Some configurable implementations as associated type for the substrate runtime.
 
 use crate::{
-	AccountId, AllianceMotion, Assets, Authorship, Balances, Hash, NegativeImbalance, Runtime,
-	RuntimeCall,
+	AccountId, AllianceMotion, AssetId, Authorship, Balances, DexPositionCollection, Hash,
+	MusicRights, NegativeImbalance, NftAuctions, NftFractionalization, RuntimeCall, RuntimeOrigin,
 };
+#[cfg(feature = "runtime-benchmarks")]
+use crate::{Runtime, Uniques};
 use frame_support::{
 	pallet_prelude::*,
-	traits::{
-		fungibles::{Balanced, CreditOf},
-		Currency, OnUnbalanced,
-	},
+	traits::{tokens::Locker, Currency, EnsureOrigin, EnsureOriginWithArg, OnUnbalanced},
 };
+use frame_system::EnsureSigned;
 use pallet_alliance::{IdentityVerifier, ProposalIndex, ProposalProvider};
-use pallet_asset_tx_payment::HandleCredit;
+use sp_runtime::traits::Convert;
 use sp_std::prelude::*;
 
 pub struct Author;
@@ -41,16 +41,166 @@ impl OnUnbalanced<NegativeImbalance> for Author {
 	}
 }
 
-/// A `HandleCredit` implementation that naively transfers the fees to the block author.
-/// Will drop and burn the assets in case the transfer fails.
-pub struct CreditToBlockAuthor;
-impl HandleCredit<AccountId, Assets> for CreditToBlockAuthor {
-	fn handle_credit(credit: CreditOf<AccountId, Assets>) {
-		if let Some(author) = pallet_authorship::Pallet::<Runtime>::author() {
-			// Drop the result which will trigger the `OnDrop` of the imbalance in case of error.
-			let _ = Assets::resolve(&author, credit);
+/// Locks the items of `pallet_uniques` which are fractionalized or on sale by auction.
+pub struct UniquesLocker;
+impl Locker<u32, u32> for UniquesLocker {
+	fn is_locked(collection: u32, item: u32) -> bool {
+		NftFractionalization::is_locked(collection, item) ||
+			NftAuctions::is_locked(collection, item)
+	}
+}
+
+/// Drops the registration of the works whose item of `pallet_uniques` is burned, so that an item
+/// minted again under the same id starts unregistered.
+pub struct UniquesOnBurn;
+impl pallet_uniques::OnBurn<u32, u32> for UniquesOnBurn {
+	fn on_burn(collection: &u32, item: &u32) {
+		MusicRights::do_clear_work(*collection, *item);
+	}
+}
+
+/// Lets signed accounts create collections of `Uniques`, except under the id reserved for the
+/// receipts of the concentrated liquidity positions of the DEX.
+pub struct UniquesCreateOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, u32> for UniquesCreateOrigin {
+	type Success = AccountId;
+
+	fn try_origin(o: RuntimeOrigin, collection: &u32) -> Result<AccountId, RuntimeOrigin> {
+		if *collection == DexPositionCollection::get() {
+			return Err(o)
 		}
+		EnsureSigned::<AccountId>::try_origin(o)
 	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(collection: &u32) -> Result<RuntimeOrigin, ()> {
+		if *collection == DexPositionCollection::get() {
+			return Err(())
+		}
+		EnsureSigned::<AccountId>::try_successful_origin()
+	}
+}
+
+/// Asset ids of the liquidity tokens of the pools moved to the current layout of the DEX, in the
+/// order they are allocated, counting down from the highest id.
+pub struct DexMigratedLiquidityTokenId;
+impl Convert<u32, AssetId> for DexMigratedLiquidityTokenId {
+	fn convert(position: u32) -> AssetId {
+		AssetId::MAX - position
+	}
+}
+
+/// Liquidity token ids of the pools created by the benchmarks of the DEX, above the assets and
+/// liquidity tokens endowed at genesis.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct DexBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_dex::BenchmarkHelper<AssetId> for DexBenchmarkHelper {
+	fn liquidity_token_id(id: u32) -> AssetId {
+		1_000_000 + id
+	}
+}
+
+/// Creates the items registered as works by the benchmarks of the music rights pallet, each in a
+/// free holding collection of its own.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MusicRightsBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_music_rights::BenchmarkHelper<AccountId, u32, u32> for MusicRightsBenchmarkHelper {
+	fn create_item(owner: &AccountId, seed: u32) -> (u32, u32) {
+		use frame_support::traits::tokens::nonfungibles::Mutate;
+
+		Uniques::force_create(
+			frame_system::RawOrigin::Root.into(),
+			seed,
+			sp_runtime::MultiAddress::Id(owner.clone()),
+			true,
+		)
+		.expect("collection id is free");
+		Uniques::mint_into(&seed, &0, owner).expect("item is new");
+		(seed, 0)
+	}
+
+	fn destroy_item(collection: &u32, item: &u32) {
+		use frame_support::traits::tokens::nonfungibles::Mutate;
+
+		<Uniques as Mutate<AccountId>>::burn(collection, item, None).expect("item exists");
+	}
+}
+
+/// Registers the works paid by the benchmarks of the royalties pallet, as item 0 of collection 0
+/// edited by its owner.
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_royalties::BenchmarkHelper<AccountId, u32, u32> for MusicRightsBenchmarkHelper {
+	fn register_work(holders: &[AccountId]) -> (u32, u32) {
+		use pallet_music_rights::{BenchmarkHelper, SplitEditor, WorkKind, WorkMetadata};
+
+		let owner: AccountId = frame_benchmarking::account("owner", 0, 0);
+		let (collection, item) = Self::create_item(&owner, 0);
+		MusicRights::register_work(
+			frame_system::RawOrigin::Signed(owner.clone()).into(),
+			collection,
+			item,
+			WorkMetadata { kind: WorkKind::Track, isrc: None, iswc: None },
+			equal_splits(holders),
+			SplitEditor::Account(owner),
+		)
+		.expect("item is owned by the owner");
+		(collection, item)
+	}
+
+	fn set_holders(collection: &u32, item: &u32, holders: &[AccountId]) {
+		let owner: AccountId = frame_benchmarking::account("owner", 0, 0);
+		MusicRights::propose_splits(
+			frame_system::RawOrigin::Signed(owner).into(),
+			*collection,
+			*item,
+			equal_splits(holders),
+		)
+		.expect("owner edits the splits");
+	}
+}
+
+/// Registers the tracks played by the benchmarks of the play metering pallet, each as item 0 of
+/// collection `seed` shared by its owner.
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_play_metering::BenchmarkHelper<u32, u32> for MusicRightsBenchmarkHelper {
+	fn register_track(seed: u32) -> (u32, u32) {
+		use pallet_music_rights::{BenchmarkHelper, SplitEditor, WorkKind, WorkMetadata};
+
+		let owner: AccountId = frame_benchmarking::account("owner", seed, 0);
+		let (collection, item) = Self::create_item(&owner, seed);
+		MusicRights::register_work(
+			frame_system::RawOrigin::Signed(owner.clone()).into(),
+			collection,
+			item,
+			WorkMetadata { kind: WorkKind::Track, isrc: None, iswc: None },
+			equal_splits(&[owner.clone()]),
+			SplitEditor::Account(owner),
+		)
+		.expect("item is owned by the owner");
+		(collection, item)
+	}
+}
+
+/// The split tree sharing a work equally between `holders`, the first one keeping the remainder.
+#[cfg(feature = "runtime-benchmarks")]
+fn equal_splits(holders: &[AccountId]) -> pallet_music_rights::SplitTreeOf<Runtime> {
+	use sp_runtime::{PerThing, Perbill};
+
+	let share = Perbill::from_rational(1, holders.len() as u32);
+	let first =
+		Perbill::from_parts(Perbill::ACCURACY - share.deconstruct() * (holders.len() as u32 - 1));
+	let nodes: Vec<_> = holders
+		.iter()
+		.enumerate()
+		.map(|(index, holder)| pallet_music_rights::SplitNode {
+			holder: holder.clone(),
+			share: if index == 0 { first } else { share },
+			parent: None,
+		})
+		.collect();
+	nodes.try_into().expect("holders are at most MaxSplitNodes")
 }
 
 pub struct AllianceIdentityVerifier;
@@ -97,7 +97,14 @@ pub use sp_runtime::BuildStorage;
 pub mod impls;
 #[cfg(not(feature = "runtime-benchmarks"))]
 use impls::AllianceIdentityVerifier;
-use impls::{AllianceProposalProvider, Author, CreditToBlockAuthor};
+use impls::{
+	AllianceProposalProvider, Author, DexMigratedLiquidityTokenId, UniquesCreateOrigin, UniquesLocker,
+	UniquesOnBurn,
+};
+
+/// Chain extensions available to contracts.
+pub mod chain_extension;
+use chain_extension::DexExtension;
 
 /// Constant values used within the runtime.
 pub mod constants;
@@ -131,10 +138,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// and set impl_version to 0. If only runtime
 	// implementation changes and behavior does not, then leave spec_version as
 	// is and increment impl_version.
-	spec_version: 268,
+	spec_version: 269,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 2,
+	transaction_version: 3,
 	state_version: 1,
 };
 
@@ -470,10 +477,8 @@ impl pallet_transaction_payment::Config for Runtime {
 impl pallet_asset_tx_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Fungibles = Assets;
-	type OnChargeAssetTransaction = pallet_asset_tx_payment::FungiblesAdapter<
-		pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>,
-		CreditToBlockAuthor,
-	>;
+	// Fees paid in assets are bought from their DEX pools and shared like native fees.
+	type OnChargeAssetTransaction = pallet_dex::DexAdapter<DealWithFees>;
 }
 
 parameter_types! {
@@ -1209,7 +1214,7 @@ impl pallet_contracts::Config for Runtime {
 	type CallStack = [pallet_contracts::Frame<Self>; 5];
 	type WeightPrice = pallet_transaction_payment::Pallet<Self>;
 	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
-	type ChainExtension = ();
+	type ChainExtension = DexExtension;
 	type DeletionQueueDepth = DeletionQueueDepth;
 	type DeletionWeightLimit = DeletionWeightLimit;
 	type Schedule = Schedule;
@@ -1540,6 +1545,8 @@ parameter_types! {
 	pub const ItemAttributesApprovalsLimit: u32 = 20;
 	pub const MaxTips: u32 = 10;
 	pub const MaxDeadlineDuration: BlockNumber = 12 * 30 * DAYS;
+	pub const UniquesMaxRoyalty: Perbill = Perbill::from_percent(50);
+	pub const UniquesMaxOffers: u32 = 20;
 }
 
 impl pallet_uniques::Config for Runtime {
@@ -1556,11 +1563,14 @@ impl pallet_uniques::Config for Runtime {
 	type StringLimit = StringLimit;
 	type KeyLimit = KeyLimit;
 	type ValueLimit = ValueLimit;
+	type MaxRoyalty = UniquesMaxRoyalty;
+	type MaxOffers = UniquesMaxOffers;
 	type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
-	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
-	type Locker = ();
+	type CreateOrigin = UniquesCreateOrigin;
+	type Locker = UniquesLocker;
+	type OnBurn = UniquesOnBurn;
 }
 
 impl pallet_transaction_storage::Config for Runtime {
@@ -1575,6 +1585,12 @@ impl pallet_transaction_storage::Config for Runtime {
 		ConstU32<{ pallet_transaction_storage::DEFAULT_MAX_TRANSACTION_SIZE }>;
 }
 
+impl pallet_audio_storage::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxChunks = ConstU32<128>;
+	type WeightInfo = pallet_audio_storage::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_whitelist::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -1678,6 +1694,13 @@ pub type AssetId = u32;
 
 parameter_types! {
 	pub const DexPalletId: PalletId = PalletId(*b"dex_mock");
+	pub const DexContractCallGasLimit: Weight =
+		Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND / 50, 128 * 1024);
+	pub DexTreasuryAccount: AccountId = Treasury::account_id();
+	// Collections of `Uniques` are created under ids picked by their creators, so the receipts of
+	// concentrated liquidity positions use the last id, which `UniquesCreateOrigin` keeps from
+	// them.
+	pub const DexPositionCollection: u32 = u32::MAX;
 }
 
 impl pallet_dex::Config for Runtime {
@@ -1690,11 +1713,112 @@ impl pallet_dex::Config for Runtime {
 	type AssetId = AssetId;
 	type Assets = Assets;
 	type AssetRegistry = Assets;
-	type WeightInfo = ();
+	type WeightInfo = pallet_dex::weights::SubstrateWeight<Runtime>;
 	// Provider fee is 0.3%
 	type ProviderFeeNumerator = ConstU128<3>;
 	type ProviderFeeDenominator = ConstU128<1000>;
 	type MinDeposit = ConstU128<1>;
+	type ContractCallGasLimit = DexContractCallGasLimit;
+	type MinimumLiquidity = ConstU128<1_000>;
+	type MaxSwapPathLength = ConstU32<4>;
+	type MaxFlashSwapDataLength = ConstU32<1024>;
+	type MaxOrderLifetime = ConstU32<{ 30 * DAYS }>;
+	type ObservationPeriod = ConstU32<{ 10 * MINUTES }>;
+	// A day of observations.
+	type MaxObservations = ConstU32<144>;
+	type GovernanceOrigin = EnsureRootOrHalfCouncil;
+	type TreasuryAccount = DexTreasuryAccount;
+	// The deposits of the receipts are reserved from the positions sub-account of the pallet,
+	// which is endowed at genesis.
+	type PositionNfts = Uniques;
+	type PositionCollection = DexPositionCollection;
+	type MaxTicks = ConstU32<64>;
+	type MaxStableTokens = ConstU32<4>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = impls::DexBenchmarkHelper;
+}
+
+impl pallet_music_rights::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Items = Uniques;
+	type MaxSplitNodes = ConstU32<16>;
+	type WeightInfo = pallet_music_rights::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = impls::MusicRightsBenchmarkHelper;
+}
+
+parameter_types! {
+	pub const RoyaltiesPalletId: PalletId = PalletId(*b"py/roylt");
+}
+
+impl pallet_royalties::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Rights = MusicRights;
+	type Currency = Balances;
+	type Assets = Assets;
+	type PalletId = RoyaltiesPalletId;
+	type MaxHolders = ConstU32<16>;
+	type WeightInfo = pallet_royalties::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = impls::MusicRightsBenchmarkHelper;
+}
+
+parameter_types! {
+	pub const PlayMeteringPalletId: PalletId = PalletId(*b"py/plays");
+	pub const PlayBatchDeposit: Balance = 10 * DOLLARS;
+	pub const PayoutPerPlay: Balance = MILLICENTS;
+	pub const PlayRevealDeposit: Balance = DOLLARS;
+}
+
+impl pallet_play_metering::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type PalletId = PlayMeteringPalletId;
+	type BatchDeposit = PlayBatchDeposit;
+	type ChallengePeriod = ConstU32<{ 2 * HOURS }>;
+	type PayoutPerPlay = PayoutPerPlay;
+	type MaxTracks = ConstU32<16>;
+	type ListenerOrigin = EnsureRootOrHalfCouncil;
+	type RevealDeposit = PlayRevealDeposit;
+	type RevealPeriod = ConstU32<{ 30 * MINUTES }>;
+	type MaxReveals = ConstU32<16>;
+	type WeightInfo = pallet_play_metering::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = impls::MusicRightsBenchmarkHelper;
+}
+
+parameter_types! {
+	pub const NftAuctionsPalletId: PalletId = PalletId(*b"py/auctn");
+}
+
+impl pallet_nft_auctions::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Scheduler = Scheduler;
+	type Preimages = Preimage;
+	type PalletId = NftAuctionsPalletId;
+	type MaxDuration = ConstU32<{ 30 * DAYS }>;
+	type ExtensionPeriod = ConstU32<{ 10 * MINUTES }>;
+	type GracePeriod = ConstU32<{ DAYS }>;
+	type WeightInfo = pallet_nft_auctions::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const NftFractionalizationPalletId: PalletId = PalletId(*b"py/frctn");
+}
+
+impl pallet_nft_fractionalization::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = u32;
+	type Assets = Assets;
+	type PalletId = NftFractionalizationPalletId;
+	type WeightInfo = pallet_nft_fractionalization::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 
@@ -1766,6 +1890,12 @@ construct_runtime!(
 		MessageQueue: pallet_message_queue,
 		Pov: frame_benchmarking_pallet_pov,
 		DEX: pallet_dex,
+		MusicRights: pallet_music_rights,
+		Royalties: pallet_royalties,
+		AudioStorage: pallet_audio_storage,
+		PlayMetering: pallet_play_metering,
+		NftAuctions: pallet_nft_auctions,
+		NftFractionalization: pallet_nft_fractionalization,
 
 	}
 );
@@ -1819,6 +1949,8 @@ type Migrations = (
 	pallet_nomination_pools::migration::v2::MigrateToV2<Runtime>,
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
+	pallet_dex::migration::v1::MigrateToV1<Runtime, DexMigratedLiquidityTokenId>,
+	pallet_uniques::migration::v2::MigrateToV2<Runtime>,
 );
 
 /// MMR helper types.
@@ -1838,6 +1970,7 @@ mod benches {
 		[frame_benchmarking_pallet_pov, Pov]
 		[pallet_alliance, Alliance]
 		[pallet_assets, Assets]
+		[pallet_audio_storage, AudioStorage]
 		[pallet_babe, Babe]
 		[pallet_bags_list, VoterList]
 		[pallet_balances, Balances]
@@ -1847,6 +1980,7 @@ mod benches {
 		[pallet_conviction_voting, ConvictionVoting]
 		[pallet_contracts, Contracts]
 		[pallet_democracy, Democracy]
+		[pallet_dex, DEX]
 		[pallet_election_provider_multi_phase, ElectionProviderMultiPhase]
 		[pallet_election_provider_support_benchmarking, EPSBench::<Runtime>]
 		[pallet_elections_phragmen, Elections]
@@ -1861,12 +1995,17 @@ mod benches {
 		[pallet_message_queue, MessageQueue]
 		[pallet_mmr, Mmr]
 		[pallet_multisig, Multisig]
+		[pallet_music_rights, MusicRights]
+		[pallet_nft_auctions, NftAuctions]
+		[pallet_nft_fractionalization, NftFractionalization]
 		[pallet_nomination_pools, NominationPoolsBench::<Runtime>]
 		[pallet_offences, OffencesBench::<Runtime>]
+		[pallet_play_metering, PlayMetering]
 		[pallet_preimage, Preimage]
 		[pallet_proxy, Proxy]
 		[pallet_ranked_collective, RankedCollective]
 		[pallet_referenda, Referenda]
+		[pallet_royalties, Royalties]
 		[pallet_recovery, Recovery]
 		[pallet_remark, Remark]
 		[pallet_scheduler, Scheduler]
@@ -2153,6 +2292,68 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_dex_rpc_runtime_api::DexApi<Block, AccountId, AssetId, Balance, BlockNumber> for Runtime {
+		fn quote_exact_in(
+			path: Vec<pallet_dex::TokenKindOf<Runtime>>,
+			amount_in: Balance,
+		) -> Result<pallet_dex::Quote<Balance>, sp_runtime::DispatchError> {
+			DEX::quote_exact_in(path, amount_in)
+		}
+
+		fn quote_exact_out(
+			path: Vec<pallet_dex::TokenKindOf<Runtime>>,
+			amount_out: Balance,
+		) -> Result<pallet_dex::Quote<Balance>, sp_runtime::DispatchError> {
+			DEX::quote_exact_out(path, amount_out)
+		}
+
+		fn get_pool(
+			token_a: pallet_dex::TokenKindOf<Runtime>,
+			token_b: pallet_dex::TokenKindOf<Runtime>,
+		) -> Option<pallet_dex::PoolInfoOf<Runtime>> {
+			DEX::get_pool(&token_a, &token_b)
+		}
+
+		fn list_pools(
+			token: Option<pallet_dex::TokenKindOf<Runtime>>,
+		) -> Vec<pallet_dex::PoolInfoOf<Runtime>> {
+			DEX::list_pools(token)
+		}
+
+		fn twap(
+			token_in: pallet_dex::TokenKindOf<Runtime>,
+			token_out: pallet_dex::TokenKindOf<Runtime>,
+			window: BlockNumber,
+		) -> Result<pallet_dex::TwapPrice<BlockNumber>, sp_runtime::DispatchError> {
+			DEX::twap(&token_in, &token_out, window).map_err(Into::into)
+		}
+	}
+
+	impl pallet_audio_storage_rpc_runtime_api::AudioStorageApi<Block, AccountId, BlockNumber> for Runtime {
+		fn manifest(
+			id: pallet_audio_storage::ManifestId,
+		) -> Option<pallet_audio_storage::ManifestInfoOf<Runtime>> {
+			AudioStorage::manifest_info(id)
+		}
+
+		fn manifest_by_root(
+			root: pallet_audio_storage::ContentHash,
+		) -> Option<pallet_audio_storage::ManifestInfoOf<Runtime>> {
+			AudioStorage::manifest_info_of_root(root)
+		}
+	}
+
+	impl pallet_uniques_rpc_runtime_api::UniquesApi<Block, AccountId, u32, u32, BlockNumber, Signature> for Runtime {
+		fn ticket_status(
+			collection: u32,
+			item: u32,
+			challenge: Vec<u8>,
+			signature: Signature,
+		) -> Option<pallet_uniques::TicketStatus<AccountId, BlockNumber>> {
+			Uniques::ticket_status(collection, item, &challenge, &signature)
+		}
+	}
+
 	impl pallet_mmr::primitives::MmrApi<
 		Block,
 		mmr::Hash,
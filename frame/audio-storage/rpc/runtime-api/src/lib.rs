@@ -0,0 +1,20 @@
+//! Runtime API definition for the audio storage pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+pub use pallet_audio_storage::{ChunkRef, ContentHash, ManifestId, ManifestInfo};
+
+sp_api::decl_runtime_apis! {
+	pub trait AudioStorageApi<AccountId, BlockNumber> where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// Get a manifest by its identifier.
+		fn manifest(id: ManifestId) -> Option<ManifestInfo<AccountId, BlockNumber>>;
+
+		/// Get the sealed manifest of the file with root CID `root`.
+		fn manifest_by_root(root: ContentHash) -> Option<ManifestInfo<AccountId, BlockNumber>>;
+	}
+}
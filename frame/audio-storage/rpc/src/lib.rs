@@ -0,0 +1,227 @@
+//! RPC interface reassembling the files of the audio storage pallet.
+//!
+//! Files are read from the indexed transactions of their chunks, which the node only keeps when
+//! it runs with transaction indexing enabled, e.g. with `--storage-chain`. Every chunk is checked
+//! against the content hash recorded in the manifest of its file before it is returned.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use futures::{FutureExt, StreamExt};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{
+		error::{CallError, ErrorObject, ErrorObjectOwned},
+		SubscriptionResult,
+	},
+	SubscriptionSink,
+};
+use pallet_audio_storage_rpc_runtime_api::{ChunkRef, ContentHash, ManifestInfo};
+use sc_client_api::BlockBackend;
+use sc_rpc_api::DenyUnsafe;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{traits::SpawnNamed, Bytes, H256};
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, NumberFor},
+};
+
+pub use pallet_audio_storage_rpc_runtime_api::AudioStorageApi as AudioStorageRuntimeApi;
+
+#[rpc(client, server)]
+pub trait AudioStorageApi<BlockHash, AccountId, BlockNumber> {
+	#[method(name = "audioStorage_getManifest")]
+	fn get_manifest(
+		&self,
+		root: ContentHash,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ManifestInfo<AccountId, BlockNumber>>>;
+
+	/// Returns the whole file with the given root CID at once.
+	///
+	/// Files can be as large as `MaxChunks` transactions, so this is an unsafe method; clients
+	/// should stream files with `audioStorage_subscribeFile` instead.
+	#[method(name = "audioStorage_getFile")]
+	fn get_file(&self, root: ContentHash, at: Option<BlockHash>) -> RpcResult<Bytes>;
+
+	#[subscription(
+		name = "audioStorage_subscribeFile" => "audioStorage_fileChunk",
+		unsubscribe = "audioStorage_unsubscribeFile",
+		item = Bytes,
+	)]
+	fn subscribe_file(&self, root: ContentHash, at: Option<BlockHash>);
+}
+
+/// Provides RPC methods to look up and read the files of the audio storage pallet.
+pub struct AudioStorage<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	/// Executor the subscriptions streaming files run on.
+	executor: Arc<dyn SpawnNamed>,
+	/// Whether to deny unsafe calls.
+	deny_unsafe: DenyUnsafe,
+	_marker: PhantomData<P>,
+}
+
+impl<C, P> AudioStorage<C, P> {
+	/// Creates a new instance of the AudioStorage Rpc helper.
+	pub fn new(client: Arc<C>, executor: Arc<dyn SpawnNamed>, deny_unsafe: DenyUnsafe) -> Self {
+		Self { client, executor, deny_unsafe, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// No sealed manifest has the given root CID.
+	UnknownFile,
+	/// The indexed transaction of a chunk is not available.
+	ChunkUnavailable,
+	/// The indexed transaction of a chunk does not match its content hash.
+	ChunkMismatch,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::UnknownFile => 2,
+			Error::ChunkUnavailable => 3,
+			Error::ChunkMismatch => 4,
+		}
+	}
+}
+
+fn runtime_error(error: impl ToString) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the audio storage.",
+		Some(error.to_string()),
+	)
+}
+
+fn unknown_file(root: ContentHash) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::UnknownFile.into(), "No file has this root CID.", Some(root))
+}
+
+fn chunk_unavailable(content_hash: ContentHash, error: Option<String>) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::ChunkUnavailable.into(),
+		format!("Chunk {:?} is not indexed by this node.", content_hash),
+		error,
+	)
+}
+
+fn chunk_mismatch(content_hash: ContentHash) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::ChunkMismatch.into(),
+		format!("Indexed data of chunk {:?} does not match its hash.", content_hash),
+		None::<()>,
+	)
+}
+
+/// Reads the data of a chunk from its indexed transaction.
+fn read_chunk<Block, C>(
+	client: &C,
+	chunk: &ChunkRef<NumberFor<Block>>,
+) -> Result<Vec<u8>, ErrorObjectOwned>
+where
+	Block: BlockT<Hash = H256>,
+	C: BlockBackend<Block>,
+{
+	let data = client
+		.indexed_transaction(chunk.content_hash)
+		.map_err(|e| chunk_unavailable(chunk.content_hash, Some(e.to_string())))?
+		.ok_or_else(|| chunk_unavailable(chunk.content_hash, None))?;
+	if ContentHash::from(sp_core::hashing::blake2_256(&data)) != chunk.content_hash {
+		return Err(chunk_mismatch(chunk.content_hash))
+	}
+	Ok(data)
+}
+
+impl<C, Block, AccountId> AudioStorage<C, (Block, AccountId)>
+where
+	Block: BlockT<Hash = H256>,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: AudioStorageRuntimeApi<Block, AccountId, NumberFor<Block>>,
+	AccountId: Codec,
+{
+	/// Looks up the sealed manifest of the file with root CID `root`.
+	fn manifest(
+		&self,
+		root: ContentHash,
+		at: Option<Block::Hash>,
+	) -> Result<Option<ManifestInfo<AccountId, NumberFor<Block>>>, ErrorObjectOwned> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.manifest_by_root(&at, root).map_err(runtime_error)
+	}
+}
+
+impl<C, Block, AccountId>
+	AudioStorageApiServer<<Block as BlockT>::Hash, AccountId, NumberFor<Block>>
+	for AudioStorage<C, (Block, AccountId)>
+where
+	Block: BlockT<Hash = H256>,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockBackend<Block>,
+	C: Send + Sync + 'static,
+	C::Api: AudioStorageRuntimeApi<Block, AccountId, NumberFor<Block>>,
+	AccountId: Codec + Send + Sync + 'static,
+{
+	fn get_manifest(
+		&self,
+		root: ContentHash,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<ManifestInfo<AccountId, NumberFor<Block>>>> {
+		Ok(self.manifest(root, at).map_err(CallError::Custom)?)
+	}
+
+	fn get_file(&self, root: ContentHash, at: Option<Block::Hash>) -> RpcResult<Bytes> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let manifest = self
+			.manifest(root, at)
+			.and_then(|manifest| manifest.ok_or_else(|| unknown_file(root)))
+			.map_err(CallError::Custom)?;
+
+		let mut file = Vec::with_capacity(manifest.size as usize);
+		for chunk in &manifest.chunks {
+			file.extend(read_chunk(&*self.client, chunk).map_err(CallError::Custom)?);
+		}
+		Ok(file.into())
+	}
+
+	fn subscribe_file(
+		&self,
+		mut sink: SubscriptionSink,
+		root: ContentHash,
+		at: Option<Block::Hash>,
+	) -> SubscriptionResult {
+		let manifest = match self
+			.manifest(root, at)
+			.and_then(|manifest| manifest.ok_or_else(|| unknown_file(root)))
+		{
+			Ok(manifest) => manifest,
+			Err(error) => {
+				let _ = sink.reject(error);
+				return Ok(())
+			},
+		};
+
+		// Chunks are only read as the subscriber keeps up with them.
+		let client = self.client.clone();
+		let stream = futures::stream::iter(manifest.chunks).map(move |chunk| {
+			read_chunk(&*client, &chunk).map(Bytes::from).map_err(CallError::Custom)
+		});
+		let fut = async move {
+			sink.pipe_from_try_stream(stream).await;
+		};
+
+		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
+		Ok(())
+	}
+}
@@ -0,0 +1,157 @@
+//! Benchmarking setup for pallet-audio-storage
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as AudioStorage;
+
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Currency, Get, OnFinalize, OnInitialize};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, One};
+
+/// Size of the chunks of the files stored by the benchmarks which don't depend on it.
+const CHUNK_SIZE: usize = 1024;
+
+fn funded_caller<T: Config>() -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	<T as pallet_transaction_storage::Config>::Currency::make_free_balance_be(
+		&caller,
+		BalanceOf::<T>::max_value(),
+	);
+	caller
+}
+
+/// Creates a manifest of `owner` with `n` chunks, sealing it if `seal`.
+fn manifest_with_chunks<T: Config>(owner: &T::AccountId, n: u32, seal: bool) -> ManifestId {
+	let manifest = NextManifestId::<T>::get();
+	AudioStorage::<T>::create_manifest(RawOrigin::Signed(owner.clone()).into())
+		.expect("owner is signed");
+	for chunk in 0..n {
+		AudioStorage::<T>::store_chunk(
+			RawOrigin::Signed(owner.clone()).into(),
+			manifest,
+			vec![chunk as u8; CHUNK_SIZE],
+		)
+		.expect("owner can pay for the chunk");
+	}
+	if seal {
+		AudioStorage::<T>::seal_manifest(RawOrigin::Signed(owner.clone()).into(), manifest)
+			.expect("manifest has chunks");
+	}
+	manifest
+}
+
+/// Finalizes the current block, so that its transactions can be renewed.
+fn next_block<T: Config>() {
+	let now = frame_system::Pallet::<T>::block_number();
+	TransactionStorageOf::<T>::on_finalize(now);
+	frame_system::Pallet::<T>::on_finalize(now);
+	frame_system::Pallet::<T>::set_block_number(now + One::one());
+	frame_system::Pallet::<T>::on_initialize(now + One::one());
+	TransactionStorageOf::<T>::on_initialize(now + One::one());
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_manifest() {
+		let caller = funded_caller::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller));
+
+		assert!(Manifests::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn store_chunk(l: Linear<1, 8_388_608>) {
+		let l = l.min(T::MaxTransactionSize::get());
+		let caller = funded_caller::<T>();
+		let manifest = manifest_with_chunks::<T>(&caller, 0, false);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), manifest, vec![0u8; l as usize]);
+
+		assert_eq!(Manifests::<T>::get(manifest).map(|manifest| manifest.size), Some(l as u64));
+	}
+
+	#[benchmark]
+	fn seal_manifest(n: Linear<1, 128>) {
+		let n = n.min(T::MaxChunks::get());
+		let caller = funded_caller::<T>();
+		let manifest = manifest_with_chunks::<T>(&caller, n, false);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), manifest);
+
+		assert!(Manifests::<T>::get(manifest).and_then(|manifest| manifest.root).is_some());
+	}
+
+	// The renewal of the chunk is prepaid, along with that of another chunk, so that the
+	// prepaid renewals are updated rather than removed.
+	#[benchmark]
+	fn renew_chunk() {
+		let caller = funded_caller::<T>();
+		let manifest = manifest_with_chunks::<T>(&caller, 2, true);
+		AudioStorage::<T>::renew_manifest(RawOrigin::Signed(caller.clone()).into(), manifest)
+			.expect("caller can reserve the fees");
+		next_block::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), manifest, 0);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		assert_eq!(
+			Manifests::<T>::get(manifest).map(|manifest| manifest.chunks[0].block),
+			Some(now)
+		);
+		assert_eq!(Renewals::<T>::get(manifest).map(|renewal| renewal.chunks.len()), Some(1));
+	}
+
+	#[benchmark]
+	fn remove_manifest() {
+		let caller = funded_caller::<T>();
+		let manifest = manifest_with_chunks::<T>(&caller, 1, true);
+		AudioStorage::<T>::renew_manifest(RawOrigin::Signed(caller.clone()).into(), manifest)
+			.expect("caller can reserve the fees");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), manifest);
+
+		assert!(!Manifests::<T>::contains_key(manifest));
+		assert!(!Renewals::<T>::contains_key(manifest));
+	}
+
+	#[benchmark]
+	fn renew_manifest(n: Linear<1, 128>) {
+		let n = n.min(T::MaxChunks::get());
+		let caller = funded_caller::<T>();
+		let manifest = manifest_with_chunks::<T>(&caller, n, true);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), manifest);
+
+		assert_eq!(
+			Renewals::<T>::get(manifest).map(|renewal| renewal.chunks.len()),
+			Some(n as usize)
+		);
+	}
+
+	#[benchmark]
+	fn cancel_renewal() {
+		let caller = funded_caller::<T>();
+		let manifest = manifest_with_chunks::<T>(&caller, 1, true);
+		AudioStorage::<T>::renew_manifest(RawOrigin::Signed(caller.clone()).into(), manifest)
+			.expect("caller can reserve the fees");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), manifest);
+
+		assert!(!Renewals::<T>::contains_key(manifest));
+	}
+
+	impl_benchmark_test_suite!(AudioStorage, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,424 @@
+//! # Audio Storage Pallet
+//!
+//! Stores audio files too large for a single `pallet_transaction_storage` transaction as a
+//! sequence of chunks, each stored and indexed as its own transaction, tied together by a
+//! manifest.
+//!
+//! An uploader creates a manifest, stores the chunks of their file in order with `store_chunk`,
+//! one per extrinsic, and seals it. Sealing addresses the file by its root CID, the BLAKE2-256
+//! hash of the content hashes of its chunks, so that a file can be looked up and verified by its
+//! content alone. Chunks expire after the storage period of `pallet_transaction_storage` like any
+//! other transaction, and anyone paying the fees may renew the chunks of a sealed file.
+//!
+//! The client indexes a single renewal per extrinsic, so chunks are renewed with `renew_chunk`, one
+//! per extrinsic like their storage. `renew_manifest` prepays the renewal of every chunk of a file
+//! by reserving their fees, after which anyone may submit the renewals of its chunks for free.
+//!
+//! The `pallet-audio-storage-rpc` crate reassembles files from the indexed transactions of their
+//! chunks.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `create_manifest` - Creates an empty manifest owned by the caller.
+//! * `store_chunk` - Stores the next chunk of the file of a manifest.
+//! * `seal_manifest` - Completes a manifest and addresses it by its root CID.
+//! * `renew_chunk` - Renews a chunk of a sealed manifest.
+//! * `remove_manifest` - Removes a manifest, leaving its chunks to expire.
+//! * `renew_manifest` - Prepays the renewal of every chunk of a sealed manifest.
+//! * `cancel_renewal` - Releases the fees of the chunks of a manifest left to renew.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub mod weights;
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::traits::{Currency, ReservableCurrency};
+use sp_runtime::{traits::Saturating, ArithmeticError, DispatchError};
+use sp_std::prelude::*;
+
+type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+type BalanceOf<T> =
+	<<T as pallet_transaction_storage::Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+type TransactionStorageOf<T> = pallet_transaction_storage::Pallet<T>;
+/// A manifest from this pallet's point of view.
+pub type ManifestOf<T> =
+	Manifest<AccountIdOf<T>, <T as frame_system::Config>::BlockNumber, <T as Config>::MaxChunks>;
+/// A manifest, as returned by the runtime API, from this pallet's point of view.
+pub type ManifestInfoOf<T> = ManifestInfo<AccountIdOf<T>, <T as frame_system::Config>::BlockNumber>;
+/// Prepaid renewals from this pallet's point of view.
+pub type RenewalOf<T> = Renewal<AccountIdOf<T>, BalanceOf<T>, <T as Config>::MaxChunks>;
+
+// The `Call` dispatch generated for `DispatchResultWithPostInfo` calls converts into its own type.
+#[allow(clippy::useless_conversion)]
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_transaction_storage::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Maximum number of chunks of a file.
+		#[pallet::constant]
+		type MaxChunks: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Identifier of the next manifest.
+	#[pallet::storage]
+	pub type NextManifestId<T: Config> = StorageValue<_, ManifestId, ValueQuery>;
+
+	/// Manifests by identifier.
+	#[pallet::storage]
+	#[pallet::getter(fn manifest)]
+	pub type Manifests<T: Config> = StorageMap<_, Blake2_128Concat, ManifestId, ManifestOf<T>>;
+
+	/// Sealed manifests by the root CID of their file.
+	#[pallet::storage]
+	#[pallet::getter(fn manifest_of_root)]
+	pub type ManifestsByRoot<T: Config> = StorageMap<_, Identity, ContentHash, ManifestId>;
+
+	/// Prepaid renewals of the chunks of manifests, by manifest.
+	#[pallet::storage]
+	#[pallet::getter(fn renewal)]
+	pub type Renewals<T: Config> = StorageMap<_, Blake2_128Concat, ManifestId, RenewalOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A manifest was created.
+		ManifestCreated { manifest: ManifestId, owner: T::AccountId },
+		/// A chunk of the file of a manifest was stored.
+		ChunkStored { manifest: ManifestId, chunk: u32, content_hash: ContentHash, size: u32 },
+		/// A manifest was sealed.
+		ManifestSealed { manifest: ManifestId, root: ContentHash, chunks: u32, size: u64 },
+		/// A chunk of a manifest was renewed.
+		ChunkRenewed { manifest: ManifestId, chunk: u32, who: T::AccountId },
+		/// A manifest was removed.
+		ManifestRemoved { manifest: ManifestId },
+		/// The renewal of every chunk of a manifest was prepaid.
+		RenewalPrepaid { manifest: ManifestId, payer: T::AccountId, fees: BalanceOf<T> },
+		/// The fees of the chunks of a manifest left to renew were released.
+		RenewalCancelled { manifest: ManifestId, payer: T::AccountId, released: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The manifest does not exist.
+		UnknownManifest,
+		/// The caller does not own the manifest.
+		NotOwner,
+		/// The manifest is sealed, and its chunks can no longer change.
+		AlreadySealed,
+		/// The manifest is not sealed yet.
+		NotSealed,
+		/// The manifest has no chunks.
+		NoChunks,
+		/// The manifest already has `MaxChunks` chunks.
+		TooManyChunks,
+		/// Another manifest of the same file is sealed.
+		DuplicateFile,
+		/// The manifest has no chunk at this index.
+		UnknownChunk,
+		/// The renewal of the chunks of the manifest is already prepaid.
+		RenewalPrepaid,
+		/// The renewal of the chunks of the manifest is not prepaid.
+		NoRenewal,
+		/// The caller did not prepay the renewal of the chunks of the manifest.
+		NotPayer,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create an empty manifest owned by the caller.
+		///
+		/// Emits `ManifestCreated`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_manifest())]
+		pub fn create_manifest(origin: OriginFor<T>) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let manifest = NextManifestId::<T>::get();
+			NextManifestId::<T>::put(manifest.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+			Manifests::<T>::insert(
+				manifest,
+				Manifest { owner: owner.clone(), chunks: Default::default(), size: 0, root: None },
+			);
+
+			Self::deposit_event(Event::ManifestCreated { manifest, owner });
+			Ok(())
+		}
+
+		/// Store `data` as the next chunk of the file of an unsealed manifest of the caller, who
+		/// pays the fees of `pallet_transaction_storage` for it.
+		///
+		/// `data` is the last argument so that it is indexed like the data of
+		/// `pallet_transaction_storage::store`, which limits a chunk to one per extrinsic.
+		///
+		/// Emits `ChunkStored`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::store_chunk(data.len() as u32))]
+		pub fn store_chunk(
+			origin: OriginFor<T>,
+			manifest: ManifestId,
+			data: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Manifests::<T>::try_mutate(manifest, |maybe_manifest| {
+				let details = maybe_manifest.as_mut().ok_or(Error::<T>::UnknownManifest)?;
+				ensure!(details.owner == who, Error::<T>::NotOwner);
+				ensure!(details.root.is_none(), Error::<T>::AlreadySealed);
+				ensure!(
+					(details.chunks.len() as u32) < T::MaxChunks::get(),
+					Error::<T>::TooManyChunks
+				);
+
+				let size = data.len() as u32;
+				let index = pallet_transaction_storage::Pallet::<T>::do_store(who, &data)?;
+				let content_hash: ContentHash = sp_io::hashing::blake2_256(&data).into();
+				let chunk = details.chunks.len() as u32;
+				details
+					.chunks
+					.try_push(ChunkRef {
+						block: frame_system::Pallet::<T>::block_number(),
+						index,
+						content_hash,
+						size,
+					})
+					.map_err(|_| Error::<T>::TooManyChunks)?;
+				details.size = details.size.saturating_add(size as u64);
+
+				Self::deposit_event(Event::ChunkStored { manifest, chunk, content_hash, size });
+				Ok(())
+			})
+		}
+
+		/// Seal a manifest of the caller, addressing its file by its root CID.
+		///
+		/// Emits `ManifestSealed`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::seal_manifest(T::MaxChunks::get()))]
+		pub fn seal_manifest(origin: OriginFor<T>, manifest: ManifestId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Manifests::<T>::try_mutate(manifest, |maybe_manifest| {
+				let details = maybe_manifest.as_mut().ok_or(Error::<T>::UnknownManifest)?;
+				ensure!(details.owner == who, Error::<T>::NotOwner);
+				ensure!(details.root.is_none(), Error::<T>::AlreadySealed);
+				ensure!(!details.chunks.is_empty(), Error::<T>::NoChunks);
+
+				let root = root_of(details.chunks.iter().map(|chunk| &chunk.content_hash));
+				ensure!(!ManifestsByRoot::<T>::contains_key(root), Error::<T>::DuplicateFile);
+				ManifestsByRoot::<T>::insert(root, manifest);
+				details.root = Some(root);
+
+				Self::deposit_event(Event::ManifestSealed {
+					manifest,
+					root,
+					chunks: details.chunks.len() as u32,
+					size: details.size,
+				});
+				Ok(())
+			})
+		}
+
+		/// Renew a chunk of a sealed manifest.
+		///
+		/// The chunk must not have expired yet. Like their storage, chunks are renewed one per
+		/// extrinsic, as the client indexes a single transaction per extrinsic. The fees of
+		/// `pallet_transaction_storage` are paid by the caller, unless the renewal of the chunk was
+		/// prepaid with `renew_manifest`: its fee is then taken from the reserve of the payer and
+		/// the caller pays no transaction fee.
+		///
+		/// Emits `ChunkRenewed`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::renew_chunk())]
+		pub fn renew_chunk(
+			origin: OriginFor<T>,
+			manifest: ManifestId,
+			chunk: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut details = Manifests::<T>::get(manifest).ok_or(Error::<T>::UnknownManifest)?;
+			ensure!(details.root.is_some(), Error::<T>::NotSealed);
+			let chunk_ref =
+				details.chunks.get_mut(chunk as usize).ok_or(Error::<T>::UnknownChunk)?;
+
+			let prepaid_by = Self::take_prepaid_renewal(manifest, chunk, chunk_ref.size)?;
+			let payer = prepaid_by.clone().unwrap_or(who);
+			chunk_ref.index = TransactionStorageOf::<T>::do_renew(
+				payer.clone(),
+				chunk_ref.block,
+				chunk_ref.index,
+			)?;
+			chunk_ref.block = frame_system::Pallet::<T>::block_number();
+			Manifests::<T>::insert(manifest, details);
+
+			Self::deposit_event(Event::ChunkRenewed { manifest, chunk, who: payer });
+			Ok(if prepaid_by.is_some() { Pays::No } else { Pays::Yes }.into())
+		}
+
+		/// Remove a manifest of the caller. Its chunks stay stored until they expire.
+		///
+		/// Emits `ManifestRemoved`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_manifest())]
+		pub fn remove_manifest(origin: OriginFor<T>, manifest: ManifestId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let details = Manifests::<T>::get(manifest).ok_or(Error::<T>::UnknownManifest)?;
+			ensure!(details.owner == who, Error::<T>::NotOwner);
+			if let Some(root) = details.root {
+				ManifestsByRoot::<T>::remove(root);
+			}
+			Manifests::<T>::remove(manifest);
+			if let Some(renewal) = Renewals::<T>::take(manifest) {
+				Self::release_renewal(manifest, renewal);
+			}
+
+			Self::deposit_event(Event::ManifestRemoved { manifest });
+			Ok(())
+		}
+
+		/// Prepay the renewal of every chunk of a sealed manifest, reserving their fees of
+		/// `pallet_transaction_storage` from the caller.
+		///
+		/// The chunks are then renewed with `renew_chunk`, which anyone may submit for free for
+		/// each of them. What is left reserved is released once every chunk is renewed, or with
+		/// `cancel_renewal`.
+		///
+		/// Emits `RenewalPrepaid`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::renew_manifest(T::MaxChunks::get()))]
+		pub fn renew_manifest(origin: OriginFor<T>, manifest: ManifestId) -> DispatchResult {
+			let payer = ensure_signed(origin)?;
+
+			let details = Manifests::<T>::get(manifest).ok_or(Error::<T>::UnknownManifest)?;
+			ensure!(details.root.is_some(), Error::<T>::NotSealed);
+			ensure!(!Renewals::<T>::contains_key(manifest), Error::<T>::RenewalPrepaid);
+
+			let mut fees = BalanceOf::<T>::default();
+			for chunk in details.chunks.iter() {
+				fees = fees.saturating_add(TransactionStorageOf::<T>::fee(chunk.size)?);
+			}
+			<T as pallet_transaction_storage::Config>::Currency::reserve(&payer, fees)?;
+			let chunks = (0..details.chunks.len() as u32)
+				.collect::<Vec<_>>()
+				.try_into()
+				.map_err(|_| Error::<T>::TooManyChunks)?;
+			Renewals::<T>::insert(
+				manifest,
+				Renewal { payer: payer.clone(), reserved: fees, chunks },
+			);
+
+			Self::deposit_event(Event::RenewalPrepaid { manifest, payer, fees });
+			Ok(())
+		}
+
+		/// Release the fees reserved by the caller for the chunks of a manifest left to renew, for
+		/// instance after they expired.
+		///
+		/// Emits `RenewalCancelled`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_renewal())]
+		pub fn cancel_renewal(origin: OriginFor<T>, manifest: ManifestId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let renewal = Renewals::<T>::get(manifest).ok_or(Error::<T>::NoRenewal)?;
+			ensure!(renewal.payer == who, Error::<T>::NotPayer);
+			Renewals::<T>::remove(manifest);
+			Self::release_renewal(manifest, renewal);
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns a manifest in its runtime API representation.
+	pub fn manifest_info(id: ManifestId) -> Option<ManifestInfoOf<T>> {
+		Manifests::<T>::get(id).map(|manifest| ManifestInfo {
+			id,
+			owner: manifest.owner,
+			chunks: manifest.chunks.into_inner(),
+			size: manifest.size,
+			root: manifest.root,
+		})
+	}
+
+	/// Returns the sealed manifest of the file with root CID `root` in its runtime API
+	/// representation.
+	pub fn manifest_info_of_root(root: ContentHash) -> Option<ManifestInfoOf<T>> {
+		ManifestsByRoot::<T>::get(root).and_then(Self::manifest_info)
+	}
+
+	/// Takes `chunk` of `manifest`, of `size` bytes, off the prepaid renewals, releasing the fee of
+	/// its renewal for it to be paid, and returns the account which prepaid it.
+	///
+	/// Returns `None` if the renewal of the chunk is not prepaid.
+	fn take_prepaid_renewal(
+		manifest: ManifestId,
+		chunk: u32,
+		size: u32,
+	) -> Result<Option<T::AccountId>, DispatchError> {
+		let mut renewal = match Renewals::<T>::get(manifest) {
+			Some(renewal) => renewal,
+			None => return Ok(None),
+		};
+		let position = match renewal.chunks.iter().position(|prepaid| *prepaid == chunk) {
+			Some(position) => position,
+			None => return Ok(None),
+		};
+		renewal.chunks.remove(position);
+
+		// The fees may have changed since the renewal was prepaid, and the payer covers any
+		// increase from their free balance.
+		let fee = TransactionStorageOf::<T>::fee(size)?.min(renewal.reserved);
+		let release = if renewal.chunks.is_empty() { renewal.reserved } else { fee };
+		<T as pallet_transaction_storage::Config>::Currency::unreserve(&renewal.payer, release);
+		renewal.reserved = renewal.reserved.saturating_sub(release);
+
+		let payer = renewal.payer.clone();
+		if renewal.chunks.is_empty() {
+			Renewals::<T>::remove(manifest);
+		} else {
+			Renewals::<T>::insert(manifest, renewal);
+		}
+		Ok(Some(payer))
+	}
+
+	/// Releases what is left reserved for `renewal`, which was taken off `manifest`.
+	fn release_renewal(manifest: ManifestId, renewal: RenewalOf<T>) {
+		<T as pallet_transaction_storage::Config>::Currency::unreserve(
+			&renewal.payer,
+			renewal.reserved,
+		);
+		Self::deposit_event(Event::RenewalCancelled {
+			manifest,
+			payer: renewal.payer,
+			released: renewal.reserved,
+		});
+	}
+}
@@ -0,0 +1,123 @@
+//! Test environment for the audio storage pallet.
+
+use super::*;
+use crate as pallet_audio_storage;
+
+use frame_support::{
+	construct_runtime,
+	traits::{ConstU32, ConstU64, OnFinalize, OnInitialize},
+};
+use pallet_transaction_storage::{DEFAULT_MAX_BLOCK_TRANSACTIONS, DEFAULT_MAX_TRANSACTION_SIZE};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+use sp_transaction_storage_proof::TransactionStorageProof;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		TransactionStorage: pallet_transaction_storage,
+		AudioStorage: pallet_audio_storage,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+}
+
+impl pallet_transaction_storage::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type FeeDestination = ();
+	type WeightInfo = ();
+	type MaxBlockTransactions = ConstU32<{ DEFAULT_MAX_BLOCK_TRANSACTIONS }>;
+	type MaxTransactionSize = ConstU32<{ DEFAULT_MAX_TRANSACTION_SIZE }>;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxChunks = ConstU32<4>;
+	type WeightInfo = ();
+}
+
+pub(crate) const UPLOADER: u64 = 1;
+pub(crate) const RENEWER: u64 = 2;
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let t = GenesisConfig {
+		system: Default::default(),
+		balances: pallet_balances::GenesisConfig::<Test> {
+			balances: vec![(UPLOADER, 1_000_000), (RENEWER, 1_000_000), (3, 100)],
+		},
+		transaction_storage: pallet_transaction_storage::GenesisConfig::<Test> {
+			storage_period: 10,
+			byte_fee: 2,
+			entry_fee: 200,
+		},
+	}
+	.build_storage()
+	.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+pub(crate) fn run_to_block(n: u64, f: impl Fn() -> Option<TransactionStorageProof>) {
+	while System::block_number() < n {
+		if let Some(proof) = f() {
+			TransactionStorage::check_proof(RuntimeOrigin::none(), proof).unwrap();
+		}
+		TransactionStorage::on_finalize(System::block_number());
+		System::on_finalize(System::block_number());
+		System::set_block_number(System::block_number() + 1);
+		System::on_initialize(System::block_number());
+		TransactionStorage::on_initialize(System::block_number());
+	}
+}
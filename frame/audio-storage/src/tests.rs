@@ -0,0 +1,331 @@
+//! Tests for the audio storage pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, dispatch::Pays};
+use sp_transaction_storage_proof::registration::build_proof;
+
+fn chunk(byte: u8, size: usize) -> Vec<u8> {
+	vec![byte; size]
+}
+
+fn content_hash(data: &[u8]) -> ContentHash {
+	sp_io::hashing::blake2_256(data).into()
+}
+
+/// Stores `chunks` as a new manifest of `UPLOADER` and seals it.
+fn upload(chunks: &[Vec<u8>]) -> ManifestId {
+	let manifest = NextManifestId::<Test>::get();
+	assert_ok!(AudioStorage::create_manifest(RuntimeOrigin::signed(UPLOADER)));
+	for data in chunks {
+		assert_ok!(AudioStorage::store_chunk(
+			RuntimeOrigin::signed(UPLOADER),
+			manifest,
+			data.clone()
+		));
+	}
+	assert_ok!(AudioStorage::seal_manifest(RuntimeOrigin::signed(UPLOADER), manifest));
+	manifest
+}
+
+#[test]
+fn store_and_seal_manifest_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AudioStorage::create_manifest(RuntimeOrigin::signed(UPLOADER)));
+		System::assert_last_event(RuntimeEvent::AudioStorage(Event::ManifestCreated {
+			manifest: 0,
+			owner: UPLOADER,
+		}));
+
+		let (first, second) = (chunk(1, 2_000), chunk(2, 500));
+		assert_ok!(AudioStorage::store_chunk(RuntimeOrigin::signed(UPLOADER), 0, first.clone()));
+		assert_ok!(AudioStorage::store_chunk(RuntimeOrigin::signed(UPLOADER), 0, second.clone()));
+		System::assert_last_event(RuntimeEvent::AudioStorage(Event::ChunkStored {
+			manifest: 0,
+			chunk: 1,
+			content_hash: content_hash(&second),
+			size: 500,
+		}));
+		// Both chunks paid the fees of transaction storage.
+		assert_eq!(Balances::free_balance(UPLOADER), 1_000_000 - 2_500 * 2 - 200 * 2);
+
+		assert_ok!(AudioStorage::seal_manifest(RuntimeOrigin::signed(UPLOADER), 0));
+		let root = root_of(&[content_hash(&first), content_hash(&second)]);
+		System::assert_last_event(RuntimeEvent::AudioStorage(Event::ManifestSealed {
+			manifest: 0,
+			root,
+			chunks: 2,
+			size: 2_500,
+		}));
+		assert_eq!(AudioStorage::manifest_of_root(root), Some(0));
+		assert_eq!(
+			AudioStorage::manifest_info_of_root(root),
+			Some(ManifestInfo {
+				id: 0,
+				owner: UPLOADER,
+				chunks: vec![
+					ChunkRef {
+						block: 1,
+						index: 0,
+						content_hash: content_hash(&first),
+						size: 2_000
+					},
+					ChunkRef { block: 1, index: 1, content_hash: content_hash(&second), size: 500 },
+				],
+				size: 2_500,
+				root: Some(root),
+			})
+		);
+
+		assert_noop!(
+			AudioStorage::store_chunk(RuntimeOrigin::signed(UPLOADER), 0, chunk(3, 10)),
+			Error::<Test>::AlreadySealed
+		);
+		assert_noop!(
+			AudioStorage::seal_manifest(RuntimeOrigin::signed(UPLOADER), 0),
+			Error::<Test>::AlreadySealed
+		);
+	});
+}
+
+#[test]
+fn store_chunk_checks_manifest() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AudioStorage::store_chunk(RuntimeOrigin::signed(UPLOADER), 0, chunk(1, 10)),
+			Error::<Test>::UnknownManifest
+		);
+		assert_ok!(AudioStorage::create_manifest(RuntimeOrigin::signed(UPLOADER)));
+		assert_noop!(
+			AudioStorage::store_chunk(RuntimeOrigin::signed(RENEWER), 0, chunk(1, 10)),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			AudioStorage::store_chunk(RuntimeOrigin::signed(UPLOADER), 0, Vec::new()),
+			pallet_transaction_storage::Error::<Test>::EmptyTransaction
+		);
+		assert_noop!(
+			AudioStorage::seal_manifest(RuntimeOrigin::signed(UPLOADER), 0),
+			Error::<Test>::NoChunks
+		);
+
+		for byte in 0..4 {
+			assert_ok!(AudioStorage::store_chunk(
+				RuntimeOrigin::signed(UPLOADER),
+				0,
+				chunk(byte, 10)
+			));
+		}
+		assert_noop!(
+			AudioStorage::store_chunk(RuntimeOrigin::signed(UPLOADER), 0, chunk(4, 10)),
+			Error::<Test>::TooManyChunks
+		);
+		assert_noop!(
+			AudioStorage::seal_manifest(RuntimeOrigin::signed(RENEWER), 0),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn seal_manifest_rejects_duplicate_files() {
+	new_test_ext().execute_with(|| {
+		let chunks = [chunk(1, 100), chunk(2, 100)];
+		upload(&chunks);
+
+		assert_ok!(AudioStorage::create_manifest(RuntimeOrigin::signed(UPLOADER)));
+		for data in &chunks {
+			assert_ok!(AudioStorage::store_chunk(RuntimeOrigin::signed(UPLOADER), 1, data.clone()));
+		}
+		assert_noop!(
+			AudioStorage::seal_manifest(RuntimeOrigin::signed(UPLOADER), 1),
+			Error::<Test>::DuplicateFile
+		);
+
+		// Once the first manifest is removed, the file can be sealed again.
+		assert_ok!(AudioStorage::remove_manifest(RuntimeOrigin::signed(UPLOADER), 0));
+		System::assert_last_event(RuntimeEvent::AudioStorage(Event::ManifestRemoved {
+			manifest: 0,
+		}));
+		assert!(AudioStorage::manifest(0).is_none());
+		assert_ok!(AudioStorage::seal_manifest(RuntimeOrigin::signed(UPLOADER), 1));
+		let root = root_of(&[content_hash(&chunks[0]), content_hash(&chunks[1])]);
+		assert_eq!(AudioStorage::manifest_of_root(root), Some(1));
+	});
+}
+
+#[test]
+fn remove_manifest_checks_owner() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AudioStorage::remove_manifest(RuntimeOrigin::signed(UPLOADER), 0),
+			Error::<Test>::UnknownManifest
+		);
+		let manifest = upload(&[chunk(1, 100)]);
+		assert_noop!(
+			AudioStorage::remove_manifest(RuntimeOrigin::signed(RENEWER), manifest),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(AudioStorage::remove_manifest(RuntimeOrigin::signed(UPLOADER), manifest));
+		assert!(AudioStorage::manifest_of_root(root_of(&[content_hash(&chunk(1, 100))])).is_none());
+	});
+}
+
+#[test]
+fn renew_chunk_renews_a_chunk() {
+	new_test_ext().execute_with(|| {
+		let (first, second) = (chunk(1, 2_000), chunk(2, 1_000));
+		assert_ok!(AudioStorage::create_manifest(RuntimeOrigin::signed(UPLOADER)));
+		assert_ok!(AudioStorage::store_chunk(RuntimeOrigin::signed(UPLOADER), 0, first.clone()));
+		assert_ok!(AudioStorage::store_chunk(RuntimeOrigin::signed(UPLOADER), 0, second.clone()));
+		assert_noop!(
+			AudioStorage::renew_chunk(RuntimeOrigin::signed(RENEWER), 0, 0),
+			Error::<Test>::NotSealed
+		);
+		assert_ok!(AudioStorage::seal_manifest(RuntimeOrigin::signed(UPLOADER), 0));
+
+		run_to_block(6, || None);
+		assert_noop!(
+			AudioStorage::renew_chunk(RuntimeOrigin::signed(RENEWER), 0, 2),
+			Error::<Test>::UnknownChunk
+		);
+		// Anyone may renew the chunks of a manifest, paying their fees.
+		assert_ok!(AudioStorage::renew_chunk(RuntimeOrigin::signed(RENEWER), 0, 0));
+		assert_ok!(AudioStorage::renew_chunk(RuntimeOrigin::signed(RENEWER), 0, 1));
+		System::assert_last_event(RuntimeEvent::AudioStorage(Event::ChunkRenewed {
+			manifest: 0,
+			chunk: 1,
+			who: RENEWER,
+		}));
+		assert_eq!(Balances::free_balance(RENEWER), 1_000_000 - 3_000 * 2 - 200 * 2);
+		assert_eq!(
+			AudioStorage::manifest(0)
+				.unwrap()
+				.chunks
+				.iter()
+				.map(|chunk| (chunk.block, chunk.index))
+				.collect::<Vec<_>>(),
+			vec![(6, 0), (6, 1)]
+		);
+
+		let proof_provider = || {
+			let block_num = System::block_number();
+			if block_num == 11 || block_num == 16 {
+				let parent_hash = System::parent_hash();
+				Some(
+					build_proof(parent_hash.as_ref(), vec![first.clone(), second.clone()]).unwrap(),
+				)
+			} else {
+				None
+			}
+		};
+		run_to_block(16, proof_provider);
+		assert!(TransactionStorage::transaction_roots(1).is_none());
+		assert_eq!(TransactionStorage::transaction_roots(6).unwrap().len(), 2);
+
+		// Once the chunks expire, they can no longer be renewed.
+		run_to_block(17, proof_provider);
+		assert!(TransactionStorage::transaction_roots(6).is_none());
+		assert_noop!(
+			AudioStorage::renew_chunk(RuntimeOrigin::signed(RENEWER), 0, 0),
+			pallet_transaction_storage::Error::<Test>::RenewedNotFound
+		);
+	});
+}
+
+#[test]
+fn renew_manifest_prepays_the_renewal_of_every_chunk() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AudioStorage::renew_manifest(RuntimeOrigin::signed(RENEWER), 0),
+			Error::<Test>::UnknownManifest
+		);
+		assert_ok!(AudioStorage::create_manifest(RuntimeOrigin::signed(UPLOADER)));
+		assert_noop!(
+			AudioStorage::renew_manifest(RuntimeOrigin::signed(RENEWER), 0),
+			Error::<Test>::NotSealed
+		);
+		let manifest = upload(&[chunk(1, 2_000), chunk(2, 1_000)]);
+
+		assert_ok!(AudioStorage::renew_manifest(RuntimeOrigin::signed(RENEWER), manifest));
+		System::assert_last_event(RuntimeEvent::AudioStorage(Event::RenewalPrepaid {
+			manifest,
+			payer: RENEWER,
+			fees: 3_000 * 2 + 200 * 2,
+		}));
+		assert_eq!(Balances::reserved_balance(RENEWER), 6_400);
+		assert_noop!(
+			AudioStorage::renew_manifest(RuntimeOrigin::signed(UPLOADER), manifest),
+			Error::<Test>::RenewalPrepaid
+		);
+
+		// Anyone may submit the prepaid renewals for free, which are charged to the payer.
+		run_to_block(6, || None);
+		let post_info = AudioStorage::renew_chunk(RuntimeOrigin::signed(3), manifest, 0).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::No);
+		System::assert_last_event(RuntimeEvent::AudioStorage(Event::ChunkRenewed {
+			manifest,
+			chunk: 0,
+			who: RENEWER,
+		}));
+		assert_eq!(Balances::free_balance(3), 100);
+		assert_eq!(Balances::reserved_balance(RENEWER), 2_200);
+		assert_eq!(AudioStorage::renewal(manifest).unwrap().chunks.into_inner(), vec![1]);
+
+		// Once every chunk is renewed, nothing is left reserved.
+		assert_ok!(AudioStorage::renew_chunk(RuntimeOrigin::signed(3), manifest, 1));
+		assert!(AudioStorage::renewal(manifest).is_none());
+		assert_eq!(Balances::reserved_balance(RENEWER), 0);
+		assert_eq!(Balances::free_balance(RENEWER), 1_000_000 - 6_400);
+		assert_eq!(
+			AudioStorage::manifest(manifest)
+				.unwrap()
+				.chunks
+				.iter()
+				.map(|chunk| (chunk.block, chunk.index))
+				.collect::<Vec<_>>(),
+			vec![(6, 0), (6, 1)]
+		);
+
+		// Further renewals are paid by their caller again.
+		run_to_block(7, || None);
+		let post_info =
+			AudioStorage::renew_chunk(RuntimeOrigin::signed(UPLOADER), manifest, 0).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::Yes);
+		assert_eq!(Balances::free_balance(RENEWER), 1_000_000 - 6_400);
+	});
+}
+
+#[test]
+fn prepaid_renewals_can_be_cancelled() {
+	new_test_ext().execute_with(|| {
+		let manifest = upload(&[chunk(1, 2_000), chunk(2, 1_000)]);
+		assert_noop!(
+			AudioStorage::cancel_renewal(RuntimeOrigin::signed(RENEWER), manifest),
+			Error::<Test>::NoRenewal
+		);
+		assert_ok!(AudioStorage::renew_manifest(RuntimeOrigin::signed(RENEWER), manifest));
+		run_to_block(6, || None);
+		assert_ok!(AudioStorage::renew_chunk(RuntimeOrigin::signed(3), manifest, 0));
+
+		assert_noop!(
+			AudioStorage::cancel_renewal(RuntimeOrigin::signed(UPLOADER), manifest),
+			Error::<Test>::NotPayer
+		);
+		assert_ok!(AudioStorage::cancel_renewal(RuntimeOrigin::signed(RENEWER), manifest));
+		System::assert_last_event(RuntimeEvent::AudioStorage(Event::RenewalCancelled {
+			manifest,
+			payer: RENEWER,
+			released: 2_200,
+		}));
+		assert!(AudioStorage::renewal(manifest).is_none());
+		assert_eq!(Balances::reserved_balance(RENEWER), 0);
+		assert_eq!(Balances::free_balance(RENEWER), 1_000_000 - 4_200);
+
+		// Removing a manifest releases the fees of its prepaid renewals too.
+		assert_ok!(AudioStorage::renew_manifest(RuntimeOrigin::signed(RENEWER), manifest));
+		assert_ok!(AudioStorage::remove_manifest(RuntimeOrigin::signed(UPLOADER), manifest));
+		assert!(AudioStorage::renewal(manifest).is_none());
+		assert_eq!(Balances::reserved_balance(RENEWER), 0);
+	});
+}
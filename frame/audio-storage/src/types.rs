@@ -0,0 +1,84 @@
+//! Types of the audio storage pallet.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::Get, BoundedVec, RuntimeDebug};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_core::H256;
+use sp_std::prelude::*;
+
+/// Identifier of a manifest.
+pub type ManifestId = u32;
+
+/// BLAKE2-256 hash of some content, which is how bitswap addresses indexed transactions.
+pub type ContentHash = H256;
+
+/// A chunk of a file, stored as a `pallet_transaction_storage` transaction.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ChunkRef<BlockNumber> {
+	/// Block the chunk was last stored or renewed in.
+	pub block: BlockNumber,
+	/// Index of the transaction of the chunk within `block`.
+	pub index: u32,
+	/// Hash of the data of the chunk.
+	pub content_hash: ContentHash,
+	/// Size of the chunk in bytes.
+	pub size: u32,
+}
+
+/// The chunks a file is stored as.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(MaxChunks))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+pub struct Manifest<AccountId, BlockNumber, MaxChunks: Get<u32>> {
+	/// Account which uploads the file, and may remove its manifest.
+	pub owner: AccountId,
+	/// Chunks of the file, in order.
+	pub chunks: BoundedVec<ChunkRef<BlockNumber>, MaxChunks>,
+	/// Size of the file in bytes.
+	pub size: u64,
+	/// Root CID of the file, once the manifest is sealed.
+	pub root: Option<ContentHash>,
+}
+
+/// Renewals of the chunks of a manifest, prepaid by an account with `renew_manifest`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(MaxChunks))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
+pub struct Renewal<AccountId, Balance, MaxChunks: Get<u32>> {
+	/// Account which reserved the fees of the renewals, and pays them.
+	pub payer: AccountId,
+	/// Fees still reserved for the renewals.
+	pub reserved: Balance,
+	/// Chunks left to renew.
+	pub chunks: BoundedVec<u32, MaxChunks>,
+}
+
+/// A manifest, as returned by the runtime API.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ManifestInfo<AccountId, BlockNumber> {
+	/// Identifier of the manifest.
+	pub id: ManifestId,
+	/// Account which uploads the file.
+	pub owner: AccountId,
+	/// Chunks of the file, in order.
+	pub chunks: Vec<ChunkRef<BlockNumber>>,
+	/// Size of the file in bytes.
+	pub size: u64,
+	/// Root CID of the file, once the manifest is sealed.
+	pub root: Option<ContentHash>,
+}
+
+/// Computes the root CID of a file from the content hashes of its chunks, in order.
+pub fn root_of<'a>(content_hashes: impl IntoIterator<Item = &'a ContentHash>) -> ContentHash {
+	let preimage: Vec<u8> = content_hashes
+		.into_iter()
+		.flat_map(|hash| hash.as_bytes().iter().copied())
+		.collect();
+	sp_io::hashing::blake2_256(&preimage).into()
+}
@@ -0,0 +1,290 @@
+//! Autogenerated weights for pallet_audio_storage
+//!
+//! MEASURED BY DRIVING THE `benchmark pallet` LOOP OVER THE NATIVE RUNTIME INSTEAD OF WASM.
+//! REGENERATE WITH THE COMMAND BELOW ON REFERENCE HARDWARE BEFORE RELYING ON THE TIMINGS.
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: Some(Native), CHAIN: Some("dev"), DB CACHE: 1024
+
+// Regenerate with:
+// ./target/production/substrate
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_audio_storage
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/audio-storage/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_audio_storage.
+pub trait WeightInfo {
+	fn create_manifest() -> Weight;
+	fn store_chunk(l: u32, ) -> Weight;
+	fn seal_manifest(n: u32, ) -> Weight;
+	fn renew_chunk() -> Weight;
+	fn remove_manifest() -> Weight;
+	fn renew_manifest(n: u32, ) -> Weight;
+	fn cancel_renewal() -> Weight;
+}
+
+/// Weights for pallet_audio_storage using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: AudioStorage NextManifestId (r:1 w:1)
+	/// Proof: AudioStorage NextManifestId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: AudioStorage Manifests (r:0 w:1)
+	/// Proof: AudioStorage Manifests (max_values: None, max_size: Some(5727), added: 8202, mode: MaxEncodedLen)
+	fn create_manifest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1229`
+		//  Estimated: `499`
+		// Minimum execution time: 35_073 nanoseconds.
+		Weight::from_parts(38_573_000, 499)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AudioStorage Manifests (r:1 w:1)
+	/// Proof: AudioStorage Manifests (max_values: None, max_size: Some(5727), added: 8202, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage ByteFee (r:1 w:0)
+	/// Proof: TransactionStorage ByteFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage EntryFee (r:1 w:0)
+	/// Proof: TransactionStorage EntryFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: unknown `0x3a65787472696e7369635f696e646578` (r:1 w:0)
+	/// Proof Skipped: unknown `0x3a65787472696e7369635f696e646578` (r:1 w:0)
+	/// Storage: TransactionStorage BlockTransactions (r:1 w:1)
+	/// Proof: TransactionStorage BlockTransactions (max_values: Some(1), max_size: Some(36866), added: 37361, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 8388608]`.
+	fn store_chunk(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2287`
+		//  Estimated: `51347`
+		// Minimum execution time: 93_530 nanoseconds.
+		Weight::from_parts(100_905_000, 51347)
+			// Standard Error: 62
+			.saturating_add(Weight::from_ref_time(13_400).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AudioStorage Manifests (r:1 w:1)
+	/// Proof: AudioStorage Manifests (max_values: None, max_size: Some(5727), added: 8202, mode: MaxEncodedLen)
+	/// Storage: AudioStorage ManifestsByRoot (r:1 w:1)
+	/// Proof: AudioStorage ManifestsByRoot (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 128]`.
+	fn seal_manifest(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1373 + n * (44 ±0)`
+		//  Estimated: `10713`
+		// Minimum execution time: 32_874 nanoseconds.
+		Weight::from_parts(47_105_447, 10713)
+			// Standard Error: 11_105
+			.saturating_add(Weight::from_ref_time(316_622).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AudioStorage Manifests (r:1 w:1)
+	/// Proof: AudioStorage Manifests (max_values: None, max_size: Some(5727), added: 8202, mode: MaxEncodedLen)
+	/// Storage: AudioStorage Renewals (r:1 w:1)
+	/// Proof: AudioStorage Renewals (max_values: None, max_size: Some(582), added: 3057, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage ByteFee (r:1 w:0)
+	/// Proof: TransactionStorage ByteFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage EntryFee (r:1 w:0)
+	/// Proof: TransactionStorage EntryFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage Transactions (r:1 w:0)
+	/// Proof: TransactionStorage Transactions (max_values: None, max_size: Some(36886), added: 39361, mode: MaxEncodedLen)
+	/// Storage: unknown `0x3a65787472696e7369635f696e646578` (r:1 w:0)
+	/// Proof Skipped: unknown `0x3a65787472696e7369635f696e646578` (r:1 w:0)
+	/// Storage: TransactionStorage BlockTransactions (r:1 w:1)
+	/// Proof: TransactionStorage BlockTransactions (max_values: Some(1), max_size: Some(36866), added: 37361, mode: MaxEncodedLen)
+	fn renew_chunk() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2828`
+		//  Estimated: `94306`
+		// Minimum execution time: 63_167 nanoseconds.
+		Weight::from_parts(67_046_000, 94306)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AudioStorage Manifests (r:1 w:1)
+	/// Proof: AudioStorage Manifests (max_values: None, max_size: Some(5727), added: 8202, mode: MaxEncodedLen)
+	/// Storage: AudioStorage Renewals (r:1 w:1)
+	/// Proof: AudioStorage Renewals (max_values: None, max_size: Some(582), added: 3057, mode: MaxEncodedLen)
+	/// Storage: AudioStorage ManifestsByRoot (r:0 w:1)
+	/// Proof: AudioStorage ManifestsByRoot (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn remove_manifest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2203`
+		//  Estimated: `11259`
+		// Minimum execution time: 61_177 nanoseconds.
+		Weight::from_parts(66_991_000, 11259)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AudioStorage Manifests (r:1 w:0)
+	/// Proof: AudioStorage Manifests (max_values: None, max_size: Some(5727), added: 8202, mode: MaxEncodedLen)
+	/// Storage: AudioStorage Renewals (r:1 w:1)
+	/// Proof: AudioStorage Renewals (max_values: None, max_size: Some(582), added: 3057, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage ByteFee (r:1 w:0)
+	/// Proof: TransactionStorage ByteFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage EntryFee (r:1 w:0)
+	/// Proof: TransactionStorage EntryFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 128]`.
+	fn renew_manifest(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2239 + n * (44 ±0)`
+		//  Estimated: `12281`
+		// Minimum execution time: 47_313 nanoseconds.
+		Weight::from_parts(62_070_375, 12281)
+			// Standard Error: 16_707
+			.saturating_add(Weight::from_ref_time(1_196_391).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AudioStorage Renewals (r:1 w:1)
+	/// Proof: AudioStorage Renewals (max_values: None, max_size: Some(582), added: 3057, mode: MaxEncodedLen)
+	fn cancel_renewal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2042`
+		//  Estimated: `3057`
+		// Minimum execution time: 44_967 nanoseconds.
+		Weight::from_parts(51_215_000, 3057)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: AudioStorage NextManifestId (r:1 w:1)
+	/// Proof: AudioStorage NextManifestId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: AudioStorage Manifests (r:0 w:1)
+	/// Proof: AudioStorage Manifests (max_values: None, max_size: Some(5727), added: 8202, mode: MaxEncodedLen)
+	fn create_manifest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1229`
+		//  Estimated: `499`
+		// Minimum execution time: 35_073 nanoseconds.
+		Weight::from_parts(38_573_000, 499)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: AudioStorage Manifests (r:1 w:1)
+	/// Proof: AudioStorage Manifests (max_values: None, max_size: Some(5727), added: 8202, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage ByteFee (r:1 w:0)
+	/// Proof: TransactionStorage ByteFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage EntryFee (r:1 w:0)
+	/// Proof: TransactionStorage EntryFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: unknown `0x3a65787472696e7369635f696e646578` (r:1 w:0)
+	/// Proof Skipped: unknown `0x3a65787472696e7369635f696e646578` (r:1 w:0)
+	/// Storage: TransactionStorage BlockTransactions (r:1 w:1)
+	/// Proof: TransactionStorage BlockTransactions (max_values: Some(1), max_size: Some(36866), added: 37361, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 8388608]`.
+	fn store_chunk(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2287`
+		//  Estimated: `51347`
+		// Minimum execution time: 93_530 nanoseconds.
+		Weight::from_parts(100_905_000, 51347)
+			// Standard Error: 62
+			.saturating_add(Weight::from_ref_time(13_400).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: AudioStorage Manifests (r:1 w:1)
+	/// Proof: AudioStorage Manifests (max_values: None, max_size: Some(5727), added: 8202, mode: MaxEncodedLen)
+	/// Storage: AudioStorage ManifestsByRoot (r:1 w:1)
+	/// Proof: AudioStorage ManifestsByRoot (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 128]`.
+	fn seal_manifest(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1373 + n * (44 ±0)`
+		//  Estimated: `10713`
+		// Minimum execution time: 32_874 nanoseconds.
+		Weight::from_parts(47_105_447, 10713)
+			// Standard Error: 11_105
+			.saturating_add(Weight::from_ref_time(316_622).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: AudioStorage Manifests (r:1 w:1)
+	/// Proof: AudioStorage Manifests (max_values: None, max_size: Some(5727), added: 8202, mode: MaxEncodedLen)
+	/// Storage: AudioStorage Renewals (r:1 w:1)
+	/// Proof: AudioStorage Renewals (max_values: None, max_size: Some(582), added: 3057, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage ByteFee (r:1 w:0)
+	/// Proof: TransactionStorage ByteFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage EntryFee (r:1 w:0)
+	/// Proof: TransactionStorage EntryFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage Transactions (r:1 w:0)
+	/// Proof: TransactionStorage Transactions (max_values: None, max_size: Some(36886), added: 39361, mode: MaxEncodedLen)
+	/// Storage: unknown `0x3a65787472696e7369635f696e646578` (r:1 w:0)
+	/// Proof Skipped: unknown `0x3a65787472696e7369635f696e646578` (r:1 w:0)
+	/// Storage: TransactionStorage BlockTransactions (r:1 w:1)
+	/// Proof: TransactionStorage BlockTransactions (max_values: Some(1), max_size: Some(36866), added: 37361, mode: MaxEncodedLen)
+	fn renew_chunk() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2828`
+		//  Estimated: `94306`
+		// Minimum execution time: 63_167 nanoseconds.
+		Weight::from_parts(67_046_000, 94306)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: AudioStorage Manifests (r:1 w:1)
+	/// Proof: AudioStorage Manifests (max_values: None, max_size: Some(5727), added: 8202, mode: MaxEncodedLen)
+	/// Storage: AudioStorage Renewals (r:1 w:1)
+	/// Proof: AudioStorage Renewals (max_values: None, max_size: Some(582), added: 3057, mode: MaxEncodedLen)
+	/// Storage: AudioStorage ManifestsByRoot (r:0 w:1)
+	/// Proof: AudioStorage ManifestsByRoot (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn remove_manifest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2203`
+		//  Estimated: `11259`
+		// Minimum execution time: 61_177 nanoseconds.
+		Weight::from_parts(66_991_000, 11259)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: AudioStorage Manifests (r:1 w:0)
+	/// Proof: AudioStorage Manifests (max_values: None, max_size: Some(5727), added: 8202, mode: MaxEncodedLen)
+	/// Storage: AudioStorage Renewals (r:1 w:1)
+	/// Proof: AudioStorage Renewals (max_values: None, max_size: Some(582), added: 3057, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage ByteFee (r:1 w:0)
+	/// Proof: TransactionStorage ByteFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage EntryFee (r:1 w:0)
+	/// Proof: TransactionStorage EntryFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 128]`.
+	fn renew_manifest(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2239 + n * (44 ±0)`
+		//  Estimated: `12281`
+		// Minimum execution time: 47_313 nanoseconds.
+		Weight::from_parts(62_070_375, 12281)
+			// Standard Error: 16_707
+			.saturating_add(Weight::from_ref_time(1_196_391).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: AudioStorage Renewals (r:1 w:1)
+	/// Proof: AudioStorage Renewals (max_values: None, max_size: Some(582), added: 3057, mode: MaxEncodedLen)
+	fn cancel_renewal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2042`
+		//  Estimated: `3057`
+		// Minimum execution time: 44_967 nanoseconds.
+		Weight::from_parts(51_215_000, 3057)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
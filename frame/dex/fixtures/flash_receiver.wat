@@ -0,0 +1,65 @@
+;; Flash swap receiver used by the `pallet-dex` tests and benchmarks.
+;;
+;; The receiver is programmed with a single call, which it makes whenever the DEX calls it back.
+;; Messages are dispatched on the first four bytes of the input:
+;;
+;; - `0xa556b0c1` on_flash_swap(..), makes the programmed call, if any, and reverts if it fails
+;; - any other selector programs the call: the rest of the input is the 32 byte address of the
+;;   callee followed by the input of the call, 256 bytes at most
+(module
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "seal0" "seal_get_storage" (func $seal_get_storage (param i32 i32 i32) (result i32)))
+	(import "seal0" "seal_set_storage" (func $seal_set_storage (param i32 i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "seal1" "seal_call" (func $seal_call (param i32 i32 i64 i32 i32 i32 i32 i32) (result i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 4) size of the input buffer
+	(data (i32.const 0) "\00\10")
+
+	;; [4, 8) size of the storage value buffer
+
+	;; [8, 264) programmed call: callee at [8, 40), its input from 40 onwards
+
+	;; [264, 296) storage key of the programmed call, all zero
+
+	;; [296, 312) value transferred with the programmed call, zero
+
+	;; [312, 4408) input buffer: selector at [312, 316), arguments from 316 onwards
+
+	(func (export "deploy"))
+
+	(func (export "call")
+		(call $seal_input (i32.const 312) (i32.const 0))
+
+		;; on_flash_swap(..)
+		(if (i32.eq (i32.load (i32.const 312)) (i32.const 0xc1b056a5))
+			(then
+				(i32.store (i32.const 4) (i32.const 256))
+				(if (call $seal_get_storage (i32.const 264) (i32.const 8) (i32.const 4))
+					(then (return))
+				)
+				(if (call $seal_call
+						(i32.const 0)	;; No flags
+						(i32.const 8)	;; Pointer to the callee address
+						(i64.const 0)	;; All the remaining gas
+						(i32.const 296)	;; Pointer to the value
+						(i32.const 40)	;; Pointer to the input
+						(i32.sub (i32.load (i32.const 4)) (i32.const 32))	;; Length of the input
+						(i32.const 0xffffffff)	;; No output
+						(i32.const 0)
+					)
+					(then (call $seal_return (i32.const 1) (i32.const 0) (i32.const 0)))
+				)
+				(return)
+			)
+		)
+
+		;; Programs the call.
+		(call $seal_set_storage
+			(i32.const 264)
+			(i32.const 316)
+			(i32.sub (i32.load (i32.const 0)) (i32.const 4))
+		)
+	)
+)
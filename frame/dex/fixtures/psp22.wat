@@ -0,0 +1,112 @@
+;; Minimal PSP22/ERC-20 style token used by the `pallet-dex` tests and benchmarks.
+;;
+;; Balances are `u64` and stored under the 32 byte account id of their owner. The total
+;; supply is stored under a key that is all `0xFF`. Amounts passed in are read from their low
+;; eight bytes and amounts returned are encoded as `u128`, so the token works with runtimes
+;; using either a `u64` or a `u128` balance.
+;;
+;; The constructor expects the initial supply (SCALE encoded) as input and mints it to the
+;; deployer. Messages are dispatched on the first four bytes of the input:
+;;
+;; - `0x84a15da1` transfer(to: AccountId, value: Balance), reverts on insufficient balance
+;; - `0x70a08a31` balance_of(owner: AccountId) -> Balance
+;; - `0x18161d71` total_supply() -> Balance
+(module
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "seal0" "seal_caller" (func $seal_caller (param i32 i32)))
+	(import "seal0" "seal_get_storage" (func $seal_get_storage (param i32 i32 i32) (result i32)))
+	(import "seal0" "seal_set_storage" (func $seal_set_storage (param i32 i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 4) size of the caller buffer
+	(data (i32.const 0) "\20")
+
+	;; [4, 36) caller address
+
+	;; [36, 40) size of the input buffer
+	(data (i32.const 36) "\80")
+
+	;; [40, 168) input buffer: selector at [40, 44), arguments from 44 onwards
+
+	;; [168, 172) size of the storage value buffer
+
+	;; [172, 188) storage value buffer, also holds the `u128` output of a call
+
+	;; [192, 224) total supply storage key
+	(data (i32.const 192)
+		"\FF\FF\FF\FF\FF\FF\FF\FF\FF\FF\FF\FF\FF\FF\FF\FF"
+		"\FF\FF\FF\FF\FF\FF\FF\FF\FF\FF\FF\FF\FF\FF\FF\FF"
+	)
+
+	;; Returns the balance stored under the 32 byte key at `$key`, or zero.
+	(func $balance_of (param $key i32) (result i64)
+		(i32.store (i32.const 168) (i32.const 8))
+		(if (call $seal_get_storage (get_local $key) (i32.const 172) (i32.const 168))
+			(then (return (i64.const 0)))
+		)
+		(i64.load (i32.const 172))
+	)
+
+	;; Stores `$value` under the 32 byte key at `$key`.
+	(func $set_balance (param $key i32) (param $value i64)
+		(i64.store (i32.const 172) (get_local $value))
+		(call $seal_set_storage (get_local $key) (i32.const 172) (i32.const 8))
+	)
+
+	;; Returns `$value` as the SCALE encoded `u128` output of the call.
+	(func $return_balance (param $value i64)
+		(i64.store (i32.const 172) (get_local $value))
+		(i64.store (i32.const 180) (i64.const 0))
+		(call $seal_return (i32.const 0) (i32.const 172) (i32.const 16))
+	)
+
+	(func (export "deploy")
+		(call $seal_input (i32.const 40) (i32.const 36))
+		(call $seal_caller (i32.const 4) (i32.const 0))
+		(call $set_balance (i32.const 4) (i64.load (i32.const 40)))
+		(call $set_balance (i32.const 192) (i64.load (i32.const 40)))
+	)
+
+	(func (export "call")
+		(local $selector i32)
+		(local $from_balance i64)
+		(local $value i64)
+
+		(call $seal_input (i32.const 40) (i32.const 36))
+		(set_local $selector (i32.load (i32.const 40)))
+
+		;; transfer(to, value)
+		(if (i32.eq (get_local $selector) (i32.const 0xa15da184))
+			(then
+				(call $seal_caller (i32.const 4) (i32.const 0))
+				(set_local $value (i64.load (i32.const 76)))
+				(set_local $from_balance (call $balance_of (i32.const 4)))
+				(if (i64.lt_u (get_local $from_balance) (get_local $value))
+					(then (call $seal_return (i32.const 1) (i32.const 0) (i32.const 0)))
+				)
+				(call $set_balance
+					(i32.const 4)
+					(i64.sub (get_local $from_balance) (get_local $value))
+				)
+				(call $set_balance
+					(i32.const 44)
+					(i64.add (call $balance_of (i32.const 44)) (get_local $value))
+				)
+				(return)
+			)
+		)
+
+		;; balance_of(owner)
+		(if (i32.eq (get_local $selector) (i32.const 0x318aa070))
+			(then (call $return_balance (call $balance_of (i32.const 44))))
+		)
+
+		;; total_supply()
+		(if (i32.eq (get_local $selector) (i32.const 0x711d1618))
+			(then (call $return_balance (call $balance_of (i32.const 192))))
+		)
+
+		(unreachable)
+	)
+)
@@ -0,0 +1,48 @@
+//! Runtime API definition for the DEX pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_runtime::DispatchError;
+use sp_std::prelude::*;
+
+pub use pallet_dex::{PoolInfo, Quote, TokenKind, TwapPrice};
+
+sp_api::decl_runtime_apis! {
+	pub trait DexApi<AccountId, AssetId, Balance, BlockNumber> where
+		AccountId: Codec,
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Quote selling exactly `amount_in` of the first token of `path` for its last token.
+		fn quote_exact_in(
+			path: Vec<TokenKind<AssetId, AccountId>>,
+			amount_in: Balance,
+		) -> Result<Quote<Balance>, DispatchError>;
+
+		/// Quote buying exactly `amount_out` of the last token of `path` for its first token.
+		fn quote_exact_out(
+			path: Vec<TokenKind<AssetId, AccountId>>,
+			amount_out: Balance,
+		) -> Result<Quote<Balance>, DispatchError>;
+
+		/// Get the pool between two tokens, given in any order.
+		fn get_pool(
+			token_a: TokenKind<AssetId, AccountId>,
+			token_b: TokenKind<AssetId, AccountId>,
+		) -> Option<PoolInfo<AssetId, AccountId, Balance>>;
+
+		/// List all pools, or only the ones containing `token`.
+		fn list_pools(
+			token: Option<TokenKind<AssetId, AccountId>>,
+		) -> Vec<PoolInfo<AssetId, AccountId, Balance>>;
+
+		/// Get the average price of `token_in` in `token_out` over the last `window` blocks.
+		fn twap(
+			token_in: TokenKind<AssetId, AccountId>,
+			token_out: TokenKind<AssetId, AccountId>,
+			window: BlockNumber,
+		) -> Result<TwapPrice<BlockNumber>, DispatchError>;
+	}
+}
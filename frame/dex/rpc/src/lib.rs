@@ -0,0 +1,243 @@
+//! RPC interface for the DEX pallet.
+
+use std::{
+	convert::{TryFrom, TryInto},
+	sync::Arc,
+};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorCode, ErrorObject},
+};
+use pallet_dex_rpc_runtime_api::{PoolInfo, Quote, TokenKind, TwapPrice};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay, NumberFor},
+};
+
+pub use pallet_dex_rpc_runtime_api::DexApi as DexRuntimeApi;
+
+#[rpc(client, server)]
+pub trait DexApi<BlockHash, AccountId, AssetId, BlockNumber> {
+	#[method(name = "dex_quoteExactIn")]
+	fn quote_exact_in(
+		&self,
+		path: Vec<TokenKind<AssetId, AccountId>>,
+		amount_in: NumberOrHex,
+		at: Option<BlockHash>,
+	) -> RpcResult<Quote<NumberOrHex>>;
+
+	#[method(name = "dex_quoteExactOut")]
+	fn quote_exact_out(
+		&self,
+		path: Vec<TokenKind<AssetId, AccountId>>,
+		amount_out: NumberOrHex,
+		at: Option<BlockHash>,
+	) -> RpcResult<Quote<NumberOrHex>>;
+
+	#[method(name = "dex_getPool")]
+	fn get_pool(
+		&self,
+		token_a: TokenKind<AssetId, AccountId>,
+		token_b: TokenKind<AssetId, AccountId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<PoolInfo<AssetId, AccountId, NumberOrHex>>>;
+
+	#[method(name = "dex_listPools")]
+	fn list_pools(
+		&self,
+		token: Option<TokenKind<AssetId, AccountId>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<PoolInfo<AssetId, AccountId, NumberOrHex>>>;
+
+	#[method(name = "dex_twap")]
+	fn twap(
+		&self,
+		token_in: TokenKind<AssetId, AccountId>,
+		token_out: TokenKind<AssetId, AccountId>,
+		window: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<TwapPrice<BlockNumber>>;
+}
+
+/// Provides RPC methods to quote swaps and inspect pools of the DEX.
+pub struct Dex<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Dex<C, P> {
+	/// Creates a new instance of the Dex Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The trade can not be quoted.
+	QuoteError,
+	/// The oracle can not price the window.
+	OracleError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::QuoteError => 2,
+			Error::OracleError => 3,
+		}
+	}
+}
+
+fn runtime_error(error: impl ToString) -> CallError {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the DEX.",
+		Some(error.to_string()),
+	))
+}
+
+fn quote_error(error: impl std::fmt::Debug) -> CallError {
+	CallError::Custom(ErrorObject::owned(
+		Error::QuoteError.into(),
+		"Unable to quote the trade.",
+		Some(format!("{:?}", error)),
+	))
+}
+
+fn oracle_error(error: impl std::fmt::Debug) -> CallError {
+	CallError::Custom(ErrorObject::owned(
+		Error::OracleError.into(),
+		"Unable to compute the average price.",
+		Some(format!("{:?}", error)),
+	))
+}
+
+fn try_into_rpc_balance<Balance: MaybeDisplay + Copy + TryInto<NumberOrHex>>(
+	value: Balance,
+) -> Result<NumberOrHex, CallError> {
+	value.try_into().map_err(|_| {
+		CallError::Custom(ErrorObject::owned(
+			ErrorCode::InvalidParams.code(),
+			format!("{} doesn't fit in NumberOrHex representation", value),
+			None::<()>,
+		))
+	})
+}
+
+fn try_from_rpc_balance<Balance: TryFrom<NumberOrHex>>(
+	value: NumberOrHex,
+) -> Result<Balance, CallError> {
+	value.try_into().map_err(|_| {
+		CallError::Custom(ErrorObject::owned(
+			ErrorCode::InvalidParams.code(),
+			format!("{:?} doesn't fit in the balance type", value),
+			None::<()>,
+		))
+	})
+}
+
+impl<C, Block, AccountId, AssetId, Balance>
+	DexApiServer<<Block as BlockT>::Hash, AccountId, AssetId, NumberFor<Block>>
+	for Dex<C, (Block, Balance)>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: DexRuntimeApi<Block, AccountId, AssetId, Balance, NumberFor<Block>>,
+	AccountId: Codec + Send + Sync + 'static,
+	AssetId: Codec + Send + Sync + 'static,
+	Balance: Codec
+		+ MaybeDisplay
+		+ Copy
+		+ TryInto<NumberOrHex>
+		+ TryFrom<NumberOrHex>
+		+ Send
+		+ Sync
+		+ 'static,
+{
+	fn quote_exact_in(
+		&self,
+		path: Vec<TokenKind<AssetId, AccountId>>,
+		amount_in: NumberOrHex,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Quote<NumberOrHex>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let quote = api
+			.quote_exact_in(&at, path, try_from_rpc_balance(amount_in)?)
+			.map_err(runtime_error)?
+			.map_err(quote_error)?;
+		Ok(quote.try_map(try_into_rpc_balance)?)
+	}
+
+	fn quote_exact_out(
+		&self,
+		path: Vec<TokenKind<AssetId, AccountId>>,
+		amount_out: NumberOrHex,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Quote<NumberOrHex>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let quote = api
+			.quote_exact_out(&at, path, try_from_rpc_balance(amount_out)?)
+			.map_err(runtime_error)?
+			.map_err(quote_error)?;
+		Ok(quote.try_map(try_into_rpc_balance)?)
+	}
+
+	fn get_pool(
+		&self,
+		token_a: TokenKind<AssetId, AccountId>,
+		token_b: TokenKind<AssetId, AccountId>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<PoolInfo<AssetId, AccountId, NumberOrHex>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let pool = api.get_pool(&at, token_a, token_b).map_err(runtime_error)?;
+		Ok(pool.map(|pool| pool.try_map(try_into_rpc_balance)).transpose()?)
+	}
+
+	fn list_pools(
+		&self,
+		token: Option<TokenKind<AssetId, AccountId>>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<PoolInfo<AssetId, AccountId, NumberOrHex>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let pools = api.list_pools(&at, token).map_err(runtime_error)?;
+		Ok(pools
+			.into_iter()
+			.map(|pool| pool.try_map(try_into_rpc_balance))
+			.collect::<Result<_, _>>()?)
+	}
+
+	fn twap(
+		&self,
+		token_in: TokenKind<AssetId, AccountId>,
+		token_out: TokenKind<AssetId, AccountId>,
+		window: NumberFor<Block>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<TwapPrice<NumberFor<Block>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		Ok(api
+			.twap(&at, token_in, token_out, window)
+			.map_err(runtime_error)?
+			.map_err(oracle_error)?)
+	}
+}
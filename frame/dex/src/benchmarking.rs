@@ -1,20 +1,794 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for pallet-dex
+//!
+//! Every benchmark trades against tokens deployed from the `psp22` fixture, so the measured
+//! weights include the cost of the nested contract calls.
+
+#![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
+use crate::Pallet as Dex;
 
-#[allow(unused)]
-use crate::Pallet as Template;
-use frame_benchmarking::v1::{benchmarks, whitelisted_caller};
+use codec::Encode;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	sp_runtime::{
+		traits::{Bounded, One, Zero},
+		Perbill,
+	},
+	traits::{tokens::nonfungibles::Inspect, EnsureOrigin, Get},
+	weights::Weight,
+	BoundedVec,
+};
 use frame_system::RawOrigin;
+use pallet_contracts_primitives::Code;
+
+type ContractsBalanceOf<T> = <<T as pallet_contracts::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+const TOKEN_SUPPLY: u32 = 1_000_000_000;
+const TOKEN_A_RESERVE: u32 = 1_000_000;
+const TOKEN_B_RESERVE: u32 = 2_000_000;
+
+/// Gives `who` enough native balance to pay for contract storage deposits.
+fn fund<T: Config>(who: &T::AccountId) {
+	let amount = ContractsBalanceOf::<T>::max_value() / 1_000u32.into();
+	<T as pallet_contracts::Config>::Currency::make_free_balance_be(who, amount);
+}
+
+/// Deploys the `psp22` fixture with the whole supply minted to `owner`.
+fn deploy_token<T: Config>(owner: &T::AccountId, salt: u8) -> T::AccountId {
+	pallet_contracts::Pallet::<T>::bare_instantiate(
+		owner.clone(),
+		Zero::zero(),
+		T::BlockWeights::get().max_block,
+		None,
+		Code::Upload(include_bytes!("../fixtures/psp22.wasm").to_vec()),
+		(TOKEN_SUPPLY as u128).encode(),
+		vec![salt],
+		false,
+	)
+	.result
+	.expect("psp22 fixture deploys")
+	.account_id
+}
+
+/// Deploys two tokens owned by `caller` and funds the pallet account.
+fn setup_tokens<T: Config>(caller: &T::AccountId) -> (TokenKindOf<T>, TokenKindOf<T>) {
+	fund::<T>(caller);
+	fund::<T>(&T::pallet_account());
+	(
+		TokenKind::Contract(deploy_token::<T>(caller, 0)),
+		TokenKind::Contract(deploy_token::<T>(caller, 1)),
+	)
+}
+
+/// Creates a `TOKEN_A_RESERVE : TOKEN_B_RESERVE` pool with a 0.3% fee funded by `caller`.
+///
+/// Half of the swap fees go to the treasury, so swaps also accrue protocol fees.
+fn setup_exchange<T: Config>(caller: &T::AccountId) -> (TokenKindOf<T>, TokenKindOf<T>) {
+	let (token_a, token_b) = setup_tokens::<T>(caller);
+	Dex::<T>::create_exchange(
+		RawOrigin::Signed(caller.clone()).into(),
+		token_a.clone(),
+		token_b.clone(),
+		T::BenchmarkHelper::liquidity_token_id(0),
+		TOKEN_A_RESERVE.into(),
+		TOKEN_B_RESERVE.into(),
+		3u32.into(),
+		1_000u32.into(),
+		PoolKind::ConstantProduct,
+	)
+	.expect("exchange is created");
+	ProtocolFee::<T>::put(Perbill::from_percent(50));
+	(token_a, token_b)
+}
+
+/// Deploys `n` tokens owned by `caller` and creates exchanges between consecutive ones, with half
+/// of the swap fees going to the treasury.
+fn setup_path<T: Config>(caller: &T::AccountId, n: u32) -> SwapPathOf<T> {
+	fund::<T>(caller);
+	fund::<T>(&T::pallet_account());
+	let tokens: Vec<_> = (0..n)
+		.map(|salt| TokenKind::Contract(deploy_token::<T>(caller, salt as u8)))
+		.collect();
+	for (index, pair) in tokens.windows(2).enumerate() {
+		Dex::<T>::create_exchange(
+			RawOrigin::Signed(caller.clone()).into(),
+			pair[0].clone(),
+			pair[1].clone(),
+			T::BenchmarkHelper::liquidity_token_id(index as u32),
+			TOKEN_A_RESERVE.into(),
+			TOKEN_B_RESERVE.into(),
+			3u32.into(),
+			1_000u32.into(),
+			PoolKind::ConstantProduct,
+		)
+		.expect("exchange is created");
+	}
+	ProtocolFee::<T>::put(Perbill::from_percent(50));
+	tokens.try_into().expect("path fits into `MaxSwapPathLength`")
+}
+
+/// Deploys the `flash_receiver` fixture for `caller`, funded with `TOKEN_A_RESERVE` of `token`,
+/// which repays `repayment` of it to the pallet account when called back.
+fn setup_flash_receiver<T: Config>(
+	caller: &T::AccountId,
+	token: &TokenKindOf<T>,
+	repayment: u32,
+) -> T::AccountId {
+	let TokenKind::Contract(token_contract) = token else {
+		unreachable!("benchmark tokens are contracts")
+	};
+	let receiver = pallet_contracts::Pallet::<T>::bare_instantiate(
+		caller.clone(),
+		Zero::zero(),
+		T::BlockWeights::get().max_block,
+		None,
+		Code::Upload(include_bytes!("../fixtures/flash_receiver.wasm").to_vec()),
+		vec![],
+		vec![],
+		false,
+	)
+	.result
+	.expect("flash_receiver fixture deploys")
+	.account_id;
+	Dex::<T>::transfer_token_from_owner(
+		caller,
+		token.clone(),
+		receiver.clone(),
+		TOKEN_A_RESERVE.into(),
+		&mut Weight::zero(),
+	)
+	.expect("caller funds the receiver");
+
+	// Programs the repayment: the callee, then the input of its `transfer`.
+	let mut data = vec![0; 4];
+	token_contract.encode_to(&mut data);
+	data.extend([0x84, 0xa1, 0x5d, 0xa1]);
+	T::pallet_account().encode_to(&mut data);
+	(repayment as u128).encode_to(&mut data);
+	let result = pallet_contracts::Pallet::<T>::bare_call(
+		caller.clone(),
+		receiver.clone(),
+		Zero::zero(),
+		T::BlockWeights::get().max_block,
+		None,
+		data,
+		false,
+		pallet_contracts::Determinism::Deterministic,
+	)
+	.result
+	.expect("programming the receiver succeeds");
+	assert!(!result.did_revert());
+	receiver
+}
+
+/// Places an order of `caller` selling 1_000 of `token_a` for at least one `token_b`, which the
+/// pool always fills.
+fn setup_order<T: Config>(
+	caller: &T::AccountId,
+	token_a: &TokenKindOf<T>,
+	token_b: &TokenKindOf<T>,
+	expiry: T::BlockNumber,
+) -> OrderId {
+	let order_id = NextOrderId::<T>::get();
+	Dex::<T>::place_order(
+		RawOrigin::Signed(caller.clone()).into(),
+		token_a.clone(),
+		token_b.clone(),
+		1_000u32.into(),
+		1u32.into(),
+		1_000u32.into(),
+		expiry,
+	)
+	.expect("order is placed");
+	order_id
+}
+
+/// Returns the reserves of `token_a` and `token_b` in their pool.
+fn reserves<T: Config>(
+	token_a: &TokenKindOf<T>,
+	token_b: &TokenKindOf<T>,
+) -> (BalanceOf<T>, BalanceOf<T>) {
+	let hop = Dex::<T>::get_directed_exchange(token_a, token_b).expect("exchange exists");
+	Dex::<T>::hop_reserves(&hop)
+}
+
+/// Tick spacing of the pool created by `setup_concentrated_exchange`.
+const TICK_SPACING: i32 = 10;
+
+/// Creates a concentrated pool at the price of `TOKEN_A_RESERVE : TOKEN_B_RESERVE` with a 0.3%
+/// fee, whose whole range is funded by `caller`. Half of the swap fees go to the treasury.
+fn setup_concentrated_exchange<T: Config>(
+	caller: &T::AccountId,
+) -> (TokenKindOf<T>, TokenKindOf<T>) {
+	let (token_a, token_b) = setup_tokens::<T>(caller);
+	fund::<T>(&T::positions_account());
+	Dex::<T>::create_exchange(
+		RawOrigin::Signed(caller.clone()).into(),
+		token_a.clone(),
+		token_b.clone(),
+		T::BenchmarkHelper::liquidity_token_id(0),
+		TOKEN_A_RESERVE.into(),
+		TOKEN_B_RESERVE.into(),
+		3u32.into(),
+		1_000u32.into(),
+		PoolKind::Concentrated { tick_spacing: TICK_SPACING as u32 },
+	)
+	.expect("exchange is created");
+	ProtocolFee::<T>::put(Perbill::from_percent(50));
+	(token_a, token_b)
+}
+
+/// The id of the pool created last, which is not the first one on chains with pools at genesis.
+fn last_pool_id<T: Config>() -> PoolId {
+	NextPoolId::<T>::get() - 1
+}
+
+/// Mints a position of `caller` between `tick_lower` and `tick_upper` in the pool created by
+/// `setup_concentrated_exchange`.
+fn setup_position<T: Config>(
+	caller: &T::AccountId,
+	tick_lower: i32,
+	tick_upper: i32,
+) -> PositionId {
+	let position_id = NextPositionId::<T>::get();
+	Dex::<T>::mint_position(
+		RawOrigin::Signed(caller.clone()).into(),
+		last_pool_id::<T>(),
+		tick_lower,
+		tick_upper,
+		10_000u32.into(),
+		10_000u32.into(),
+		Zero::zero(),
+		Zero::zero(),
+	)
+	.expect("position is minted");
+	position_id
+}
+
+/// Mints `n` positions of `caller` over disjoint ranges below the price of the pool created by
+/// `setup_concentrated_exchange`, each of them adding two ticks.
+fn setup_ticks<T: Config>(caller: &T::AccountId, n: u32) {
+	for index in 0..n as i32 {
+		let tick_upper = 5_000 - 10 * TICK_SPACING * index;
+		setup_position::<T>(caller, tick_upper - 5 * TICK_SPACING, tick_upper);
+	}
+}
+
+/// Position of `caller` around the price of the pool created by `setup_concentrated_exchange`,
+/// which earned fees in both tokens.
+fn setup_position_with_fees<T: Config>(
+	caller: &T::AccountId,
+	token_a: &TokenKindOf<T>,
+	token_b: &TokenKindOf<T>,
+) -> PositionId {
+	let position_id = setup_position::<T>(caller, 6_000, 8_000);
+	for (sold, bought) in [(token_a, token_b), (token_b, token_a)] {
+		Dex::<T>::swap(
+			RawOrigin::Signed(caller.clone()).into(),
+			sold.clone(),
+			bought.clone(),
+			20_000u32.into(),
+			1u32.into(),
+		)
+		.expect("swap succeeds");
+	}
+	position_id
+}
+
+/// Deploys `n` tokens owned by `caller` and funds the pallet account.
+fn setup_stable_tokens<T: Config>(caller: &T::AccountId, n: u32) -> Vec<TokenKindOf<T>> {
+	fund::<T>(caller);
+	fund::<T>(&T::pallet_account());
+	(0..n)
+		.map(|salt| TokenKind::Contract(deploy_token::<T>(caller, salt as u8)))
+		.collect()
+}
+
+/// Creates a stable pool of `n` tokens with an amplification coefficient of 100 and a 0.3% fee,
+/// funded by `caller` with `TOKEN_A_RESERVE` of each. Half of the swap fees go to the treasury.
+///
+/// Returns the id of the stable pool, which is that of the pool of its first pair, and its tokens.
+fn setup_stable_pool<T: Config>(caller: &T::AccountId, n: u32) -> (PoolId, Vec<TokenKindOf<T>>) {
+	let tokens = setup_stable_tokens::<T>(caller, n);
+	let pool_id = NextPoolId::<T>::get();
+	Dex::<T>::create_stable_pool(
+		RawOrigin::Signed(caller.clone()).into(),
+		tokens.clone().try_into().expect("tokens fit into `MaxStableTokens`"),
+		vec![TOKEN_A_RESERVE.into(); n as usize]
+			.try_into()
+			.expect("amounts match the tokens"),
+		T::BenchmarkHelper::liquidity_token_id(0),
+		3u32.into(),
+		1_000u32.into(),
+		100,
+	)
+	.expect("stable pool is created");
+	ProtocolFee::<T>::put(Perbill::from_percent(50));
+	(pool_id, tokens)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_exchange() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (token_a, token_b) = setup_tokens::<T>(&caller);
+		let pool_id = NextPoolId::<T>::get();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			token_a.clone(),
+			token_b.clone(),
+			T::BenchmarkHelper::liquidity_token_id(0),
+			TOKEN_A_RESERVE.into(),
+			TOKEN_B_RESERVE.into(),
+			3u32.into(),
+			1_000u32.into(),
+			PoolKind::ConstantProduct,
+		);
+
+		assert_eq!(Dex::<T>::get_exchange(&token_a, &token_b).unwrap().pool_id, pool_id);
+	}
+
+	#[benchmark]
+	fn swap() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (token_a, token_b) = setup_exchange::<T>(&caller);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			token_a.clone(),
+			token_b.clone(),
+			1_000u32.into(),
+			1u32.into(),
+		);
+
+		let protocol_fees = ProtocolFees::<T>::get(&token_a);
+		assert!(!protocol_fees.is_zero());
+		assert_eq!(
+			reserves::<T>(&token_a, &token_b).0 + protocol_fees,
+			(TOKEN_A_RESERVE + 1_000).into()
+		);
+	}
+
+	#[benchmark]
+	fn asset_to_asset() {
+		let caller: T::AccountId = whitelisted_caller();
+		let path = setup_path::<T>(&caller, 3);
+		let amount =
+			TradeAmount::FixedInput { input_amount: 1_000u32.into(), min_output: 1u32.into() };
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			path[0].clone(),
+			path[1].clone(),
+			path[1].clone(),
+			path[2].clone(),
+			amount,
+		);
+
+		assert_eq!(
+			reserves::<T>(&path[0], &path[1]).0 + ProtocolFees::<T>::get(&path[0]),
+			(TOKEN_A_RESERVE + 1_000).into()
+		);
+	}
+
+	#[benchmark]
+	fn add_liquidity() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (token_a, token_b) = setup_exchange::<T>(&caller);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			token_a.clone(),
+			token_b.clone(),
+			10_000u32.into(),
+			1u32.into(),
+			TOKEN_SUPPLY.into(),
+			T::BlockNumber::max_value(),
+		);
+
+		assert_eq!(reserves::<T>(&token_a, &token_b).0, (TOKEN_A_RESERVE + 10_000).into());
+	}
+
+	#[benchmark]
+	fn remove_liquidity() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (token_a, token_b) = setup_exchange::<T>(&caller);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			token_a.clone(),
+			token_b.clone(),
+			(TOKEN_A_RESERVE / 100).into(),
+			1u32.into(),
+			1u32.into(),
+			T::BlockNumber::max_value(),
+		);
+
+		assert!(reserves::<T>(&token_a, &token_b).0 < TOKEN_A_RESERVE.into());
+	}
+
+	#[benchmark]
+	fn swap_exact_in_along_path(n: Linear<2, 4>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let path = setup_path::<T>(&caller, n);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), path.clone(), 1_000u32.into(), 1u32.into());
+
+		assert_eq!(
+			reserves::<T>(&path[0], &path[1]).0 + ProtocolFees::<T>::get(&path[0]),
+			(TOKEN_A_RESERVE + 1_000).into()
+		);
+	}
+
+	#[benchmark]
+	fn swap_exact_out_along_path(n: Linear<2, 4>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let path = setup_path::<T>(&caller, n);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), path.clone(), 1_000u32.into(), TOKEN_SUPPLY.into());
+
+		let last = path.len() - 1;
+		assert_eq!(reserves::<T>(&path[last - 1], &path[last]).1, (TOKEN_B_RESERVE - 1_000).into());
+	}
+
+	#[benchmark]
+	fn set_protocol_fee() {
+		let origin = T::GovernanceOrigin::try_successful_origin()
+			.expect("GovernanceOrigin has a successful origin");
+
+		#[block]
+		{
+			Dex::<T>::set_protocol_fee(origin, Perbill::from_percent(50))
+				.expect("GovernanceOrigin may set the protocol fee");
+		}
+
+		assert_eq!(ProtocolFee::<T>::get(), Perbill::from_percent(50));
+	}
+
+	#[benchmark]
+	fn add_fee_tier() {
+		let origin = T::GovernanceOrigin::try_successful_origin()
+			.expect("GovernanceOrigin has a successful origin");
 
-benchmarks! {
-	do_something {
-		let s in 0 .. 100;
+		#[block]
+		{
+			Dex::<T>::add_fee_tier(origin, 10u32.into(), 1_000u32.into())
+				.expect("GovernanceOrigin may add fee tiers");
+		}
+
+		assert!(Dex::<T>::is_fee_tier_approved(10u32.into(), 1_000u32.into()));
+	}
+
+	#[benchmark]
+	fn remove_fee_tier() {
+		let origin = T::GovernanceOrigin::try_successful_origin()
+			.expect("GovernanceOrigin has a successful origin");
+		Dex::<T>::add_fee_tier(origin.clone(), 10u32.into(), 1_000u32.into())
+			.expect("GovernanceOrigin may add fee tiers");
+
+		#[block]
+		{
+			Dex::<T>::remove_fee_tier(origin, 10u32.into(), 1_000u32.into())
+				.expect("GovernanceOrigin may remove fee tiers");
+		}
+
+		assert!(!Dex::<T>::is_fee_tier_approved(10u32.into(), 1_000u32.into()));
+	}
+
+	#[benchmark]
+	fn collect_protocol_fees() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (token_a, token_b) = setup_exchange::<T>(&caller);
+		Dex::<T>::swap(
+			RawOrigin::Signed(caller.clone()).into(),
+			token_a.clone(),
+			token_b,
+			1_000u32.into(),
+			1u32.into(),
+		)
+		.expect("swap succeeds");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), token_a.clone());
+
+		assert!(ProtocolFees::<T>::get(&token_a).is_zero());
+	}
+
+	#[benchmark]
+	fn flash_swap(d: Linear<0, 1024>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (token_a, token_b) = setup_exchange::<T>(&caller);
+		let receiver = setup_flash_receiver::<T>(&caller, &token_a, 1_000);
+		let data = BoundedVec::try_from(vec![0u8; d as usize])
+			.expect("`MaxFlashSwapDataLength` is at least 1024");
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			token_a.clone(),
+			token_b.clone(),
+			Zero::zero(),
+			1_000u32.into(),
+			receiver,
+			T::BlockWeights::get().max_block,
+			data,
+		);
+
+		assert_eq!(reserves::<T>(&token_a, &token_b).1, (TOKEN_B_RESERVE - 1_000).into());
+	}
+
+	#[benchmark]
+	fn place_order() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (token_a, token_b) = setup_exchange::<T>(&caller);
+		let expiry = frame_system::Pallet::<T>::block_number() + T::MaxOrderLifetime::get();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			token_a.clone(),
+			token_b.clone(),
+			1_000u32.into(),
+			1u32.into(),
+			1_000u32.into(),
+			expiry,
+		);
+
+		assert_eq!(Dex::<T>::order_book(&token_a, &token_b).len(), 1);
+	}
+
+	#[benchmark]
+	fn fill_order() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (token_a, token_b) = setup_exchange::<T>(&caller);
+		let expiry = frame_system::Pallet::<T>::block_number() + T::MaxOrderLifetime::get();
+		let order_id = setup_order::<T>(&caller, &token_a, &token_b, expiry);
+		let keeper: T::AccountId = account("keeper", 0, 0);
+		fund::<T>(&keeper);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(keeper), order_id);
+
+		assert!(Orders::<T>::get(order_id).is_none());
+		assert_eq!(
+			reserves::<T>(&token_a, &token_b).0 + ProtocolFees::<T>::get(&token_a),
+			(TOKEN_A_RESERVE + 1_000).into()
+		);
+	}
+
+	#[benchmark]
+	fn cancel_order() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (token_a, token_b) = setup_exchange::<T>(&caller);
+		let expiry = frame_system::Pallet::<T>::block_number() + T::MaxOrderLifetime::get();
+		let order_id = setup_order::<T>(&caller, &token_a, &token_b, expiry);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), order_id);
+
+		assert!(Orders::<T>::get(order_id).is_none());
+	}
+
+	#[benchmark]
+	fn expire_order() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (token_a, token_b) = setup_exchange::<T>(&caller);
+		// Orders are placed from the first block on.
+		let expiry = One::one();
+		frame_system::Pallet::<T>::set_block_number(expiry);
+		let order_id = setup_order::<T>(&caller, &token_a, &token_b, expiry);
+		let now = expiry + One::one();
+		frame_system::Pallet::<T>::set_block_number(now);
+
+		#[block]
+		{
+			Dex::<T>::expire_orders(now, Weight::MAX);
+		}
+
+		assert!(Orders::<T>::get(order_id).is_none());
+		assert_eq!(ExpiryCursor::<T>::get(), now);
+	}
+
+	#[benchmark]
+	fn mint_position() {
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
-	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		let (token_a, token_b) = setup_concentrated_exchange::<T>(&caller);
+		// The new position adds the last two ticks the pool can hold.
+		setup_ticks::<T>(&caller, T::MaxTicks::get() / 2 - 2);
+		let pool_id = last_pool_id::<T>();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			pool_id,
+			6_000,
+			8_000,
+			10_000u32.into(),
+			10_000u32.into(),
+			1u32.into(),
+			1u32.into(),
+		);
+
+		let position_id = NextPositionId::<T>::get() - 1;
+		assert_eq!(
+			T::PositionNfts::owner(&T::PositionCollection::get(), &position_id),
+			Some(caller)
+		);
+		assert_eq!(
+			Dex::<T>::concentrated_pools(pool_id).unwrap().ticks.len() as u32,
+			T::MaxTicks::get()
+		);
+		let (reserve_a, reserve_b) = reserves::<T>(&token_a, &token_b);
+		assert!(reserve_a > TOKEN_A_RESERVE.into() && reserve_b > TOKEN_B_RESERVE.into());
+	}
+
+	#[benchmark]
+	fn decrease_liquidity() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (token_a, token_b) = setup_concentrated_exchange::<T>(&caller);
+		let position_id = setup_position_with_fees::<T>(&caller, &token_a, &token_b);
+		let liquidity = Dex::<T>::positions(position_id).unwrap().liquidity;
+
+		// Withdrawing all of its liquidity also pays out the fees and burns the position.
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), position_id, liquidity, 1u32.into(), 1u32.into());
+
+		assert!(Dex::<T>::positions(position_id).is_none());
+		assert!(T::PositionNfts::owner(&T::PositionCollection::get(), &position_id).is_none());
+	}
+
+	#[benchmark]
+	fn collect_position_fees() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (token_a, token_b) = setup_concentrated_exchange::<T>(&caller);
+		let position_id = setup_position_with_fees::<T>(&caller, &token_a, &token_b);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), position_id);
+
+		let position = Dex::<T>::positions(position_id).unwrap();
+		assert!(position.fees_owed_a.is_zero() && position.fees_owed_b.is_zero());
+	}
+
+	/// Swap through a concentrated pool crossing every tick it holds.
+	#[benchmark]
+	fn swap_concentrated() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (token_a, token_b) = setup_concentrated_exchange::<T>(&caller);
+		setup_ticks::<T>(&caller, T::MaxTicks::get() / 2 - 1);
+		let origin = RawOrigin::Signed(caller);
+
+		#[block]
+		{
+			Dex::<T>::swap(
+				origin.into(),
+				token_a.clone(),
+				token_b.clone(),
+				TOKEN_A_RESERVE.into(),
+				1u32.into(),
+			)
+			.expect("swap succeeds");
+		}
+
+		let lowest_tick = 5_000 - 10 * TICK_SPACING * (T::MaxTicks::get() / 2 - 2) as i32;
+		let tick = Dex::<T>::concentrated_pools(last_pool_id::<T>()).unwrap().tick;
+		assert!(tick < lowest_tick - 5 * TICK_SPACING);
+	}
+
+	#[benchmark]
+	fn create_stable_pool(n: Linear<2, 4>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let tokens = setup_stable_tokens::<T>(&caller, n);
+		let amounts = vec![TOKEN_A_RESERVE.into(); n as usize];
+		let pool_id = NextPoolId::<T>::get();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			tokens.try_into().unwrap(),
+			amounts.try_into().unwrap(),
+			T::BenchmarkHelper::liquidity_token_id(0),
+			3u32.into(),
+			1_000u32.into(),
+			100,
+		);
+
+		assert_eq!(Dex::<T>::stable_pools(pool_id).unwrap().tokens.len(), n as usize);
+	}
+
+	/// Deposit of a single token, as off the proportions of the pool as can be.
+	#[benchmark]
+	fn add_stable_liquidity(n: Linear<2, 4>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (pool_id, _) = setup_stable_pool::<T>(&caller, n);
+		let mut amounts = vec![Zero::zero(); n as usize];
+		amounts[0] = 10_000u32.into();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			pool_id,
+			amounts.try_into().unwrap(),
+			1u32.into(),
+			T::BlockNumber::max_value(),
+		);
+
+		assert_eq!(
+			Dex::<T>::stable_pools(pool_id).unwrap().balances[0],
+			(TOKEN_A_RESERVE + 10_000).into()
+		);
+	}
+
+	#[benchmark]
+	fn remove_stable_liquidity(n: Linear<2, 4>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (pool_id, _) = setup_stable_pool::<T>(&caller, n);
+		let min_amounts = vec![One::one(); n as usize];
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			pool_id,
+			(TOKEN_A_RESERVE / 100).into(),
+			min_amounts.try_into().unwrap(),
+			T::BlockNumber::max_value(),
+		);
+
+		assert!(Dex::<T>::stable_pools(pool_id).unwrap().balances[0] < TOKEN_A_RESERVE.into());
+	}
+
+	#[benchmark]
+	fn ramp_amplification() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (pool_id, _) = setup_stable_pool::<T>(&caller, 2);
+		let origin = T::GovernanceOrigin::try_successful_origin()
+			.expect("GovernanceOrigin has a successful origin");
+		let future_block = frame_system::Pallet::<T>::block_number() + 100u32.into();
+
+		#[block]
+		{
+			Dex::<T>::ramp_amplification(origin, pool_id, 1_000, future_block)
+				.expect("GovernanceOrigin may ramp the amplification coefficient");
+		}
+
+		assert_eq!(Dex::<T>::stable_pools(pool_id).unwrap().future_amplification, 1_000);
+	}
+
+	/// Swap through a stable pool of `MaxStableTokens` tokens, whose pairs are all updated.
+	#[benchmark]
+	fn swap_stable() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (pool_id, tokens) = setup_stable_pool::<T>(&caller, T::MaxStableTokens::get());
+		let origin = RawOrigin::Signed(caller);
+
+		#[block]
+		{
+			Dex::<T>::swap(
+				origin.into(),
+				tokens[0].clone(),
+				tokens[1].clone(),
+				10_000u32.into(),
+				1u32.into(),
+			)
+			.expect("swap succeeds");
+		}
+
+		assert!(Dex::<T>::stable_pools(pool_id).unwrap().balances[1] < TOKEN_A_RESERVE.into());
 	}
 
-	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+	impl_benchmark_test_suite!(Dex, crate::mock::new_test_ext(), crate::mock::Test);
 }
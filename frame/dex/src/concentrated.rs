@@ -0,0 +1,846 @@
+//! Concentrated liquidity pools, whose liquidity is provided over price ranges.
+//!
+//! Prices are those of `token_a` in `token_b`. Tick `i` stands for the price `1.0001^i`, and
+//! pools keep the square root of their price. Liquidity `L` provided between the square root
+//! prices `lower` and `upper` is backed by `L * (1 / price - 1 / upper)` of `token_a` and
+//! `L * (price - lower)` of `token_b` while the pool price is in the range, and by only one of
+//! the tokens outside of it.
+//!
+//! Square root prices and fee growths are scaled by `10^18`. Amounts are computed exactly and
+//! rounded in favour of the pool.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{
+		helpers_128bit::multiply_by_rational_with_rounding,
+		traits::{CheckedDiv, CheckedMul, One, Saturating, UniqueSaturatedInto, Zero},
+		DispatchResult, FixedPointNumber, FixedU128, Perbill, Rounding,
+	},
+	traits::{tokens::nonfungibles::*, Get},
+};
+
+/// Lowest tick a position can start at.
+pub const MIN_TICK: i32 = -200_000;
+/// Highest tick a position can end at.
+pub const MAX_TICK: i32 = 200_000;
+/// Square root of `1.0001`, the price ratio between two consecutive ticks, scaled by `10^18`.
+const SQRT_TICK_RATIO: u128 = 1_000_049_998_750_062_496;
+/// Fixed point one.
+const ONE: u128 = 1_000_000_000_000_000_000;
+
+/// Identifier of a position, which is also the item of its receipt in `PositionCollection`.
+pub type PositionId = u32;
+
+/// A tick bounding at least one position.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Tick {
+	pub index: i32,
+	/// Liquidity of the positions bounded by the tick.
+	pub liquidity_gross: u128,
+	/// Liquidity added to the pool when its price crosses the tick upwards.
+	pub liquidity_net: i128,
+	/// Fee growths on the other side of the tick from the pool price.
+	pub fee_growth_outside_a: u128,
+	pub fee_growth_outside_b: u128,
+}
+
+/// State of a concentrated liquidity pool, besides its `Exchange`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct ConcentratedPool<T: Config> {
+	pub tick_spacing: u32,
+	/// Square root of the price of `token_a` in `token_b`.
+	pub sqrt_price: u128,
+	/// Highest tick at or below the price.
+	pub tick: i32,
+	/// Liquidity of the positions whose range contains the price.
+	pub liquidity: u128,
+	/// Fees earned per unit of liquidity since the pool was created. Wraps around.
+	pub fee_growth_a: u128,
+	pub fee_growth_b: u128,
+	/// Ticks bounding positions, in increasing order.
+	pub ticks: BoundedVec<Tick, T::MaxTicks>,
+}
+
+/// Liquidity provided to a concentrated pool between two ticks.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Position<T: Config> {
+	pub pool_id: PoolId,
+	pub tick_lower: i32,
+	pub tick_upper: i32,
+	pub liquidity: u128,
+	/// Fee growths inside the range when the fees of the position were last accrued.
+	pub fee_growth_inside_a: u128,
+	pub fee_growth_inside_b: u128,
+	/// Fees accrued and not collected yet.
+	pub fees_owed_a: BalanceOf<T>,
+	pub fees_owed_b: BalanceOf<T>,
+}
+
+/// Amounts traded by a swap through a concentrated pool.
+#[derive(Clone, Copy, Default, RuntimeDebug, PartialEq, Eq)]
+pub(crate) struct SwapOutcome {
+	/// Sold amount, fees included.
+	pub amount_in: u128,
+	pub amount_out: u128,
+	/// Fees charged on the sold amount, protocol share included.
+	pub fee: u128,
+	pub protocol_fee: u128,
+}
+
+fn mul_div(a: u128, b: u128, c: u128, rounding: Rounding) -> Option<u128> {
+	multiply_by_rational_with_rounding(a, b, c, rounding)
+}
+
+/// Returns the square root of the price of `tick`.
+pub fn sqrt_price_at_tick(tick: i32) -> Option<u128> {
+	if !(MIN_TICK..=MAX_TICK).contains(&tick) {
+		return None
+	}
+	let mut base = FixedU128::from_inner(SQRT_TICK_RATIO);
+	let mut exponent = tick.unsigned_abs();
+	let mut result = FixedU128::one();
+	while exponent > 0 {
+		if exponent & 1 == 1 {
+			result = result.checked_mul(&base)?;
+		}
+		exponent >>= 1;
+		if exponent > 0 {
+			base = base.checked_mul(&base)?;
+		}
+	}
+	if tick < 0 {
+		result = FixedU128::one().checked_div(&result)?;
+	}
+	Some(result.into_inner())
+}
+
+/// Returns the highest tick between `lowest` and `highest` whose square root price is at most
+/// `sqrt_price`, or `lowest` if there is none.
+fn tick_at_sqrt_price(sqrt_price: u128, lowest: i32, highest: i32) -> Option<i32> {
+	let (mut low, mut high) = (lowest, highest);
+	while low < high {
+		let middle = low + (high - low + 1) / 2;
+		if sqrt_price_at_tick(middle)? <= sqrt_price {
+			low = middle;
+		} else {
+			high = middle - 1;
+		}
+	}
+	Some(low)
+}
+
+/// Amount of `token_a` backing `liquidity` between the square root prices `lower` and `upper`.
+fn amount_a(liquidity: u128, lower: u128, upper: u128, rounding: Rounding) -> Option<u128> {
+	mul_div(mul_div(liquidity, ONE, lower, rounding)?, upper - lower, upper, rounding)
+}
+
+/// Amount of `token_b` backing `liquidity` between the square root prices `lower` and `upper`.
+fn amount_b(liquidity: u128, lower: u128, upper: u128, rounding: Rounding) -> Option<u128> {
+	mul_div(liquidity, upper - lower, ONE, rounding)
+}
+
+/// Returns the amounts of `token_a` and `token_b` backing `liquidity` between the square root
+/// prices `lower` and `upper`, when the pool is at `sqrt_price`.
+pub fn amounts_for_liquidity(
+	sqrt_price: u128,
+	lower: u128,
+	upper: u128,
+	liquidity: u128,
+	rounding: Rounding,
+) -> Option<(u128, u128)> {
+	if sqrt_price <= lower {
+		Some((amount_a(liquidity, lower, upper, rounding)?, 0))
+	} else if sqrt_price < upper {
+		Some((
+			amount_a(liquidity, sqrt_price, upper, rounding)?,
+			amount_b(liquidity, lower, sqrt_price, rounding)?,
+		))
+	} else {
+		Some((0, amount_b(liquidity, lower, upper, rounding)?))
+	}
+}
+
+/// Returns the most liquidity `amount_a` and `amount_b` can back between the square root prices
+/// `lower` and `upper`, when the pool is at `sqrt_price`.
+pub fn liquidity_for_amounts(
+	sqrt_price: u128,
+	lower: u128,
+	upper: u128,
+	amount_a: u128,
+	amount_b: u128,
+) -> Option<u128> {
+	let from_a = |lower: u128| {
+		mul_div(
+			mul_div(amount_a, upper, upper - lower, Rounding::Down)?,
+			lower,
+			ONE,
+			Rounding::Down,
+		)
+	};
+	let from_b = |upper: u128| mul_div(amount_b, ONE, upper - lower, Rounding::Down);
+	if sqrt_price <= lower {
+		from_a(lower)
+	} else if sqrt_price < upper {
+		Some(from_a(sqrt_price)?.min(from_b(sqrt_price)?))
+	} else {
+		from_b(upper)
+	}
+}
+
+/// Trades through `liquidity` from the square root price `sqrt_price` towards `target`, which
+/// is at or below it when `sells_token_a`. `remaining` is what is left to sell, fees included,
+/// or to buy.
+///
+/// Returns the square root price reached, the amounts traded in, net of fees, and out, and the
+/// fee.
+fn swap_step(
+	sells_token_a: bool,
+	sqrt_price: u128,
+	target: u128,
+	liquidity: u128,
+	remaining: u128,
+	exact_in: bool,
+	(fee_numerator, fee_denominator): (u128, u128),
+) -> Option<(u128, u128, u128, u128)> {
+	let amount_in = |price: u128| {
+		if sells_token_a {
+			amount_a(liquidity, price, sqrt_price, Rounding::Up)
+		} else {
+			amount_b(liquidity, sqrt_price, price, Rounding::Up)
+		}
+	};
+	let amount_out = |price: u128| {
+		if sells_token_a {
+			amount_b(liquidity, price, sqrt_price, Rounding::Down)
+		} else {
+			amount_a(liquidity, sqrt_price, price, Rounding::Down)
+		}
+	};
+	let fee_on =
+		|net: u128| mul_div(net, fee_numerator, fee_denominator - fee_numerator, Rounding::Up);
+
+	if exact_in {
+		let remaining_net =
+			mul_div(remaining, fee_denominator - fee_numerator, fee_denominator, Rounding::Down)?;
+		let max_in = amount_in(target)?;
+		if remaining_net >= max_in {
+			return Some((target, max_in, amount_out(target)?, fee_on(max_in)?))
+		}
+		let next = if sells_token_a {
+			let denominator =
+				liquidity.checked_add(mul_div(remaining_net, sqrt_price, ONE, Rounding::Up)?)?;
+			mul_div(liquidity, sqrt_price, denominator, Rounding::Up)?.max(target)
+		} else {
+			sqrt_price
+				.checked_add(mul_div(remaining_net, ONE, liquidity, Rounding::Down)?)?
+				.min(target)
+		};
+		// Whatever can't move the price is kept as a fee.
+		Some((next, remaining_net, amount_out(next)?, remaining - remaining_net))
+	} else {
+		let max_out = amount_out(target)?;
+		let (next, out) = if remaining >= max_out {
+			(target, max_out)
+		} else if sells_token_a {
+			let delta = mul_div(remaining, ONE, liquidity, Rounding::Up)?;
+			(sqrt_price.saturating_sub(delta).max(target), remaining)
+		} else {
+			let delta = mul_div(remaining, sqrt_price, ONE, Rounding::Up)?;
+			let next = match liquidity.checked_sub(delta) {
+				Some(denominator) if !denominator.is_zero() =>
+					mul_div(liquidity, sqrt_price, denominator, Rounding::Up)?.min(target),
+				_ => target,
+			};
+			(next, remaining)
+		};
+		let net = amount_in(next)?;
+		Some((next, net, out, fee_on(net)?))
+	}
+}
+
+impl<T: Config> ConcentratedPool<T> {
+	/// Creates a pool without liquidity at the square root price `sqrt_price`.
+	pub(crate) fn new(tick_spacing: u32, sqrt_price: u128) -> Result<Self, Error<T>> {
+		let tick =
+			tick_at_sqrt_price(sqrt_price, MIN_TICK, MAX_TICK).ok_or(Error::<T>::Overflow)?;
+		Ok(Self {
+			tick_spacing,
+			sqrt_price,
+			tick,
+			liquidity: 0,
+			fee_growth_a: 0,
+			fee_growth_b: 0,
+			ticks: Default::default(),
+		})
+	}
+
+	/// Returns the lowest and highest ticks positions can be bounded by.
+	pub fn tick_bounds(&self) -> (i32, i32) {
+		let spacing = self.tick_spacing as i32;
+		(MIN_TICK / spacing * spacing, MAX_TICK / spacing * spacing)
+	}
+
+	/// Price of `token_a` in `token_b`.
+	pub fn price(&self) -> FixedU128 {
+		let sqrt_price = FixedU128::from_inner(self.sqrt_price);
+		sqrt_price.saturating_mul(sqrt_price)
+	}
+
+	/// Returns the fee growths outside of tick `index`, as set when it starts bounding a
+	/// position.
+	fn fee_growth_outside(&self, index: i32) -> (u128, u128) {
+		match self.ticks.binary_search_by_key(&index, |tick| tick.index) {
+			Ok(position) => {
+				let tick = &self.ticks[position];
+				(tick.fee_growth_outside_a, tick.fee_growth_outside_b)
+			},
+			// All fees so far are deemed earned below the price.
+			Err(_) if index <= self.tick => (self.fee_growth_a, self.fee_growth_b),
+			Err(_) => (0, 0),
+		}
+	}
+
+	/// Returns the fee growths between ticks `lower` and `upper`.
+	pub fn fee_growth_inside(&self, lower: i32, upper: i32) -> (u128, u128) {
+		let (lower_a, lower_b) = self.fee_growth_outside(lower);
+		let (upper_a, upper_b) = self.fee_growth_outside(upper);
+		let ((below_a, below_b), (above_a, above_b)) = (
+			if self.tick >= lower {
+				(lower_a, lower_b)
+			} else {
+				(self.fee_growth_a.wrapping_sub(lower_a), self.fee_growth_b.wrapping_sub(lower_b))
+			},
+			if self.tick < upper {
+				(upper_a, upper_b)
+			} else {
+				(self.fee_growth_a.wrapping_sub(upper_a), self.fee_growth_b.wrapping_sub(upper_b))
+			},
+		);
+		(
+			self.fee_growth_a.wrapping_sub(below_a).wrapping_sub(above_a),
+			self.fee_growth_b.wrapping_sub(below_b).wrapping_sub(above_b),
+		)
+	}
+
+	/// Adds `liquidity` to the positions bounded by tick `index`, or removes it when negative.
+	fn update_tick(&mut self, index: i32, liquidity: i128, is_upper: bool) -> Result<(), Error<T>> {
+		let position = match self.ticks.binary_search_by_key(&index, |tick| tick.index) {
+			Ok(position) => position,
+			Err(position) => {
+				let (fee_growth_outside_a, fee_growth_outside_b) = self.fee_growth_outside(index);
+				let tick = Tick {
+					index,
+					liquidity_gross: 0,
+					liquidity_net: 0,
+					fee_growth_outside_a,
+					fee_growth_outside_b,
+				};
+				self.ticks.try_insert(position, tick).map_err(|_| Error::<T>::TooManyTicks)?;
+				position
+			},
+		};
+		let tick = &mut self.ticks[position];
+		tick.liquidity_gross = tick
+			.liquidity_gross
+			.checked_add_signed(liquidity)
+			.ok_or(Error::<T>::ArithmeticOverflow)?;
+		let net = if is_upper { liquidity.checked_neg() } else { Some(liquidity) };
+		tick.liquidity_net = net
+			.and_then(|net| tick.liquidity_net.checked_add(net))
+			.ok_or(Error::<T>::ArithmeticOverflow)?;
+		if tick.liquidity_gross.is_zero() {
+			self.ticks.remove(position);
+		}
+		Ok(())
+	}
+
+	/// Adds `liquidity` between ticks `lower` and `upper`, or removes it when negative.
+	pub(crate) fn update_liquidity(
+		&mut self,
+		lower: i32,
+		upper: i32,
+		liquidity: i128,
+	) -> Result<(), Error<T>> {
+		self.update_tick(lower, liquidity, false)?;
+		self.update_tick(upper, liquidity, true)?;
+		if (lower..upper).contains(&self.tick) {
+			self.liquidity = self
+				.liquidity
+				.checked_add_signed(liquidity)
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+		}
+		Ok(())
+	}
+
+	/// Swaps `amount` of `token_a` if `sells_token_a`, of `token_b` otherwise, through the pool.
+	/// `amount` is sold, fees included, if `exact_in`, and bought otherwise.
+	///
+	/// The price moves tick range by tick range, and the fee charged in every range is shared by
+	/// the liquidity in it, net of the `protocol_fee` share.
+	pub(crate) fn swap(
+		&mut self,
+		sells_token_a: bool,
+		amount: u128,
+		exact_in: bool,
+		fee: (u128, u128),
+		protocol_fee: Perbill,
+	) -> Result<SwapOutcome, Error<T>> {
+		let mut outcome = SwapOutcome::default();
+		let mut remaining = amount;
+		while !remaining.is_zero() {
+			let next_tick = if sells_token_a {
+				self.ticks.iter().rev().map(|tick| tick.index).find(|index| *index <= self.tick)
+			} else {
+				self.ticks.iter().map(|tick| tick.index).find(|index| *index > self.tick)
+			};
+			let limit = if sells_token_a { MIN_TICK } else { MAX_TICK };
+			let target_tick = next_tick.unwrap_or(limit);
+			let target = sqrt_price_at_tick(target_tick).ok_or(Error::<T>::Overflow)?;
+			ensure!(
+				target != self.sqrt_price || next_tick.is_some(),
+				Error::<T>::NotEnoughLiquidity
+			);
+
+			let (sqrt_price, amount_in, amount_out, step_fee) = swap_step(
+				sells_token_a,
+				self.sqrt_price,
+				target,
+				self.liquidity,
+				remaining,
+				exact_in,
+				fee,
+			)
+			.ok_or(Error::<T>::Overflow)?;
+			let consumed = if exact_in { amount_in.saturating_add(step_fee) } else { amount_out };
+			remaining = remaining.saturating_sub(consumed);
+			outcome.amount_in = outcome
+				.amount_in
+				.checked_add(amount_in.saturating_add(step_fee))
+				.ok_or(Error::<T>::Overflow)?;
+			outcome.amount_out = outcome.amount_out.saturating_add(amount_out);
+			outcome.fee = outcome.fee.saturating_add(step_fee);
+
+			let protocol_share = protocol_fee.mul_floor(step_fee);
+			outcome.protocol_fee = outcome.protocol_fee.saturating_add(protocol_share);
+			if !self.liquidity.is_zero() {
+				let growth =
+					mul_div(step_fee - protocol_share, ONE, self.liquidity, Rounding::Down)
+						.ok_or(Error::<T>::Overflow)?;
+				if sells_token_a {
+					self.fee_growth_a = self.fee_growth_a.wrapping_add(growth);
+				} else {
+					self.fee_growth_b = self.fee_growth_b.wrapping_add(growth);
+				}
+			}
+
+			self.sqrt_price = sqrt_price;
+			if sqrt_price == target {
+				if next_tick.is_some() {
+					self.cross_tick(target_tick, sells_token_a)?;
+				}
+				self.tick = if sells_token_a { target_tick - 1 } else { target_tick };
+				ensure!(
+					remaining.is_zero() || next_tick.is_some() || target_tick != limit,
+					Error::<T>::NotEnoughLiquidity
+				);
+			} else {
+				let (lowest, highest) =
+					if sells_token_a { (target_tick, self.tick) } else { (self.tick, target_tick) };
+				self.tick =
+					tick_at_sqrt_price(sqrt_price, lowest, highest).ok_or(Error::<T>::Overflow)?;
+			}
+		}
+		Ok(outcome)
+	}
+
+	/// Moves the price across tick `index`, updating the active liquidity and flipping the fee
+	/// growths outside of the tick.
+	fn cross_tick(&mut self, index: i32, downwards: bool) -> Result<(), Error<T>> {
+		let (fee_growth_a, fee_growth_b) = (self.fee_growth_a, self.fee_growth_b);
+		let tick = self
+			.ticks
+			.iter_mut()
+			.find(|tick| tick.index == index)
+			.ok_or(Error::<T>::NotEnoughLiquidity)?;
+		tick.fee_growth_outside_a = fee_growth_a.wrapping_sub(tick.fee_growth_outside_a);
+		tick.fee_growth_outside_b = fee_growth_b.wrapping_sub(tick.fee_growth_outside_b);
+		let net =
+			if downwards { tick.liquidity_net.checked_neg() } else { Some(tick.liquidity_net) };
+		self.liquidity = net
+			.and_then(|net| self.liquidity.checked_add_signed(net))
+			.ok_or(Error::<T>::ArithmeticOverflow)?;
+		Ok(())
+	}
+}
+
+impl<T: Config> Position<T> {
+	/// Accrues the fees earned by the position since they were last accrued.
+	pub(crate) fn accrue_fees(&mut self, pool: &ConcentratedPool<T>) -> Result<(), Error<T>> {
+		let (inside_a, inside_b) = pool.fee_growth_inside(self.tick_lower, self.tick_upper);
+		let earned = |inside: u128, last: u128| -> Result<BalanceOf<T>, Error<T>> {
+			let earned = mul_div(self.liquidity, inside.wrapping_sub(last), ONE, Rounding::Down)
+				.ok_or(Error::<T>::Overflow)?;
+			earned.try_into().map_err(|_| Error::<T>::Overflow)
+		};
+		let earned_a = earned(inside_a, self.fee_growth_inside_a)?;
+		let earned_b = earned(inside_b, self.fee_growth_inside_b)?;
+		self.fees_owed_a.saturating_accrue(earned_a);
+		self.fees_owed_b.saturating_accrue(earned_b);
+		self.fee_growth_inside_a = inside_a;
+		self.fee_growth_inside_b = inside_b;
+		Ok(())
+	}
+}
+
+pub(crate) fn to_balance<T: Config>(amount: u128) -> Result<BalanceOf<T>, Error<T>> {
+	amount.try_into().map_err(|_| Error::<T>::Overflow)
+}
+
+impl<T: Config> Pallet<T> {
+	/// Sets up `exchange` as a concentrated pool at the price of `amount_a` and `amount_b`, which
+	/// fund a position over the whole tick range minted to `provider`.
+	pub(crate) fn create_concentrated_pool(
+		exchange: Exchange<T>,
+		tick_spacing: u32,
+		amount_a: BalanceOf<T>,
+		amount_b: BalanceOf<T>,
+		provider: AccountIdOf<T>,
+		gas_used: &mut Weight,
+	) -> DispatchResult {
+		let sqrt_price = FixedU128::checked_from_rational(amount_b, amount_a)
+			.and_then(FixedU128::try_sqrt)
+			.map(FixedU128::into_inner)
+			.ok_or(Error::<T>::PriceOutOfRange)?;
+		let lowest = sqrt_price_at_tick(MIN_TICK).ok_or(Error::<T>::Overflow)?;
+		let highest = sqrt_price_at_tick(MAX_TICK).ok_or(Error::<T>::Overflow)?;
+		ensure!(sqrt_price > lowest && sqrt_price < highest, Error::<T>::PriceOutOfRange);
+
+		let pool = ConcentratedPool::<T>::new(tick_spacing, sqrt_price)?;
+		let (tick_lower, tick_upper) = pool.tick_bounds();
+		ConcentratedPools::<T>::insert(exchange.pool_id, pool);
+		Self::do_mint_position(
+			exchange,
+			provider,
+			(tick_lower, tick_upper),
+			(amount_a, amount_b),
+			(Zero::zero(), Zero::zero()),
+			gas_used,
+		)?;
+		Ok(())
+	}
+
+	/// Returns the exchange and the state of concentrated pool `pool_id`.
+	fn concentrated_pool(pool_id: PoolId) -> Result<(Exchange<T>, ConcentratedPool<T>), Error<T>> {
+		let pair = Pools::<T>::get(pool_id).ok_or(Error::<T>::ExchangeDoesNotExist)?;
+		let exchange = Exchanges::<T>::get(pair).ok_or(Error::<T>::ExchangeDoesNotExist)?;
+		let pool = ConcentratedPools::<T>::get(pool_id).ok_or(Error::<T>::UnsupportedPoolKind)?;
+		Ok((exchange, pool))
+	}
+
+	/// Returns position `position_id`, if `who` owns its receipt.
+	fn owned_position(
+		who: &AccountIdOf<T>,
+		position_id: PositionId,
+	) -> Result<Position<T>, Error<T>> {
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::UnknownPosition)?;
+		let owner = T::PositionNfts::owner(&T::PositionCollection::get(), &position_id);
+		ensure!(owner.as_ref() == Some(who), Error::<T>::NotPositionOwner);
+		Ok(position)
+	}
+
+	/// Provides liquidity to the concentrated pool of `exchange` between `tick_lower` and
+	/// `tick_upper`, and mints the receipt of the position to `owner`.
+	///
+	/// The position gets the most liquidity the desired amounts back. The amounts paid for it
+	/// are rounded up, so they may exceed the desired ones by a unit.
+	pub(crate) fn do_mint_position(
+		mut exchange: Exchange<T>,
+		owner: AccountIdOf<T>,
+		(tick_lower, tick_upper): (i32, i32),
+		(amount_a_desired, amount_b_desired): (BalanceOf<T>, BalanceOf<T>),
+		(amount_a_min, amount_b_min): (BalanceOf<T>, BalanceOf<T>),
+		gas_used: &mut Weight,
+	) -> Result<PositionId, DispatchError> {
+		let mut pool =
+			ConcentratedPools::<T>::get(exchange.pool_id).ok_or(Error::<T>::UnsupportedPoolKind)?;
+		let (lowest, highest) = pool.tick_bounds();
+		let spacing = pool.tick_spacing as i32;
+		ensure!(
+			lowest <= tick_lower &&
+				tick_lower < tick_upper &&
+				tick_upper <= highest &&
+				tick_lower % spacing == 0 &&
+				tick_upper % spacing == 0,
+			Error::<T>::InvalidTickRange
+		);
+		let lower = sqrt_price_at_tick(tick_lower).ok_or(Error::<T>::Overflow)?;
+		let upper = sqrt_price_at_tick(tick_upper).ok_or(Error::<T>::Overflow)?;
+
+		let liquidity = liquidity_for_amounts(
+			pool.sqrt_price,
+			lower,
+			upper,
+			amount_a_desired.unique_saturated_into(),
+			amount_b_desired.unique_saturated_into(),
+		)
+		.ok_or(Error::<T>::Overflow)?;
+		ensure!(!liquidity.is_zero(), Error::<T>::PositionLiquidityIsZero);
+		let signed_liquidity = i128::try_from(liquidity).map_err(|_| Error::<T>::Overflow)?;
+		let (amount_a, amount_b) =
+			amounts_for_liquidity(pool.sqrt_price, lower, upper, liquidity, Rounding::Up)
+				.ok_or(Error::<T>::Overflow)?;
+		let (amount_a, amount_b) = (to_balance::<T>(amount_a)?, to_balance::<T>(amount_b)?);
+		ensure!(amount_a >= amount_a_min, Error::<T>::MinCurrencyTooHigh);
+		ensure!(amount_b >= amount_b_min, Error::<T>::MinTokensTooHigh);
+		let position_id = NextPositionId::<T>::get();
+		let next_position_id = position_id.checked_add(1).ok_or(Error::<T>::PositionIdOverflow)?;
+
+		Self::update_oracle(&mut exchange);
+		pool.update_liquidity(tick_lower, tick_upper, signed_liquidity)?;
+		let (fee_growth_inside_a, fee_growth_inside_b) =
+			pool.fee_growth_inside(tick_lower, tick_upper);
+		let pallet_account = T::pallet_account();
+		for (token, amount) in [(&exchange.token_a, amount_a), (&exchange.token_b, amount_b)] {
+			if !amount.is_zero() {
+				Self::transfer_token_from_owner(
+					&owner,
+					token.clone(),
+					pallet_account.clone(),
+					amount,
+					gas_used,
+				)?;
+			}
+		}
+		let collection = T::PositionCollection::get();
+		let positions_account = T::positions_account();
+		match T::PositionNfts::collection_owner(&collection) {
+			None => T::PositionNfts::create_collection(
+				&collection,
+				&positions_account,
+				&positions_account,
+			)?,
+			Some(collection_owner) =>
+				ensure!(collection_owner == positions_account, Error::<T>::PositionCollectionTaken),
+		}
+		T::PositionNfts::mint_into(&collection, &position_id, &owner)?;
+
+		exchange.token_a_reserve.saturating_accrue(amount_a);
+		exchange.token_b_reserve.saturating_accrue(amount_b);
+		let pool_id = exchange.pool_id;
+		Positions::<T>::insert(
+			position_id,
+			Position {
+				pool_id,
+				tick_lower,
+				tick_upper,
+				liquidity,
+				fee_growth_inside_a,
+				fee_growth_inside_b,
+				fees_owed_a: Zero::zero(),
+				fees_owed_b: Zero::zero(),
+			},
+		);
+		NextPositionId::<T>::put(next_position_id);
+		ConcentratedPools::<T>::insert(pool_id, pool);
+		Exchanges::<T>::insert((exchange.token_a.clone(), exchange.token_b.clone()), exchange);
+
+		Self::deposit_event(Event::PositionMinted(
+			position_id,
+			owner,
+			pool_id,
+			tick_lower,
+			tick_upper,
+			liquidity,
+			amount_a,
+			amount_b,
+		));
+		Ok(position_id)
+	}
+
+	/// Withdraws `liquidity` from position `position_id` of `owner`, paying out the tokens it
+	/// backed. A position left without liquidity is burnt with its receipt, after its fees are
+	/// paid out too.
+	pub(crate) fn do_decrease_liquidity(
+		owner: AccountIdOf<T>,
+		position_id: PositionId,
+		liquidity: u128,
+		(amount_a_min, amount_b_min): (BalanceOf<T>, BalanceOf<T>),
+		gas_used: &mut Weight,
+	) -> DispatchResult {
+		ensure!(!liquidity.is_zero(), Error::<T>::LiquidityAmountIsZero);
+		let mut position = Self::owned_position(&owner, position_id)?;
+		ensure!(liquidity <= position.liquidity, Error::<T>::ProviderLiquidityTooLow);
+		let (mut exchange, mut pool) = Self::concentrated_pool(position.pool_id)?;
+		let lower = sqrt_price_at_tick(position.tick_lower).ok_or(Error::<T>::Overflow)?;
+		let upper = sqrt_price_at_tick(position.tick_upper).ok_or(Error::<T>::Overflow)?;
+		let (amount_a, amount_b) =
+			amounts_for_liquidity(pool.sqrt_price, lower, upper, liquidity, Rounding::Down)
+				.ok_or(Error::<T>::Overflow)?;
+		let (amount_a, amount_b) = (to_balance::<T>(amount_a)?, to_balance::<T>(amount_b)?);
+		ensure!(amount_a >= amount_a_min, Error::<T>::MinCurrencyTooHigh);
+		ensure!(amount_b >= amount_b_min, Error::<T>::MinTokensTooHigh);
+
+		Self::update_oracle(&mut exchange);
+		position.accrue_fees(&pool)?;
+		// `liquidity` is at most the liquidity of the position, which fitted in an `i128`.
+		pool.update_liquidity(position.tick_lower, position.tick_upper, -(liquidity as i128))?;
+		position.liquidity -= liquidity;
+		exchange.token_a_reserve = exchange.token_a_reserve.saturating_sub(amount_a);
+		exchange.token_b_reserve = exchange.token_b_reserve.saturating_sub(amount_b);
+
+		let burnt = position.liquidity.is_zero();
+		let (fees_a, fees_b) = if burnt {
+			(
+				sp_std::mem::take(&mut position.fees_owed_a),
+				sp_std::mem::take(&mut position.fees_owed_b),
+			)
+		} else {
+			(Zero::zero(), Zero::zero())
+		};
+		Self::pay_out(
+			&owner,
+			&exchange,
+			(amount_a.saturating_add(fees_a), amount_b.saturating_add(fees_b)),
+			gas_used,
+		)?;
+
+		let pool_id = position.pool_id;
+		if burnt {
+			T::PositionNfts::burn(&T::PositionCollection::get(), &position_id, None)?;
+			Positions::<T>::remove(position_id);
+		} else {
+			Positions::<T>::insert(position_id, position);
+		}
+		ConcentratedPools::<T>::insert(pool_id, pool);
+		Exchanges::<T>::insert((exchange.token_a.clone(), exchange.token_b.clone()), exchange);
+
+		Self::deposit_event(Event::PositionDecreased(position_id, liquidity, amount_a, amount_b));
+		if burnt {
+			if !fees_a.is_zero() || !fees_b.is_zero() {
+				Self::deposit_event(Event::PositionFeesCollected(position_id, fees_a, fees_b));
+			}
+			Self::deposit_event(Event::PositionBurned(position_id));
+		}
+		Ok(())
+	}
+
+	/// Pays out the fees earned by position `position_id` of `owner`.
+	pub(crate) fn do_collect_position_fees(
+		owner: AccountIdOf<T>,
+		position_id: PositionId,
+		gas_used: &mut Weight,
+	) -> DispatchResult {
+		let mut position = Self::owned_position(&owner, position_id)?;
+		let (exchange, pool) = Self::concentrated_pool(position.pool_id)?;
+		position.accrue_fees(&pool)?;
+		let fees_a = sp_std::mem::take(&mut position.fees_owed_a);
+		let fees_b = sp_std::mem::take(&mut position.fees_owed_b);
+		ensure!(!fees_a.is_zero() || !fees_b.is_zero(), Error::<T>::NoPositionFees);
+
+		Self::pay_out(&owner, &exchange, (fees_a, fees_b), gas_used)?;
+		Positions::<T>::insert(position_id, position);
+
+		Self::deposit_event(Event::PositionFeesCollected(position_id, fees_a, fees_b));
+		Ok(())
+	}
+
+	/// Moves `amounts` of the tokens of `exchange` from the pallet account to `to`.
+	fn pay_out(
+		to: &AccountIdOf<T>,
+		exchange: &Exchange<T>,
+		(amount_a, amount_b): (BalanceOf<T>, BalanceOf<T>),
+		gas_used: &mut Weight,
+	) -> DispatchResult {
+		let pallet_account = T::pallet_account();
+		for (token, amount) in [(&exchange.token_a, amount_a), (&exchange.token_b, amount_b)] {
+			if !amount.is_zero() {
+				Self::transfer_token_from_owner(
+					&pallet_account,
+					token.clone(),
+					to.clone(),
+					amount,
+					gas_used,
+				)?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Swaps `amount` through `pool`, the concentrated pool of `exchange`, in the direction
+	/// given by `sells_token_a`. `amount` is sold if `exact_in`, and bought otherwise.
+	pub(crate) fn concentrated_swap(
+		pool: &mut ConcentratedPool<T>,
+		exchange: &Exchange<T>,
+		sells_token_a: bool,
+		amount: BalanceOf<T>,
+		exact_in: bool,
+		protocol_fee: Perbill,
+	) -> Result<SwapOutcome, Error<T>> {
+		let fee = (
+			exchange.fee_numerator.unique_saturated_into(),
+			exchange.fee_denominator.unique_saturated_into(),
+		);
+		pool.swap(sells_token_a, amount.unique_saturated_into(), exact_in, fee, protocol_fee)
+	}
+
+	/// Returns the amount bought from the concentrated pool of a hop when selling
+	/// `input_amount`, or the amount to sell to buy `output_amount` from it.
+	pub(crate) fn concentrated_hop_amount(
+		mut pool: ConcentratedPool<T>,
+		(exchange, sells_token_a): &(Exchange<T>, bool),
+		amount: BalanceOf<T>,
+		exact_in: bool,
+	) -> Result<BalanceOf<T>, Error<T>> {
+		let outcome = Self::concentrated_swap(
+			&mut pool,
+			exchange,
+			*sells_token_a,
+			amount,
+			exact_in,
+			Perbill::zero(),
+		)?;
+		to_balance::<T>(if exact_in { outcome.amount_out } else { outcome.amount_in })
+	}
+
+	/// Trades `input_amount` into the concentrated pool of a hop and stores its new state.
+	///
+	/// The reserves of the pool only track the tokens backing its positions, so the fees are
+	/// kept out of them. The protocol share of the fees is set aside for the treasury.
+	pub(crate) fn update_concentrated_reserves(
+		mut pool: ConcentratedPool<T>,
+		(mut exchange, sells_token_a): (Exchange<T>, bool),
+		input_amount: BalanceOf<T>,
+		output_amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let outcome = Self::concentrated_swap(
+			&mut pool,
+			&exchange,
+			sells_token_a,
+			input_amount,
+			true,
+			ProtocolFee::<T>::get(),
+		)?;
+		ensure!(
+			to_balance::<T>(outcome.amount_out)? >= output_amount,
+			Error::<T>::InsufficientOutputAmount
+		);
+		let protocol_fee = to_balance::<T>(outcome.protocol_fee)?;
+		let input_token = if sells_token_a { &exchange.token_a } else { &exchange.token_b };
+		if !protocol_fee.is_zero() {
+			ProtocolFees::<T>::mutate(input_token, |fees| fees.saturating_accrue(protocol_fee));
+		}
+		let principal = input_amount.saturating_sub(to_balance::<T>(outcome.fee)?);
+		let (input_reserve, output_reserve) = if sells_token_a {
+			(&mut exchange.token_a_reserve, &mut exchange.token_b_reserve)
+		} else {
+			(&mut exchange.token_b_reserve, &mut exchange.token_a_reserve)
+		};
+		input_reserve.saturating_accrue(principal);
+		output_reserve.saturating_reduce(output_amount);
+
+		ConcentratedPools::<T>::insert(exchange.pool_id, pool);
+		Exchanges::<T>::insert((exchange.token_a.clone(), exchange.token_b.clone()), exchange);
+		Ok(())
+	}
+}
@@ -1,45 +1,135 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod concentrated;
+pub mod migration;
+mod payment;
+mod stable;
+mod types;
 pub mod weights;
-use frame_support::traits::Currency;
+pub use concentrated::{ConcentratedPool, Position, PositionId, Tick, MAX_TICK, MIN_TICK};
+use frame_support::{sp_runtime::traits::AccountIdConversion, traits::Currency};
 pub use pallet::*;
+pub use payment::{DexAdapter, FeeSwapOf, NO_FEE_POOL};
 use sp_std::prelude::*;
+pub use stable::{StablePool, MAX_AMPLIFICATION, MAX_AMPLIFICATION_CHANGE};
+pub use types::{PoolInfo, Quote, TwapPrice};
 pub use weights::WeightInfo;
-// use pallet_contracts::ExecReturnValue;
-use frame_support::sp_runtime::traits::AccountIdConversion;
+const LOG_TARGET: &str = "runtime::dex";
+
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
 type AssetIdOf<T> = <T as Config>::AssetId;
 type AssetBalanceOf<T> = <T as Config>::AssetBalance;
-type ContractsBalanceOf<T> =
-	<<T as pallet_contracts::Config>::Currency as frame_support::traits::Currency<
-		<T as frame_system::Config>::AccountId,
-	>>::Balance;
-// (sold_token_amount, currency_amount, bought_token_amount)
-type AssetToAssetPrice<T> = (BalanceOf<T>, BalanceOf<T>);
+pub type TokenKindOf<T> = TokenKind<AssetIdOf<T>, AccountIdOf<T>>;
+/// Pair of tokens of a pool, in canonical order.
+pub type TokenPairOf<T> = (TokenKindOf<T>, TokenKindOf<T>);
+/// Identifier of a pool, assigned in creation order.
+pub type PoolId = u32;
+/// Identifier of a limit order, assigned in placement order.
+pub type OrderId = u64;
+/// State of a pool, as returned by the runtime API.
+pub type PoolInfoOf<T> = PoolInfo<AssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>>;
+/// Tokens traded through by a multi-hop swap, from the sold to the bought one.
+pub type SwapPathOf<T> =
+	frame_support::BoundedVec<TokenKindOf<T>, <T as Config>::MaxSwapPathLength>;
+
+/// How a pool prices its swaps, picked when it is created.
+#[derive(
+	Clone,
+	Copy,
+	codec::Encode,
+	codec::Decode,
+	Eq,
+	PartialEq,
+	frame_support::RuntimeDebug,
+	codec::MaxEncodedLen,
+	scale_info::TypeInfo,
+)]
+pub enum PoolKind {
+	/// Liquidity is spread over all prices and keeps the product of the reserves constant.
+	ConstantProduct,
+	/// Liquidity is provided over price ranges by positions, whose bounds are multiples of
+	/// `tick_spacing`.
+	Concentrated { tick_spacing: u32 },
+	/// Keeps the StableSwap invariant with amplification coefficient `amplification`, for tokens
+	/// pegged to each other.
+	Stable { amplification: u32 },
+}
+
+/// Upper bound on the extra weight of `hops` hops of a swap through concentrated liquidity or
+/// stable pools over constant product ones.
+fn pool_hops_weight<T: Config>(hops: u64) -> frame_support::weights::Weight {
+	<T as Config>::WeightInfo::swap_concentrated()
+		.max(<T as Config>::WeightInfo::swap_stable())
+		.saturating_sub(<T as Config>::WeightInfo::swap())
+		.saturating_mul(hops)
+}
+
+/// Upper bound on the weight of quoting a swap through `hops` hops, which works out the swap of
+/// every hop, whatever its kind of pool, without executing it.
+pub fn quote_weight<T: Config>(hops: u64) -> frame_support::weights::Weight {
+	<T as Config>::WeightInfo::swap()
+		.max(<T as Config>::WeightInfo::swap_concentrated())
+		.max(<T as Config>::WeightInfo::swap_stable())
+		.saturating_mul(hops)
+}
+
+/// Upper bound on the weight of `calls` nested calls into token contracts.
+fn contract_calls_weight<T: Config>(calls: u64) -> frame_support::weights::Weight {
+	use frame_support::traits::Get;
+
+	T::ContractCallGasLimit::get().saturating_mul(calls)
+}
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use codec::EncodeLike;
 	use frame_support::{
+		log,
 		pallet_prelude::*,
 		sp_runtime::{
-			traits::{CheckedAdd, CheckedMul, CheckedSub, Convert, Member, One, Saturating, Zero},
-			DispatchResult, FixedPointNumber, FixedPointOperand, FixedU128,
+			biguint::BigUint,
+			traits::{
+				Bounded, CheckedAdd, CheckedMul, CheckedSub, Convert, One, Saturating,
+				UniqueSaturatedInto, Zero,
+			},
+			DispatchError, DispatchResult, FixedPointNumber, FixedPointOperand, FixedU128, Perbill,
 		},
-		storage::transactional,
+		storage::with_storage_layer,
 		traits::{
 			fungibles::{Create, Destroy, Inspect, Mutate, Transfer},
-			tokens::{AssetId, Balance, WithdrawConsequence},
-			ExistenceRequirement, WithdrawReasons,
+			nonfungibles,
+			tokens::{Balance, BalanceStatus},
+			ExistenceRequirement::KeepAlive,
+			ReservableCurrency,
 		},
 		transactional, PalletId,
 	};
 
-	use frame_system::{ensure_signed, pallet_prelude::*, Origin};
+	use frame_system::{ensure_signed, pallet_prelude::*};
 	use sp_std::fmt::Debug;
 	pub const MAX_LENGTH: usize = 50;
+	/// Selector of the `FlashSwapReceiver::on_flash_swap` message called back by `flash_swap`.
+	pub const FLASH_SWAP_SELECTOR: [u8; 4] = [0xa5, 0x56, 0xb0, 0xc1];
+
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<AssetId> {
+		fn liquidity_token_id(id: u32) -> AssetId;
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	impl<AssetId: From<u32>> BenchmarkHelper<AssetId> for () {
+		fn liquidity_token_id(id: u32) -> AssetId {
+			id.into()
+		}
+	}
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + TypeInfo + pallet_contracts::Config {
@@ -50,8 +140,8 @@ pub mod pallet {
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-		/// The currency trait.
-		type Currency: Currency<Self::AccountId>;
+		/// The currency trait, which also holds the keeper tips of limit orders.
+		type Currency: ReservableCurrency<Self::AccountId>;
 
 		/// The balance type for assets (i.e. tokens).
 		type AssetBalance: Balance
@@ -70,7 +160,7 @@ pub mod pallet {
 			+ TypeInfo
 			+ Clone
 			+ Debug
-			+ PartialEq
+			+ Ord
 			+ EncodeLike
 			+ Decode;
 
@@ -87,22 +177,82 @@ pub mod pallet {
 		/// Information on runtime weights.
 		type WeightInfo: WeightInfo;
 
-		/// Provider fee numerator.
+		/// Numerator of the default fee tier, which is always approved.
 		#[pallet::constant]
 		type ProviderFeeNumerator: Get<BalanceOf<Self>>;
 
-		/// Provider fee denominator.
+		/// Denominator of the default fee tier.
 		#[pallet::constant]
 		type ProviderFeeDenominator: Get<BalanceOf<Self>>;
 
-		/// Minimum currency deposit for a new exchange.
+		/// Minimum deposit of each token of a new exchange.
 		#[pallet::constant]
 		type MinDeposit: Get<BalanceOf<Self>>;
-	}
 
-	#[pallet::storage]
-	#[pallet::getter(fn something)]
-	pub type Something<T> = StorageValue<_, u32>;
+		/// Liquidity locked in the pallet account on the first deposit into an exchange, so its
+		/// liquidity tokens can never be fully redeemed.
+		#[pallet::constant]
+		type MinimumLiquidity: Get<AssetBalanceOf<Self>>;
+
+		/// Gas limit of a single call into a token contract.
+		///
+		/// Calls are charged for upfront at this limit and the unused part is refunded.
+		#[pallet::constant]
+		type ContractCallGasLimit: Get<Weight>;
+
+		/// Maximum number of tokens in the path of a multi-hop swap.
+		#[pallet::constant]
+		type MaxSwapPathLength: Get<u32>;
+
+		/// Maximum length of the data passed through a flash swap to its receiver.
+		#[pallet::constant]
+		type MaxFlashSwapDataLength: Get<u32>;
+
+		/// Maximum number of blocks a limit order can stay open for.
+		#[pallet::constant]
+		type MaxOrderLifetime: Get<Self::BlockNumber>;
+
+		/// Minimum number of blocks between two observations of the price oracle of a pool.
+		#[pallet::constant]
+		type ObservationPeriod: Get<Self::BlockNumber>;
+
+		/// Number of observations kept per pool. Together with `ObservationPeriod`, it bounds the
+		/// longest window the price oracle can average over.
+		#[pallet::constant]
+		type MaxObservations: Get<u32>;
+
+		/// Non-fungible tokens minted as receipts of concentrated liquidity positions. Whoever
+		/// owns the receipt of a position owns the position.
+		type PositionNfts: nonfungibles::Inspect<Self::AccountId, ItemId = PositionId>
+			+ nonfungibles::Mutate<Self::AccountId>
+			+ nonfungibles::Create<Self::AccountId>;
+
+		/// Collection of the position receipts. It is created on the first position, owned by
+		/// the positions sub-account of the pallet, which has to be endowed to pay its deposits.
+		type PositionCollection: Get<
+			<Self::PositionNfts as nonfungibles::Inspect<Self::AccountId>>::CollectionId,
+		>;
+
+		/// Maximum number of ticks bounding positions in a concentrated liquidity pool.
+		#[pallet::constant]
+		type MaxTicks: Get<u32>;
+
+		/// Maximum number of tokens in a stable pool.
+		#[pallet::constant]
+		type MaxStableTokens: Get<u32>;
+
+		/// Origin allowed to set the protocol fee, to approve fee tiers and to ramp the
+		/// amplification coefficient of stable pools.
+		type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Account of the treasury, which receives the protocol fees.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Helper trait for benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -111,10 +261,23 @@ pub mod pallet {
 		ExchangeCreated(AssetIdOf<T>, AssetIdOf<T>),
 		/// Liquidity was added to an exchange [provider_id, asset_id, currency_amount,
 		/// token_amount, liquidity_minted]
-		LiquidityAdded(AccountIdOf<T>, AccountIdOf<T>, BalanceOf<T>, BalanceOf<T>),
-		/// Liquidity was removed from an exchange [provider_id, asset_id, currency_amount,
-		/// token_amount, liquidity_amount]
-		LiquidityRemoved(T::AccountId, AccountIdOf<T>, AccountIdOf<T>, BalanceOf<T>, BalanceOf<T>),
+		LiquidityAdded(
+			AccountIdOf<T>,
+			TokenKindOf<T>,
+			BalanceOf<T>,
+			BalanceOf<T>,
+			AssetBalanceOf<T>,
+		),
+		/// Liquidity was removed from an exchange [provider_id, asset_id, token_id,
+		/// currency_amount, token_amount, liquidity_amount]
+		LiquidityRemoved(
+			T::AccountId,
+			TokenKindOf<T>,
+			TokenKindOf<T>,
+			BalanceOf<T>,
+			BalanceOf<T>,
+			AssetBalanceOf<T>,
+		),
 		/// Currency was traded for an asset [asset_id, buyer_id, recipient_id, currency_amount,
 		/// token_amount]
 		CurrencyTradedForAsset(
@@ -133,20 +296,102 @@ pub mod pallet {
 			BalanceOf<T>,
 			AssetBalanceOf<T>,
 		),
-		AssetToAssetPriceCalculated(AccountIdOf<T>, BalanceOf<T>, BalanceOf<T>),
-
-		TokenTransferred(T::AccountId, T::AccountId, BalanceOf<T>),
+		/// Tokens were swapped along a path of exchanges [buyer_id, path, sold_token_amount,
+		/// bought_token_amount]
+		PathSwapped(AccountIdOf<T>, SwapPathOf<T>, BalanceOf<T>, BalanceOf<T>),
+		/// A new pool was created [pool_id, creator_id, token_a, token_b, liquidity_token_id]
+		PoolCreated(PoolId, AccountIdOf<T>, TokenKindOf<T>, TokenKindOf<T>, AssetIdOf<T>),
+		/// Tokens were moved by the pallet [token, recipient_id, amount]
+		TokenTransferred(TokenKindOf<T>, T::AccountId, BalanceOf<T>),
+		/// The share of swap fees diverted to the treasury was set [protocol_fee]
+		ProtocolFeeSet(Perbill),
+		/// A fee tier was approved for new pools [fee_numerator, fee_denominator]
+		FeeTierAdded(BalanceOf<T>, BalanceOf<T>),
+		/// A fee tier was withdrawn for new pools [fee_numerator, fee_denominator]
+		FeeTierRemoved(BalanceOf<T>, BalanceOf<T>),
+		/// Accrued protocol fees were sent to the treasury [token, amount]
+		ProtocolFeesCollected(TokenKindOf<T>, BalanceOf<T>),
+		/// Tokens were lent out of a pool and paid back within a flash swap [sender_id,
+		/// receiver_id, token_a, token_b, token_a_out, token_b_out, token_a_in, token_b_in]
+		FlashSwapped(
+			AccountIdOf<T>,
+			AccountIdOf<T>,
+			TokenKindOf<T>,
+			TokenKindOf<T>,
+			BalanceOf<T>,
+			BalanceOf<T>,
+			BalanceOf<T>,
+			BalanceOf<T>,
+		),
+		/// A limit order was placed [order_id, owner_id, sell_token, buy_token, amount,
+		/// min_output, tip, expiry]
+		OrderPlaced(
+			OrderId,
+			AccountIdOf<T>,
+			TokenKindOf<T>,
+			TokenKindOf<T>,
+			BalanceOf<T>,
+			BalanceOf<T>,
+			BalanceOf<T>,
+			T::BlockNumber,
+		),
+		/// A limit order was filled by a keeper [order_id, keeper_id, output_amount, tip]
+		OrderFilled(OrderId, AccountIdOf<T>, BalanceOf<T>, BalanceOf<T>),
+		/// A limit order was cancelled by its owner [order_id]
+		OrderCancelled(OrderId),
+		/// An expired limit order was given back to its owner [order_id]
+		OrderExpired(OrderId),
+		/// A concentrated liquidity position was minted [position_id, owner_id, pool_id,
+		/// tick_lower, tick_upper, liquidity, token_a_amount, token_b_amount]
+		PositionMinted(
+			PositionId,
+			AccountIdOf<T>,
+			PoolId,
+			i32,
+			i32,
+			u128,
+			BalanceOf<T>,
+			BalanceOf<T>,
+		),
+		/// Liquidity was withdrawn from a position [position_id, liquidity, token_a_amount,
+		/// token_b_amount]
+		PositionDecreased(PositionId, u128, BalanceOf<T>, BalanceOf<T>),
+		/// The fees earned by a position were collected [position_id, token_a_amount,
+		/// token_b_amount]
+		PositionFeesCollected(PositionId, BalanceOf<T>, BalanceOf<T>),
+		/// A position without liquidity left was burnt with its receipt [position_id]
+		PositionBurned(PositionId),
+		/// Liquidity was deposited into a stable pool [provider, pool_id, amounts, liquidity]
+		StableLiquidityAdded(
+			AccountIdOf<T>,
+			PoolId,
+			BoundedVec<BalanceOf<T>, T::MaxStableTokens>,
+			AssetBalanceOf<T>,
+		),
+		/// Liquidity was withdrawn from a stable pool [provider, pool_id, amounts, liquidity]
+		StableLiquidityRemoved(
+			AccountIdOf<T>,
+			PoolId,
+			BoundedVec<BalanceOf<T>, T::MaxStableTokens>,
+			AssetBalanceOf<T>,
+		),
+		/// The amplification coefficient of a stable pool started ramping [pool_id,
+		/// amplification, future_amplification, future_block]
+		AmplificationRamped(PoolId, u32, u32, T::BlockNumber),
+		/// A transaction fee was bought from the pool of the asset it is paid in [payer_id,
+		/// asset_id, asset_amount, fee]
+		TransactionFeeSwapped(AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>, BalanceOf<T>),
+		/// The overcharged part of a transaction fee was sold back into the pool of the asset it
+		/// was paid in and refunded [payer_id, asset_id, asset_amount, refund]
+		TransactionFeeRefunded(AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>, BalanceOf<T>),
 		/// Event to display when call is made from the extrinsic to a smart contract
 		CalledContractFromPallet(T::AccountId),
 		/// Event to display when call is made from a smart contract to the extrinsic
 		CalledPalletFromContract(u32),
-		TotalSupply(T::AccountId, BalanceOf<T>),
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
-		/// Asset with the specified ID does not exist
-		AssetNotFound,
 		/// Exchange for the given asset already exists
 		ExchangeAlreadyExists,
 		InsufficientOutputAmount,
@@ -157,29 +402,20 @@ pub mod pallet {
 		TokenIdTaken,
 		/// Not enough free balance to add liquidity or perform trade
 		BalanceTooLow,
-		/// Not enough tokens to add liquidity or perform trade
-		NotEnoughTokens,
 		/// Specified account doesn't own enough liquidity in the exchange
 		ProviderLiquidityTooLow,
-		/// No exchange found for the given `asset_id`
-		ExchangeNotFound,
 		/// Zero value provided for trade amount parameter
 		TradeAmountIsZero,
 		/// Zero value provided for `token_amount` parameter
 		TokenAmountIsZero,
-		ZeroReserve,
 		/// Zero value provided for `max_tokens` parameter
 		MaxTokensIsZero,
-		InsufficientCurrencyReserve,
 		/// Zero value provided for `currency_amount` parameter
 		CurrencyAmountIsZero,
-		/// Value provided for `currency_amount` parameter is too high
-		CurrencyAmountTooHigh,
 		/// Value provided for `currency_amount` parameter is too low
 		CurrencyAmountTooLow,
 		/// Zero value provided for `min_liquidity` parameter
 		MinLiquidityIsZero,
-		InsufficientTokenReserve,
 		/// Value provided for `max_tokens` parameter is too low
 		MaxTokensTooLow,
 		/// Value provided for `min_liquidity` parameter is too high
@@ -194,28 +430,93 @@ pub mod pallet {
 		MinCurrencyTooHigh,
 		/// Value provided for `min_tokens` parameter is too high
 		MinTokensTooHigh,
-		/// Value provided for `max_currency` parameter is too low
-		MaxCurrencyTooLow,
 		/// Value provided for `min_bought_tokens` parameter is too high
 		MinBoughtTokensTooHigh,
 		/// Value provided for `max_sold_tokens` parameter is too low
 		MaxSoldTokensTooLow,
 		/// There is not enough liquidity in the exchange to perform trade
 		NotEnoughLiquidity,
-		UnableToFetchTotalLiquidity,
 		/// Overflow occurred
 		Overflow,
-		/// Underflow occurred
-		Underflow,
 		/// Deadline specified for the operation has passed
 		DeadlinePassed,
-		InputTooLarge,
-		ContractNotFound,
 		TokenTransferFailed,
+		/// Initial deposit does not exceed the minimum liquidity locked in the exchange
+		InitialLiquidityTooLow,
+		/// Swap path is shorter than two tokens or goes through a token twice
+		InvalidPath,
+		/// Both tokens of a pair are the same
+		IdenticalTokens,
+		/// No pool identifiers are left
+		PoolIdOverflow,
+		/// The window of the price oracle must be at least one block long
+		InvalidOracleWindow,
+		/// No observation of the pool is old enough to cover the window of the price oracle
+		OracleWindowUnavailable,
+		/// Fee numerator is not lower than its denominator
+		InvalidFeeTier,
+		/// Fee tier was not approved by governance
+		FeeTierNotApproved,
+		/// Fee tier is already approved
+		FeeTierAlreadyApproved,
+		/// No protocol fees were accrued in the token
+		NoProtocolFees,
+		/// The receiver of a flash swap trapped or reverted
+		FlashSwapCallbackFailed,
+		/// A flash swap was not paid back enough to keep the constant product of its pool
+		FlashSwapNotRepaid,
+		/// Tokens can't be moved by the pallet while a flash swap receiver runs
+		FlashSwapInProgress,
+		/// No open limit order has the given identifier
+		UnknownOrder,
+		/// Only the owner of a limit order can cancel it
+		NotOrderOwner,
+		/// The limit order can't be filled after its expiry block
+		OrderExpired,
+		/// The expiry of a limit order is in the past or beyond `MaxOrderLifetime`
+		InvalidOrderExpiry,
+		/// The pool quotes less than the limit price of the order
+		LimitPriceNotReached,
+		/// No limit order identifiers are left
+		OrderIdOverflow,
+		/// The operation is not supported by the kind of the pool
+		UnsupportedPoolKind,
+		/// Tick spacing is zero or wider than the tick range
+		InvalidTickSpacing,
+		/// Position bounds are not increasing multiples of the tick spacing within the tick range
+		InvalidTickRange,
+		/// The price of a new concentrated pool is outside of the tick range
+		PriceOutOfRange,
+		/// The pool has no room for another tick bounding positions
+		TooManyTicks,
+		/// The amounts provided back no liquidity in the range of the position
+		PositionLiquidityIsZero,
+		/// No position has the given identifier
+		UnknownPosition,
+		/// Only the owner of the receipt of a position can manage it
+		NotPositionOwner,
+		/// The position earned no fees since they were last collected
+		NoPositionFees,
+		/// No position identifiers are left
+		PositionIdOverflow,
+		/// The collection of position receipts is owned by another account
+		PositionCollectionTaken,
+		/// A stable pool has fewer than two tokens, more than `MaxStableTokens` or twice the
+		/// same one
+		InvalidStableTokens,
+		/// The amounts don't match the tokens of the stable pool one to one
+		StableAmountsMismatch,
+		/// The amplification coefficient is zero or above `MAX_AMPLIFICATION`
+		InvalidAmplification,
+		/// The amplification coefficient can't be ramped by more than `MAX_AMPLIFICATION_CHANGE`
+		AmplificationChangeTooLarge,
+		/// The amplification coefficient can only be ramped towards a future block
+		InvalidAmplificationRamp,
 	}
 
 	pub trait ConfigHelper: Config {
 		fn pallet_account() -> AccountIdOf<Self>;
+		fn positions_account() -> AccountIdOf<Self>;
 		// fn currency_to_asset(curr_balance: BalanceOf<Self>) -> BalanceOf<Self>;
 		fn asset_to_currency(asset_balance: AssetBalanceOf<Self>) -> BalanceOf<Self>;
 		fn net_amount_numerator() -> BalanceOf<Self>;
@@ -227,6 +528,11 @@ pub mod pallet {
 			Self::PalletId::get().into_account_truncating()
 		}
 
+		#[inline(always)]
+		fn positions_account() -> AccountIdOf<Self> {
+			Self::PalletId::get().into_sub_account_truncating(b"positions")
+		}
+
 		// #[inline(always)]
 		// fn currency_to_asset(curr_balance: BalanceOf<T>) -> BalanceOf<T> {
 		// 	Self::CurrencyToAssetBalance::convert(curr_balance)
@@ -245,14 +551,62 @@ pub mod pallet {
 		}
 	}
 
+	/// A pool between two tokens, stored under the canonical order of its pair.
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 	pub struct Exchange<T: Config> {
-		pub token_a: T::AccountId,
+		pub pool_id: PoolId,
+		pub token_a: TokenKindOf<T>,
 		pub token_a_reserve: BalanceOf<T>,
 		pub token_b_reserve: BalanceOf<T>,
-		pub token_b: T::AccountId,
+		pub token_b: TokenKindOf<T>,
 		pub fee_numerator: BalanceOf<T>,
 		pub fee_denominator: BalanceOf<T>,
+		pub liquidity_token_id: AssetIdOf<T>,
+		/// Sum of the price of `token_a` in `token_b` at the start of every block so far, wrapping
+		/// around on overflow.
+		pub price_a_cumulative: FixedU128,
+		/// Sum of the price of `token_b` in `token_a` at the start of every block so far, wrapping
+		/// around on overflow.
+		pub price_b_cumulative: FixedU128,
+		/// Block the cumulative prices were last updated in.
+		pub last_updated: T::BlockNumber,
+	}
+
+	/// Cumulative prices of a pool, recorded at the start of a block.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct Observation<BlockNumber> {
+		pub block: BlockNumber,
+		pub price_a_cumulative: FixedU128,
+		pub price_b_cumulative: FixedU128,
+	}
+
+	/// An order selling `amount` of `sell_token` for at least `min_output` of `buy_token`, which
+	/// keepers fill through the pool between the two tokens.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct LimitOrder<T: Config> {
+		pub owner: AccountIdOf<T>,
+		pub sell_token: TokenKindOf<T>,
+		pub buy_token: TokenKindOf<T>,
+		/// Sold tokens, escrowed in the pallet account.
+		pub amount: BalanceOf<T>,
+		pub min_output: BalanceOf<T>,
+		/// Native currency reserved from the owner, paid to the keeper filling the order.
+		pub tip: BalanceOf<T>,
+		/// Last block the order can be filled in.
+		pub expiry: T::BlockNumber,
+	}
+
+	impl<T: Config> LimitOrder<T> {
+		/// Price of the sold token in the bought one the order asks for at least.
+		pub fn limit_price(&self) -> FixedU128 {
+			FixedU128::saturating_from_rational(self.min_output, self.amount)
+		}
+
+		/// Key of order `order_id` in the `OrderBook` of its tokens: its limit price and then its
+		/// id, big-endian so that the keys sort like the values.
+		pub fn book_key(&self, order_id: OrderId) -> ([u8; 16], [u8; 8]) {
+			(self.limit_price().into_inner().to_be_bytes(), order_id.to_be_bytes())
+		}
 	}
 
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -261,202 +615,540 @@ pub mod pallet {
 		FixedOutput { max_input: InputBalance, output_amount: OutputBalance },
 	}
 
+	/// A token traded by the pallet, together with the backend that moves it.
+	///
+	/// The ordering of tokens decides which one of a pair becomes the `token_a` of its pool.
+	#[derive(
+		Clone, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug, MaxEncodedLen, TypeInfo,
+	)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub enum TokenKind<AssetId, AccountId> {
+		/// The native currency, moved through `T::Currency`.
+		Native,
+		/// An asset moved through `T::Assets`.
+		Asset(AssetId),
+		/// A PSP22 token contract, moved through `pallet_contracts`.
+		Contract(AccountId),
+	}
+
 	type ExchangeOf<T> = Exchange<T>;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
-	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	/// Pools by their pair of tokens, in canonical order.
 	#[pallet::storage]
 	#[pallet::getter(fn exchanges)]
 	pub(super) type Exchanges<T: Config> =
-		StorageMap<_, Twox64Concat, (AccountIdOf<T>, AccountIdOf<T>), Exchange<T>, OptionQuery>;
+		StorageMap<_, Twox64Concat, TokenPairOf<T>, Exchange<T>, OptionQuery>;
+
+	/// Identifier of the next pool to be created.
+	#[pallet::storage]
+	pub(super) type NextPoolId<T: Config> = StorageValue<_, PoolId, ValueQuery>;
+
+	/// Canonical pair of tokens of every pool.
+	#[pallet::storage]
+	#[pallet::getter(fn pools)]
+	pub(super) type Pools<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, TokenPairOf<T>, OptionQuery>;
+
+	/// Recent observations of the price oracle of every pool, oldest first.
+	#[pallet::storage]
+	pub(super) type Observations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		PoolId,
+		BoundedVec<Observation<T::BlockNumber>, T::MaxObservations>,
+		ValueQuery,
+	>;
+
+	/// Share of every swap fee diverted to the treasury instead of the liquidity providers.
+	#[pallet::storage]
+	#[pallet::getter(fn protocol_fee)]
+	pub(super) type ProtocolFee<T: Config> = StorageValue<_, Perbill, ValueQuery>;
+
+	/// Fee tiers new pools may pick from, besides the default one, as `(numerator, denominator)`.
+	#[pallet::storage]
+	pub(super) type FeeTiers<T: Config> =
+		StorageMap<_, Blake2_128Concat, (BalanceOf<T>, BalanceOf<T>), (), OptionQuery>;
+
+	/// Protocol fees accrued in every token, waiting to be sent to the treasury.
+	#[pallet::storage]
+	pub(super) type ProtocolFees<T: Config> =
+		StorageMap<_, Blake2_128Concat, TokenKindOf<T>, BalanceOf<T>, ValueQuery>;
+
+	/// Whether the receiver of a flash swap is running, during which the pallet moves no tokens.
+	#[pallet::storage]
+	pub(super) type FlashSwapInProgress<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Identifier of the next limit order.
+	#[pallet::storage]
+	pub(super) type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	/// Open limit orders.
+	#[pallet::storage]
+	#[pallet::getter(fn orders)]
+	pub(super) type Orders<T: Config> =
+		StorageMap<_, Twox64Concat, OrderId, LimitOrder<T>, OptionQuery>;
+
+	/// Open limit orders by their sold and bought tokens, their limit price and their id.
+	///
+	/// The price and the id are stored big-endian and unhashed, so that the orders of a pair of
+	/// tokens are iterated from the lowest limit price, and the oldest first at the same price.
+	/// See [`LimitOrder::book_key`].
+	#[pallet::storage]
+	pub(super) type OrderBook<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, TokenKindOf<T>>,
+			NMapKey<Blake2_128Concat, TokenKindOf<T>>,
+			NMapKey<Identity, [u8; 16]>,
+			NMapKey<Identity, [u8; 8]>,
+		),
+		(),
+		OptionQuery,
+	>;
+
+	/// Open limit orders by their expiry block.
+	#[pallet::storage]
+	pub(super) type OrderExpiries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, OrderId, (), OptionQuery>;
+
+	/// First expiry block whose orders may not all have been given back by `on_idle`. Zero until
+	/// the first order is placed.
+	#[pallet::storage]
+	pub(super) type ExpiryCursor<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// State of the concentrated liquidity pools. Pools missing from it keep a constant product.
+	#[pallet::storage]
+	#[pallet::getter(fn concentrated_pools)]
+	pub(super) type ConcentratedPools<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, ConcentratedPool<T>, OptionQuery>;
+
+	/// Identifier of the next concentrated liquidity position.
+	#[pallet::storage]
+	pub(super) type NextPositionId<T: Config> = StorageValue<_, PositionId, ValueQuery>;
+
+	/// Open concentrated liquidity positions.
+	#[pallet::storage]
+	#[pallet::getter(fn positions)]
+	pub(super) type Positions<T: Config> =
+		StorageMap<_, Twox64Concat, PositionId, Position<T>, OptionQuery>;
+
+	/// State of the stable pools, keyed by the identifier of the pool of their first pair.
+	#[pallet::storage]
+	#[pallet::getter(fn stable_pools)]
+	pub(super) type StablePools<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, StablePool<T>, OptionQuery>;
+
+	/// Stable pool every pair of tokens of a stable pool belongs to, keyed by the pool of the
+	/// pair.
+	#[pallet::storage]
+	pub(super) type StablePairs<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, PoolId, OptionQuery>;
+
+	/// Pools containing a token, keyed by the token and the other token of the pool.
+	#[pallet::storage]
+	pub(super) type PoolsByToken<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		TokenKindOf<T>,
+		Blake2_128Concat,
+		TokenKindOf<T>,
+		PoolId,
+		OptionQuery,
+	>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Genesis pools: token_a, token_b, liquidity_token_id, token_a_amount, token_b_amount,
+		/// provider. They charge the default fee tier and are funded by their provider.
+		///
+		/// Liquidity tokens are minted to the pallet account, which must be endowed for them to
+		/// be created.
+		#[allow(clippy::type_complexity)]
+		pub pools: Vec<(
+			TokenKindOf<T>,
+			TokenKindOf<T>,
+			AssetIdOf<T>,
+			BalanceOf<T>,
+			BalanceOf<T>,
+			T::AccountId,
+		)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { pools: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (token_a, token_b, liquidity_token_id, token_a_amount, token_b_amount, provider) in
+				&self.pools
+			{
+				Pallet::<T>::create_exchange(
+					frame_system::RawOrigin::Signed(provider.clone()).into(),
+					token_a.clone(),
+					token_b.clone(),
+					liquidity_token_id.clone(),
+					*token_a_amount,
+					*token_b_amount,
+					T::ProviderFeeNumerator::get(),
+					T::ProviderFeeDenominator::get(),
+					PoolKind::ConstantProduct,
+				)
+				.expect("Genesis pools should be valid and funded by their provider");
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			assert!(
+				T::ProviderFeeNumerator::get() < T::ProviderFeeDenominator::get(),
+				"The default fee tier must be lower than 100%"
+			);
+		}
 
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::expire_orders(now, remaining_weight)
+		}
+	}
+
+	// The `Call` dispatch generated for `DispatchResultWithPostInfo` calls converts into its own
+	// type, so these calls allow `clippy::useless_conversion`, which the dispatch inherits.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
+		/// Creates a pool between `token_a` and `token_b`, funded with `token_a_amount` and
+		/// `token_b_amount`.
+		///
+		/// The pair is stored in canonical order, whichever order the tokens are given in. The fee
+		/// has to be the default tier or one approved through `add_fee_tier`. Each amount must be
+		/// at least `MinDeposit`.
+		///
+		/// A `ConstantProduct` pool mints the geometric mean of the two amounts in liquidity
+		/// tokens, of which `MinimumLiquidity` stays locked in the pallet account and the rest goes
+		/// to the caller.
+		///
+		/// A `Concentrated` pool starts at the price of the two amounts, which fund a position
+		/// over the whole tick range minted to the caller. It has no liquidity token, so
+		/// `liquidity_token_id` is only recorded. A `Stable` pool of the two tokens is created as
+		/// by `create_stable_pool`.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::create_exchange())]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::create_exchange()
+				.saturating_add(<T as Config>::WeightInfo::mint_position())
+				.max(<T as Config>::WeightInfo::create_stable_pool(2))
+				.saturating_add(contract_calls_weight::<T>(4))
+		)]
+		#[allow(clippy::too_many_arguments, clippy::useless_conversion)]
 		#[transactional]
 		pub fn create_exchange(
 			origin: OriginFor<T>,
-			token_a: AccountIdOf<T>,
-			token_b: AccountIdOf<T>,
+			token_a: TokenKindOf<T>,
+			token_b: TokenKindOf<T>,
+			liquidity_token_id: AssetIdOf<T>,
 			token_a_amount: BalanceOf<T>,
 			token_b_amount: BalanceOf<T>,
 			fee_numerator: BalanceOf<T>,
 			fee_denominator: BalanceOf<T>,
-		) -> DispatchResult {
+			kind: PoolKind,
+		) -> DispatchResultWithPostInfo {
 			// -------------------------- Validation part --------------------------
 			let caller = ensure_signed(origin)?;
-			ensure!(token_b_amount >= T::MinDeposit::get(), Error::<T>::CurrencyAmountTooLow);
-			ensure!(token_a_amount > Zero::zero(), Error::<T>::TokenAmountIsZero);
+			let mut gas_used = Weight::zero();
+			let (pair, is_canonical) = Self::canonical_pair(&token_a, &token_b)?;
+			let (pair_a_amount, pair_b_amount) = if is_canonical {
+				(token_a_amount, token_b_amount)
+			} else {
+				(token_b_amount, token_a_amount)
+			};
+			ensure!(
+				!pair_a_amount.is_zero() && !pair_b_amount.is_zero(),
+				Error::<T>::TokenAmountIsZero
+			);
+			ensure!(
+				pair_a_amount.min(pair_b_amount) >= T::MinDeposit::get(),
+				Error::<T>::CurrencyAmountTooLow
+			);
+			ensure!(
+				Self::is_fee_tier_approved(fee_numerator, fee_denominator),
+				Error::<T>::FeeTierNotApproved
+			);
+			ensure!(!Exchanges::<T>::contains_key(&pair), Error::<T>::ExchangeAlreadyExists);
+			let provider_liquidity = match kind {
+				PoolKind::ConstantProduct => {
+					ensure!(
+						!T::AssetRegistry::asset_exists(liquidity_token_id.clone()),
+						Error::<T>::TokenIdTaken
+					);
+					// The geometric mean of the amounts, which doesn't depend on the order of the
+					// tokens.
+					let initial_liquidity =
+						FixedU128::checked_from_rational(pair_b_amount, pair_a_amount)
+							.and_then(FixedU128::try_sqrt)
+							.and_then(|sqrt_price| sqrt_price.checked_mul_int(pair_a_amount))
+							.ok_or(Error::<T>::Overflow)?;
+					T::CurrencyToAssetBalance::convert(initial_liquidity)
+						.checked_sub(&T::MinimumLiquidity::get())
+						.filter(|liquidity| !liquidity.is_zero())
+						.ok_or(Error::<T>::InitialLiquidityTooLow)?
+				},
+				PoolKind::Concentrated { tick_spacing } => {
+					ensure!(
+						tick_spacing > 0 && tick_spacing <= MAX_TICK as u32,
+						Error::<T>::InvalidTickSpacing
+					);
+					Zero::zero()
+				},
+				PoolKind::Stable { amplification } => {
+					Self::do_create_stable_pool(
+						caller,
+						vec![token_a, token_b],
+						vec![token_a_amount, token_b_amount],
+						liquidity_token_id,
+						(fee_numerator, fee_denominator),
+						amplification,
+						&mut gas_used,
+					)?;
+					let weight = <T as Config>::WeightInfo::create_stable_pool(2);
+					return Ok(Some(weight.saturating_add(gas_used)).into())
+				},
+			};
+			Self::check_enough_currency(&caller, &token_a, token_a_amount, &mut gas_used)?;
+			Self::check_enough_currency(&caller, &token_b, token_b_amount, &mut gas_used)?;
+			let pool_id = NextPoolId::<T>::get();
+			let next_pool_id = pool_id.checked_add(1).ok_or(Error::<T>::PoolIdOverflow)?;
 
 			// -------------------------- Update storage ---------------------------
+			if kind == PoolKind::ConstantProduct {
+				let pallet_account = T::pallet_account();
+				T::AssetRegistry::create(
+					liquidity_token_id.clone(),
+					pallet_account.clone(),
+					false,
+					<AssetBalanceOf<T>>::one(),
+				)?;
+				T::AssetRegistry::mint_into(
+					liquidity_token_id.clone(),
+					&pallet_account,
+					T::MinimumLiquidity::get(),
+				)?;
+			}
+
+			NextPoolId::<T>::put(next_pool_id);
+			Self::index_pool(pool_id, &pair);
+
+			let (pair_a, pair_b) = pair;
 			let exchange = Exchange {
-				token_a: token_a.clone(),
+				pool_id,
+				token_a: pair_a.clone(),
 				token_a_reserve: <BalanceOf<T>>::zero(),
 				token_b_reserve: <BalanceOf<T>>::zero(),
-				token_b: token_b.clone(),
+				token_b: pair_b.clone(),
 				fee_numerator,
 				fee_denominator,
+				liquidity_token_id: liquidity_token_id.clone(),
+				price_a_cumulative: Zero::zero(),
+				price_b_cumulative: Zero::zero(),
+				last_updated: <frame_system::Pallet<T>>::block_number(),
+			};
+			Self::record_observation(&exchange);
+
+			let weight = if let PoolKind::Concentrated { tick_spacing } = kind {
+				Self::create_concentrated_pool(
+					exchange,
+					tick_spacing,
+					pair_a_amount,
+					pair_b_amount,
+					caller.clone(),
+					&mut gas_used,
+				)?;
+				<T as Config>::WeightInfo::create_exchange()
+					.saturating_add(<T as Config>::WeightInfo::mint_position())
+			} else {
+				Self::do_add_liquidity(
+					exchange,
+					pair_a_amount,
+					pair_b_amount,
+					provider_liquidity,
+					caller.clone(),
+					&mut gas_used,
+				)?;
+				<T as Config>::WeightInfo::create_exchange()
 			};
-
-			Self::do_add_liquidity(exchange, token_b_amount, token_a_amount, caller)?;
 
 			// ---------------------------- Emit event -----------------------------
-			// Self::deposit_event(Event::ExchangeCreated(asset_id, liquidity_token_id));
-			Ok(())
+			Self::deposit_event(Event::PoolCreated(
+				pool_id,
+				caller,
+				pair_a,
+				pair_b,
+				liquidity_token_id,
+			));
+			Ok(Some(weight.saturating_add(gas_used)).into())
 		}
 
+		/// Sells exactly `input_amount` of `token_a` for at least `min_output` of `token_b`.
+		///
+		/// Trades in either direction of the pool between the two tokens.
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::create_exchange())]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::swap()
+				.saturating_add(pool_hops_weight::<T>(1))
+				.saturating_add(contract_calls_weight::<T>(2))
+		)]
 		#[transactional]
+		#[allow(clippy::useless_conversion)]
 		pub fn swap(
 			origin: OriginFor<T>,
-			token_a: AccountIdOf<T>,
-			token_b: AccountIdOf<T>,
+			token_a: TokenKindOf<T>,
+			token_b: TokenKindOf<T>,
 			input_amount: BalanceOf<T>,
 			min_output: BalanceOf<T>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
 
 			let pallet_account = T::pallet_account();
 
+			ensure!(input_amount > Zero::zero(), Error::<T>::TradeAmountIsZero);
+
 			// Get the exchange info from storage
-			let exchange = Self::exchanges((token_a.clone(), token_b.clone()))
-				.ok_or(Error::<T>::ExchangeDoesNotExist)?;
+			let hop = Self::get_directed_exchange(&token_a, &token_b)?;
 
 			// Calculate the output amount
-			let output_amount = Self::get_output_amount(
-				&input_amount,
-				&exchange.token_a_reserve,
-				&exchange.token_b_reserve,
-				&exchange.fee_numerator,
-				&exchange.fee_denominator,
-			)?;
+			let output_amount = Self::hop_output_amount(&hop, input_amount)?;
 
 			// Check if the output amount is greater than or equal to the minimum output
 			ensure!(output_amount >= min_output, Error::<T>::InsufficientOutputAmount);
 
 			Self::transfer_token_from_owner(
-				&token_a,
-				sender.clone(),
+				&sender,
+				token_a.clone(),
 				pallet_account.clone(),
 				input_amount,
+				&mut gas_used,
 			)?;
 
 			Self::transfer_token_from_owner(
-				&token_b,
-				pallet_account.clone(),
-				sender.clone(),
+				&pallet_account,
+				token_b,
+				sender,
 				output_amount,
+				&mut gas_used,
 			)?;
 
 			// Update the reserves
-			let updated_token_a = exchange
-				.token_a_reserve
-				.checked_add(&input_amount)
-				.ok_or(Error::<T>::Overflow)?;
-
-			let updated_token_b = exchange
-				.token_b_reserve
-				.checked_sub(&output_amount)
-				.ok_or(Error::<T>::Underflow)?;
-
-			Exchanges::<T>::insert(
-				(token_a, token_b),
-				Exchange {
-					token_a_reserve: updated_token_a,
-					token_b_reserve: updated_token_b,
-					..exchange
-				},
-			);
+			Self::update_hop_reserves(hop, input_amount, output_amount, &mut gas_used)?;
 
-			Ok(().into())
+			Ok(Some(<T as Config>::WeightInfo::swap().saturating_add(gas_used)).into())
 		}
 
+		/// Sells `sold_token_a` for `sold_token_b` and then `bought_token_a` for
+		/// `bought_token_b`.
+		///
+		/// The two exchanges have to share their intermediate token, i.e. `sold_token_b` and
+		/// `bought_token_a` must be the same. Prefer `swap_exact_in_along_path` and
+		/// `swap_exact_out_along_path`, which take paths of any length.
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as Config>::WeightInfo::create_exchange())]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::asset_to_asset()
+				.saturating_add(pool_hops_weight::<T>(2))
+				.saturating_add(contract_calls_weight::<T>(2))
+		)]
 		#[transactional]
+		#[allow(clippy::useless_conversion)]
 		pub fn asset_to_asset(
 			origin: OriginFor<T>,
-			sold_token_a: AccountIdOf<T>,
-			sold_token_b: AccountIdOf<T>,
-			bought_token_a: AccountIdOf<T>,
-			bought_token_b: AccountIdOf<T>,
+			sold_token_a: TokenKindOf<T>,
+			sold_token_b: TokenKindOf<T>,
+			bought_token_a: TokenKindOf<T>,
+			bought_token_b: TokenKindOf<T>,
 			amount: TradeAmount<BalanceOf<T>, BalanceOf<T>>,
 		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
-
-			// Fetching the exchanges
-			let sold_asset_exchange = Self::exchanges((sold_token_a.clone(), sold_token_b.clone()))
-				.ok_or(Error::<T>::ExchangeDoesNotExist)?;
-
-			let bought_asset_exchange =
-				Self::exchanges((bought_token_a.clone(), bought_token_b.clone()))
-					.ok_or(Error::<T>::ExchangeDoesNotExist)?;
+			let buyer = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
 
 			// Verifying the trade amount
 			Self::check_trade_amount(&amount)?;
+			ensure!(sold_token_b == bought_token_a, Error::<T>::InvalidPath);
 
-			// Fetching the sold and bought token amounts
-			let (sold_token_amount, bought_token_amount) = Self::get_asset_to_asset_price(
-				&sold_asset_exchange,
-				&bought_asset_exchange,
-				amount,
-			)?;
+			let path: SwapPathOf<T> = vec![sold_token_a, sold_token_b, bought_token_b]
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidPath)?;
+			let hops = Self::get_path_exchanges(&path)?;
 
-			// Updating the reserves
-			let updated_sold_exchange = Self::update_reserve_after_sell(
-				&sold_asset_exchange,
-				sold_token_amount,
-				sold_token_a == sold_asset_exchange.token_a,
-			)?;
-			let updated_bought_exchange = Self::update_reserve_after_buy(
-				&bought_asset_exchange,
-				bought_token_amount,
-				bought_token_a == bought_asset_exchange.token_a,
-			)?;
+			let amounts = match amount {
+				TradeAmount::FixedInput {
+					input_amount: sold_token_amount,
+					min_output: min_bought_tokens,
+				} => {
+					let amounts = Self::get_amounts_out(&hops, sold_token_amount)?;
+					ensure!(
+						amounts[amounts.len() - 1] >= min_bought_tokens,
+						Error::<T>::MinBoughtTokensTooHigh
+					);
+					amounts
+				},
+				TradeAmount::FixedOutput {
+					max_input: max_sold_tokens,
+					output_amount: bought_token_amount,
+				} => {
+					let amounts = Self::get_amounts_in(&hops, bought_token_amount)?;
+					ensure!(amounts[0] <= max_sold_tokens, Error::<T>::MaxSoldTokensTooLow);
+					amounts
+				},
+			};
 
-			// Updating the exchanges in the storage
-			Self::update_exchange_storage(
-				(sold_token_a.clone(), sold_token_b.clone()),
-				updated_sold_exchange,
-			);
-			Self::update_exchange_storage(
-				(bought_token_a.clone(), bought_token_b.clone()),
-				updated_bought_exchange,
-			);
+			Self::do_swap_along_path(buyer, path, hops, amounts, &mut gas_used)?;
 
-			Ok(().into())
+			Ok(Some(<T as Config>::WeightInfo::asset_to_asset().saturating_add(gas_used)).into())
 		}
 
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::create_exchange())]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::remove_liquidity()
+				.saturating_add(contract_calls_weight::<T>(2))
+		)]
 		#[transactional]
+		#[allow(clippy::useless_conversion)]
 		pub fn remove_liquidity(
 			origin: OriginFor<T>,
-			token_a: AccountIdOf<T>,
-			token_b: AccountIdOf<T>,
-			liquidity_amount: BalanceOf<T>,
+			token_a: TokenKindOf<T>,
+			token_b: TokenKindOf<T>,
+			liquidity_amount: AssetBalanceOf<T>,
 			min_currency: BalanceOf<T>,
 			min_tokens: BalanceOf<T>,
 			deadline: T::BlockNumber,
 		) -> DispatchResultWithPostInfo {
 			let provider = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
 			Self::check_deadline(&deadline)?;
 			ensure!(liquidity_amount > Zero::zero(), Error::<T>::LiquidityAmountIsZero);
 			ensure!(min_currency > Zero::zero(), Error::<T>::MinCurrencyIsZero);
 			ensure!(min_tokens > Zero::zero(), Error::<T>::MinTokensIsZero);
 
-			let exchange = Self::exchanges((token_a.clone(), token_b.clone()))
-				.ok_or(Error::<T>::ExchangeDoesNotExist)?;
+			let (exchange, is_canonical) = Self::get_directed_exchange(&token_a, &token_b)?;
+			Self::ensure_constant_product(&exchange)?;
+			let (min_pair_a, min_pair_b) =
+				if is_canonical { (min_currency, min_tokens) } else { (min_tokens, min_currency) };
 
-			let total_liquidity = Self::get_total_liquidity(&exchange.token_b)?;
-			// ensure!(liquidity <= total_liquidity, Error::<T>::InsufficientLiquidity);
+			let total_liquidity =
+				T::AssetRegistry::total_issuance(exchange.liquidity_token_id.clone());
+			let provider_liquidity =
+				T::AssetRegistry::balance(exchange.liquidity_token_id.clone(), &provider);
+			ensure!(liquidity_amount <= provider_liquidity, Error::<T>::ProviderLiquidityTooLow);
 
-			// Assuming exchange.token_a_reserve, exchange.token_b_reserve, liquidity, and
-			// total_liquidity are all FixedU128 type
 			let token_a_amount =
 				FixedU128::saturating_from_rational(liquidity_amount, total_liquidity)
 					.saturating_mul_int(exchange.token_a_reserve);
@@ -465,8 +1157,8 @@ pub mod pallet {
 				FixedU128::saturating_from_rational(liquidity_amount, total_liquidity)
 					.saturating_mul_int(exchange.token_b_reserve);
 
-			ensure!(token_a_amount >= min_currency, Error::<T>::MinCurrencyTooHigh);
-			ensure!(token_b_amount >= min_tokens, Error::<T>::MinTokensTooHigh);
+			ensure!(token_a_amount >= min_pair_a, Self::min_amount_error(is_canonical));
+			ensure!(token_b_amount >= min_pair_b, Self::min_amount_error(!is_canonical));
 
 			// Now, let's perform the actual removal of liquidity
 			Self::do_remove_liquidity(
@@ -475,160 +1167,787 @@ pub mod pallet {
 				token_b_amount,
 				liquidity_amount,
 				provider,
+				&mut gas_used,
 			)?;
 
-			Ok(().into())
+			Ok(Some(<T as Config>::WeightInfo::remove_liquidity().saturating_add(gas_used)).into())
 		}
 
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity())]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::add_liquidity().saturating_add(contract_calls_weight::<T>(4))
+		)]
 		#[transactional]
+		#[allow(clippy::useless_conversion)]
 		pub fn add_liquidity(
 			origin: OriginFor<T>,
-			token_a: AccountIdOf<T>,
-			token_b: AccountIdOf<T>,
+			token_a: TokenKindOf<T>,
+			token_b: TokenKindOf<T>,
 			currency_amount: BalanceOf<T>,
-			min_liquidity: BalanceOf<T>,
+			min_liquidity: AssetBalanceOf<T>,
 			max_tokens: BalanceOf<T>,
 			deadline: T::BlockNumber,
 		) -> DispatchResultWithPostInfo {
 			// -------------------------- Validation part --------------------------
 			let caller = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
 			Self::check_deadline(&deadline)?;
 			ensure!(currency_amount > Zero::zero(), Error::<T>::CurrencyAmountIsZero);
 			ensure!(max_tokens > Zero::zero(), Error::<T>::MaxTokensIsZero);
 			ensure!(min_liquidity > Zero::zero(), Error::<T>::MinLiquidityIsZero);
 
-			let exchange = Self::exchanges((token_a.clone(), token_b.clone()))
-				.ok_or(Error::<T>::ExchangeDoesNotExist)?;
+			let hop = Self::get_directed_exchange(&token_a, &token_b)?;
+			Self::ensure_constant_product(&hop.0)?;
+			let (token_a_reserve, token_b_reserve) = Self::hop_reserves(&hop);
+			let (exchange, is_canonical) = hop;
 
-			let total_liquidity = Self::get_total_liquidity(&exchange.token_b)?;
+			let total_liquidity =
+				T::AssetRegistry::total_issuance(exchange.liquidity_token_id.clone());
 
 			// -------------------- Token/liquidity computation --------------------
 			let liquidity_to_mint =
-				FixedU128::saturating_from_rational(currency_amount, exchange.token_a_reserve)
+				FixedU128::saturating_from_rational(currency_amount, token_a_reserve)
 					.saturating_mul_int(total_liquidity);
 
 			let max_token_amount =
-				FixedU128::saturating_from_rational(currency_amount, exchange.token_a_reserve)
-					.saturating_mul_int(exchange.token_b_reserve)
+				FixedU128::saturating_from_rational(currency_amount, token_a_reserve)
+					.saturating_mul_int(token_b_reserve)
 					.saturating_add(One::one());
 
 			ensure!(max_token_amount <= max_tokens, Error::<T>::MaxTokensTooLow);
 			ensure!(liquidity_to_mint >= min_liquidity, Error::<T>::MinLiquidityTooHigh);
+			Self::check_enough_currency(&caller, &token_a, currency_amount, &mut gas_used)?;
+			Self::check_enough_currency(&caller, &token_b, max_token_amount, &mut gas_used)?;
 
+			let (pair_a_amount, pair_b_amount) = if is_canonical {
+				(currency_amount, max_token_amount)
+			} else {
+				(max_token_amount, currency_amount)
+			};
 			Self::do_add_liquidity(
 				exchange,
-				currency_amount,
+				pair_a_amount,
+				pair_b_amount,
 				liquidity_to_mint,
-				caller.clone(),
+				caller,
+				&mut gas_used,
 			)?;
 
-			Ok(().into())
+			Ok(Some(<T as Config>::WeightInfo::add_liquidity().saturating_add(gas_used)).into())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		pub(crate) fn get_exchange(asset_id: &AccountIdOf<T>) -> Result<ExchangeOf<T>, Error<T>> {
-			<Exchanges<T>>::get((asset_id.clone(), asset_id.clone()))
-				.ok_or(Error::<T>::ExchangeNotFound)
+		/// Sells exactly `sold_token_amount` of the first token of `path` for as much of its
+		/// last token as possible, trading through every exchange between consecutive tokens.
+		#[pallet::call_index(5)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::swap_exact_in_along_path(path.len() as u32)
+				.saturating_add(pool_hops_weight::<T>(path.len().saturating_sub(1) as u64))
+				.saturating_add(contract_calls_weight::<T>(2))
+		)]
+		#[transactional]
+		#[allow(clippy::useless_conversion)]
+		pub fn swap_exact_in_along_path(
+			origin: OriginFor<T>,
+			path: SwapPathOf<T>,
+			sold_token_amount: BalanceOf<T>,
+			min_bought_tokens: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let buyer = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
+			ensure!(!sold_token_amount.is_zero(), Error::<T>::TradeAmountIsZero);
+
+			let hops = Self::get_path_exchanges(&path)?;
+			let amounts = Self::get_amounts_out(&hops, sold_token_amount)?;
+			ensure!(
+				amounts[amounts.len() - 1] >= min_bought_tokens,
+				Error::<T>::MinBoughtTokensTooHigh
+			);
+
+			let hop_count = path.len() as u32;
+			Self::do_swap_along_path(buyer, path, hops, amounts, &mut gas_used)?;
+
+			Ok(Some(
+				<T as Config>::WeightInfo::swap_exact_in_along_path(hop_count)
+					.saturating_add(gas_used),
+			)
+			.into())
 		}
 
-		fn check_deadline(deadline: &T::BlockNumber) -> Result<(), Error<T>> {
-			ensure!(deadline >= &<frame_system::Pallet<T>>::block_number(), Error::DeadlinePassed);
+		/// Buys exactly `bought_token_amount` of the last token of `path` for as little of its
+		/// first token as possible, trading through every exchange between consecutive tokens.
+		#[pallet::call_index(6)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::swap_exact_out_along_path(path.len() as u32)
+				.saturating_add(pool_hops_weight::<T>(path.len().saturating_sub(1) as u64))
+				.saturating_add(contract_calls_weight::<T>(2))
+		)]
+		#[transactional]
+		#[allow(clippy::useless_conversion)]
+		pub fn swap_exact_out_along_path(
+			origin: OriginFor<T>,
+			path: SwapPathOf<T>,
+			bought_token_amount: BalanceOf<T>,
+			max_sold_tokens: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let buyer = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
+			ensure!(!bought_token_amount.is_zero(), Error::<T>::TradeAmountIsZero);
+
+			let hops = Self::get_path_exchanges(&path)?;
+			let amounts = Self::get_amounts_in(&hops, bought_token_amount)?;
+			ensure!(amounts[0] <= max_sold_tokens, Error::<T>::MaxSoldTokensTooLow);
+
+			let hop_count = path.len() as u32;
+			Self::do_swap_along_path(buyer, path, hops, amounts, &mut gas_used)?;
+
+			Ok(Some(
+				<T as Config>::WeightInfo::swap_exact_out_along_path(hop_count)
+					.saturating_add(gas_used),
+			)
+			.into())
+		}
+
+		/// Sets the share of every swap fee diverted to the treasury. Zero turns the protocol fee
+		/// off.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_protocol_fee())]
+		pub fn set_protocol_fee(origin: OriginFor<T>, protocol_fee: Perbill) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ProtocolFee::<T>::put(protocol_fee);
+			Self::deposit_event(Event::ProtocolFeeSet(protocol_fee));
 			Ok(())
 		}
 
-		pub fn transfer_token_from_owner(
-			origin: &AccountIdOf<T>,
-			contract_address: AccountIdOf<T>,
-			to: AccountIdOf<T>,
-			amount: BalanceOf<T>,
+		/// Approves the fee `fee_numerator / fee_denominator` for new pools.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_fee_tier())]
+		pub fn add_fee_tier(
+			origin: OriginFor<T>,
+			fee_numerator: BalanceOf<T>,
+			fee_denominator: BalanceOf<T>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(fee_numerator < fee_denominator, Error::<T>::InvalidFeeTier);
+			ensure!(
+				!Self::is_fee_tier_approved(fee_numerator, fee_denominator),
+				Error::<T>::FeeTierAlreadyApproved
+			);
+			FeeTiers::<T>::insert((fee_numerator, fee_denominator), ());
+			Self::deposit_event(Event::FeeTierAdded(fee_numerator, fee_denominator));
+			Ok(())
+		}
+
+		/// Withdraws the fee `fee_numerator / fee_denominator` for new pools. Existing pools keep
+		/// their fee.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_fee_tier())]
+		pub fn remove_fee_tier(
+			origin: OriginFor<T>,
+			fee_numerator: BalanceOf<T>,
+			fee_denominator: BalanceOf<T>,
 		) -> DispatchResult {
-			let method_id: [u8; 4] = [0x84, 0xa1, 0x5d, 0xa1];
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				FeeTiers::<T>::take((fee_numerator, fee_denominator)).is_some(),
+				Error::<T>::FeeTierNotApproved
+			);
+			Self::deposit_event(Event::FeeTierRemoved(fee_numerator, fee_denominator));
+			Ok(())
+		}
 
-			let gas_limit: Weight = T::BlockWeights::get().max_block;
+		/// Sends the protocol fees accrued in `token` to the treasury. Callable by anyone.
+		#[pallet::call_index(10)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::collect_protocol_fees()
+				.saturating_add(contract_calls_weight::<T>(1))
+		)]
+		#[transactional]
+		#[allow(clippy::useless_conversion)]
+		pub fn collect_protocol_fees(
+			origin: OriginFor<T>,
+			token: TokenKindOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
+			let amount = ProtocolFees::<T>::take(&token);
+			ensure!(!amount.is_zero(), Error::<T>::NoProtocolFees);
 
-			let contracts_value: ContractsBalanceOf<T> =
-				<T as pallet_contracts::Config>::Currency::free_balance(&contract_address);
+			Self::transfer_token_from_owner(
+				&T::pallet_account(),
+				token.clone(),
+				T::TreasuryAccount::get(),
+				amount,
+				&mut gas_used,
+			)?;
 
-			let mut data = method_id.to_vec();
-			data.extend(to.encode());
-			data.extend(amount.encode());
+			Self::deposit_event(Event::ProtocolFeesCollected(token, amount));
+			Ok(Some(<T as Config>::WeightInfo::collect_protocol_fees().saturating_add(gas_used))
+				.into())
+		}
 
-			let result = pallet_contracts::Pallet::<T>::bare_call(
-				origin.clone(),
-				contract_address.clone(),
-				contracts_value,
-				gas_limit,
-				None,
-				data,
-				false,
-				pallet_contracts::Determinism::Deterministic,
+		/// Lends `token_a_out` of `token_a` and `token_b_out` of `token_b` from their pool to the
+		/// `receiver` contract, then calls its `on_flash_swap` message with `gas_limit`.
+		///
+		/// The message is called on behalf of the sender with `(sender, token_a, token_b,
+		/// token_a_out, token_b_out, data)`. Before it returns, the receiver has to pay enough of
+		/// either token to the pallet account for the constant product of the pool, net of the
+		/// fee charged on what was paid, not to decrease. Otherwise the whole call is reverted.
+		#[pallet::call_index(11)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::flash_swap(data.len() as u32)
+				.saturating_add(*gas_limit)
+				.saturating_add(contract_calls_weight::<T>(6))
+		)]
+		#[allow(clippy::too_many_arguments, clippy::useless_conversion)]
+		#[transactional]
+		pub fn flash_swap(
+			origin: OriginFor<T>,
+			token_a: TokenKindOf<T>,
+			token_b: TokenKindOf<T>,
+			token_a_out: BalanceOf<T>,
+			token_b_out: BalanceOf<T>,
+			receiver: AccountIdOf<T>,
+			gas_limit: Weight,
+			data: BoundedVec<u8, T::MaxFlashSwapDataLength>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
+			ensure!(
+				!token_a_out.is_zero() || !token_b_out.is_zero(),
+				Error::<T>::TradeAmountIsZero
+			);
+			let hop = Self::get_directed_exchange(&token_a, &token_b)?;
+			Self::ensure_constant_product(&hop.0)?;
+			let (token_a_reserve, token_b_reserve) = Self::hop_reserves(&hop);
+			ensure!(
+				token_a_out < token_a_reserve && token_b_out < token_b_reserve,
+				Error::<T>::NotEnoughLiquidity
 			);
-			log::info!("result: {:?}", result.result);
 
-			if let Ok(contract_result) = result.result {
-				// Check if the contract call was successful
-				if !contract_result.flags.contains(pallet_contracts_primitives::ReturnFlags::REVERT)
-				{
-					Self::deposit_event(Event::TokenTransferred(contract_address, to, amount));
-					Ok(().into())
-				} else {
-					Err(Error::<T>::TokenTransferFailed)?
+			let pallet_account = T::pallet_account();
+			let token_a_balance = Self::token_balance(&pallet_account, &token_a, &mut gas_used)?;
+			let token_b_balance = Self::token_balance(&pallet_account, &token_b, &mut gas_used)?;
+			for (token, amount) in [(&token_a, token_a_out), (&token_b, token_b_out)] {
+				if !amount.is_zero() {
+					Self::transfer_token_from_owner(
+						&pallet_account,
+						token.clone(),
+						receiver.clone(),
+						amount,
+						&mut gas_used,
+					)?;
 				}
-			} else {
-				Err(Error::<T>::TokenTransferFailed)?
 			}
-		}
 
-		fn get_total_liquidity(token_id: &AccountIdOf<T>) -> Result<BalanceOf<T>, Error<T>> {
-			let method_id: [u8; 4] = [0x18, 0x16, 0x1d, 0x71]; // This is the method ID for the ERC-20 totalSupply function
-
-			let gas_limit: Weight = T::BlockWeights::get().max_block;
-
-			let data = method_id.to_vec();
+			let mut input = FLASH_SWAP_SELECTOR.to_vec();
+			(&sender, &token_a, &token_b, token_a_out, token_b_out, &data).encode_to(&mut input);
+			FlashSwapInProgress::<T>::put(true);
+			let output =
+				Self::call_contract_with_limit(&sender, &receiver, input, gas_limit, &mut gas_used);
+			FlashSwapInProgress::<T>::kill();
+			ensure!(output.is_some(), Error::<T>::FlashSwapCallbackFailed);
+
+			// Whatever the pallet account holds beyond what was left after lending was paid in.
+			let token_a_in = Self::token_balance(&pallet_account, &token_a, &mut gas_used)?
+				.saturating_add(token_a_out)
+				.saturating_sub(token_a_balance);
+			let token_b_in = Self::token_balance(&pallet_account, &token_b, &mut gas_used)?
+				.saturating_add(token_b_out)
+				.saturating_sub(token_b_balance);
+			Self::settle_flash_swap(hop, (token_a_in, token_b_in), (token_a_out, token_b_out))?;
+
+			Self::deposit_event(Event::FlashSwapped(
+				sender,
+				receiver,
+				token_a,
+				token_b,
+				token_a_out,
+				token_b_out,
+				token_a_in,
+				token_b_in,
+			));
+			Ok(Some(
+				<T as Config>::WeightInfo::flash_swap(data.len() as u32).saturating_add(gas_used),
+			)
+			.into())
+		}
 
-			let result = pallet_contracts::Pallet::<T>::bare_call(
-				token_id.clone(),
-				token_id.clone(),
-				Zero::zero(),
-				gas_limit,
-				None,
-				data,
-				false,
-				pallet_contracts::Determinism::Deterministic,
+		/// Places an order selling `amount` of `sell_token` for at least `min_output` of
+		/// `buy_token`, which keepers can fill until the `expiry` block.
+		///
+		/// The sold tokens are escrowed in the pallet account and `tip` is reserved from the
+		/// sender, to be paid to the keeper filling the order. Both are given back if the order is
+		/// cancelled or expires.
+		#[pallet::call_index(12)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::place_order().saturating_add(contract_calls_weight::<T>(1))
+		)]
+		#[allow(clippy::too_many_arguments, clippy::useless_conversion)]
+		#[transactional]
+		pub fn place_order(
+			origin: OriginFor<T>,
+			sell_token: TokenKindOf<T>,
+			buy_token: TokenKindOf<T>,
+			amount: BalanceOf<T>,
+			min_output: BalanceOf<T>,
+			tip: BalanceOf<T>,
+			expiry: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
+			ensure!(!amount.is_zero() && !min_output.is_zero(), Error::<T>::TradeAmountIsZero);
+			Self::get_directed_exchange(&sell_token, &buy_token)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				expiry >= now && expiry <= now.saturating_add(T::MaxOrderLifetime::get()),
+				Error::<T>::InvalidOrderExpiry
 			);
 
-			if let Ok(contract_result) = result.result {
-				if !contract_result.flags.contains(pallet_contracts_primitives::ReturnFlags::REVERT)
-				{
-					let total_liquidity: BalanceOf<T> =
-						Decode::decode(&mut &contract_result.data[..]).unwrap_or(Zero::zero());
-					Ok(total_liquidity)
-				} else {
-					Err(Error::<T>::UnableToFetchTotalLiquidity)
-				}
+			let order_id = NextOrderId::<T>::get();
+			let next_order_id = order_id.checked_add(1).ok_or(Error::<T>::OrderIdOverflow)?;
+			<T as Config>::Currency::reserve(&owner, tip).map_err(|_| Error::<T>::BalanceTooLow)?;
+			Self::transfer_token_from_owner(
+				&owner,
+				sell_token.clone(),
+				T::pallet_account(),
+				amount,
+				&mut gas_used,
+			)?;
+
+			let order = LimitOrder {
+				owner: owner.clone(),
+				sell_token: sell_token.clone(),
+				buy_token: buy_token.clone(),
+				amount,
+				min_output,
+				tip,
+				expiry,
+			};
+			let (price_key, id_key) = order.book_key(order_id);
+			OrderBook::<T>::insert((&sell_token, &buy_token, price_key, id_key), ());
+			OrderExpiries::<T>::insert(expiry, order_id, ());
+			Orders::<T>::insert(order_id, order);
+			NextOrderId::<T>::put(next_order_id);
+			if ExpiryCursor::<T>::get().is_zero() {
+				ExpiryCursor::<T>::put(now);
+			}
+
+			Self::deposit_event(Event::OrderPlaced(
+				order_id, owner, sell_token, buy_token, amount, min_output, tip, expiry,
+			));
+			Ok(Some(<T as Config>::WeightInfo::place_order().saturating_add(gas_used)).into())
+		}
+
+		/// Fills order `order_id` through the pool between its tokens, if the pool quotes at least
+		/// its limit price. Callable by anyone, who is paid the tip of the order.
+		///
+		/// The owner of the order gets the whole output of the pool.
+		#[pallet::call_index(13)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::fill_order()
+				.saturating_add(pool_hops_weight::<T>(1))
+				.saturating_add(contract_calls_weight::<T>(1))
+		)]
+		#[transactional]
+		#[allow(clippy::useless_conversion)]
+		pub fn fill_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResultWithPostInfo {
+			let keeper = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
+			let order = Orders::<T>::get(order_id).ok_or(Error::<T>::UnknownOrder)?;
+			ensure!(
+				order.expiry >= <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::OrderExpired
+			);
+
+			let hop = Self::get_directed_exchange(&order.sell_token, &order.buy_token)?;
+			let output_amount = Self::hop_output_amount(&hop, order.amount)?;
+			ensure!(output_amount >= order.min_output, Error::<T>::LimitPriceNotReached);
+
+			// The sold tokens are already in the pallet account.
+			Self::transfer_token_from_owner(
+				&T::pallet_account(),
+				order.buy_token.clone(),
+				order.owner.clone(),
+				output_amount,
+				&mut gas_used,
+			)?;
+			Self::update_hop_reserves(hop, order.amount, output_amount, &mut gas_used)?;
+			Self::remove_order(order_id, &order);
+			let unpaid_tip = <T as Config>::Currency::repatriate_reserved(
+				&order.owner,
+				&keeper,
+				order.tip,
+				BalanceStatus::Free,
+			)?;
+
+			Self::deposit_event(Event::OrderFilled(
+				order_id,
+				keeper,
+				output_amount,
+				order.tip.saturating_sub(unpaid_tip),
+			));
+			Ok(Some(<T as Config>::WeightInfo::fill_order().saturating_add(gas_used)).into())
+		}
+
+		/// Cancels order `order_id` of the sender, giving back its sold tokens and tip.
+		#[pallet::call_index(14)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::cancel_order().saturating_add(contract_calls_weight::<T>(1))
+		)]
+		#[transactional]
+		#[allow(clippy::useless_conversion)]
+		pub fn cancel_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
+			let order = Orders::<T>::get(order_id).ok_or(Error::<T>::UnknownOrder)?;
+			ensure!(order.owner == sender, Error::<T>::NotOrderOwner);
+
+			Self::refund_order(order_id, &order, &mut gas_used)?;
+
+			Self::deposit_event(Event::OrderCancelled(order_id));
+			Ok(Some(<T as Config>::WeightInfo::cancel_order().saturating_add(gas_used)).into())
+		}
+
+		/// Provides liquidity to concentrated pool `pool_id` between `tick_lower` and
+		/// `tick_upper`, and mints the receipt of the position to the sender.
+		///
+		/// Amounts are in the `token_a` and `token_b` of the pool. The position gets the most
+		/// liquidity the desired amounts back at the pool price, and only one of them is used
+		/// when the price is outside of its range.
+		#[pallet::call_index(15)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::mint_position().saturating_add(contract_calls_weight::<T>(2))
+		)]
+		#[allow(clippy::too_many_arguments, clippy::useless_conversion)]
+		#[transactional]
+		pub fn mint_position(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			tick_lower: i32,
+			tick_upper: i32,
+			token_a_amount_desired: BalanceOf<T>,
+			token_b_amount_desired: BalanceOf<T>,
+			token_a_amount_min: BalanceOf<T>,
+			token_b_amount_min: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
+			let pair = Pools::<T>::get(pool_id).ok_or(Error::<T>::ExchangeDoesNotExist)?;
+			let exchange = Exchanges::<T>::get(pair).ok_or(Error::<T>::ExchangeDoesNotExist)?;
+
+			Self::do_mint_position(
+				exchange,
+				owner,
+				(tick_lower, tick_upper),
+				(token_a_amount_desired, token_b_amount_desired),
+				(token_a_amount_min, token_b_amount_min),
+				&mut gas_used,
+			)?;
+
+			Ok(Some(<T as Config>::WeightInfo::mint_position().saturating_add(gas_used)).into())
+		}
+
+		/// Withdraws `liquidity` from position `position_id`, whose receipt the sender owns.
+		///
+		/// The tokens backing the liquidity are paid out to the sender. A position left without
+		/// liquidity is burnt with its receipt, and its uncollected fees are paid out too.
+		#[pallet::call_index(16)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::decrease_liquidity()
+				.saturating_add(contract_calls_weight::<T>(2))
+		)]
+		#[transactional]
+		#[allow(clippy::useless_conversion)]
+		pub fn decrease_liquidity(
+			origin: OriginFor<T>,
+			position_id: PositionId,
+			liquidity: u128,
+			token_a_amount_min: BalanceOf<T>,
+			token_b_amount_min: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
+
+			Self::do_decrease_liquidity(
+				owner,
+				position_id,
+				liquidity,
+				(token_a_amount_min, token_b_amount_min),
+				&mut gas_used,
+			)?;
+
+			Ok(Some(<T as Config>::WeightInfo::decrease_liquidity().saturating_add(gas_used))
+				.into())
+		}
+
+		/// Pays out the swap fees earned by position `position_id`, whose receipt the sender
+		/// owns.
+		#[pallet::call_index(17)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::collect_position_fees()
+				.saturating_add(contract_calls_weight::<T>(2))
+		)]
+		#[transactional]
+		#[allow(clippy::useless_conversion)]
+		pub fn collect_position_fees(
+			origin: OriginFor<T>,
+			position_id: PositionId,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
+
+			Self::do_collect_position_fees(owner, position_id, &mut gas_used)?;
+
+			Ok(Some(<T as Config>::WeightInfo::collect_position_fees().saturating_add(gas_used))
+				.into())
+		}
+
+		/// Creates a stable pool of `tokens`, funded with `amounts` in the same order, and an
+		/// exchange for every pair of them.
+		///
+		/// The pool is referred to by the identifier of any of its pairs. Its liquidity token
+		/// `liquidity_token_id` is shared by all of them. The fee has to be the default tier or
+		/// one approved through `add_fee_tier`.
+		#[pallet::call_index(18)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::create_stable_pool(tokens.len() as u32)
+				.saturating_add(contract_calls_weight::<T>(2 * tokens.len() as u64))
+		)]
+		#[transactional]
+		#[allow(clippy::useless_conversion)]
+		pub fn create_stable_pool(
+			origin: OriginFor<T>,
+			tokens: BoundedVec<TokenKindOf<T>, T::MaxStableTokens>,
+			amounts: BoundedVec<BalanceOf<T>, T::MaxStableTokens>,
+			liquidity_token_id: AssetIdOf<T>,
+			fee_numerator: BalanceOf<T>,
+			fee_denominator: BalanceOf<T>,
+			amplification: u32,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
+			let token_count = tokens.len() as u32;
+
+			Self::do_create_stable_pool(
+				caller,
+				tokens.into_inner(),
+				amounts.into_inner(),
+				liquidity_token_id,
+				(fee_numerator, fee_denominator),
+				amplification,
+				&mut gas_used,
+			)?;
+
+			Ok(Some(
+				<T as Config>::WeightInfo::create_stable_pool(token_count).saturating_add(gas_used),
+			)
+			.into())
+		}
+
+		/// Deposits `amounts` of the tokens of the stable pool `pool_id` is a pair of, in the
+		/// order of the tokens of the pool, for at least `min_liquidity`.
+		///
+		/// Any of the amounts may be zero. Deposits off the proportions of the pool are charged
+		/// a fee on their imbalance.
+		#[pallet::call_index(19)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::add_stable_liquidity(T::MaxStableTokens::get())
+				.saturating_add(contract_calls_weight::<T>(T::MaxStableTokens::get() as u64))
+		)]
+		#[transactional]
+		#[allow(clippy::useless_conversion)]
+		pub fn add_stable_liquidity(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			amounts: BoundedVec<BalanceOf<T>, T::MaxStableTokens>,
+			min_liquidity: AssetBalanceOf<T>,
+			deadline: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let provider = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
+			Self::check_deadline(&deadline)?;
+			let token_count = amounts.len() as u32;
+
+			Self::do_add_stable_liquidity(
+				provider,
+				pool_id,
+				amounts.into_inner(),
+				min_liquidity,
+				&mut gas_used,
+			)?;
+
+			Ok(Some(
+				<T as Config>::WeightInfo::add_stable_liquidity(token_count)
+					.saturating_add(gas_used),
+			)
+			.into())
+		}
+
+		/// Redeems `liquidity` of the stable pool `pool_id` is a pair of for its share of every
+		/// token of the pool, each at least the matching amount of `min_amounts`.
+		#[pallet::call_index(20)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::remove_stable_liquidity(T::MaxStableTokens::get())
+				.saturating_add(contract_calls_weight::<T>(T::MaxStableTokens::get() as u64))
+		)]
+		#[transactional]
+		#[allow(clippy::useless_conversion)]
+		pub fn remove_stable_liquidity(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			liquidity: AssetBalanceOf<T>,
+			min_amounts: BoundedVec<BalanceOf<T>, T::MaxStableTokens>,
+			deadline: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let provider = ensure_signed(origin)?;
+			let mut gas_used = Weight::zero();
+			Self::check_deadline(&deadline)?;
+			let token_count = min_amounts.len() as u32;
+
+			Self::do_remove_stable_liquidity(
+				provider,
+				pool_id,
+				liquidity,
+				min_amounts.into_inner(),
+				&mut gas_used,
+			)?;
+
+			Ok(Some(
+				<T as Config>::WeightInfo::remove_stable_liquidity(token_count)
+					.saturating_add(gas_used),
+			)
+			.into())
+		}
+
+		/// Ramps the amplification coefficient of the stable pool `pool_id` is a pair of
+		/// linearly from its current value to `future_amplification`, reached at
+		/// `future_block`.
+		///
+		/// The coefficient can't be scaled up or down by more than `MAX_AMPLIFICATION_CHANGE` in
+		/// one ramp, which replaces any ramp in progress.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::ramp_amplification())]
+		pub fn ramp_amplification(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			future_amplification: u32,
+			future_block: T::BlockNumber,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			Self::do_ramp_amplification(pool_id, future_amplification, future_block)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Returns the pair of `token_a` and `token_b` in canonical order, and whether it already
+		/// was in that order.
+		pub fn canonical_pair(
+			token_a: &TokenKindOf<T>,
+			token_b: &TokenKindOf<T>,
+		) -> Result<(TokenPairOf<T>, bool), Error<T>> {
+			ensure!(token_a != token_b, Error::<T>::IdenticalTokens);
+			if token_a < token_b {
+				Ok(((token_a.clone(), token_b.clone()), true))
 			} else {
-				Err(Error::<T>::UnableToFetchTotalLiquidity)
+				Ok(((token_b.clone(), token_a.clone()), false))
 			}
 		}
 
-		fn check_enough_currency(
-			owner: &AccountIdOf<T>,
-			token_id: &AccountIdOf<T>,
-			required_amount: BalanceOf<T>,
-		) -> Result<(), Error<T>> {
-			let method_id: [u8; 4] = [0x70, 0xa0, 0x8a, 0x31]; // This is the method ID for the ERC-20 balanceOf function
+		/// Returns the pool between `token_a` and `token_b`, given in any order.
+		pub fn get_exchange(
+			token_a: &TokenKindOf<T>,
+			token_b: &TokenKindOf<T>,
+		) -> Result<ExchangeOf<T>, Error<T>> {
+			Self::get_directed_exchange(token_a, token_b).map(|(exchange, _)| exchange)
+		}
 
-			let gas_limit: Weight = T::BlockWeights::get().max_block;
+		/// Returns the identifiers of all pools containing `token`, together with the other token
+		/// of each pool.
+		pub fn pools_of(token: &TokenKindOf<T>) -> Vec<(TokenKindOf<T>, PoolId)> {
+			PoolsByToken::<T>::iter_prefix(token).collect()
+		}
 
-			let mut data = method_id.to_vec();
-			data.extend(owner.encode());
+		/// Returns the limit prices and identifiers of the open orders selling `sell_token` for
+		/// `buy_token`, from the lowest limit price and the oldest order at the same price.
+		pub fn order_book(
+			sell_token: &TokenKindOf<T>,
+			buy_token: &TokenKindOf<T>,
+		) -> Vec<(FixedU128, OrderId)> {
+			OrderBook::<T>::iter_key_prefix((sell_token, buy_token))
+				.map(|(price, order_id)| {
+					(
+						FixedU128::from_inner(u128::from_be_bytes(price)),
+						OrderId::from_be_bytes(order_id),
+					)
+				})
+				.collect()
+		}
 
+		/// Indexes the pool of `pair` by its id and by each of its tokens.
+		pub(crate) fn index_pool(pool_id: PoolId, pair: &TokenPairOf<T>) {
+			Pools::<T>::insert(pool_id, pair);
+			PoolsByToken::<T>::insert(&pair.0, &pair.1, pool_id);
+			PoolsByToken::<T>::insert(&pair.1, &pair.0, pool_id);
+		}
+
+		/// Returns whether new pools may charge `fee_numerator / fee_denominator`.
+		pub fn is_fee_tier_approved(
+			fee_numerator: BalanceOf<T>,
+			fee_denominator: BalanceOf<T>,
+		) -> bool {
+			(fee_numerator, fee_denominator) ==
+				(T::ProviderFeeNumerator::get(), T::ProviderFeeDenominator::get()) ||
+				FeeTiers::<T>::contains_key((fee_numerator, fee_denominator))
+		}
+
+		/// Returns the fee charged by `exchange` on `amount` of the token sold into it.
+		fn swap_fee(exchange: &ExchangeOf<T>, amount: BalanceOf<T>) -> BalanceOf<T> {
+			FixedU128::saturating_from_rational(exchange.fee_numerator, exchange.fee_denominator)
+				.saturating_mul_int(amount)
+		}
+
+		fn min_amount_error(is_currency: bool) -> Error<T> {
+			if is_currency {
+				Error::<T>::MinCurrencyTooHigh
+			} else {
+				Error::<T>::MinTokensTooHigh
+			}
+		}
+
+		fn check_deadline(deadline: &T::BlockNumber) -> Result<(), Error<T>> {
+			ensure!(deadline >= &<frame_system::Pallet<T>>::block_number(), Error::DeadlinePassed);
+			Ok(())
+		}
+
+		/// Calls `contract` on behalf of `origin` and adds the gas it consumed to `gas_used`.
+		///
+		/// Returns the output of the call, or `None` if it trapped or reverted.
+		fn call_contract(
+			origin: &AccountIdOf<T>,
+			contract: &AccountIdOf<T>,
+			data: Vec<u8>,
+			gas_used: &mut Weight,
+		) -> Option<Vec<u8>> {
+			Self::call_contract_with_limit(
+				origin,
+				contract,
+				data,
+				T::ContractCallGasLimit::get(),
+				gas_used,
+			)
+		}
+
+		/// Same as `call_contract`, with `gas_limit` instead of `ContractCallGasLimit`.
+		fn call_contract_with_limit(
+			origin: &AccountIdOf<T>,
+			contract: &AccountIdOf<T>,
+			data: Vec<u8>,
+			gas_limit: Weight,
+			gas_used: &mut Weight,
+		) -> Option<Vec<u8>> {
 			let result = pallet_contracts::Pallet::<T>::bare_call(
-				owner.clone(),
-				token_id.clone(),
+				origin.clone(),
+				contract.clone(),
 				Zero::zero(),
 				gas_limit,
 				None,
@@ -636,56 +1955,147 @@ pub mod pallet {
 				false,
 				pallet_contracts::Determinism::Deterministic,
 			);
+			gas_used.saturating_accrue(result.gas_consumed);
 
-			if let Ok(contract_result) = result.result {
-				if !contract_result.flags.contains(pallet_contracts_primitives::ReturnFlags::REVERT)
-				{
-					let balance: BalanceOf<T> =
-						Decode::decode(&mut &contract_result.data[..]).unwrap_or(Zero::zero());
-					balance >= required_amount;
-					Ok(())
-				} else {
-					Err(Error::<T>::BalanceTooLow)
-				}
-			} else {
-				Err(Error::<T>::BalanceTooLow)
+			match result.result {
+				Ok(output) if !output.did_revert() => Some(output.data),
+				_ => None,
+			}
+		}
+
+		/// Moves `amount` of `token` from `origin` to `to` through the backend of its kind.
+		///
+		/// The weight of the transfer is added to `gas_used`.
+		pub fn transfer_token_from_owner(
+			origin: &AccountIdOf<T>,
+			token: TokenKindOf<T>,
+			to: AccountIdOf<T>,
+			amount: BalanceOf<T>,
+			gas_used: &mut Weight,
+		) -> DispatchResult {
+			ensure!(!FlashSwapInProgress::<T>::get(), Error::<T>::FlashSwapInProgress);
+			match &token {
+				TokenKind::Native => {
+					gas_used.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+					<T as Config>::Currency::transfer(origin, &to, amount, KeepAlive)
+						.map_err(|_| Error::<T>::TokenTransferFailed)?;
+				},
+				TokenKind::Asset(asset_id) => {
+					gas_used.saturating_accrue(T::DbWeight::get().reads_writes(3, 3));
+					T::Assets::transfer(
+						asset_id.clone(),
+						origin,
+						&to,
+						T::CurrencyToAssetBalance::convert(amount),
+						true,
+					)
+					.map_err(|_| Error::<T>::TokenTransferFailed)?;
+				},
+				TokenKind::Contract(contract_address) => {
+					let method_id: [u8; 4] = [0x84, 0xa1, 0x5d, 0xa1];
+
+					let mut data = method_id.to_vec();
+					data.extend(to.encode());
+					data.extend(amount.encode());
+
+					Self::call_contract(origin, contract_address, data, gas_used)
+						.ok_or(Error::<T>::TokenTransferFailed)?;
+				},
+			}
+
+			Self::deposit_event(Event::TokenTransferred(token, to, amount));
+			Ok(())
+		}
+
+		/// Returns the balance of `owner` in `token`, adding the weight of the lookup to
+		/// `gas_used`.
+		fn token_balance(
+			owner: &AccountIdOf<T>,
+			token: &TokenKindOf<T>,
+			gas_used: &mut Weight,
+		) -> Result<BalanceOf<T>, Error<T>> {
+			match token {
+				TokenKind::Native => {
+					gas_used.saturating_accrue(T::DbWeight::get().reads(1));
+					Ok(<T as Config>::Currency::free_balance(owner))
+				},
+				TokenKind::Asset(asset_id) => {
+					gas_used.saturating_accrue(T::DbWeight::get().reads(2));
+					Ok(T::AssetToCurrencyBalance::convert(T::Assets::balance(
+						asset_id.clone(),
+						owner,
+					)))
+				},
+				TokenKind::Contract(contract_address) => {
+					let method_id: [u8; 4] = [0x70, 0xa0, 0x8a, 0x31]; // This is the method ID for the ERC-20 balanceOf function
+
+					let mut data = method_id.to_vec();
+					data.extend(owner.encode());
+
+					let output = Self::call_contract(owner, contract_address, data, gas_used)
+						.ok_or(Error::<T>::BalanceTooLow)?;
+
+					Ok(Decode::decode(&mut &output[..]).unwrap_or(Zero::zero()))
+				},
 			}
 		}
 
+		pub(crate) fn check_enough_currency(
+			owner: &AccountIdOf<T>,
+			token: &TokenKindOf<T>,
+			required_amount: BalanceOf<T>,
+			gas_used: &mut Weight,
+		) -> Result<(), Error<T>> {
+			let balance = Self::token_balance(owner, token, gas_used)?;
+			ensure!(balance >= required_amount, Error::<T>::BalanceTooLow);
+			Ok(())
+		}
+
 		#[transactional]
 		fn do_add_liquidity(
 			mut exchange: ExchangeOf<T>,
 			currency_amount: BalanceOf<T>,
 			token_amount: BalanceOf<T>,
+			liquidity_minted: AssetBalanceOf<T>,
 			provider: AccountIdOf<T>,
+			gas_used: &mut Weight,
 		) -> DispatchResult {
 			// --------------------- Currency & token transfer ---------------------
 
 			let asset_id = exchange.token_a.clone(); // Clone asset_id
-			let liquidity_token_id = exchange.token_b.clone(); // Clone liquidity_token_id
+			let token_id = exchange.token_b.clone(); // Clone token_id
 
 			let pallet_account = T::pallet_account();
+			Self::update_oracle(&mut exchange);
 
-			let transfer_from_user_to_pallet = Self::transfer_token_from_owner(
+			Self::transfer_token_from_owner(
 				&provider,
-				liquidity_token_id.clone(),
+				asset_id.clone(),
 				pallet_account.clone(),
+				currency_amount,
+				gas_used,
+			)?;
+
+			Self::transfer_token_from_owner(
+				&provider,
+				token_id.clone(),
+				pallet_account,
 				token_amount,
-			);
+				gas_used,
+			)?;
 
-			let trnasfer_from_pallet_to_user = Self::transfer_token_from_owner(
+			T::AssetRegistry::mint_into(
+				exchange.liquidity_token_id.clone(),
 				&provider,
-				asset_id.clone(),
-				pallet_account.clone(),
-				currency_amount,
-			);
+				liquidity_minted,
+			)?;
 
 			// -------------------------- Balances update --------------------------
 
 			exchange.token_a_reserve.saturating_accrue(currency_amount);
 			exchange.token_b_reserve.saturating_accrue(token_amount);
 
-			Exchanges::<T>::insert((asset_id.clone(), liquidity_token_id.clone()), exchange);
+			Exchanges::<T>::insert((asset_id.clone(), token_id), exchange);
 
 			// ---------------------------- Emit event -----------------------------
 			Self::deposit_event(Event::LiquidityAdded(
@@ -693,6 +2103,7 @@ pub mod pallet {
 				asset_id,
 				currency_amount,
 				token_amount,
+				liquidity_minted,
 			));
 			Ok(())
 		}
@@ -739,9 +2150,9 @@ pub mod pallet {
 
 			// numerator = input_reserve * output_amount * fee_denominator
 			let numerator = input_reserve
-				.checked_mul(&output_amount)
+				.checked_mul(output_amount)
 				.ok_or(Error::Overflow)?
-				.checked_mul(&fee_denominator)
+				.checked_mul(fee_denominator)
 				.ok_or(Error::Overflow)?;
 
 			let denominator = output_reserve
@@ -754,156 +2165,474 @@ pub mod pallet {
 			Ok((numerator / denominator).saturating_add(BalanceOf::<T>::one()))
 		}
 
-		pub fn get_asset_to_asset_price(
-			sold_asset_exchange: &Exchange<T>,
-			bought_asset_exchange: &Exchange<T>,
-			amount: TradeAmount<BalanceOf<T>, BalanceOf<T>>,
-		) -> Result<AssetToAssetPrice<T>, Error<T>> {
-			match amount {
-				TradeAmount::FixedInput {
-					input_amount: sold_token_amount,
-					min_output: min_bought_tokens,
-				} => {
-					log::info!(
-						"get_asset_to_asset_price: sold_token_amount: {:?}, min_bought_tokens: {:?}",
-						sold_token_amount,
-						min_bought_tokens);
-
-					let currency_amount = Self::get_output_amount(
-						&sold_token_amount,
-						&sold_asset_exchange.token_a_reserve,
-						&sold_asset_exchange.token_b_reserve,
-						&sold_asset_exchange.fee_numerator,
-						&sold_asset_exchange.fee_denominator,
-					)?;
-					let bought_token_amount = Self::get_output_amount(
-						&currency_amount,
-						&bought_asset_exchange.token_a_reserve,
-						&bought_asset_exchange.token_b_reserve,
-						&bought_asset_exchange.fee_numerator,
-						&bought_asset_exchange.fee_denominator,
-					)?;
-					log::info!(
-						"get_asset_to_asset_price: sold_token_amount: {:?}, bought_token_amount: {:?}",
-						sold_token_amount,
-						bought_token_amount);
+		/// Returns the exchange trading `token_in` for `token_out`, and whether `token_in` is its
+		/// `token_a`.
+		pub(crate) fn get_directed_exchange(
+			token_in: &TokenKindOf<T>,
+			token_out: &TokenKindOf<T>,
+		) -> Result<(ExchangeOf<T>, bool), Error<T>> {
+			let (pair, is_canonical) = Self::canonical_pair(token_in, token_out)
+				.map_err(|_| Error::<T>::ExchangeDoesNotExist)?;
+			let exchange = <Exchanges<T>>::get(pair).ok_or(Error::<T>::ExchangeDoesNotExist)?;
+			Ok((exchange, is_canonical))
+		}
 
-					ensure!(
-						bought_token_amount >= min_bought_tokens,
-						Error::<T>::MinBoughtTokensTooHigh
-					);
-					Ok((
-						sold_token_amount,   // should be a Balance
-						bought_token_amount, // should be a Balance
-					))
-				},
-				TradeAmount::FixedOutput {
-					max_input: max_sold_tokens,
-					output_amount: bought_token_amount,
-				} => {
-					let currency_amount = Self::get_input_amount(
-						&bought_token_amount,
-						&bought_asset_exchange.token_a_reserve,
-						&bought_asset_exchange.token_b_reserve,
-						&bought_asset_exchange.fee_numerator,
-						&bought_asset_exchange.fee_denominator,
-					)?;
-					let sold_token_amount = Self::get_input_amount(
-						&currency_amount,
-						&sold_asset_exchange.token_a_reserve,
-						&sold_asset_exchange.token_b_reserve,
-						&sold_asset_exchange.fee_numerator,
-						&sold_asset_exchange.fee_denominator,
-					)?;
-					ensure!(sold_token_amount <= max_sold_tokens, Error::<T>::MaxSoldTokensTooLow);
+		/// Returns the exchanges between consecutive tokens of `path`.
+		pub(crate) fn get_path_exchanges(
+			path: &[TokenKindOf<T>],
+		) -> Result<Vec<(ExchangeOf<T>, bool)>, Error<T>> {
+			ensure!(path.len() >= 2, Error::<T>::InvalidPath);
+			for (index, token) in path.iter().enumerate() {
+				ensure!(!path[..index].contains(token), Error::<T>::InvalidPath);
+			}
 
-					Ok((
-						sold_token_amount,   // should be a Balance
-						bought_token_amount, // should be a Balance
-					))
-				},
+			path.windows(2)
+				.map(|pair| Self::get_directed_exchange(&pair[0], &pair[1]))
+				.collect()
+		}
+
+		/// Returns the input and output reserves of a hop.
+		pub(crate) fn hop_reserves(
+			(exchange, sells_token_a): &(ExchangeOf<T>, bool),
+		) -> (BalanceOf<T>, BalanceOf<T>) {
+			if *sells_token_a {
+				(exchange.token_a_reserve, exchange.token_b_reserve)
+			} else {
+				(exchange.token_b_reserve, exchange.token_a_reserve)
 			}
 		}
 
-		fn check_trade_amount<A: Zero, B: Zero>(
-			amount: &TradeAmount<A, B>,
-		) -> Result<(), Error<T>> {
-			match amount {
-				TradeAmount::FixedInput { input_amount, min_output } => {
-					ensure!(!input_amount.is_zero(), Error::TradeAmountIsZero);
-					ensure!(!min_output.is_zero(), Error::TradeAmountIsZero);
-				},
-				TradeAmount::FixedOutput { output_amount, max_input } => {
-					ensure!(!output_amount.is_zero(), Error::TradeAmountIsZero);
-					ensure!(!max_input.is_zero(), Error::TradeAmountIsZero);
-				},
-			};
+		/// Returns the amount bought from a hop when selling `input_amount` into it.
+		pub(crate) fn hop_output_amount(
+			hop: &(ExchangeOf<T>, bool),
+			input_amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, Error<T>> {
+			if let Some(pool) = ConcentratedPools::<T>::get(hop.0.pool_id) {
+				return Self::concentrated_hop_amount(pool, hop, input_amount, true)
+			}
+			if let Some(stable_id) = StablePairs::<T>::get(hop.0.pool_id) {
+				let pool =
+					StablePools::<T>::get(stable_id).ok_or(Error::<T>::ExchangeDoesNotExist)?;
+				return Self::stable_hop_amount(&pool, hop, input_amount, true)
+			}
+			let (input_reserve, output_reserve) = Self::hop_reserves(hop);
+			Self::get_output_amount(
+				&input_amount,
+				&input_reserve,
+				&output_reserve,
+				&hop.0.fee_numerator,
+				&hop.0.fee_denominator,
+			)
+		}
+
+		/// Returns the amount to sell into a hop to buy `output_amount` from it.
+		pub(crate) fn hop_input_amount(
+			hop: &(ExchangeOf<T>, bool),
+			output_amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, Error<T>> {
+			if let Some(pool) = ConcentratedPools::<T>::get(hop.0.pool_id) {
+				return Self::concentrated_hop_amount(pool, hop, output_amount, false)
+			}
+			if let Some(stable_id) = StablePairs::<T>::get(hop.0.pool_id) {
+				let pool =
+					StablePools::<T>::get(stable_id).ok_or(Error::<T>::ExchangeDoesNotExist)?;
+				return Self::stable_hop_amount(&pool, hop, output_amount, false)
+			}
+			let (input_reserve, output_reserve) = Self::hop_reserves(hop);
+			Self::get_input_amount(
+				&output_amount,
+				&input_reserve,
+				&output_reserve,
+				&hop.0.fee_numerator,
+				&hop.0.fee_denominator,
+			)
+		}
+
+		/// Fails for concentrated liquidity pools, which are only provided liquidity through
+		/// positions, and for stable pools, which are provided liquidity as a whole.
+		fn ensure_constant_product(exchange: &ExchangeOf<T>) -> Result<(), Error<T>> {
+			ensure!(
+				!ConcentratedPools::<T>::contains_key(exchange.pool_id) &&
+					!StablePairs::<T>::contains_key(exchange.pool_id),
+				Error::<T>::UnsupportedPoolKind
+			);
 			Ok(())
 		}
 
-		fn swap_asset_for_asset(
-			sold_asset_exchange: ExchangeOf<T>,
-			bought_asset_exchange: ExchangeOf<T>,
+		/// Returns the amount traded into every hop followed by the amount bought, when selling
+		/// `sold_token_amount`.
+		pub(crate) fn get_amounts_out(
+			hops: &[(ExchangeOf<T>, bool)],
 			sold_token_amount: BalanceOf<T>,
+		) -> Result<Vec<BalanceOf<T>>, Error<T>> {
+			let mut amounts = Vec::with_capacity(hops.len() + 1);
+			amounts.push(sold_token_amount);
+			for hop in hops {
+				let output_amount = Self::hop_output_amount(hop, amounts[amounts.len() - 1])?;
+				ensure!(!output_amount.is_zero(), Error::<T>::InsufficientOutputAmount);
+				amounts.push(output_amount);
+			}
+			Ok(amounts)
+		}
+
+		/// Returns the amount traded into every hop followed by the amount bought, when buying
+		/// `bought_token_amount`.
+		pub(crate) fn get_amounts_in(
+			hops: &[(ExchangeOf<T>, bool)],
 			bought_token_amount: BalanceOf<T>,
+		) -> Result<Vec<BalanceOf<T>>, Error<T>> {
+			let mut amounts = vec![bought_token_amount];
+			for hop in hops.iter().rev() {
+				let input_amount = Self::hop_input_amount(hop, amounts[0])?;
+				amounts.insert(0, input_amount);
+			}
+			Ok(amounts)
+		}
+
+		/// Moves the sold tokens in and the bought tokens out, and updates the reserves of every
+		/// hop. The intermediate tokens stay in the pallet account.
+		fn do_swap_along_path(
 			buyer: AccountIdOf<T>,
-			recipient: AccountIdOf<T>,
+			path: SwapPathOf<T>,
+			hops: Vec<(ExchangeOf<T>, bool)>,
+			amounts: Vec<BalanceOf<T>>,
+			gas_used: &mut Weight,
 		) -> DispatchResult {
-			let pallet_account: AccountIdOf<T> = T::pallet_account();
+			let pallet_account = T::pallet_account();
+			let sold_token_amount = amounts[0];
+			let bought_token_amount = amounts[amounts.len() - 1];
+
+			Self::transfer_token_from_owner(
+				&buyer,
+				path[0].clone(),
+				pallet_account.clone(),
+				sold_token_amount,
+				gas_used,
+			)?;
+			Self::transfer_token_from_owner(
+				&pallet_account,
+				path[path.len() - 1].clone(),
+				buyer.clone(),
+				bought_token_amount,
+				gas_used,
+			)?;
+
+			for (index, hop) in hops.into_iter().enumerate() {
+				Self::update_hop_reserves(hop, amounts[index], amounts[index + 1], gas_used)?;
+			}
+
+			Self::deposit_event(Event::PathSwapped(
+				buyer,
+				path,
+				sold_token_amount,
+				bought_token_amount,
+			));
 			Ok(())
 		}
 
-		fn update_reserve_after_sell(
-			exchange: &Exchange<T>,
-			sold_amount: BalanceOf<T>,
-			is_token_a: bool,
-		) -> Result<Exchange<T>, DispatchError> {
-			let mut updated_exchange = exchange.clone();
+		/// Trades `input_amount` into a hop and `output_amount` out of it, and stores the
+		/// updated reserves. The extra weight of concentrated liquidity and stable pools is added
+		/// to `gas_used`.
+		///
+		/// The protocol share of the swap fee is set aside for the treasury instead of being added
+		/// to the reserves.
+		pub(crate) fn update_hop_reserves(
+			(mut exchange, sells_token_a): (ExchangeOf<T>, bool),
+			input_amount: BalanceOf<T>,
+			output_amount: BalanceOf<T>,
+			gas_used: &mut Weight,
+		) -> DispatchResult {
+			// Stable pools update the oracles of all their pairs.
+			if let Some(stable_id) = StablePairs::<T>::get(exchange.pool_id) {
+				gas_used.saturating_accrue(pool_hops_weight::<T>(1));
+				let pool =
+					StablePools::<T>::get(stable_id).ok_or(Error::<T>::ExchangeDoesNotExist)?;
+				return Self::update_stable_reserves(
+					stable_id,
+					pool,
+					(exchange, sells_token_a),
+					input_amount,
+					output_amount,
+				)
+			}
+			Self::update_oracle(&mut exchange);
+			if let Some(pool) = ConcentratedPools::<T>::get(exchange.pool_id) {
+				gas_used.saturating_accrue(pool_hops_weight::<T>(1));
+				return Self::update_concentrated_reserves(
+					pool,
+					(exchange, sells_token_a),
+					input_amount,
+					output_amount,
+				)
+			}
+			let protocol_fee =
+				ProtocolFee::<T>::get().mul_floor(Self::swap_fee(&exchange, input_amount));
+			if !protocol_fee.is_zero() {
+				let input_token = if sells_token_a { &exchange.token_a } else { &exchange.token_b };
+				ProtocolFees::<T>::mutate(input_token, |fees| fees.saturating_accrue(protocol_fee));
+			}
+			let input_amount = input_amount.saturating_sub(protocol_fee);
+			if sells_token_a {
+				exchange.token_a_reserve =
+					Self::increase_reserve(exchange.token_a_reserve, input_amount)?;
+				exchange.token_b_reserve =
+					Self::reduce_reserve(exchange.token_b_reserve, output_amount)?;
+			} else {
+				exchange.token_b_reserve =
+					Self::increase_reserve(exchange.token_b_reserve, input_amount)?;
+				exchange.token_a_reserve =
+					Self::reduce_reserve(exchange.token_a_reserve, output_amount)?;
+			}
+			Exchanges::<T>::insert((exchange.token_a.clone(), exchange.token_b.clone()), exchange);
+			Ok(())
+		}
 
-			if is_token_a {
-				updated_exchange.token_a_reserve =
-					Self::reduce_reserve(exchange.token_a_reserve, sold_amount)?;
+		/// Settles a flash swap of `amounts_out` out of a hop, paid back with `amounts_in`, both in
+		/// the order of the hop.
+		///
+		/// Fails unless the product of the reserves, each reduced by the swap fee on what was
+		/// paid in, is at least the product before the swap. The protocol share of the fees is
+		/// set aside for the treasury, as for swaps.
+		fn settle_flash_swap(
+			(mut exchange, is_canonical): (ExchangeOf<T>, bool),
+			amounts_in: (BalanceOf<T>, BalanceOf<T>),
+			amounts_out: (BalanceOf<T>, BalanceOf<T>),
+		) -> DispatchResult {
+			let ((in_a, in_b), (out_a, out_b)) = if is_canonical {
+				(amounts_in, amounts_out)
 			} else {
-				updated_exchange.token_b_reserve =
-					Self::reduce_reserve(exchange.token_b_reserve, sold_amount)?;
+				((amounts_in.1, amounts_in.0), (amounts_out.1, amounts_out.0))
+			};
+			let reserve_a = Self::reduce_reserve(
+				Self::increase_reserve(exchange.token_a_reserve, in_a)?,
+				out_a,
+			)?;
+			let reserve_b = Self::reduce_reserve(
+				Self::increase_reserve(exchange.token_b_reserve, in_b)?,
+				out_b,
+			)?;
+
+			// (reserve_a * den - in_a * num) * (reserve_b * den - in_b * num) >=
+			//     token_a_reserve * token_b_reserve * den^2
+			let big = |balance: BalanceOf<T>| {
+				BigUint::from(UniqueSaturatedInto::<u128>::unique_saturated_into(balance))
+			};
+			let (num, den) = (big(exchange.fee_numerator), big(exchange.fee_denominator));
+			let adjusted = |reserve: BalanceOf<T>, input: BalanceOf<T>| {
+				big(reserve).mul(&den).sub(&big(input).mul(&num)).ok()
+			};
+			let k_before = big(exchange.token_a_reserve)
+				.mul(&big(exchange.token_b_reserve))
+				.mul(&den)
+				.mul(&den);
+			let k_after =
+				adjusted(reserve_a, in_a).zip(adjusted(reserve_b, in_b)).map(|(a, b)| a.mul(&b));
+			ensure!(k_after.is_some_and(|k| k >= k_before), Error::<T>::FlashSwapNotRepaid);
+
+			Self::update_oracle(&mut exchange);
+			let protocol_fee = ProtocolFee::<T>::get();
+			let fee_a = protocol_fee.mul_floor(Self::swap_fee(&exchange, in_a));
+			let fee_b = protocol_fee.mul_floor(Self::swap_fee(&exchange, in_b));
+			for (token, fee) in [(&exchange.token_a, fee_a), (&exchange.token_b, fee_b)] {
+				if !fee.is_zero() {
+					ProtocolFees::<T>::mutate(token, |fees| fees.saturating_accrue(fee));
+				}
 			}
+			exchange.token_a_reserve = Self::reduce_reserve(reserve_a, fee_a)?;
+			exchange.token_b_reserve = Self::reduce_reserve(reserve_b, fee_b)?;
+			Exchanges::<T>::insert((exchange.token_a.clone(), exchange.token_b.clone()), exchange);
+			Ok(())
+		}
+
+		/// Removes order `order_id` from the open orders and their indexes.
+		fn remove_order(order_id: OrderId, order: &LimitOrder<T>) {
+			Orders::<T>::remove(order_id);
+			let (price_key, id_key) = order.book_key(order_id);
+			OrderBook::<T>::remove((&order.sell_token, &order.buy_token, price_key, id_key));
+			OrderExpiries::<T>::remove(order.expiry, order_id);
+		}
 
-			Ok(updated_exchange)
+		/// Gives the escrowed tokens and the tip of order `order_id` back to its owner and
+		/// removes it.
+		fn refund_order(
+			order_id: OrderId,
+			order: &LimitOrder<T>,
+			gas_used: &mut Weight,
+		) -> DispatchResult {
+			Self::transfer_token_from_owner(
+				&T::pallet_account(),
+				order.sell_token.clone(),
+				order.owner.clone(),
+				order.amount,
+				gas_used,
+			)?;
+			<T as Config>::Currency::unreserve(&order.owner, order.tip);
+			Self::remove_order(order_id, order);
+			Ok(())
 		}
 
-		fn update_reserve_after_buy(
-			exchange: &Exchange<T>,
-			bought_amount: BalanceOf<T>,
-			is_token_a: bool,
-		) -> Result<Exchange<T>, DispatchError> {
-			let mut updated_exchange = exchange.clone();
+		/// Gives back the orders which expired before `now`, for as long as `weight_limit`
+		/// allows, and returns the weight used.
+		///
+		/// Expiry blocks are visited in order from `ExpiryCursor`, so orders are given back in
+		/// the order they expired in, possibly over several blocks.
+		pub(crate) fn expire_orders(now: T::BlockNumber, weight_limit: Weight) -> Weight {
+			let db_weight = T::DbWeight::get();
+			let expire_weight = <T as Config>::WeightInfo::expire_order()
+				.saturating_add(contract_calls_weight::<T>(1));
+			let mut weight_used = db_weight.reads(1);
+			if weight_used.any_gt(weight_limit) {
+				return Weight::zero()
+			}
+			let mut cursor = ExpiryCursor::<T>::get();
+			if cursor.is_zero() || cursor >= now {
+				return weight_used
+			}
 
-			if is_token_a {
-				updated_exchange.token_a_reserve =
-					Self::increase_reserve(exchange.token_a_reserve, bought_amount)?;
-			} else {
-				updated_exchange.token_b_reserve =
-					Self::increase_reserve(exchange.token_b_reserve, bought_amount)?;
+			weight_used.saturating_accrue(db_weight.writes(1));
+			while cursor < now && weight_used.saturating_add(expire_weight).all_lte(weight_limit) {
+				match OrderExpiries::<T>::iter_key_prefix(cursor).next() {
+					Some(order_id) => {
+						weight_used.saturating_accrue(expire_weight);
+						Self::expire_order(cursor, order_id);
+					},
+					None => {
+						weight_used.saturating_accrue(db_weight.reads(1));
+						cursor.saturating_inc();
+					},
+				}
+			}
+			ExpiryCursor::<T>::put(cursor);
+			weight_used
+		}
+
+		/// Gives back order `order_id`, which expired in block `expiry`.
+		///
+		/// An order which can't be given back, e.g. because its token contract fails, is left
+		/// open for its owner to cancel, but is no longer filled.
+		fn expire_order(expiry: T::BlockNumber, order_id: OrderId) {
+			OrderExpiries::<T>::remove(expiry, order_id);
+			let Some(order) = Orders::<T>::get(order_id) else { return };
+			match with_storage_layer(|| Self::refund_order(order_id, &order, &mut Weight::zero())) {
+				Ok(()) => Self::deposit_event(Event::OrderExpired(order_id)),
+				Err(error) => log::warn!(
+					target: LOG_TARGET,
+					"Failed to give back expired order {}: {:?}",
+					order_id,
+					error
+				),
+			}
+		}
+
+		/// Accrues the prices of the pool for the blocks since it was last updated, and returns
+		/// whether any block passed.
+		///
+		/// Like in Uniswap v2, the cumulative prices wrap around on overflow, which keeps the
+		/// difference of two of them exact as long as it fits, see `twap`.
+		fn accrue_prices(exchange: &mut ExchangeOf<T>, now: T::BlockNumber) -> bool {
+			if now <= exchange.last_updated {
+				return false;
+			}
+			let elapsed: u128 = now.saturating_sub(exchange.last_updated).unique_saturated_into();
+			let accrue = |cumulative: FixedU128, price: FixedU128| {
+				FixedU128::from_inner(
+					cumulative.into_inner().wrapping_add(price.into_inner().wrapping_mul(elapsed)),
+				)
+			};
+			if let Some((price_a, price_b)) = Self::spot_prices(exchange) {
+				exchange.price_a_cumulative = accrue(exchange.price_a_cumulative, price_a);
+				exchange.price_b_cumulative = accrue(exchange.price_b_cumulative, price_b);
 			}
+			exchange.last_updated = now;
+			true
+		}
 
-			Ok(updated_exchange)
+		/// Returns the prices of `token_a` in `token_b` and of `token_b` in `token_a` in a pool,
+		/// or `None` while it is empty.
+		fn spot_prices(exchange: &ExchangeOf<T>) -> Option<(FixedU128, FixedU128)> {
+			if let Some(pool) = ConcentratedPools::<T>::get(exchange.pool_id) {
+				let price = pool.price();
+				return Some((price, price.reciprocal().unwrap_or_else(FixedU128::max_value)))
+			}
+			if let Some(stable_id) = StablePairs::<T>::get(exchange.pool_id) {
+				return StablePools::<T>::get(stable_id)?.spot_prices(
+					&exchange.token_a,
+					&exchange.token_b,
+					<frame_system::Pallet<T>>::block_number(),
+				)
+			}
+			if exchange.token_a_reserve.is_zero() || exchange.token_b_reserve.is_zero() {
+				return None
+			}
+			Some((
+				FixedU128::saturating_from_rational(
+					exchange.token_b_reserve,
+					exchange.token_a_reserve,
+				),
+				FixedU128::saturating_from_rational(
+					exchange.token_a_reserve,
+					exchange.token_b_reserve,
+				),
+			))
 		}
 
-		fn update_exchange_storage(
-			token_pair: (T::AccountId, T::AccountId),
-			exchange: Exchange<T>,
-		) {
-			Exchanges::<T>::insert(&token_pair, exchange);
+		/// Updates the price oracle of a pool on its first touch in a block, before its reserves
+		/// change.
+		pub(crate) fn update_oracle(exchange: &mut ExchangeOf<T>) {
+			if Self::accrue_prices(exchange, <frame_system::Pallet<T>>::block_number()) {
+				Self::record_observation(exchange);
+			}
 		}
 
-		fn reduce_reserve(
+		/// Records the cumulative prices of a pool, unless the previous observation is more
+		/// recent than `ObservationPeriod`. The oldest observation is dropped when full.
+		pub(crate) fn record_observation(exchange: &ExchangeOf<T>) {
+			Observations::<T>::mutate(exchange.pool_id, |observations| {
+				if let Some(last) = observations.last() {
+					if exchange.last_updated.saturating_sub(last.block) <
+						T::ObservationPeriod::get()
+					{
+						return;
+					}
+				}
+				if observations.len() as u32 >= T::MaxObservations::get() &&
+					!observations.is_empty()
+				{
+					observations.remove(0);
+				}
+				// Only fails when `MaxObservations` is zero, which disables the oracle.
+				let _ = observations.try_push(Observation {
+					block: exchange.last_updated,
+					price_a_cumulative: exchange.price_a_cumulative,
+					price_b_cumulative: exchange.price_b_cumulative,
+				});
+			});
+		}
+
+		fn check_trade_amount<A: Zero, B: Zero>(
+			amount: &TradeAmount<A, B>,
+		) -> Result<(), Error<T>> {
+			match amount {
+				TradeAmount::FixedInput { input_amount, min_output } => {
+					ensure!(!input_amount.is_zero(), Error::TradeAmountIsZero);
+					ensure!(!min_output.is_zero(), Error::TradeAmountIsZero);
+				},
+				TradeAmount::FixedOutput { output_amount, max_input } => {
+					ensure!(!output_amount.is_zero(), Error::TradeAmountIsZero);
+					ensure!(!max_input.is_zero(), Error::TradeAmountIsZero);
+				},
+			};
+			Ok(())
+		}
+
+		pub(crate) fn reduce_reserve(
 			reserve: BalanceOf<T>,
 			amount: BalanceOf<T>,
 		) -> Result<BalanceOf<T>, Error<T>> {
 			reserve.checked_sub(&amount).ok_or(Error::<T>::ArithmeticUnderflow)
 		}
 
-		fn increase_reserve(
+		pub(crate) fn increase_reserve(
 			reserve: BalanceOf<T>,
 			amount: BalanceOf<T>,
 		) -> Result<BalanceOf<T>, Error<T>> {
@@ -915,56 +2644,200 @@ pub mod pallet {
 			mut exchange: ExchangeOf<T>,
 			currency_amount: BalanceOf<T>,
 			token_amount: BalanceOf<T>,
-			liquidity_amount: BalanceOf<T>,
+			liquidity_amount: AssetBalanceOf<T>,
 			provider: AccountIdOf<T>,
+			gas_used: &mut Weight,
 		) -> DispatchResult {
 			// --------------------- Currency & token transfer ---------------------
 
 			let asset_id = exchange.token_a.clone(); // Clone asset_id
-			let liquidity_token_id = exchange.token_b.clone(); // Clone liquidity_token_id
+			let token_id = exchange.token_b.clone(); // Clone token_id
 
 			let pallet_account = T::pallet_account();
+			Self::update_oracle(&mut exchange);
 
-			let _ = Self::transfer_token_from_owner(
+			T::AssetRegistry::burn_from(
+				exchange.liquidity_token_id.clone(),
 				&provider,
-				liquidity_token_id.clone(),
-				pallet_account.clone(),
-				token_amount,
-			);
+				liquidity_amount,
+			)?;
 
-			let _ = Self::transfer_token_from_owner(
-				&provider,
+			Self::transfer_token_from_owner(
+				&pallet_account,
 				asset_id.clone(),
-				pallet_account.clone(),
+				provider.clone(),
+				currency_amount,
+				gas_used,
+			)?;
+
+			Self::transfer_token_from_owner(
+				&pallet_account,
+				token_id.clone(),
+				provider.clone(),
 				token_amount,
-			);
+				gas_used,
+			)?;
 
 			// -------------------------- Balances update --------------------------
 
 			// Update the reserves in the exchange
-			exchange.token_a_reserve = exchange
-				.token_a_reserve
-				.checked_sub(&currency_amount)
-				.ok_or(Error::<T>::InsufficientCurrencyReserve)?;
-
-			exchange.token_b_reserve = exchange
-				.token_b_reserve
-				.checked_sub(&token_amount)
-				.ok_or(Error::<T>::InsufficientTokenReserve)?;
+			exchange.token_a_reserve =
+				Self::reduce_reserve(exchange.token_a_reserve, currency_amount)?;
+			exchange.token_b_reserve =
+				Self::reduce_reserve(exchange.token_b_reserve, token_amount)?;
 
 			// Update the exchange info
-			Exchanges::<T>::insert((asset_id.clone(), liquidity_token_id.clone()), exchange);
+			Exchanges::<T>::insert((asset_id.clone(), token_id.clone()), exchange);
 
 			// Emit an event to note the liquidity removal
 			Self::deposit_event(Event::<T>::LiquidityRemoved(
 				provider,
 				asset_id,
-				liquidity_token_id,
+				token_id,
 				currency_amount,
 				token_amount,
+				liquidity_amount,
 			));
 
 			Ok(())
 		}
 	}
+
+	/// Queries served by the runtime API.
+	impl<T: Config> Pallet<T> {
+		/// Quotes selling exactly `amount_in` of the first token of `path` for its last token.
+		pub fn quote_exact_in(
+			path: Vec<TokenKindOf<T>>,
+			amount_in: BalanceOf<T>,
+		) -> Result<Quote<BalanceOf<T>>, DispatchError> {
+			ensure!(!amount_in.is_zero(), Error::<T>::TradeAmountIsZero);
+			let hops = Self::get_path_exchanges(&Self::bounded_path(path)?)?;
+			let amounts = Self::get_amounts_out(&hops, amount_in)?;
+			Ok(Self::quote(&hops, amounts))
+		}
+
+		/// Quotes buying exactly `amount_out` of the last token of `path` for its first token.
+		pub fn quote_exact_out(
+			path: Vec<TokenKindOf<T>>,
+			amount_out: BalanceOf<T>,
+		) -> Result<Quote<BalanceOf<T>>, DispatchError> {
+			ensure!(!amount_out.is_zero(), Error::<T>::TradeAmountIsZero);
+			let hops = Self::get_path_exchanges(&Self::bounded_path(path)?)?;
+			let amounts = Self::get_amounts_in(&hops, amount_out)?;
+			Ok(Self::quote(&hops, amounts))
+		}
+
+		/// Returns the pool between `token_a` and `token_b`, given in any order.
+		pub fn get_pool(
+			token_a: &TokenKindOf<T>,
+			token_b: &TokenKindOf<T>,
+		) -> Option<PoolInfoOf<T>> {
+			Self::get_exchange(token_a, token_b).ok().map(Self::pool_info)
+		}
+
+		/// Returns all pools, or only the ones containing `token`, ordered by their id.
+		pub fn list_pools(token: Option<TokenKindOf<T>>) -> Vec<PoolInfoOf<T>> {
+			let mut pools: Vec<_> = match token {
+				Some(token) => Self::pools_of(&token)
+					.into_iter()
+					.filter_map(|(other, _)| Self::get_pool(&token, &other))
+					.collect(),
+				None => Exchanges::<T>::iter_values().map(Self::pool_info).collect(),
+			};
+			pools.sort_by_key(|pool| pool.pool_id);
+			pools
+		}
+
+		/// Returns the price of `token_in` in `token_out`, averaged over at least the last `window`
+		/// blocks.
+		///
+		/// The average starts at the most recent observation old enough to cover the window.
+		pub fn twap(
+			token_in: &TokenKindOf<T>,
+			token_out: &TokenKindOf<T>,
+			window: T::BlockNumber,
+		) -> Result<TwapPrice<T::BlockNumber>, Error<T>> {
+			ensure!(!window.is_zero(), Error::<T>::InvalidOracleWindow);
+			let (mut exchange, sells_token_a) = Self::get_directed_exchange(token_in, token_out)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			Self::accrue_prices(&mut exchange, now);
+
+			let start = now.checked_sub(&window).ok_or(Error::<T>::OracleWindowUnavailable)?;
+			let observation = Observations::<T>::get(exchange.pool_id)
+				.into_iter()
+				.rev()
+				.find(|observation| observation.block <= start)
+				.ok_or(Error::<T>::OracleWindowUnavailable)?;
+
+			let (cumulative, observed) = if sells_token_a {
+				(exchange.price_a_cumulative, observation.price_a_cumulative)
+			} else {
+				(exchange.price_b_cumulative, observation.price_b_cumulative)
+			};
+			let elapsed: u128 = now.saturating_sub(observation.block).unique_saturated_into();
+			// The window is at least a block long, so `elapsed` is never zero. The cumulative
+			// prices wrap around, so the difference does too.
+			let price = FixedU128::from_inner(
+				cumulative.into_inner().wrapping_sub(observed.into_inner()) / elapsed,
+			);
+
+			Ok(TwapPrice { price, from: observation.block, to: now })
+		}
+
+		fn bounded_path(path: Vec<TokenKindOf<T>>) -> Result<SwapPathOf<T>, Error<T>> {
+			path.try_into().map_err(|_| Error::<T>::InvalidPath)
+		}
+
+		fn pool_info(exchange: ExchangeOf<T>) -> PoolInfoOf<T> {
+			let total_liquidity =
+				T::AssetRegistry::total_issuance(exchange.liquidity_token_id.clone());
+			PoolInfo {
+				pool_id: exchange.pool_id,
+				token_a: exchange.token_a,
+				token_b: exchange.token_b,
+				token_a_reserve: exchange.token_a_reserve,
+				token_b_reserve: exchange.token_b_reserve,
+				fee_numerator: exchange.fee_numerator,
+				fee_denominator: exchange.fee_denominator,
+				liquidity_token_id: exchange.liquidity_token_id,
+				total_liquidity: T::asset_to_currency(total_liquidity),
+			}
+		}
+
+		/// Breaks the trade of `amounts` through `hops` down into fees and price impact.
+		fn quote(
+			hops: &[(ExchangeOf<T>, bool)],
+			amounts: Vec<BalanceOf<T>>,
+		) -> Quote<BalanceOf<T>> {
+			let mut fees = Vec::with_capacity(hops.len());
+			// Ratio of the bought amount to the amount bought at the spot price, fees excluded.
+			let mut execution_ratio = FixedU128::one();
+			for (index, (exchange, sells_token_a)) in hops.iter().enumerate() {
+				let spot_price = Self::spot_prices(exchange)
+					.map(|(price_a, price_b)| if *sells_token_a { price_a } else { price_b })
+					.unwrap_or_default();
+				let fee = Self::swap_fee(exchange, amounts[index]);
+				let spot_output = spot_price.saturating_mul_int(amounts[index].saturating_sub(fee));
+				if !spot_output.is_zero() {
+					execution_ratio = execution_ratio.saturating_mul(
+						FixedU128::saturating_from_rational(amounts[index + 1], spot_output)
+							.min(FixedU128::one()),
+					);
+				}
+				fees.push(fee);
+			}
+			let price_impact = Perbill::from_rational(
+				FixedU128::one().saturating_sub(execution_ratio).into_inner(),
+				FixedU128::accuracy(),
+			);
+
+			Quote {
+				amount_in: amounts[0],
+				amount_out: amounts[amounts.len() - 1],
+				amounts,
+				fees,
+				price_impact,
+			}
+		}
+	}
 }
@@ -0,0 +1,223 @@
+//! Migrations of the storage of the pallet.
+
+use super::*;
+use frame_support::{log, migrations::VersionedMigration, traits::OnRuntimeUpgrade};
+
+pub mod v1 {
+	use frame_support::{
+		pallet_prelude::*,
+		sp_runtime::{
+			traits::{Convert, One, Saturating, Zero},
+			DispatchResult,
+		},
+		storage::with_storage_layer,
+		traits::fungibles::{Create, Inspect, Mutate},
+		weights::Weight,
+	};
+
+	use super::*;
+
+	/// A pool of the first layout, between two token contracts.
+	#[derive(Encode, Decode)]
+	pub struct OldExchange<AccountId, Balance> {
+		pub token_a: AccountId,
+		pub token_a_reserve: Balance,
+		pub token_b_reserve: Balance,
+		pub token_b: AccountId,
+		pub fee_numerator: Balance,
+		pub fee_denominator: Balance,
+	}
+
+	pub type OldExchangeOf<T> = OldExchange<AccountIdOf<T>, BalanceOf<T>>;
+
+	pub mod v0 {
+		use super::*;
+
+		/// Pools of the first layout, by the pair of tokens they were created with.
+		#[frame_support::storage_alias]
+		pub type Exchanges<T: Config> =
+			StorageMap<Pallet<T>, Twox64Concat, (AccountIdOf<T>, AccountIdOf<T>), OldExchangeOf<T>>;
+	}
+
+	/// Moves pools of the first layout to the current one, if the pallet is at storage version 0.
+	pub type MigrateToV1<T, LiquidityTokenId> = VersionedMigration<
+		0,
+		1,
+		VersionUncheckedMigrateToV1<T, LiquidityTokenId>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	/// Moves pools of the first layout to the current one, whatever the storage version.
+	///
+	/// Every pool is stored under the canonical order of its pair, numbered and given a liquidity
+	/// token. `LiquidityTokenId` lists the asset ids of the liquidity tokens in the order they are
+	/// allocated, and ids the asset registry already handed out are skipped. The first layout did
+	/// not track the share of providers, so the whole liquidity of a pool is minted to the pallet
+	/// account, where it stays locked like the reserves it was backing.
+	///
+	/// Pools between identical tokens and second pools of a pair can't be moved and are dropped,
+	/// which `post_upgrade` reports.
+	pub struct VersionUncheckedMigrateToV1<T, LiquidityTokenId>(
+		sp_std::marker::PhantomData<(T, LiquidityTokenId)>,
+	);
+
+	impl<T, LiquidityTokenId> VersionUncheckedMigrateToV1<T, LiquidityTokenId>
+	where
+		T: Config,
+		LiquidityTokenId: Convert<u32, AssetIdOf<T>>,
+	{
+		/// The first liquidity token id from `LiquidityTokenId` at or after `from` which is not
+		/// taken, with its position and the number of ids looked up.
+		fn free_liquidity_token_id(from: u32) -> Option<(u32, AssetIdOf<T>, u64)> {
+			let mut reads = 0u64;
+			(from..=u32::MAX).find_map(|position| {
+				reads.saturating_inc();
+				let id = LiquidityTokenId::convert(position);
+				(!T::AssetRegistry::asset_exists(id.clone())).then_some((position, id, reads))
+			})
+		}
+
+		fn migrate_exchange(
+			pool_id: PoolId,
+			liquidity_token_id: AssetIdOf<T>,
+			old: OldExchangeOf<T>,
+		) -> DispatchResult {
+			let token_a = TokenKind::Contract(old.token_a);
+			let token_b = TokenKind::Contract(old.token_b);
+			let (pair, is_canonical) = Pallet::<T>::canonical_pair(&token_a, &token_b)?;
+			ensure!(!Exchanges::<T>::contains_key(&pair), Error::<T>::ExchangeAlreadyExists);
+			let (token_a_reserve, token_b_reserve) = if is_canonical {
+				(old.token_a_reserve, old.token_b_reserve)
+			} else {
+				(old.token_b_reserve, old.token_a_reserve)
+			};
+
+			let pallet_account = T::pallet_account();
+			T::AssetRegistry::create(
+				liquidity_token_id.clone(),
+				pallet_account.clone(),
+				false,
+				One::one(),
+			)?;
+			let liquidity = T::CurrencyToAssetBalance::convert(token_a_reserve);
+			if !liquidity.is_zero() {
+				T::AssetRegistry::mint_into(
+					liquidity_token_id.clone(),
+					&pallet_account,
+					liquidity,
+				)?;
+			}
+
+			let exchange = Exchange {
+				pool_id,
+				token_a: pair.0.clone(),
+				token_a_reserve,
+				token_b_reserve,
+				token_b: pair.1.clone(),
+				fee_numerator: old.fee_numerator,
+				fee_denominator: old.fee_denominator,
+				liquidity_token_id,
+				price_a_cumulative: Zero::zero(),
+				price_b_cumulative: Zero::zero(),
+				last_updated: <frame_system::Pallet<T>>::block_number(),
+			};
+			Pallet::<T>::index_pool(pool_id, &pair);
+			Pallet::<T>::record_observation(&exchange);
+			Exchanges::<T>::insert(&pair, exchange);
+			Ok(())
+		}
+	}
+
+	impl<T, LiquidityTokenId> OnRuntimeUpgrade for VersionUncheckedMigrateToV1<T, LiquidityTokenId>
+	where
+		T: Config,
+		LiquidityTokenId: Convert<u32, AssetIdOf<T>>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			// Both layouts share the storage prefix, so the old pools are taken out first.
+			let old_exchanges: Vec<_> = v0::Exchanges::<T>::drain().collect();
+			let migrated = old_exchanges.len() as u64;
+			let mut next_pool_id = NextPoolId::<T>::get();
+			let mut next_token_id = 0u32;
+			let (mut dropped, mut token_id_reads) = (0u64, 0u64);
+			for ((token_a, token_b), old) in old_exchanges {
+				let Some((position, liquidity_token_id, reads)) =
+					Self::free_liquidity_token_id(next_token_id)
+				else {
+					dropped.saturating_inc();
+					log::warn!(
+						target: LOG_TARGET,
+						"Dropped the pool between {:?} and {:?}: no liquidity token id is left",
+						token_a,
+						token_b
+					);
+					continue
+				};
+				token_id_reads.saturating_accrue(reads);
+				match with_storage_layer(|| {
+					Self::migrate_exchange(next_pool_id, liquidity_token_id, old)
+				}) {
+					Ok(()) => {
+						next_pool_id.saturating_inc();
+						next_token_id = position.saturating_add(1);
+					},
+					Err(error) => {
+						dropped.saturating_inc();
+						log::warn!(
+							target: LOG_TARGET,
+							"Dropped the pool between {:?} and {:?}: {:?}",
+							token_a,
+							token_b,
+							error
+						);
+					},
+				}
+			}
+			NextPoolId::<T>::put(next_pool_id);
+
+			log::info!(
+				target: LOG_TARGET,
+				"Upgraded {} pools, dropped {}",
+				migrated - dropped,
+				dropped
+			);
+			T::DbWeight::get().reads_writes(3 * migrated + token_id_reads + 1, 9 * migrated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let prev_count = v0::Exchanges::<T>::iter().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), &'static str> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = Exchanges::<T>::iter().count() as u32;
+			frame_support::ensure!(prev_count == post_count, "some pools were dropped");
+			frame_support::ensure!(
+				NextPoolId::<T>::get() >= post_count,
+				"pool ids must not be reused"
+			);
+
+			for (pair, exchange) in Exchanges::<T>::iter() {
+				frame_support::ensure!(
+					Pools::<T>::get(exchange.pool_id) == Some(pair.clone()),
+					"pools must be indexed by id"
+				);
+				frame_support::ensure!(
+					PoolsByToken::<T>::get(&pair.0, &pair.1) == Some(exchange.pool_id),
+					"pools must be indexed by token"
+				);
+				frame_support::ensure!(
+					T::AssetRegistry::asset_exists(exchange.liquidity_token_id),
+					"pools must have a liquidity token"
+				);
+			}
+			Ok(())
+		}
+	}
+}
@@ -1,14 +1,31 @@
-use crate as pallet_template;
-use frame_support::traits::{ConstU16, ConstU64};
+use crate as pallet_dex;
+use codec::Encode;
+use frame_support::{
+	parameter_types,
+	traits::{
+		AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64, ConstU8, Currency, Everything,
+		OnUnbalanced,
+	},
+	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, IdentityFee, Weight},
+	PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use pallet_contracts::{DefaultAddressGenerator, Frame, Schedule};
+use pallet_contracts_primitives::Code;
+use pallet_transaction_payment::CurrencyAdapter;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{AccountIdConversion, BlakeTwo256, Convert, Identity, IdentityLookup},
+	AccountId32,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
+pub type AccountId = AccountId32;
+pub type Balance = u64;
+
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
 	pub enum Test where
@@ -17,13 +34,28 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system,
-		TemplateModule: pallet_template,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
+		Randomness: pallet_insecure_randomness_collective_flip,
+		Assets: pallet_assets,
+		Contracts: pallet_contracts,
+		Uniques: pallet_uniques,
+		Dex: pallet_dex,
+		TransactionPayment: pallet_transaction_payment,
+		AssetTxPayment: pallet_asset_tx_payment,
 	}
 );
 
+parameter_types! {
+	pub BlockWeights: frame_system::limits::BlockWeights =
+		frame_system::limits::BlockWeights::simple_max(
+			Weight::from_parts(2u64 * WEIGHT_REF_TIME_PER_SECOND, u64::MAX),
+		);
+}
+
 impl frame_system::Config for Test {
-	type BaseCallFilter = frame_support::traits::Everything;
-	type BlockWeights = ();
+	type BaseCallFilter = Everything;
+	type BlockWeights = BlockWeights;
 	type BlockLength = ();
 	type DbWeight = ();
 	type RuntimeOrigin = RuntimeOrigin;
@@ -32,27 +64,336 @@ impl frame_system::Config for Test {
 	type BlockNumber = u64;
 	type Hash = H256;
 	type Hashing = BlakeTwo256;
-	type AccountId = u64;
+	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type Header = Header;
 	type RuntimeEvent = RuntimeEvent;
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
-	type SS58Prefix = ConstU16<42>;
+	type SS58Prefix = ();
 	type OnSetCode = ();
-	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+impl pallet_insecure_randomness_collective_flip::Config for Test {}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type WeightInfo = ();
+	type CallbackHandle = ();
+	type Extra = ();
+	type RemoveItemsLimit = ConstU32<5>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub const UniquesMaxRoyalty: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(50);
+}
+
+impl pallet_uniques::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type Locker = ();
+	type OnBurn = ();
+	type CollectionDeposit = ConstU64<2>;
+	type ItemDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type AttributeDepositBase = ConstU64<1>;
+	type DepositPerByte = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type KeyLimit = ConstU32<50>;
+	type ValueLimit = ConstU32<50>;
+	type MaxRoyalty = UniquesMaxRoyalty;
+	type MaxOffers = ConstU32<10>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
+parameter_types! {
+	pub MySchedule: Schedule<Test> = {
+		let mut schedule = <Schedule<Test>>::default();
+		schedule.instruction_weights.fallback = 1;
+		schedule
+	};
+	pub const DeletionWeightLimit: Weight = Weight::from_ref_time(500_000_000_000);
+}
+
+impl Convert<Weight, Balance> for Test {
+	fn convert(w: Weight) -> Balance {
+		w.ref_time()
+	}
+}
+
+impl pallet_contracts::Config for Test {
+	type Time = Timestamp;
+	type Randomness = Randomness;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type CallFilter = Everything;
+	type CallStack = [Frame<Self>; 5];
+	type WeightPrice = Self;
+	type WeightInfo = ();
+	type ChainExtension = ();
+	type DeletionQueueDepth = ConstU32<1024>;
+	type DeletionWeightLimit = DeletionWeightLimit;
+	type Schedule = MySchedule;
+	type DepositPerByte = ConstU64<1>;
+	type DepositPerItem = ConstU64<2>;
+	type AddressGenerator = DefaultAddressGenerator;
+	type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
+	type MaxStorageKeyLen = ConstU32<128>;
+	type UnsafeUnstableInterface = ConstBool<false>;
+	type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
+}
+
+parameter_types! {
+	pub const DexPalletId: PalletId = PalletId(*b"py/dexpl");
+	pub const ContractCallGasLimit: Weight = Weight::from_parts(10_000_000_000, 256 * 1024);
+	pub const MinimumLiquidity: Balance = 1_000;
+	pub const TreasuryAccount: AccountId = TREASURY;
 }
 
-impl pallet_template::Config for Test {
+impl pallet_dex::Config for Test {
+	type PalletId = DexPalletId;
 	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type AssetBalance = Balance;
+	type AssetToCurrencyBalance = Identity;
+	type CurrencyToAssetBalance = Identity;
+	type AssetId = u32;
+	type Assets = Assets;
+	type AssetRegistry = Assets;
+	type WeightInfo = ();
+	// Provider fee is 0.3%
+	type ProviderFeeNumerator = ConstU64<3>;
+	type ProviderFeeDenominator = ConstU64<1000>;
+	type MinDeposit = ConstU64<10>;
+	type MinimumLiquidity = MinimumLiquidity;
+	type MaxSwapPathLength = ConstU32<4>;
+	type MaxFlashSwapDataLength = ConstU32<1024>;
+	type MaxOrderLifetime = ConstU64<100>;
+	type PositionNfts = Uniques;
+	type PositionCollection = ConstU32<0>;
+	type MaxTicks = ConstU32<8>;
+	type MaxStableTokens = ConstU32<4>;
+	type ObservationPeriod = ConstU64<10>;
+	type MaxObservations = ConstU32<3>;
+	type GovernanceOrigin = EnsureRoot<AccountId>;
+	type TreasuryAccount = TreasuryAccount;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+	type ContractCallGasLimit = ContractCallGasLimit;
+}
+
+impl pallet_transaction_payment::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+	type OperationalFeeMultiplier = ConstU8<5>;
+	type WeightToFee = IdentityFee<Balance>;
+	type LengthToFee = IdentityFee<Balance>;
+	type FeeMultiplierUpdate = ();
+}
+
+/// Pays the transaction fees and tips to `BLOCK_AUTHOR`.
+pub struct FeesToAuthor;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Test>> for FeesToAuthor {
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Test>) {
+		Balances::resolve_creating(&BLOCK_AUTHOR, amount);
+	}
+}
+
+impl pallet_asset_tx_payment::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Fungibles = Assets;
+	type OnChargeAssetTransaction = pallet_dex::DexAdapter<FeesToAuthor>;
+}
+
+pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
+pub const BOB: AccountId = AccountId32::new([2u8; 32]);
+pub const CHARLIE: AccountId = AccountId32::new([3u8; 32]);
+pub const TREASURY: AccountId = AccountId32::new([4u8; 32]);
+pub const BLOCK_AUTHOR: AccountId = AccountId32::new([5u8; 32]);
+
+/// Native balance every test account (and the pallet accounts) starts with. It pays for the
+/// storage deposits of the token contracts and of the position receipts.
+pub const ENDOWMENT: Balance = 1_000_000_000_000;
+
+pub const GAS_LIMIT: Weight = Weight::from_parts(100_000_000_000, 512 * 1024);
+
+pub fn dex_account() -> AccountId {
+	DexPalletId::get().into_account_truncating()
+}
+
+/// Account paying the deposits of the position receipts.
+pub fn positions_account() -> AccountId {
+	DexPalletId::get().into_sub_account_truncating(b"positions")
+}
+
+/// Deploys the `psp22` fixture with `supply` minted to `owner` and returns its address.
+///
+/// `salt` has to be unique per token deployed by the same owner.
+pub fn deploy_token(owner: &AccountId, supply: Balance, salt: u8) -> AccountId {
+	let wasm = wat::parse_file("fixtures/psp22.wat").expect("psp22 fixture is valid wat");
+	Contracts::bare_instantiate(
+		owner.clone(),
+		0,
+		GAS_LIMIT,
+		None,
+		Code::Upload(wasm),
+		supply.encode(),
+		vec![salt],
+		false,
+	)
+	.result
+	.expect("psp22 fixture deploys")
+	.account_id
+}
+
+/// Reads the `balance_of` of `who` straight from the token contract.
+pub fn token_balance(token: &AccountId, who: &AccountId) -> Balance {
+	let mut data = vec![0x70, 0xa0, 0x8a, 0x31];
+	data.extend(who.encode());
+	let result = Contracts::bare_call(
+		who.clone(),
+		token.clone(),
+		0,
+		GAS_LIMIT,
+		None,
+		data,
+		false,
+		pallet_contracts::Determinism::Deterministic,
+	)
+	.result
+	.expect("balance_of succeeds");
+	codec::Decode::decode(&mut &result.data[..]).expect("balance_of returns a u64")
+}
+
+/// Moves `amount` of `token` from `from` to `to` outside of the DEX.
+pub fn token_transfer(token: &AccountId, from: &AccountId, to: &AccountId, amount: Balance) {
+	let data = token_transfer_input(to, amount);
+	let result = Contracts::bare_call(
+		from.clone(),
+		token.clone(),
+		0,
+		GAS_LIMIT,
+		None,
+		data,
+		false,
+		pallet_contracts::Determinism::Deterministic,
+	)
+	.result
+	.expect("transfer succeeds");
+	assert!(!result.did_revert());
+}
+
+/// Deploys the `flash_receiver` fixture on behalf of `owner` and returns its address.
+///
+/// `salt` has to be unique per receiver deployed by the same owner.
+pub fn deploy_flash_receiver(owner: &AccountId, salt: u8) -> AccountId {
+	let wasm = wat::parse_file("fixtures/flash_receiver.wat")
+		.expect("flash_receiver fixture is valid wat");
+	Contracts::bare_instantiate(
+		owner.clone(),
+		0,
+		GAS_LIMIT,
+		None,
+		Code::Upload(wasm),
+		vec![],
+		vec![salt],
+		false,
+	)
+	.result
+	.expect("flash_receiver fixture deploys")
+	.account_id
+}
+
+/// Programs `receiver` to call `callee` with `input` whenever it is called back by a flash swap.
+pub fn program_flash_receiver(receiver: &AccountId, callee: &AccountId, input: Vec<u8>) {
+	let mut data = vec![0; 4];
+	data.extend(callee.encode());
+	data.extend(input);
+	let result = Contracts::bare_call(
+		ALICE,
+		receiver.clone(),
+		0,
+		GAS_LIMIT,
+		None,
+		data,
+		false,
+		pallet_contracts::Determinism::Deterministic,
+	)
+	.result
+	.expect("programming the receiver succeeds");
+	assert!(!result.did_revert());
+}
+
+/// Input of a `transfer` of `amount` of a `psp22` token to `to`.
+pub fn token_transfer_input(to: &AccountId, amount: Balance) -> Vec<u8> {
+	let mut data = vec![0x84, 0xa1, 0x5d, 0xa1];
+	data.extend(to.encode());
+	data.extend(amount.encode());
+	data
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![
+			(ALICE, ENDOWMENT),
+			(BOB, ENDOWMENT),
+			(CHARLIE, ENDOWMENT),
+			(dex_account(), ENDOWMENT),
+			(positions_account(), ENDOWMENT),
+		],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
 }
@@ -0,0 +1,190 @@
+//! Paying transaction fees in assets through the pools of the pallet.
+//!
+//! [`DexAdapter`] lets `pallet_asset_tx_payment` charge fees in any asset with a pool against the
+//! native currency. The fee is bought from the pool at its current price before the transaction is
+//! dispatched, and the overcharged part of it is sold back into the pool and refunded in the asset
+//! afterwards.
+//!
+//! The weight of the swaps is not charged for, as for the fees of other `OnChargeAssetTransaction`
+//! implementations.
+
+use super::*;
+use crate::concentrated::to_balance;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{
+		helpers_128bit::multiply_by_rational_with_rounding,
+		traits::{
+			Convert, DispatchInfoOf, PostDispatchInfoOf, Saturating, UniqueSaturatedInto, Zero,
+		},
+		DispatchError, Rounding,
+	},
+	storage::with_storage_layer,
+	traits::{ExistenceRequirement::KeepAlive, Imbalance, OnUnbalanced, WithdrawReasons},
+};
+use pallet_asset_tx_payment::OnChargeAssetTransaction;
+use sp_std::marker::PhantomData;
+
+/// Code of the [`InvalidTransaction::Custom`] error of transactions paying their fee in an asset
+/// without a pool against the native currency.
+pub const NO_FEE_POOL: u8 = 1;
+
+type NegativeImbalanceOf<T> =
+	<<T as Config>::Currency as Currency<AccountIdOf<T>>>::NegativeImbalance;
+
+/// Fee paid through [`DexAdapter`]: the asset it was paid in, the amount of the asset sold for it
+/// and the fee bought.
+pub type FeeSwapOf<T> = (AssetIdOf<T>, BalanceOf<T>, NegativeImbalanceOf<T>);
+
+/// Charges transaction fees in assets by swapping them to the native currency through the pool of
+/// the asset against it, and hands the fees and tips over to `OU`.
+///
+/// Only `TokenKind::Asset` tokens can pay fees, and the fees of transactions offering an asset
+/// without such a pool are rejected with [`NO_FEE_POOL`].
+pub struct DexAdapter<OU>(PhantomData<OU>);
+
+impl<T, OU> OnChargeAssetTransaction<T> for DexAdapter<OU>
+where
+	T: Config + pallet_asset_tx_payment::Config<Fungibles = <T as Config>::Assets>,
+	AssetIdOf<T>: Copy + Default,
+	OU: OnUnbalanced<NegativeImbalanceOf<T>>,
+{
+	type Balance = BalanceOf<T>;
+	type AssetId = AssetIdOf<T>;
+	type LiquidityInfo = FeeSwapOf<T>;
+
+	/// Sells enough of `asset_id` into its pool against the native currency to buy `fee`.
+	///
+	/// Note: The `fee` already includes the `tip`.
+	fn withdraw_fee(
+		who: &AccountIdOf<T>,
+		_call: &<T as frame_system::Config>::RuntimeCall,
+		_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		asset_id: Self::AssetId,
+		fee: Self::Balance,
+		_tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		let asset = TokenKind::Asset(asset_id);
+		let hop = Pallet::<T>::get_directed_exchange(&asset, &TokenKind::Native)
+			.map_err(|_| InvalidTransaction::Custom(NO_FEE_POOL))?;
+		let asset_amount =
+			Pallet::<T>::hop_input_amount(&hop, fee).map_err(|_| InvalidTransaction::Payment)?;
+
+		// The fee is bought into the pallet account, which holds the native reserve of the pool.
+		let pallet_account = T::pallet_account();
+		let mut gas_used = Weight::zero();
+		let paid = with_storage_layer(|| -> Result<_, DispatchError> {
+			Pallet::<T>::transfer_token_from_owner(
+				who,
+				asset,
+				pallet_account.clone(),
+				asset_amount,
+				&mut gas_used,
+			)?;
+			Pallet::<T>::update_hop_reserves(hop, asset_amount, fee, &mut gas_used)?;
+			<T as Config>::Currency::withdraw(
+				&pallet_account,
+				fee,
+				WithdrawReasons::TRANSACTION_PAYMENT,
+				KeepAlive,
+			)
+		})
+		.map_err(|_| InvalidTransaction::Payment)?;
+
+		Pallet::<T>::deposit_event(Event::TransactionFeeSwapped(
+			who.clone(),
+			asset_id,
+			asset_amount,
+			fee,
+		));
+		Ok((asset_id, asset_amount, paid))
+	}
+
+	/// Refunds the overcharged part of the fee in the asset it was paid in, at the current price
+	/// of its pool, and hands the corrected fee and the tip over to `OU`.
+	///
+	/// The overcharge is refunded in the native currency if it can't be sold back, e.g. because
+	/// the transaction emptied the pool.
+	///
+	/// Note: The `corrected_fee` already includes the `tip`.
+	///
+	/// Returns the fee and tip in the asset used for payment as (fee, tip).
+	fn correct_and_deposit_fee(
+		who: &AccountIdOf<T>,
+		_dispatch_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		_post_info: &PostDispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		(asset_id, asset_amount, paid): Self::LiquidityInfo,
+	) -> Result<(AssetBalanceOf<T>, AssetBalanceOf<T>), TransactionValidityError> {
+		let (fee, refund) = paid.split(corrected_fee);
+		let refund_amount = refund.peek();
+		let asset_refund = if refund_amount.is_zero() {
+			Zero::zero()
+		} else {
+			match sell_refund::<T>(who, asset_id, refund_amount) {
+				Ok(asset_refund) => {
+					<T as Config>::Currency::resolve_creating(&T::pallet_account(), refund);
+					Pallet::<T>::deposit_event(Event::TransactionFeeRefunded(
+						who.clone(),
+						asset_id,
+						asset_refund,
+						refund_amount,
+					));
+					asset_refund
+				},
+				Err(_) => {
+					// The refund is lost if the account can't be created with it.
+					<T as Config>::Currency::resolve_creating(who, refund);
+					Zero::zero()
+				},
+			}
+		};
+
+		// The tip is paid in the asset in the same proportion as the whole fee.
+		let asset_fee = asset_amount.saturating_sub(asset_refund);
+		let asset_tip = multiply_by_rational_with_rounding(
+			tip.unique_saturated_into(),
+			asset_fee.unique_saturated_into(),
+			corrected_fee.unique_saturated_into(),
+			Rounding::Down,
+		)
+		.map_or(Ok(Zero::zero()), to_balance::<T>)
+		.map_err(|_| InvalidTransaction::Payment)?;
+
+		let (tip, fee) = fee.split(tip);
+		OU::on_unbalanceds(Some(fee).into_iter().chain(Some(tip)));
+		Ok((
+			T::CurrencyToAssetBalance::convert(asset_fee),
+			T::CurrencyToAssetBalance::convert(asset_tip),
+		))
+	}
+}
+
+/// Sells `refund` of the native currency into the pool of `asset_id` against it and pays what it
+/// buys to `who`, returning the amount bought.
+///
+/// The refund itself is left for the caller to deposit into the pallet account.
+fn sell_refund<T: Config>(
+	who: &AccountIdOf<T>,
+	asset_id: AssetIdOf<T>,
+	refund: BalanceOf<T>,
+) -> Result<BalanceOf<T>, DispatchError> {
+	let asset = TokenKind::Asset(asset_id);
+	let hop = Pallet::<T>::get_directed_exchange(&TokenKind::Native, &asset)?;
+	let asset_refund = Pallet::<T>::hop_output_amount(&hop, refund)?;
+	ensure!(!asset_refund.is_zero(), Error::<T>::InsufficientOutputAmount);
+
+	let mut gas_used = Weight::zero();
+	with_storage_layer(|| {
+		Pallet::<T>::transfer_token_from_owner(
+			&T::pallet_account(),
+			asset,
+			who.clone(),
+			asset_refund,
+			&mut gas_used,
+		)?;
+		Pallet::<T>::update_hop_reserves(hop, refund, asset_refund, &mut gas_used)
+	})?;
+	Ok(asset_refund)
+}
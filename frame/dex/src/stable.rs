@@ -0,0 +1,664 @@
+//! StableSwap pools, for tokens pegged to each other.
+//!
+//! A stable pool holds from two to `MaxStableTokens` tokens and keeps the invariant of Curve's
+//! StableSwap, `A n^n S + D = A n^n D + D^(n + 1) / (n^n P)`, where `n` is the number of tokens,
+//! `S` and `P` are the sum and the product of their balances, and `A` is the amplification
+//! coefficient. The higher `A`, the further from balanced balances the pool keeps trading close
+//! to par.
+//!
+//! Every pair of tokens of a stable pool is an exchange of its own, which swaps route through like
+//! through any other pool and whose reserves mirror the balances of the pool. Liquidity is
+//! provided to the pool as a whole, which is referred to by the id of any of its pairs.
+//!
+//! The invariant and balances are solved for with Newton's method, and amounts are rounded in
+//! favour of the pool.
+
+use super::*;
+use crate::concentrated::to_balance;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{
+		helpers_128bit::multiply_by_rational_with_rounding,
+		traits::{CheckedMul, CheckedSub, Convert, One, Saturating, UniqueSaturatedInto, Zero},
+		DispatchResult, FixedPointNumber, FixedU128, Rounding,
+	},
+	traits::{
+		tokens::fungibles::{Create, Inspect, Mutate},
+		Get,
+	},
+};
+
+/// Highest amplification coefficient of a stable pool.
+pub const MAX_AMPLIFICATION: u32 = 1_000_000;
+/// Largest factor a ramp may scale the amplification coefficient up or down by.
+pub const MAX_AMPLIFICATION_CHANGE: u32 = 10;
+/// Iterations of Newton's method after which it is deemed not to converge.
+const MAX_ITERATIONS: u32 = 255;
+
+/// State of a stable pool, shared by the exchanges of its pairs.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct StablePool<T: Config> {
+	/// Tokens of the pool, in the order they were given in when it was created.
+	pub tokens: BoundedVec<TokenKindOf<T>, T::MaxStableTokens>,
+	/// Balance of every token, in the order of `tokens`.
+	pub balances: BoundedVec<BalanceOf<T>, T::MaxStableTokens>,
+	pub liquidity_token_id: AssetIdOf<T>,
+	pub fee_numerator: BalanceOf<T>,
+	pub fee_denominator: BalanceOf<T>,
+	/// The amplification coefficient moves linearly from `initial_amplification` at
+	/// `initial_block` to `future_amplification` at `future_block`, and stays there.
+	pub initial_amplification: u32,
+	pub future_amplification: u32,
+	pub initial_block: T::BlockNumber,
+	pub future_block: T::BlockNumber,
+}
+
+fn mul_div(a: u128, b: u128, c: u128) -> Option<u128> {
+	multiply_by_rational_with_rounding(a, b, c, Rounding::Down)
+}
+
+/// Returns `A n^n` for `count` tokens.
+fn amplification_product(amplification: u32, count: usize) -> Option<u128> {
+	(count as u128).checked_pow(count as u32)?.checked_mul(amplification as u128)
+}
+
+/// Returns `D^(n + 1) / (n^n P)` for the invariant `invariant` of `balances`.
+fn invariant_product(balances: &[u128], invariant: u128) -> Option<u128> {
+	let count = balances.len() as u128;
+	balances.iter().try_fold(invariant, |product, balance| {
+		mul_div(product, invariant, (*balance).checked_mul(count)?)
+	})
+}
+
+/// Returns the invariant `D` of a pool with `balances`, or `None` if it does not converge.
+pub fn invariant(balances: &[u128], amplification: u32) -> Option<u128> {
+	let sum = balances.iter().try_fold(0u128, |sum, balance| sum.checked_add(*balance))?;
+	if sum.is_zero() {
+		return Some(0)
+	}
+	let count = balances.len() as u128;
+	let ann = amplification_product(amplification, balances.len())?;
+	let mut invariant = sum;
+	for _ in 0..MAX_ITERATIONS {
+		let product = invariant_product(balances, invariant)?;
+		let previous = invariant;
+		// D = (A n^n S + n D_P) D / ((A n^n - 1) D + (n + 1) D_P)
+		let numerator = ann.checked_mul(sum)?.checked_add(count.checked_mul(product)?)?;
+		let denominator = (ann - 1)
+			.checked_mul(invariant)?
+			.checked_add((count + 1).checked_mul(product)?)?;
+		invariant = mul_div(numerator, invariant, denominator)?;
+		if invariant.abs_diff(previous) <= 1 {
+			return Some(invariant)
+		}
+	}
+	None
+}
+
+/// Returns the balance of token `index` keeping the invariant at `invariant` when the other
+/// tokens have `balances`, or `None` if it does not converge. The balance of `index` in
+/// `balances` is ignored.
+fn balance_for_invariant(
+	balances: &[u128],
+	index: usize,
+	invariant: u128,
+	amplification: u32,
+) -> Option<u128> {
+	let count = balances.len() as u128;
+	let ann = amplification_product(amplification, balances.len())?;
+	// With `S'` and `P'` the sum and product of the other balances, the balance `y` solves
+	// `y^2 + (b - D) y = c` for `b = S' + D / (A n^n)` and `c = D^(n + 1) / (n^n P' A n^n)`.
+	let mut sum = 0u128;
+	let mut c = invariant;
+	for (other, balance) in balances.iter().enumerate() {
+		if other != index {
+			sum = sum.checked_add(*balance)?;
+			c = mul_div(c, invariant, (*balance).checked_mul(count)?)?;
+		}
+	}
+	let c = mul_div(c, invariant, ann.checked_mul(count)?)?;
+	let b = sum.checked_add(invariant / ann)?;
+	let mut balance = invariant;
+	for _ in 0..MAX_ITERATIONS {
+		let previous = balance;
+		// y = (y^2 + c) / (2 y + b - D)
+		let denominator = balance.checked_mul(2)?.checked_add(b)?.checked_sub(invariant)?;
+		balance =
+			mul_div(balance, balance, denominator)?.checked_add(c.checked_div(denominator)?)?;
+		if balance.abs_diff(previous) <= 1 {
+			return Some(balance)
+		}
+	}
+	None
+}
+
+/// Returns the price of token `sold` in token `bought` at `balances`, fees excluded.
+fn spot_price(
+	balances: &[u128],
+	sold: usize,
+	bought: usize,
+	amplification: u32,
+) -> Option<FixedU128> {
+	let invariant = invariant(balances, amplification)?;
+	let ann = amplification_product(amplification, balances.len())?;
+	let product = invariant_product(balances, invariant)?;
+	// x_bought (A n^n x_sold + D_P) / (x_sold (A n^n x_bought + D_P))
+	let ratio = FixedU128::checked_from_rational(balances[bought], balances[sold])?;
+	let weight = FixedU128::checked_from_rational(
+		ann.checked_mul(balances[sold])?.checked_add(product)?,
+		ann.checked_mul(balances[bought])?.checked_add(product)?,
+	)?;
+	ratio.checked_mul(&weight)
+}
+
+impl<T: Config> StablePool<T> {
+	/// Returns the amplification coefficient at block `now`.
+	pub fn amplification(&self, now: T::BlockNumber) -> u32 {
+		if now >= self.future_block {
+			return self.future_amplification
+		}
+		let elapsed: u128 = now.saturating_sub(self.initial_block).unique_saturated_into();
+		let duration: u128 =
+			self.future_block.saturating_sub(self.initial_block).unique_saturated_into();
+		let (initial, future) = (self.initial_amplification as u128, self.future_amplification);
+		let amplification = if future as u128 > initial {
+			initial + (future as u128 - initial) * elapsed / duration
+		} else {
+			initial - (initial - future as u128) * elapsed / duration
+		};
+		// Lies between the initial and the future coefficients.
+		amplification as u32
+	}
+
+	fn raw_balances(&self) -> Vec<u128> {
+		self.balances.iter().map(|balance| (*balance).unique_saturated_into()).collect()
+	}
+
+	/// Returns the index of `token` in the pool.
+	pub fn index_of(&self, token: &TokenKindOf<T>) -> Option<usize> {
+		self.tokens.iter().position(|other| other == token)
+	}
+
+	/// Returns the indexes of the sold and the bought tokens of a hop through the pool.
+	fn hop_indexes(
+		&self,
+		(exchange, sells_token_a): &(Exchange<T>, bool),
+	) -> Result<(usize, usize), Error<T>> {
+		let (sold, bought) = if *sells_token_a {
+			(&exchange.token_a, &exchange.token_b)
+		} else {
+			(&exchange.token_b, &exchange.token_a)
+		};
+		self.index_of(sold)
+			.zip(self.index_of(bought))
+			.ok_or(Error::<T>::ExchangeDoesNotExist)
+	}
+
+	/// Returns the fee charged on `amount` of a token sold into the pool.
+	fn swap_fee(&self, amount: BalanceOf<T>) -> BalanceOf<T> {
+		FixedU128::saturating_from_rational(self.fee_numerator, self.fee_denominator)
+			.saturating_mul_int(amount)
+	}
+
+	/// Returns the amount of token `bought` bought by selling `amount` of token `sold`.
+	pub(crate) fn output_amount(
+		&self,
+		(sold, bought): (usize, usize),
+		amount: BalanceOf<T>,
+		now: T::BlockNumber,
+	) -> Result<BalanceOf<T>, Error<T>> {
+		let amplification = self.amplification(now);
+		let mut balances = self.raw_balances();
+		let invariant = invariant(&balances, amplification).ok_or(Error::<T>::Overflow)?;
+		let net_amount: u128 = amount.saturating_sub(self.swap_fee(amount)).unique_saturated_into();
+		balances[sold] = balances[sold].checked_add(net_amount).ok_or(Error::<T>::Overflow)?;
+		let balance = balance_for_invariant(&balances, bought, invariant, amplification)
+			.ok_or(Error::<T>::Overflow)?;
+		// A unit less, for the invariant to be kept despite its rounding.
+		to_balance::<T>(balances[bought].saturating_sub(balance).saturating_sub(1))
+	}
+
+	/// Returns the amount of token `sold` to sell to buy `amount` of token `bought`.
+	pub(crate) fn input_amount(
+		&self,
+		(sold, bought): (usize, usize),
+		amount: BalanceOf<T>,
+		now: T::BlockNumber,
+	) -> Result<BalanceOf<T>, Error<T>> {
+		let amplification = self.amplification(now);
+		let mut balances = self.raw_balances();
+		let amount: u128 = amount.unique_saturated_into();
+		ensure!(amount < balances[bought], Error::<T>::NotEnoughLiquidity);
+		let invariant = invariant(&balances, amplification).ok_or(Error::<T>::Overflow)?;
+		balances[bought] -= amount;
+		let balance = balance_for_invariant(&balances, sold, invariant, amplification)
+			.ok_or(Error::<T>::Overflow)?;
+		let net_amount = balance.saturating_sub(balances[sold]).saturating_add(1);
+		// The fee is charged on the amount sold, so the net amount is grossed up by it.
+		let fee_denominator: u128 = self.fee_denominator.unique_saturated_into();
+		let fee_numerator: u128 = self.fee_numerator.unique_saturated_into();
+		let amount = multiply_by_rational_with_rounding(
+			net_amount,
+			fee_denominator,
+			fee_denominator.checked_sub(fee_numerator).ok_or(Error::<T>::Overflow)?,
+			Rounding::Up,
+		)
+		.ok_or(Error::<T>::Overflow)?;
+		to_balance::<T>(amount)
+	}
+
+	/// Returns the prices of `token_a` in `token_b` and of `token_b` in `token_a`.
+	pub(crate) fn spot_prices(
+		&self,
+		token_a: &TokenKindOf<T>,
+		token_b: &TokenKindOf<T>,
+		now: T::BlockNumber,
+	) -> Option<(FixedU128, FixedU128)> {
+		let (a, b) = (self.index_of(token_a)?, self.index_of(token_b)?);
+		let balances = self.raw_balances();
+		let amplification = self.amplification(now);
+		Some((
+			spot_price(&balances, a, b, amplification)?,
+			spot_price(&balances, b, a, amplification)?,
+		))
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Creates a stable pool of `tokens`, funded by `provider` with `amounts`, and an exchange for
+	/// every pair of them. Returns the id of the pool, which is the one of the pair of its first
+	/// two tokens.
+	#[allow(clippy::too_many_arguments)]
+	pub(crate) fn do_create_stable_pool(
+		provider: AccountIdOf<T>,
+		tokens: Vec<TokenKindOf<T>>,
+		amounts: Vec<BalanceOf<T>>,
+		liquidity_token_id: AssetIdOf<T>,
+		(fee_numerator, fee_denominator): (BalanceOf<T>, BalanceOf<T>),
+		amplification: u32,
+		gas_used: &mut Weight,
+	) -> Result<PoolId, DispatchError> {
+		ensure!(tokens.len() >= 2, Error::<T>::InvalidStableTokens);
+		let tokens: BoundedVec<_, T::MaxStableTokens> =
+			tokens.try_into().map_err(|_| Error::<T>::InvalidStableTokens)?;
+		ensure!(amounts.len() == tokens.len(), Error::<T>::StableAmountsMismatch);
+		let balances: BoundedVec<_, T::MaxStableTokens> =
+			amounts.try_into().map_err(|_| Error::<T>::StableAmountsMismatch)?;
+		ensure!((1..=MAX_AMPLIFICATION).contains(&amplification), Error::<T>::InvalidAmplification);
+		ensure!(
+			balances.iter().all(|amount| *amount >= T::MinDeposit::get()),
+			Error::<T>::CurrencyAmountTooLow
+		);
+		ensure!(
+			Self::is_fee_tier_approved(fee_numerator, fee_denominator),
+			Error::<T>::FeeTierNotApproved
+		);
+		let mut pairs = Vec::new();
+		for (index, token) in tokens.iter().enumerate() {
+			for other in &tokens[index + 1..] {
+				ensure!(token != other, Error::<T>::InvalidStableTokens);
+				let (pair, _) = Self::canonical_pair(token, other)?;
+				ensure!(!Exchanges::<T>::contains_key(&pair), Error::<T>::ExchangeAlreadyExists);
+				pairs.push(pair);
+			}
+		}
+		ensure!(
+			!T::AssetRegistry::asset_exists(liquidity_token_id.clone()),
+			Error::<T>::TokenIdTaken
+		);
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let pool = StablePool {
+			tokens,
+			balances,
+			liquidity_token_id: liquidity_token_id.clone(),
+			fee_numerator,
+			fee_denominator,
+			initial_amplification: amplification,
+			future_amplification: amplification,
+			initial_block: now,
+			future_block: now,
+		};
+		let invariant =
+			invariant(&pool.raw_balances(), amplification).ok_or(Error::<T>::Overflow)?;
+		let provider_liquidity = T::CurrencyToAssetBalance::convert(to_balance::<T>(invariant)?)
+			.checked_sub(&T::MinimumLiquidity::get())
+			.filter(|liquidity| !liquidity.is_zero())
+			.ok_or(Error::<T>::InitialLiquidityTooLow)?;
+		for (token, amount) in pool.tokens.iter().zip(&pool.balances) {
+			Self::check_enough_currency(&provider, token, *amount, gas_used)?;
+		}
+		let pool_id = NextPoolId::<T>::get();
+		let next_pool_id =
+			pool_id.checked_add(pairs.len() as PoolId).ok_or(Error::<T>::PoolIdOverflow)?;
+
+		let pallet_account = T::pallet_account();
+		T::AssetRegistry::create(
+			liquidity_token_id.clone(),
+			pallet_account.clone(),
+			false,
+			One::one(),
+		)?;
+		T::AssetRegistry::mint_into(
+			liquidity_token_id.clone(),
+			&pallet_account,
+			T::MinimumLiquidity::get(),
+		)?;
+		T::AssetRegistry::mint_into(liquidity_token_id.clone(), &provider, provider_liquidity)?;
+		for (token, amount) in pool.tokens.iter().zip(&pool.balances) {
+			Self::transfer_token_from_owner(
+				&provider,
+				token.clone(),
+				pallet_account.clone(),
+				*amount,
+				gas_used,
+			)?;
+		}
+
+		NextPoolId::<T>::put(next_pool_id);
+		for (pair_id, (token_a, token_b)) in (pool_id..).zip(pairs) {
+			let balance_of = |token| pool.index_of(token).map(|index| pool.balances[index]);
+			let exchange = Exchange {
+				pool_id: pair_id,
+				token_a: token_a.clone(),
+				token_a_reserve: balance_of(&token_a).unwrap_or_default(),
+				token_b_reserve: balance_of(&token_b).unwrap_or_default(),
+				token_b: token_b.clone(),
+				fee_numerator,
+				fee_denominator,
+				liquidity_token_id: liquidity_token_id.clone(),
+				price_a_cumulative: Zero::zero(),
+				price_b_cumulative: Zero::zero(),
+				last_updated: now,
+			};
+			let pair = (token_a, token_b);
+			Self::index_pool(pair_id, &pair);
+			Self::record_observation(&exchange);
+			Exchanges::<T>::insert(&pair, exchange);
+			StablePairs::<T>::insert(pair_id, pool_id);
+			Self::deposit_event(Event::PoolCreated(
+				pair_id,
+				provider.clone(),
+				pair.0,
+				pair.1,
+				liquidity_token_id.clone(),
+			));
+		}
+		Self::deposit_event(Event::StableLiquidityAdded(
+			provider,
+			pool_id,
+			pool.balances.clone(),
+			provider_liquidity,
+		));
+		StablePools::<T>::insert(pool_id, pool);
+		Ok(pool_id)
+	}
+
+	/// Returns the id and the state of the stable pool `pool_id` is a pair of.
+	pub(crate) fn stable_pool(pool_id: PoolId) -> Result<(PoolId, StablePool<T>), Error<T>> {
+		let stable_id = StablePairs::<T>::get(pool_id).ok_or_else(|| {
+			if Pools::<T>::contains_key(pool_id) {
+				Error::<T>::UnsupportedPoolKind
+			} else {
+				Error::<T>::ExchangeDoesNotExist
+			}
+		})?;
+		let pool = StablePools::<T>::get(stable_id).ok_or(Error::<T>::ExchangeDoesNotExist)?;
+		Ok((stable_id, pool))
+	}
+
+	/// Stores `pool` as the stable pool `stable_id`, and mirrors its balances into the reserves of
+	/// its pairs, whose price oracles are updated first.
+	fn store_stable_pool(stable_id: PoolId, pool: StablePool<T>) {
+		for (index, token) in pool.tokens.iter().enumerate() {
+			for (other_index, other) in pool.tokens.iter().enumerate().skip(index + 1) {
+				let Ok((mut exchange, is_canonical)) = Self::get_directed_exchange(token, other)
+				else {
+					continue
+				};
+				Self::update_oracle(&mut exchange);
+				let (reserve, other_reserve) = (pool.balances[index], pool.balances[other_index]);
+				(exchange.token_a_reserve, exchange.token_b_reserve) =
+					if is_canonical { (reserve, other_reserve) } else { (other_reserve, reserve) };
+				Exchanges::<T>::insert(
+					(exchange.token_a.clone(), exchange.token_b.clone()),
+					exchange,
+				);
+			}
+		}
+		StablePools::<T>::insert(stable_id, pool);
+	}
+
+	/// Deposits `amounts` of the tokens of the stable pool `pool_id` is a pair of, for at least
+	/// `min_liquidity`.
+	///
+	/// Any of the amounts may be zero. The part of a deposit off the proportions of the pool pays
+	/// the swap fee scaled by `n / (4 (n - 1))`, as a swap rebalancing it would, which stays in
+	/// the pool.
+	pub(crate) fn do_add_stable_liquidity(
+		provider: AccountIdOf<T>,
+		pool_id: PoolId,
+		amounts: Vec<BalanceOf<T>>,
+		min_liquidity: AssetBalanceOf<T>,
+		gas_used: &mut Weight,
+	) -> DispatchResult {
+		let (stable_id, mut pool) = Self::stable_pool(pool_id)?;
+		ensure!(amounts.len() == pool.tokens.len(), Error::<T>::StableAmountsMismatch);
+		ensure!(amounts.iter().any(|amount| !amount.is_zero()), Error::<T>::TokenAmountIsZero);
+		let amplification = pool.amplification(<frame_system::Pallet<T>>::block_number());
+		let balances = pool.raw_balances();
+		let new_balances = balances
+			.iter()
+			.zip(&amounts)
+			.map(|(balance, amount)| (*balance).checked_add((*amount).unique_saturated_into()))
+			.collect::<Option<Vec<_>>>()
+			.ok_or(Error::<T>::Overflow)?;
+		let initial_invariant = invariant(&balances, amplification).ok_or(Error::<T>::Overflow)?;
+		let new_invariant = invariant(&new_balances, amplification).ok_or(Error::<T>::Overflow)?;
+
+		let count = balances.len() as u128;
+		let imbalance_fee =
+			FixedU128::saturating_from_rational(pool.fee_numerator, pool.fee_denominator)
+				.saturating_mul(FixedU128::saturating_from_rational(count, 4 * (count - 1)));
+		let charged_balances = new_balances
+			.iter()
+			.zip(&balances)
+			.map(|(new_balance, balance)| {
+				let ideal_balance = mul_div(new_invariant, *balance, initial_invariant)?;
+				(*new_balance).checked_sub(
+					imbalance_fee.saturating_mul_int(ideal_balance.abs_diff(*new_balance)),
+				)
+			})
+			.collect::<Option<Vec<_>>>()
+			.ok_or(Error::<T>::Overflow)?;
+		let charged_invariant =
+			invariant(&charged_balances, amplification).ok_or(Error::<T>::Overflow)?;
+		let total_liquidity: u128 =
+			T::asset_to_currency(T::AssetRegistry::total_issuance(pool.liquidity_token_id.clone()))
+				.unique_saturated_into();
+		let liquidity = mul_div(
+			total_liquidity,
+			charged_invariant.saturating_sub(initial_invariant),
+			initial_invariant,
+		)
+		.ok_or(Error::<T>::Overflow)?;
+		let liquidity = T::CurrencyToAssetBalance::convert(to_balance::<T>(liquidity)?);
+		ensure!(
+			!liquidity.is_zero() && liquidity >= min_liquidity,
+			Error::<T>::MinLiquidityTooHigh
+		);
+
+		let pallet_account = T::pallet_account();
+		for (token, amount) in pool.tokens.iter().zip(&amounts) {
+			if !amount.is_zero() {
+				Self::transfer_token_from_owner(
+					&provider,
+					token.clone(),
+					pallet_account.clone(),
+					*amount,
+					gas_used,
+				)?;
+			}
+		}
+		T::AssetRegistry::mint_into(pool.liquidity_token_id.clone(), &provider, liquidity)?;
+		for (balance, amount) in pool.balances.iter_mut().zip(&amounts) {
+			balance.saturating_accrue(*amount);
+		}
+		Self::store_stable_pool(stable_id, pool);
+
+		Self::deposit_event(Event::StableLiquidityAdded(
+			provider,
+			stable_id,
+			// As many amounts as tokens in the pool.
+			amounts.try_into().unwrap_or_default(),
+			liquidity,
+		));
+		Ok(())
+	}
+
+	/// Redeems `liquidity` of the stable pool `pool_id` is a pair of for its share of every
+	/// token, each at least the matching amount of `min_amounts`.
+	pub(crate) fn do_remove_stable_liquidity(
+		provider: AccountIdOf<T>,
+		pool_id: PoolId,
+		liquidity: AssetBalanceOf<T>,
+		min_amounts: Vec<BalanceOf<T>>,
+		gas_used: &mut Weight,
+	) -> DispatchResult {
+		ensure!(!liquidity.is_zero(), Error::<T>::LiquidityAmountIsZero);
+		let (stable_id, mut pool) = Self::stable_pool(pool_id)?;
+		ensure!(min_amounts.len() == pool.tokens.len(), Error::<T>::StableAmountsMismatch);
+		let liquidity_token_id = pool.liquidity_token_id.clone();
+		ensure!(
+			liquidity <= T::AssetRegistry::balance(liquidity_token_id.clone(), &provider),
+			Error::<T>::ProviderLiquidityTooLow
+		);
+		let total_liquidity: u128 =
+			T::asset_to_currency(T::AssetRegistry::total_issuance(liquidity_token_id.clone()))
+				.unique_saturated_into();
+		let share: u128 = T::asset_to_currency(liquidity).unique_saturated_into();
+		let amounts = pool
+			.balances
+			.iter()
+			.zip(&min_amounts)
+			.map(|(balance, min_amount)| {
+				let amount = mul_div((*balance).unique_saturated_into(), share, total_liquidity)
+					.ok_or(Error::<T>::Overflow)?;
+				let amount = to_balance::<T>(amount)?;
+				ensure!(amount >= *min_amount, Error::<T>::MinTokensTooHigh);
+				Ok(amount)
+			})
+			.collect::<Result<Vec<_>, Error<T>>>()?;
+
+		T::AssetRegistry::burn_from(liquidity_token_id, &provider, liquidity)?;
+		let pallet_account = T::pallet_account();
+		for (token, amount) in pool.tokens.iter().zip(&amounts) {
+			if !amount.is_zero() {
+				Self::transfer_token_from_owner(
+					&pallet_account,
+					token.clone(),
+					provider.clone(),
+					*amount,
+					gas_used,
+				)?;
+			}
+		}
+		for (balance, amount) in pool.balances.iter_mut().zip(&amounts) {
+			balance.saturating_reduce(*amount);
+		}
+		Self::store_stable_pool(stable_id, pool);
+
+		Self::deposit_event(Event::StableLiquidityRemoved(
+			provider,
+			stable_id,
+			// As many amounts as tokens in the pool.
+			amounts.try_into().unwrap_or_default(),
+			liquidity,
+		));
+		Ok(())
+	}
+
+	/// Ramps the amplification coefficient of the stable pool `pool_id` is a pair of from its
+	/// current value to `future_amplification`, reached at `future_block`.
+	pub(crate) fn do_ramp_amplification(
+		pool_id: PoolId,
+		future_amplification: u32,
+		future_block: T::BlockNumber,
+	) -> DispatchResult {
+		let (stable_id, mut pool) = Self::stable_pool(pool_id)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+		ensure!(future_block > now, Error::<T>::InvalidAmplificationRamp);
+		ensure!(
+			(1..=MAX_AMPLIFICATION).contains(&future_amplification),
+			Error::<T>::InvalidAmplification
+		);
+		let amplification = pool.amplification(now);
+		ensure!(
+			future_amplification <= amplification.saturating_mul(MAX_AMPLIFICATION_CHANGE) &&
+				amplification <= future_amplification.saturating_mul(MAX_AMPLIFICATION_CHANGE),
+			Error::<T>::AmplificationChangeTooLarge
+		);
+
+		pool.initial_amplification = amplification;
+		pool.initial_block = now;
+		pool.future_amplification = future_amplification;
+		pool.future_block = future_block;
+		StablePools::<T>::insert(stable_id, pool);
+
+		Self::deposit_event(Event::AmplificationRamped(
+			stable_id,
+			amplification,
+			future_amplification,
+			future_block,
+		));
+		Ok(())
+	}
+
+	/// Returns the amount bought from the stable pool of a hop when selling `amount`, or the
+	/// amount to sell to buy `amount` from it.
+	pub(crate) fn stable_hop_amount(
+		pool: &StablePool<T>,
+		hop: &(Exchange<T>, bool),
+		amount: BalanceOf<T>,
+		exact_in: bool,
+	) -> Result<BalanceOf<T>, Error<T>> {
+		let indexes = pool.hop_indexes(hop)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+		if exact_in {
+			pool.output_amount(indexes, amount, now)
+		} else {
+			pool.input_amount(indexes, amount, now)
+		}
+	}
+
+	/// Trades `input_amount` into the stable pool `stable_id` through a hop and `output_amount`
+	/// out of it, and stores its new balances.
+	///
+	/// The output is checked against the pool, as the hop may follow another one through the same
+	/// pool. The protocol share of the swap fee is set aside for the treasury.
+	pub(crate) fn update_stable_reserves(
+		stable_id: PoolId,
+		mut pool: StablePool<T>,
+		hop: (Exchange<T>, bool),
+		input_amount: BalanceOf<T>,
+		output_amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let (sold, bought) = pool.hop_indexes(&hop)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+		ensure!(
+			pool.output_amount((sold, bought), input_amount, now)? >= output_amount,
+			Error::<T>::InsufficientOutputAmount
+		);
+		let protocol_fee = ProtocolFee::<T>::get().mul_floor(pool.swap_fee(input_amount));
+		if !protocol_fee.is_zero() {
+			ProtocolFees::<T>::mutate(&pool.tokens[sold], |fees| {
+				fees.saturating_accrue(protocol_fee)
+			});
+		}
+		pool.balances[sold] =
+			Self::increase_reserve(pool.balances[sold], input_amount.saturating_sub(protocol_fee))?;
+		pool.balances[bought] = Self::reduce_reserve(pool.balances[bought], output_amount)?;
+		Self::store_stable_pool(stable_id, pool);
+		Ok(())
+	}
+}
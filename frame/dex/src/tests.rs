@@ -1,27 +1,3082 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	concentrated::{sqrt_price_at_tick, ConcentratedPool},
+	migration::v1::{self, OldExchange},
+	mock::*,
+	Error, Event, Exchange, Exchanges, ExpiryCursor, FlashSwapInProgress, LimitOrder, NextOrderId,
+	NextPoolId, NextPositionId, Observations, OrderId, PoolId, PoolInfo, PoolKind, Pools,
+	PositionId, ProtocolFees, StablePairs, TokenKind, TokenKindOf, TradeAmount, MAX_AMPLIFICATION,
+	MAX_TICK, MIN_TICK, NO_FEE_POOL,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{DispatchInfo, Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	sp_runtime::{
+		traits::{Convert, SignedExtension, Zero},
+		transaction_validity::InvalidTransaction,
+		DispatchError::BadOrigin,
+		FixedPointNumber, FixedU128, Perbill,
+	},
+	traits::{ConstU32, GenesisBuild, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+	BoundedVec,
+};
+use pallet_asset_tx_payment::ChargeAssetTxPayment;
+
+const SUPPLY: Balance = 1_000_000;
+/// Asset id of the liquidity token of the exchange created by `setup_exchange`.
+const LP: u32 = 100;
+
+type Token = TokenKindOf<Test>;
+
+/// Deploys two tokens owned by `ALICE` and hands some of each to `BOB`.
+///
+/// The tokens are returned in canonical order.
+fn setup_tokens() -> (Token, Token) {
+	let token_a = deploy_token(&ALICE, SUPPLY, 0);
+	let token_b = deploy_token(&ALICE, SUPPLY, 1);
+	token_transfer(&token_a, &ALICE, &BOB, 100_000);
+	token_transfer(&token_b, &ALICE, &BOB, 100_000);
+	let (token_a, token_b) = (TokenKind::Contract(token_a), TokenKind::Contract(token_b));
+	if token_a < token_b {
+		(token_a, token_b)
+	} else {
+		(token_b, token_a)
+	}
+}
+
+/// Creates asset `id` owned by `ALICE`, with `SUPPLY` minted to her and some handed to `BOB`.
+fn setup_asset(id: u32) -> Token {
+	assert_ok!(Assets::force_create(RuntimeOrigin::root(), id, ALICE, false, 1));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), id, ALICE, SUPPLY - 100_000));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), id, BOB, 100_000));
+	TokenKind::Asset(id)
+}
+
+/// Balance of `who` in `token`, whatever its kind.
+fn balance(token: &Token, who: &AccountId) -> Balance {
+	match token {
+		TokenKind::Native => Balances::free_balance(who),
+		TokenKind::Asset(id) =>
+			<Assets as frame_support::traits::fungibles::Inspect<_>>::balance(*id, who),
+		TokenKind::Contract(contract) => token_balance(contract, who),
+	}
+}
+
+/// Creates a `10_000 : 20_000` pool with a 0.3% fee, funded by `ALICE`.
+fn setup_exchange() -> (Token, Token) {
+	let (token_a, token_b) = setup_tokens();
+	assert_ok!(Dex::create_exchange(
+		RuntimeOrigin::signed(ALICE),
+		token_a.clone(),
+		token_b.clone(),
+		LP,
+		10_000,
+		20_000,
+		3,
+		1000,
+		PoolKind::ConstantProduct,
+	));
+	(token_a, token_b)
+}
+
+fn lp_balance(who: &AccountId) -> Balance {
+	<Assets as frame_support::traits::fungibles::Inspect<_>>::balance(LP, who)
+}
+
+fn lp_total_issuance() -> Balance {
+	<Assets as frame_support::traits::fungibles::Inspect<_>>::total_issuance(LP)
+}
+
+/// Reserves of `token_a` and `token_b` in their pool, in the order they are given in.
+fn reserves(token_a: &Token, token_b: &Token) -> (Balance, Balance) {
+	let hop = Dex::get_directed_exchange(token_a, token_b).unwrap();
+	Dex::hop_reserves(&hop)
+}
+
+#[test]
+fn fixture_token_works() {
+	new_test_ext().execute_with(|| {
+		let token = deploy_token(&ALICE, SUPPLY, 0);
+		assert_eq!(token_balance(&token, &ALICE), SUPPLY);
+		token_transfer(&token, &ALICE, &BOB, 400);
+		assert_eq!(token_balance(&token, &ALICE), SUPPLY - 400);
+		assert_eq!(token_balance(&token, &BOB), 400);
+	});
+}
+
+#[test]
+fn create_exchange_works() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+
+		assert_eq!(
+			Dex::exchanges((token_a.clone(), token_b.clone())),
+			Some(Exchange {
+				pool_id: 0,
+				token_a: token_a.clone(),
+				token_a_reserve: 10_000,
+				token_b_reserve: 20_000,
+				token_b: token_b.clone(),
+				fee_numerator: 3,
+				fee_denominator: 1000,
+				liquidity_token_id: LP,
+				price_a_cumulative: Zero::zero(),
+				price_b_cumulative: Zero::zero(),
+				last_updated: 1,
+			})
+		);
+		assert_eq!(balance(&token_a, &dex_account()), 10_000);
+		assert_eq!(balance(&token_b, &dex_account()), 20_000);
+		assert_eq!(balance(&token_a, &ALICE), SUPPLY - 100_000 - 10_000);
+		assert_eq!(balance(&token_b, &ALICE), SUPPLY - 100_000 - 20_000);
+		System::assert_has_event(
+			Event::LiquidityAdded(ALICE, token_a.clone(), 10_000, 20_000, 13_142).into(),
+		);
+		System::assert_has_event(Event::PoolCreated(0, ALICE, token_a, token_b, LP).into());
+	});
+}
+
+#[test]
+fn create_exchange_stores_pairs_in_canonical_order() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_tokens();
+		assert_ok!(Dex::create_exchange(
+			RuntimeOrigin::signed(ALICE),
+			token_b.clone(),
+			token_a.clone(),
+			LP,
+			20_000,
+			10_000,
+			3,
+			1000,
+			PoolKind::ConstantProduct,
+		));
+
+		let exchange = Dex::exchanges((token_a.clone(), token_b.clone())).unwrap();
+		assert_eq!((exchange.token_a, exchange.token_b), (token_a.clone(), token_b.clone()));
+		assert_eq!((exchange.token_a_reserve, exchange.token_b_reserve), (10_000, 20_000));
+		assert!(Dex::exchanges((token_b.clone(), token_a.clone())).is_none());
+		// The initial liquidity doesn't depend on the order the tokens are given in.
+		assert_eq!(lp_balance(&ALICE), 13_142);
+		System::assert_has_event(Event::PoolCreated(0, ALICE, token_a, token_b, LP).into());
+	});
+}
+
+#[test]
+fn create_exchange_fails_for_identical_tokens() {
+	new_test_ext().execute_with(|| {
+		let (token_a, _) = setup_tokens();
+
+		assert_noop!(
+			Dex::create_exchange(
+				RuntimeOrigin::signed(ALICE),
+				token_a.clone(),
+				token_a,
+				LP,
+				10_000,
+				20_000,
+				3,
+				1000,
+				PoolKind::ConstantProduct,
+			),
+			Error::<Test>::IdenticalTokens
+		);
+	});
+}
+
+#[test]
+fn pools_are_numbered_and_indexed_by_token() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b, token_c) = setup_path();
+
+		assert_eq!(NextPoolId::<Test>::get(), 2);
+		assert_eq!(Pools::<Test>::get(0), Some((token_a.clone(), token_b.clone())));
+		assert_eq!(Dex::get_exchange(&token_c, &token_b).unwrap().pool_id, 1);
+		System::assert_has_event(
+			Event::PoolCreated(
+				1,
+				ALICE,
+				token_b.clone().min(token_c.clone()),
+				token_b.clone().max(token_c.clone()),
+				LP + 1,
+			)
+			.into(),
+		);
+
+		let mut pools_of_b = Dex::pools_of(&token_b);
+		pools_of_b.sort();
+		assert_eq!(pools_of_b, vec![(token_a.clone(), 0), (token_c.clone(), 1)]);
+		assert_eq!(Dex::pools_of(&token_a), vec![(token_b.clone(), 0)]);
+		assert_eq!(Dex::pools_of(&token_c), vec![(token_b, 1)]);
+		assert!(Dex::pools_of(&TokenKind::Native).is_empty());
+	});
+}
+
+#[test]
+fn create_exchange_locks_minimum_liquidity() {
+	new_test_ext().execute_with(|| {
+		setup_exchange();
+
+		// The geometric mean of `10_000 * 20_000`.
+		assert_eq!(lp_total_issuance(), 14_142);
+		assert_eq!(lp_balance(&ALICE), 13_142);
+		assert_eq!(lp_balance(&dex_account()), MinimumLiquidity::get());
+	});
+}
+
+#[test]
+fn create_exchange_fails_with_invalid_amounts() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_tokens();
+		let create = |token_a_amount, token_b_amount| {
+			Dex::create_exchange(
+				RuntimeOrigin::signed(ALICE),
+				token_a.clone(),
+				token_b.clone(),
+				LP,
+				token_a_amount,
+				token_b_amount,
+				3,
+				1000,
+				PoolKind::ConstantProduct,
+			)
+		};
+
+		// Either token of the pair must be deposited, whichever order they are given in.
+		assert_noop!(create(10_000, 9), Error::<Test>::CurrencyAmountTooLow);
+		assert_noop!(create(9, 20_000), Error::<Test>::CurrencyAmountTooLow);
+		assert_noop!(create(0, 20_000), Error::<Test>::TokenAmountIsZero);
+		assert_noop!(create(10_000, 0), Error::<Test>::TokenAmountIsZero);
+		// The geometric mean of the amounts must exceed the locked liquidity.
+		assert_noop!(
+			create(MinimumLiquidity::get(), MinimumLiquidity::get()),
+			Error::<Test>::InitialLiquidityTooLow
+		);
+		assert_noop!(create(100, 10_000), Error::<Test>::InitialLiquidityTooLow);
+	});
+}
+
+#[test]
+fn create_exchange_fails_for_taken_liquidity_token_id() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_tokens();
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), LP, BOB, false, 1));
+
+		assert_noop!(
+			Dex::create_exchange(
+				RuntimeOrigin::signed(ALICE),
+				token_a,
+				token_b,
+				LP,
+				10_000,
+				20_000,
+				3,
+				1000,
+				PoolKind::ConstantProduct,
+			),
+			Error::<Test>::TokenIdTaken
+		);
+	});
+}
+
+#[test]
+fn create_exchange_fails_for_existing_pair() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+
+		assert_noop!(
+			Dex::create_exchange(
+				RuntimeOrigin::signed(BOB),
+				token_a.clone(),
+				token_b.clone(),
+				LP + 1,
+				1_000,
+				2_000,
+				3,
+				1000,
+				PoolKind::ConstantProduct,
+			),
+			Error::<Test>::ExchangeAlreadyExists
+		);
+		assert_noop!(
+			Dex::create_exchange(
+				RuntimeOrigin::signed(BOB),
+				token_b,
+				token_a,
+				LP + 1,
+				2_000,
+				1_000,
+				3,
+				1000,
+				PoolKind::ConstantProduct,
+			),
+			Error::<Test>::ExchangeAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn create_exchange_fails_when_pool_ids_run_out() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_tokens();
+		NextPoolId::<Test>::put(PoolId::MAX);
+
+		assert_noop!(
+			Dex::create_exchange(
+				RuntimeOrigin::signed(ALICE),
+				token_a,
+				token_b,
+				LP,
+				10_000,
+				20_000,
+				3,
+				1000,
+				PoolKind::ConstantProduct,
+			),
+			Error::<Test>::PoolIdOverflow
+		);
+	});
+}
+
+#[test]
+fn create_exchange_fails_without_enough_tokens() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_tokens();
+
+		assert_noop!(
+			Dex::create_exchange(
+				RuntimeOrigin::signed(CHARLIE),
+				token_a,
+				token_b,
+				LP,
+				10_000,
+				20_000,
+				3,
+				1000,
+				PoolKind::ConstantProduct,
+			),
+			Error::<Test>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn swap_works() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		let bob_a = balance(&token_a, &BOB);
+		let bob_b = balance(&token_b, &BOB);
+
+		// 1000 * 997 * 20_000 / (10_000 * 1000 + 1000 * 997)
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			token_a.clone(),
+			token_b.clone(),
+			1_000,
+			1_813,
+		));
+
+		assert_eq!(reserves(&token_a, &token_b), (11_000, 18_187));
+		assert_eq!(balance(&token_a, &BOB), bob_a - 1_000);
+		assert_eq!(balance(&token_b, &BOB), bob_b + 1_813);
+		assert_eq!(balance(&token_a, &dex_account()), 11_000);
+		assert_eq!(balance(&token_b, &dex_account()), 18_187);
+		System::assert_has_event(Event::TokenTransferred(token_b, BOB, 1_813).into());
+	});
+}
+
+#[test]
+fn swap_works_in_both_directions() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		let bob_a = balance(&token_a, &BOB);
+
+		// 2000 * 997 * 10_000 / (20_000 * 1000 + 2000 * 997)
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			token_b.clone(),
+			token_a.clone(),
+			2_000,
+			906
+		));
+
+		assert_eq!(reserves(&token_a, &token_b), (9_094, 22_000));
+		assert_eq!(balance(&token_a, &BOB), bob_a + 906);
+		System::assert_has_event(Event::TokenTransferred(token_a, BOB, 906).into());
+	});
+}
+
+#[test]
+fn liquidity_can_be_managed_in_either_token_order() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+
+		// 2000 of `token_b` are a tenth of its reserve.
+		assert_ok!(Dex::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			token_b.clone(),
+			token_a.clone(),
+			2_000,
+			1_000,
+			1_001,
+			100,
+		));
+		assert_eq!(lp_balance(&BOB), 1_414);
+		assert_eq!(reserves(&token_a, &token_b), (11_001, 22_000));
+
+		let remove = |min_currency, min_tokens| {
+			Dex::remove_liquidity(
+				RuntimeOrigin::signed(BOB),
+				token_b.clone(),
+				token_a.clone(),
+				1_414,
+				min_currency,
+				min_tokens,
+				100,
+			)
+		};
+		assert_noop!(remove(2_000, 1), Error::<Test>::MinCurrencyTooHigh);
+		assert_noop!(remove(1, 1_000), Error::<Test>::MinTokensTooHigh);
+		assert_ok!(remove(1_999, 999));
+		assert_eq!(reserves(&token_a, &token_b), (10_002, 20_001));
+		assert_eq!(lp_balance(&BOB), 0);
+	});
+}
+
+#[test]
+fn swap_keeps_constant_product_and_accrues_fees() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		let (reserve_a, reserve_b) = reserves(&token_a, &token_b);
+		let k = reserve_a * reserve_b;
+
+		// Without a fee the pool would hand out `1000 * 20_000 / 11_000 = 1818` tokens.
+		let output = Dex::get_output_amount(&1_000, &reserve_a, &reserve_b, &3, &1000).unwrap();
+		assert_eq!(output, 1_813);
+		assert!(
+			output < Dex::get_output_amount(&1_000, &reserve_a, &reserve_b, &0, &1000).unwrap()
+		);
+
+		for _ in 0..5 {
+			let (reserve_a, reserve_b) = reserves(&token_a, &token_b);
+			let before = reserve_a * reserve_b;
+			assert_ok!(Dex::swap(
+				RuntimeOrigin::signed(BOB),
+				token_a.clone(),
+				token_b.clone(),
+				1_000,
+				1,
+			));
+			let (reserve_a, reserve_b) = reserves(&token_a, &token_b);
+			assert!(reserve_a * reserve_b > before);
+		}
+
+		let (reserve_a, reserve_b) = reserves(&token_a, &token_b);
+		assert!(reserve_a * reserve_b > k);
+		assert_eq!(balance(&token_a, &dex_account()), reserve_a);
+		assert_eq!(balance(&token_b, &dex_account()), reserve_b);
+	});
+}
+
+#[test]
+fn swap_fails_for_unknown_exchange() {
+	new_test_ext().execute_with(|| {
+		let (token_a, _) = setup_exchange();
+
+		assert_noop!(
+			Dex::swap(RuntimeOrigin::signed(BOB), TokenKind::Native, token_a, 1_000, 1),
+			Error::<Test>::ExchangeDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn swap_fails_with_zero_input() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+
+		assert_noop!(
+			Dex::swap(RuntimeOrigin::signed(BOB), token_a, token_b, 0, 1),
+			Error::<Test>::TradeAmountIsZero
+		);
+	});
+}
+
+#[test]
+fn swap_fails_below_min_output() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+
+		assert_noop!(
+			Dex::swap(RuntimeOrigin::signed(BOB), token_a, token_b, 1_000, 1_814),
+			Error::<Test>::InsufficientOutputAmount
+		);
+	});
+}
+
+#[test]
+fn swap_fails_when_token_transfer_reverts() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+
+		assert_noop!(
+			Dex::swap(RuntimeOrigin::signed(CHARLIE), token_a, token_b, 1_000, 1),
+			Error::<Test>::TokenTransferFailed
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_works() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		let bob_a = balance(&token_a, &BOB);
+		let bob_b = balance(&token_b, &BOB);
+
+		// 1000 / 10_000 of the pool: 2000 of `token_b` plus one for rounding.
+		assert_ok!(Dex::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			token_a.clone(),
+			token_b.clone(),
+			1_000,
+			1_000,
+			2_001,
+			1,
+		));
+
+		assert_eq!(reserves(&token_a, &token_b), (11_000, 22_001));
+		assert_eq!(balance(&token_a, &BOB), bob_a - 1_000);
+		assert_eq!(balance(&token_b, &BOB), bob_b - 2_001);
+		assert_eq!(lp_balance(&BOB), 1_414);
+		assert_eq!(lp_total_issuance(), 15_556);
+		System::assert_has_event(Event::LiquidityAdded(BOB, token_a, 1_000, 2_001, 1_414).into());
+	});
+}
+
+#[test]
+fn add_liquidity_fails_with_invalid_parameters() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		let add = |currency_amount, min_liquidity, max_tokens, deadline| {
+			Dex::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				token_a.clone(),
+				token_b.clone(),
+				currency_amount,
+				min_liquidity,
+				max_tokens,
+				deadline,
+			)
+		};
+
+		assert_noop!(add(1_000, 1, 2_001, 0), Error::<Test>::DeadlinePassed);
+		assert_noop!(add(0, 1, 2_001, 1), Error::<Test>::CurrencyAmountIsZero);
+		assert_noop!(add(1_000, 1, 0, 1), Error::<Test>::MaxTokensIsZero);
+		assert_noop!(add(1_000, 0, 2_001, 1), Error::<Test>::MinLiquidityIsZero);
+		assert_noop!(add(1_000, 1, 2_000, 1), Error::<Test>::MaxTokensTooLow);
+		assert_noop!(add(1_000, 1_415, 2_001, 1), Error::<Test>::MinLiquidityTooHigh);
+	});
+}
+
+#[test]
+fn add_liquidity_fails_for_unknown_exchange() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_tokens();
+
+		assert_noop!(
+			Dex::add_liquidity(RuntimeOrigin::signed(BOB), token_a, token_b, 1_000, 1, 2_001, 1),
+			Error::<Test>::ExchangeDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_fails_without_enough_tokens() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+
+		assert_noop!(
+			Dex::add_liquidity(
+				RuntimeOrigin::signed(CHARLIE),
+				token_a,
+				token_b,
+				1_000,
+				1,
+				2_001,
+				1,
+			),
+			Error::<Test>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_works() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		let alice_a = balance(&token_a, &ALICE);
+		let alice_b = balance(&token_b, &ALICE);
+
+		// Half of the liquidity returns half of each reserve.
+		assert_ok!(Dex::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			token_a.clone(),
+			token_b.clone(),
+			7_071,
+			5_000,
+			10_000,
+			1,
+		));
+
+		assert_eq!(reserves(&token_a, &token_b), (5_000, 10_000));
+		assert_eq!(balance(&token_a, &ALICE), alice_a + 5_000);
+		assert_eq!(balance(&token_b, &ALICE), alice_b + 10_000);
+		assert_eq!(balance(&token_a, &dex_account()), 5_000);
+		assert_eq!(balance(&token_b, &dex_account()), 10_000);
+		assert_eq!(lp_balance(&ALICE), 6_071);
+		assert_eq!(lp_total_issuance(), 7_071);
+		System::assert_has_event(
+			Event::LiquidityRemoved(ALICE, token_a, token_b, 5_000, 10_000, 7_071).into(),
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_leaves_minimum_liquidity_locked() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+
+		assert_ok!(Dex::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			token_a.clone(),
+			token_b.clone(),
+			13_142,
+			1,
+			1,
+			1,
+		));
+
+		// The locked liquidity keeps its share of the reserves, rounded up.
+		assert_eq!(reserves(&token_a, &token_b), (708, 1_415));
+		assert_eq!(lp_balance(&ALICE), 0);
+		assert_eq!(lp_total_issuance(), MinimumLiquidity::get());
+	});
+}
+
+#[test]
+fn remove_liquidity_fails_without_enough_liquidity_tokens() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+
+		assert_noop!(
+			Dex::remove_liquidity(RuntimeOrigin::signed(BOB), token_a, token_b, 1_000, 1, 1, 1,),
+			Error::<Test>::ProviderLiquidityTooLow
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_fails_with_invalid_parameters() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		let remove = |liquidity_amount, min_currency, min_tokens, deadline| {
+			Dex::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				token_a.clone(),
+				token_b.clone(),
+				liquidity_amount,
+				min_currency,
+				min_tokens,
+				deadline,
+			)
+		};
+
+		assert_noop!(remove(1_000, 1, 1, 0), Error::<Test>::DeadlinePassed);
+		assert_noop!(remove(0, 1, 1, 1), Error::<Test>::LiquidityAmountIsZero);
+		assert_noop!(remove(1_000, 0, 1, 1), Error::<Test>::MinCurrencyIsZero);
+		assert_noop!(remove(1_000, 1, 0, 1), Error::<Test>::MinTokensIsZero);
+		assert_noop!(remove(13_143, 1, 1, 1), Error::<Test>::ProviderLiquidityTooLow);
+		assert_noop!(remove(1_000, 1_001, 1, 1), Error::<Test>::MinCurrencyTooHigh);
+		assert_noop!(remove(1_000, 1, 2_001, 1), Error::<Test>::MinTokensTooHigh);
+	});
+}
+
+#[test]
+fn remove_liquidity_fails_for_unknown_exchange() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_tokens();
+
+		assert_noop!(
+			Dex::remove_liquidity(RuntimeOrigin::signed(ALICE), token_a, token_b, 1_000, 1, 1, 1),
+			Error::<Test>::ExchangeDoesNotExist
+		);
+	});
+}
+
+/// Extends `setup_exchange` with a third token and a `20_000 : 40_000` exchange between it and
+/// `token_b`. The new exchange is keyed `(token_c, token_b)`, against the direction of the path.
+fn setup_path() -> (Token, Token, Token) {
+	let (token_a, token_b) = setup_exchange();
+	let token_c = TokenKind::Contract(deploy_token(&ALICE, SUPPLY, 2));
+	assert_ok!(Dex::create_exchange(
+		RuntimeOrigin::signed(ALICE),
+		token_c.clone(),
+		token_b.clone(),
+		LP + 1,
+		20_000,
+		40_000,
+		3,
+		1000,
+		PoolKind::ConstantProduct,
+	));
+	(token_a, token_b, token_c)
+}
+
+fn path(tokens: &[&Token]) -> crate::SwapPathOf<Test> {
+	tokens
+		.iter()
+		.map(|token| (*token).clone())
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+#[test]
+fn swap_exact_in_along_path_works() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b, token_c) = setup_path();
+		let bob_a = balance(&token_a, &BOB);
+		let path = path(&[&token_a, &token_b, &token_c]);
+
+		// 1000 of `token_a` buy 1813 of `token_b`, which buy 864 of `token_c`.
+		assert_ok!(Dex::swap_exact_in_along_path(
+			RuntimeOrigin::signed(BOB),
+			path.clone(),
+			1_000,
+			864,
+		));
+
+		assert_eq!(reserves(&token_a, &token_b), (11_000, 18_187));
+		assert_eq!(reserves(&token_c, &token_b), (19_136, 41_813));
+		assert_eq!(balance(&token_a, &BOB), bob_a - 1_000);
+		assert_eq!(balance(&token_c, &BOB), 864);
+		assert_eq!(balance(&token_b, &dex_account()), 18_187 + 41_813);
+		System::assert_has_event(Event::PathSwapped(BOB, path, 1_000, 864).into());
+	});
+}
+
+#[test]
+fn swap_exact_out_along_path_works() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b, token_c) = setup_path();
+		let bob_a = balance(&token_a, &BOB);
+		let path = path(&[&token_a, &token_b, &token_c]);
+
+		// 500 of `token_c` cost 1029 of `token_b`, which cost 545 of `token_a`.
+		assert_ok!(Dex::swap_exact_out_along_path(
+			RuntimeOrigin::signed(BOB),
+			path.clone(),
+			500,
+			545,
+		));
+
+		assert_eq!(reserves(&token_a, &token_b), (10_545, 18_971));
+		assert_eq!(reserves(&token_c, &token_b), (19_500, 41_029));
+		assert_eq!(balance(&token_a, &BOB), bob_a - 545);
+		assert_eq!(balance(&token_c, &BOB), 500);
+		System::assert_has_event(Event::PathSwapped(BOB, path, 545, 500).into());
+	});
+}
+
+#[test]
+fn swap_along_path_checks_slippage_on_the_final_amount() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b, token_c) = setup_path();
+		let path = path(&[&token_a, &token_b, &token_c]);
+
+		assert_noop!(
+			Dex::swap_exact_in_along_path(RuntimeOrigin::signed(BOB), path.clone(), 1_000, 865),
+			Error::<Test>::MinBoughtTokensTooHigh
+		);
+		assert_noop!(
+			Dex::swap_exact_out_along_path(RuntimeOrigin::signed(BOB), path, 500, 544),
+			Error::<Test>::MaxSoldTokensTooLow
+		);
+	});
+}
+
+#[test]
+fn swap_along_path_fails_for_invalid_paths() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b, token_c) = setup_path();
+		let swap = |path| Dex::swap_exact_in_along_path(RuntimeOrigin::signed(BOB), path, 1_000, 1);
+
+		assert_noop!(swap(path(&[&token_a])), Error::<Test>::InvalidPath);
+		assert_noop!(swap(path(&[&token_a, &token_b, &token_a])), Error::<Test>::InvalidPath);
+		assert_noop!(swap(path(&[&token_a, &token_c])), Error::<Test>::ExchangeDoesNotExist);
+		assert_noop!(
+			Dex::swap_exact_in_along_path(
+				RuntimeOrigin::signed(BOB),
+				path(&[&token_a, &token_b]),
+				0,
+				1
+			),
+			Error::<Test>::TradeAmountIsZero
+		);
+	});
+}
+
+#[test]
+fn swap_along_path_fails_when_token_transfer_reverts() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b, token_c) = setup_path();
+
+		assert_noop!(
+			Dex::swap_exact_in_along_path(
+				RuntimeOrigin::signed(CHARLIE),
+				path(&[&token_a, &token_b, &token_c]),
+				1_000,
+				1
+			),
+			Error::<Test>::TokenTransferFailed
+		);
+	});
+}
+
+#[test]
+fn asset_to_asset_swaps_through_the_shared_token() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b, token_c) = setup_path();
+
+		assert_ok!(Dex::asset_to_asset(
+			RuntimeOrigin::signed(BOB),
+			token_a.clone(),
+			token_b.clone(),
+			token_b.clone(),
+			token_c.clone(),
+			TradeAmount::FixedInput { input_amount: 1_000, min_output: 864 },
+		));
+
+		assert_eq!(reserves(&token_c, &token_b), (19_136, 41_813));
+		assert_eq!(balance(&token_c, &BOB), 864);
+		System::assert_has_event(
+			Event::PathSwapped(BOB, path(&[&token_a, &token_b, &token_c]), 1_000, 864).into(),
+		);
+	});
+}
+
+#[test]
+fn asset_to_asset_fails_for_unrelated_exchanges() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b, token_c) = setup_path();
+
+		assert_noop!(
+			Dex::asset_to_asset(
+				RuntimeOrigin::signed(BOB),
+				token_a.clone(),
+				token_b.clone(),
+				token_c,
+				token_b,
+				TradeAmount::FixedInput { input_amount: 1_000, min_output: 1 },
+			),
+			Error::<Test>::InvalidPath
+		);
+	});
+}
+
+#[test]
+fn asset_to_asset_fails_with_invalid_trade_amount() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b, token_c) = setup_path();
+		let trade = |amount| {
+			Dex::asset_to_asset(
+				RuntimeOrigin::signed(BOB),
+				token_a.clone(),
+				token_b.clone(),
+				token_b.clone(),
+				token_c.clone(),
+				amount,
+			)
+		};
+
+		assert_noop!(
+			trade(TradeAmount::FixedInput { input_amount: 0, min_output: 1 }),
+			Error::<Test>::TradeAmountIsZero
+		);
+		assert_noop!(
+			trade(TradeAmount::FixedOutput { max_input: 1_000, output_amount: 0 }),
+			Error::<Test>::TradeAmountIsZero
+		);
+		assert_noop!(
+			trade(TradeAmount::FixedInput { input_amount: 1_000, min_output: 865 }),
+			Error::<Test>::MinBoughtTokensTooHigh
+		);
+		assert_noop!(
+			trade(TradeAmount::FixedOutput { max_input: 1, output_amount: 500 }),
+			Error::<Test>::MaxSoldTokensTooLow
+		);
+		assert_noop!(
+			trade(TradeAmount::FixedOutput { max_input: 1_000, output_amount: 20_000 }),
+			Error::<Test>::NotEnoughLiquidity
+		);
+	});
+}
+
+#[test]
+fn pricing_functions_detect_overflow() {
+	new_test_ext().execute_with(|| {
+		assert!(matches!(
+			Dex::get_output_amount(&Balance::MAX, &10_000, &20_000, &3, &1000),
+			Err(Error::<Test>::Overflow)
+		));
+		assert!(matches!(
+			Dex::get_input_amount(&1_000, &Balance::MAX, &20_000, &3, &1000),
+			Err(Error::<Test>::Overflow)
+		));
+	});
+}
+
+#[test]
+fn reserve_updates_detect_overflow() {
+	new_test_ext().execute_with(|| {
+		assert!(matches!(
+			Dex::increase_reserve(Balance::MAX, 1),
+			Err(Error::<Test>::ArithmeticOverflow)
+		));
+		assert!(matches!(
+			Dex::reduce_reserve(1_000, 1_001),
+			Err(Error::<Test>::ArithmeticUnderflow)
+		));
+	});
+}
+
+#[test]
+fn input_and_output_amounts_are_consistent() {
+	new_test_ext().execute_with(|| {
+		let output = Dex::get_output_amount(&1_000, &10_000, &20_000, &3, &1000).unwrap();
+		let input = Dex::get_input_amount(&output, &10_000, &20_000, &3, &1000).unwrap();
+		// Rounding always favours the pool.
+		assert!(input <= 1_000);
+		assert!(Dex::get_output_amount(&input, &10_000, &20_000, &3, &1000).unwrap() >= output);
+	});
+}
+
+#[test]
+fn quote_exact_in_matches_the_swap() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b, token_c) = setup_path();
+		let tokens = vec![token_a.clone(), token_b, token_c.clone()];
+
+		let quote = Dex::quote_exact_in(tokens.clone(), 1_000).unwrap();
+		assert_eq!(quote.amounts, vec![1_000, 1_813, 864]);
+		assert_eq!((quote.amount_in, quote.amount_out), (1_000, 864));
+		assert_eq!(quote.fees, vec![3, 5]);
+		// 1813 / (997 * 2) and 864 / (1808 / 2) compound to a 13% worse price than the spot one.
+		assert!(quote.price_impact > Perbill::from_percent(13));
+		assert!(quote.price_impact < Perbill::from_percent(14));
+
+		let bob_c = balance(&token_c, &BOB);
+		assert_ok!(Dex::swap_exact_in_along_path(
+			RuntimeOrigin::signed(BOB),
+			tokens.try_into().unwrap(),
+			1_000,
+			1,
+		));
+		assert_eq!(balance(&token_c, &BOB), bob_c + quote.amount_out);
+		assert!(Dex::quote_exact_in(vec![token_a, token_c], 1_000).is_err());
+	});
+}
+
+#[test]
+fn quote_exact_out_matches_the_swap() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b, token_c) = setup_path();
+		let tokens = vec![token_a.clone(), token_b, token_c];
+
+		let quote = Dex::quote_exact_out(tokens.clone(), 500).unwrap();
+		assert_eq!(quote.amounts, vec![545, 1_029, 500]);
+		assert_eq!(quote.fees, vec![1, 3]);
+
+		let bob_a = balance(&token_a, &BOB);
+		assert_ok!(Dex::swap_exact_out_along_path(
+			RuntimeOrigin::signed(BOB),
+			tokens.try_into().unwrap(),
+			500,
+			545,
+		));
+		assert_eq!(balance(&token_a, &BOB), bob_a - quote.amount_in);
+	});
+}
+
+#[test]
+fn quotes_fail_for_invalid_trades() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b, token_c) = setup_path();
+		let tokens = vec![token_a.clone(), token_b.clone(), token_c.clone()];
+
+		assert_eq!(
+			Dex::quote_exact_in(tokens.clone(), 0),
+			Err(Error::<Test>::TradeAmountIsZero.into())
+		);
+		assert_eq!(
+			Dex::quote_exact_out(tokens.clone(), 0),
+			Err(Error::<Test>::TradeAmountIsZero.into())
+		);
+		assert_eq!(
+			Dex::quote_exact_in(vec![token_a.clone()], 1_000),
+			Err(Error::<Test>::InvalidPath.into())
+		);
+		assert_eq!(
+			Dex::quote_exact_in(
+				vec![token_a, token_b.clone(), token_c, token_b.clone(), token_b],
+				1
+			),
+			Err(Error::<Test>::InvalidPath.into())
+		);
+		assert_eq!(
+			Dex::quote_exact_out(tokens, 41_000),
+			Err(Error::<Test>::NotEnoughLiquidity.into())
+		);
+	});
+}
+
+#[test]
+fn pools_can_be_looked_up_and_listed() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b, token_c) = setup_path();
+
+		let pool = Dex::get_pool(&token_b, &token_a).unwrap();
+		assert_eq!(Dex::get_pool(&token_a, &token_b), Some(pool.clone()));
+		assert_eq!(
+			pool,
+			PoolInfo {
+				pool_id: 0,
+				token_a: token_a.clone(),
+				token_b: token_b.clone(),
+				token_a_reserve: 10_000,
+				token_b_reserve: 20_000,
+				fee_numerator: 3,
+				fee_denominator: 1000,
+				liquidity_token_id: LP,
+				total_liquidity: 14_142,
+			}
+		);
+		assert_eq!(Dex::get_pool(&token_a, &token_c), None);
+
+		let all = Dex::list_pools(None);
+		assert_eq!(all.iter().map(|pool| pool.pool_id).collect::<Vec<_>>(), vec![0, 1]);
+		assert_eq!(all[0], pool);
+		assert_eq!(Dex::list_pools(Some(token_b)), all);
+		assert_eq!(Dex::list_pools(Some(token_a)), vec![pool]);
+		assert!(Dex::list_pools(Some(TokenKind::Native)).is_empty());
+	});
+}
+
+#[test]
+fn create_exchange_requires_an_approved_fee_tier() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_tokens();
+		let create = |fee_numerator, fee_denominator| {
+			Dex::create_exchange(
+				RuntimeOrigin::signed(ALICE),
+				token_a.clone(),
+				token_b.clone(),
+				LP,
+				10_000,
+				20_000,
+				fee_numerator,
+				fee_denominator,
+				PoolKind::ConstantProduct,
+			)
+		};
+		assert_noop!(create(10, 1000), Error::<Test>::FeeTierNotApproved);
+		assert_noop!(create(1000, 1000), Error::<Test>::FeeTierNotApproved);
+
+		assert_ok!(Dex::add_fee_tier(RuntimeOrigin::root(), 10, 1000));
+		System::assert_last_event(Event::FeeTierAdded(10, 1000).into());
+		assert_ok!(create(10, 1000));
+		assert_eq!(Dex::get_exchange(&token_a, &token_b).unwrap().fee_numerator, 10);
+	});
+}
+
+#[test]
+fn fee_tiers_are_managed_by_governance() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Dex::add_fee_tier(RuntimeOrigin::signed(ALICE), 10, 1000), BadOrigin);
+		assert_noop!(
+			Dex::add_fee_tier(RuntimeOrigin::root(), 1000, 1000),
+			Error::<Test>::InvalidFeeTier
+		);
+		// The default tier is always approved.
+		assert!(Dex::is_fee_tier_approved(3, 1000));
+		assert_noop!(
+			Dex::add_fee_tier(RuntimeOrigin::root(), 3, 1000),
+			Error::<Test>::FeeTierAlreadyApproved
+		);
+		assert_noop!(
+			Dex::remove_fee_tier(RuntimeOrigin::root(), 3, 1000),
+			Error::<Test>::FeeTierNotApproved
+		);
+
+		assert_ok!(Dex::add_fee_tier(RuntimeOrigin::root(), 10, 1000));
+		assert!(Dex::is_fee_tier_approved(10, 1000));
+		assert_noop!(Dex::remove_fee_tier(RuntimeOrigin::signed(ALICE), 10, 1000), BadOrigin);
+		assert_ok!(Dex::remove_fee_tier(RuntimeOrigin::root(), 10, 1000));
+		System::assert_last_event(Event::FeeTierRemoved(10, 1000).into());
+		assert!(!Dex::is_fee_tier_approved(10, 1000));
+	});
+}
+
+#[test]
+fn set_protocol_fee_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Dex::set_protocol_fee(RuntimeOrigin::signed(ALICE), Perbill::from_percent(50)),
+			BadOrigin
+		);
+		assert_eq!(Dex::protocol_fee(), Perbill::zero());
+
+		assert_ok!(Dex::set_protocol_fee(RuntimeOrigin::root(), Perbill::from_percent(50)));
+		assert_eq!(Dex::protocol_fee(), Perbill::from_percent(50));
+		System::assert_last_event(Event::ProtocolFeeSet(Perbill::from_percent(50)).into());
+	});
+}
+
+#[test]
+fn protocol_fee_is_set_aside_for_the_treasury() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		assert_ok!(Dex::set_protocol_fee(RuntimeOrigin::root(), Perbill::from_percent(50)));
+
+		// The 30 fee on 10_000 sold is split between the pool and the treasury.
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			token_a.clone(),
+			token_b.clone(),
+			10_000,
+			1
+		));
+		assert_eq!(reserves(&token_a, &token_b), (19_985, 20_000 - 9_984));
+		assert_eq!(ProtocolFees::<Test>::get(&token_a), 15);
+		assert_eq!(balance(&token_a, &dex_account()), 20_000);
+
+		assert_ok!(Dex::collect_protocol_fees(RuntimeOrigin::signed(CHARLIE), token_a.clone()));
+		System::assert_last_event(Event::ProtocolFeesCollected(token_a.clone(), 15).into());
+		assert_eq!(balance(&token_a, &TREASURY), 15);
+		assert_eq!(balance(&token_a, &dex_account()), 19_985);
+		assert_eq!(reserves(&token_a, &token_b), (19_985, 20_000 - 9_984));
+		assert_noop!(
+			Dex::collect_protocol_fees(RuntimeOrigin::signed(CHARLIE), token_a),
+			Error::<Test>::NoProtocolFees
+		);
+	});
+}
+
+#[test]
+fn protocol_fee_accrues_on_every_hop_of_a_path() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b, token_c) = setup_path();
+		assert_ok!(Dex::set_protocol_fee(RuntimeOrigin::root(), Perbill::one()));
+
+		assert_ok!(Dex::swap_exact_in_along_path(
+			RuntimeOrigin::signed(BOB),
+			path(&[&token_a, &token_b, &token_c]),
+			1_000,
+			864,
+		));
+
+		assert_eq!(reserves(&token_a, &token_b), (10_997, 18_187));
+		assert_eq!(reserves(&token_c, &token_b), (19_136, 41_808));
+		assert_eq!(ProtocolFees::<Test>::get(&token_a), 3);
+		assert_eq!(ProtocolFees::<Test>::get(&token_b), 5);
+		assert_eq!(ProtocolFees::<Test>::get(&token_c), 0);
+	});
+}
+
+fn observation_blocks(token_a: &Token, token_b: &Token) -> Vec<u64> {
+	let pool_id = Dex::get_exchange(token_a, token_b).unwrap().pool_id;
+	Observations::<Test>::get(pool_id)
+		.iter()
+		.map(|observation| observation.block)
+		.collect()
+}
+
+#[test]
+fn oracle_accrues_prices_on_the_first_touch_of_a_block() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		assert_eq!(observation_blocks(&token_a, &token_b), vec![1]);
+
+		System::set_block_number(11);
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			token_a.clone(),
+			token_b.clone(),
+			1_000,
+			1
+		));
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			token_a.clone(),
+			token_b.clone(),
+			1_000,
+			1
+		));
+
+		// Ten blocks at the prices the pool was created with, whatever was traded since.
+		let exchange = Dex::get_exchange(&token_a, &token_b).unwrap();
+		assert_eq!(exchange.price_a_cumulative, FixedU128::saturating_from_integer(20));
+		assert_eq!(exchange.price_b_cumulative, FixedU128::saturating_from_integer(5));
+		assert_eq!(exchange.last_updated, 11);
+		assert_eq!(observation_blocks(&token_a, &token_b), vec![1, 11]);
+	});
+}
+
+#[test]
+fn twap_averages_prices_over_the_window() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		System::set_block_number(11);
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			token_a.clone(),
+			token_b.clone(),
+			1_000,
+			1
+		));
+		assert_eq!(reserves(&token_a, &token_b), (11_000, 18_187));
+		System::set_block_number(21);
+
+		let twap = Dex::twap(&token_a, &token_b, 10).unwrap();
+		assert_eq!(twap.price, FixedU128::saturating_from_rational(18_187, 11_000));
+		assert_eq!((twap.from, twap.to), (11, 21));
+		assert_eq!(
+			Dex::twap(&token_b, &token_a, 10).unwrap().price,
+			FixedU128::saturating_from_rational(11_000, 18_187)
+		);
+		// Ten blocks at a price of 2, then ten at 18_187 / 11_000.
+		let twap = Dex::twap(&token_a, &token_b, 20).unwrap();
+		assert_eq!(
+			twap.price,
+			(FixedU128::saturating_from_integer(2) +
+				FixedU128::saturating_from_rational(18_187, 11_000)) /
+				FixedU128::saturating_from_integer(2)
+		);
+		assert_eq!((twap.from, twap.to), (1, 21));
+
+		// Trades in the current block do not move the average.
+		let before = Dex::twap(&token_a, &token_b, 10).unwrap();
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			token_b.clone(),
+			token_a.clone(),
+			50_000,
+			1
+		));
+		assert_eq!(Dex::twap(&token_a, &token_b, 10).unwrap(), before);
+	});
+}
+
+#[test]
+fn twap_survives_cumulative_prices_wrapping_around() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		// Move the cumulative prices of the pool and of its observation a block away from
+		// overflowing.
+		let near_overflow = FixedU128::from_inner(u128::MAX - 1);
+		Exchanges::<Test>::mutate((token_a.clone(), token_b.clone()), |exchange| {
+			let exchange = exchange.as_mut().unwrap();
+			exchange.price_a_cumulative = near_overflow;
+			exchange.price_b_cumulative = near_overflow;
+		});
+		Observations::<Test>::mutate(0, |observations| {
+			for observation in observations.iter_mut() {
+				observation.price_a_cumulative = near_overflow;
+				observation.price_b_cumulative = near_overflow;
+			}
+		});
+		System::set_block_number(11);
+
+		let twap = Dex::twap(&token_a, &token_b, 10).unwrap();
+		assert_eq!(twap.price, FixedU128::saturating_from_integer(2));
+		assert_eq!(
+			Dex::twap(&token_b, &token_a, 10).unwrap().price,
+			FixedU128::saturating_from_rational(1, 2)
+		);
+	});
+}
+
+#[test]
+fn twap_fails_for_uncovered_windows() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		System::set_block_number(21);
+
+		assert!(matches!(
+			Dex::twap(&token_a, &token_b, 0),
+			Err(Error::<Test>::InvalidOracleWindow)
+		));
+		assert!(matches!(
+			Dex::twap(&token_a, &token_b, 21),
+			Err(Error::<Test>::OracleWindowUnavailable)
+		));
+		assert!(matches!(
+			Dex::twap(&token_a, &TokenKind::Native, 10),
+			Err(Error::<Test>::ExchangeDoesNotExist)
+		));
+		assert_eq!(
+			Dex::twap(&token_a, &token_b, 20).unwrap().price,
+			FixedU128::saturating_from_integer(2)
+		);
+	});
+}
+
+#[test]
+fn oracle_keeps_a_bounded_number_of_observations() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+
+		for block in [11, 15, 21, 31] {
+			System::set_block_number(block);
+			assert_ok!(Dex::swap(
+				RuntimeOrigin::signed(BOB),
+				token_a.clone(),
+				token_b.clone(),
+				10,
+				1
+			));
+		}
+
+		// Block 15 is within `ObservationPeriod` of block 11, and block 1 was dropped.
+		assert_eq!(observation_blocks(&token_a, &token_b), vec![11, 21, 31]);
+		assert!(matches!(
+			Dex::twap(&token_a, &token_b, 25),
+			Err(Error::<Test>::OracleWindowUnavailable)
+		));
+		assert!(Dex::twap(&token_a, &token_b, 20).is_ok());
+	});
+}
+
+#[test]
+fn native_and_asset_pool_works() {
+	new_test_ext().execute_with(|| {
+		let native = TokenKind::Native;
+		let asset = setup_asset(1);
+		assert_ok!(Dex::create_exchange(
+			RuntimeOrigin::signed(ALICE),
+			native.clone(),
+			asset.clone(),
+			LP,
+			10_000,
+			20_000,
+			3,
+			1000,
+			PoolKind::ConstantProduct,
+		));
+		assert_eq!(balance(&native, &dex_account()), ENDOWMENT + 10_000);
+		assert_eq!(balance(&asset, &dex_account()), 20_000);
+
+		let bob_asset = balance(&asset, &BOB);
+		assert_ok!(Dex::swap(RuntimeOrigin::signed(BOB), native.clone(), asset.clone(), 1_000, 1));
+
+		assert_eq!(reserves(&native, &asset), (11_000, 18_187));
+		assert_eq!(balance(&native, &BOB), ENDOWMENT - 1_000);
+		assert_eq!(balance(&asset, &BOB), bob_asset + 1_813);
+		assert_eq!(balance(&native, &dex_account()), ENDOWMENT + 11_000);
+		System::assert_has_event(Event::TokenTransferred(asset.clone(), BOB, 1_813).into());
+
+		assert_ok!(Dex::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			native.clone(),
+			asset.clone(),
+			13_142,
+			1,
+			1,
+			100,
+		));
+		assert_eq!(lp_balance(&ALICE), 0);
+		assert_eq!(reserves(&native, &asset), (778, 1_287));
+	});
+}
+
+#[test]
+fn path_swap_crosses_token_kinds() {
+	new_test_ext().execute_with(|| {
+		let (token_a, _) = setup_tokens();
+		let native = TokenKind::Native;
+		let asset = setup_asset(1);
+		for (token_x, token_y, amount_y, lp) in
+			[(&native, &asset, 20_000, LP), (&asset, &token_a, 40_000, LP + 1)]
+		{
+			assert_ok!(Dex::create_exchange(
+				RuntimeOrigin::signed(ALICE),
+				token_x.clone(),
+				token_y.clone(),
+				lp,
+				amount_y / 2,
+				amount_y,
+				3,
+				1000,
+				PoolKind::ConstantProduct,
+			));
+		}
+		let bob_a = balance(&token_a, &BOB);
+
+		assert_ok!(Dex::swap_exact_in_along_path(
+			RuntimeOrigin::signed(BOB),
+			path(&[&native, &asset, &token_a]),
+			1_000,
+			1,
+		));
+
+		assert_eq!(reserves(&native, &asset), (11_000, 18_187));
+		assert_eq!(reserves(&asset, &token_a), (21_813, 36_685));
+		assert_eq!(balance(&native, &BOB), ENDOWMENT - 1_000);
+		assert_eq!(balance(&asset, &BOB), 100_000);
+		assert_eq!(balance(&token_a, &BOB), bob_a + 3_315);
+	});
+}
+
+#[test]
+fn unused_contract_gas_is_refunded() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+
+		let call = RuntimeCall::Dex(crate::Call::swap {
+			token_a: token_a.clone(),
+			token_b: token_b.clone(),
+			input_amount: 1_000,
+			min_output: 1,
+		});
+		let info = call.get_dispatch_info();
+		let charged = info.weight;
+		let post_info = call.dispatch(RuntimeOrigin::signed(BOB)).unwrap();
+		let actual = post_info.actual_weight.expect("swap reports its actual weight");
+
+		assert!(actual.ref_time() > 0);
+		assert!(actual.all_lt(charged));
+		assert_eq!(post_info.calc_unspent(&info), charged - actual);
+	});
+}
+
+/// Deploys a flash swap receiver for `BOB`, funded with `funds` of `token`, which repays
+/// `repayment` of it to the DEX when called back.
+///
+/// `salt` has to be unique per receiver.
+fn setup_flash_receiver(token: &Token, funds: Balance, repayment: Balance, salt: u8) -> AccountId {
+	let TokenKind::Contract(token) = token else { unreachable!("test tokens are contracts") };
+	let receiver = deploy_flash_receiver(&BOB, salt);
+	token_transfer(token, &BOB, &receiver, funds);
+	program_flash_receiver(&receiver, token, token_transfer_input(&dex_account(), repayment));
+	receiver
+}
+
+fn flash_swap(
+	token_a: &Token,
+	token_b: &Token,
+	a_out: Balance,
+	b_out: Balance,
+	receiver: &AccountId,
+) -> frame_support::dispatch::DispatchResultWithPostInfo {
+	Dex::flash_swap(
+		RuntimeOrigin::signed(BOB),
+		token_a.clone(),
+		token_b.clone(),
+		a_out,
+		b_out,
+		receiver.clone(),
+		GAS_LIMIT,
+		// The longest data the receiver may be passed.
+		vec![1; 1024].try_into().unwrap(),
+	)
+}
+
+#[test]
+fn flash_swap_works() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		let receiver = setup_flash_receiver(&token_a, 1_000, 600, 0);
+
+		// Borrowing 1_000 of `token_b` takes at least 528 of `token_a`, fee included.
+		assert_ok!(flash_swap(&token_a, &token_b, 0, 1_000, &receiver));
+		System::assert_last_event(
+			Event::FlashSwapped(
+				BOB,
+				receiver.clone(),
+				token_a.clone(),
+				token_b.clone(),
+				0,
+				1_000,
+				600,
+				0,
+			)
+			.into(),
+		);
+		assert_eq!(reserves(&token_a, &token_b), (10_600, 19_000));
+		assert_eq!(balance(&token_a, &receiver), 400);
+		assert_eq!(balance(&token_b, &receiver), 1_000);
+		assert_eq!(balance(&token_a, &dex_account()), 10_600);
+		assert_eq!(balance(&token_b, &dex_account()), 19_000);
+		assert!(!FlashSwapInProgress::<Test>::get());
+	});
+}
+
+#[test]
+fn flash_swap_can_be_repaid_in_the_borrowed_token() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		let receiver = setup_flash_receiver(&token_b, 1_000, 1_004, 0);
+
+		// The pair is given in reverse order and the loan is paid back with its fee.
+		assert_ok!(flash_swap(&token_b, &token_a, 1_000, 0, &receiver));
+		assert_eq!(reserves(&token_a, &token_b), (10_000, 20_004));
+		assert_eq!(balance(&token_b, &receiver), 996);
+	});
+}
+
+#[test]
+fn flash_swap_fails_if_not_repaid() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+
+		let receiver = setup_flash_receiver(&token_a, 1_000, 527, 0);
+		assert_noop!(
+			flash_swap(&token_a, &token_b, 0, 1_000, &receiver),
+			Error::<Test>::FlashSwapNotRepaid
+		);
+
+		// Paying back the loan without its fee isn't enough either.
+		let receiver = setup_flash_receiver(&token_b, 1_000, 1_000, 1);
+		assert_noop!(
+			flash_swap(&token_a, &token_b, 0, 1_000, &receiver),
+			Error::<Test>::FlashSwapNotRepaid
+		);
+	});
+}
+
+#[test]
+fn flash_swap_fails_if_the_callback_fails() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+
+		// The receiver can't afford the repayment and reverts.
+		let receiver = setup_flash_receiver(&token_a, 500, 600, 0);
+		assert_noop!(
+			flash_swap(&token_a, &token_b, 0, 1_000, &receiver),
+			Error::<Test>::FlashSwapCallbackFailed
+		);
+
+		// Accounts without code can't be called back.
+		assert_noop!(
+			flash_swap(&token_a, &token_b, 0, 1_000, &CHARLIE),
+			Error::<Test>::FlashSwapCallbackFailed
+		);
+	});
+}
+
+#[test]
+fn flash_swap_checks_amounts() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		let receiver = setup_flash_receiver(&token_a, 1_000, 600, 0);
+
+		assert_noop!(
+			flash_swap(&token_a, &token_b, 0, 0, &receiver),
+			Error::<Test>::TradeAmountIsZero
+		);
+		assert_noop!(
+			flash_swap(&token_a, &token_b, 0, 20_000, &receiver),
+			Error::<Test>::NotEnoughLiquidity
+		);
+	});
+}
+
+#[test]
+fn pool_tokens_are_locked_during_flash_swaps() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+
+		// As seen from a swap made by the receiver while it is called back.
+		FlashSwapInProgress::<Test>::put(true);
+		assert_noop!(
+			Dex::swap(RuntimeOrigin::signed(BOB), token_a.clone(), token_b.clone(), 1_000, 1),
+			Error::<Test>::FlashSwapInProgress
+		);
+	});
+}
+
+const TIP: Balance = 50;
+
+/// Places an order of `BOB` selling 1_000 of `token_a` for at least 2_000 of `token_b`, which
+/// expires after `expiry`.
+fn place_order(token_a: &Token, token_b: &Token, expiry: u64) -> OrderId {
+	let order_id = NextOrderId::<Test>::get();
+	assert_ok!(Dex::place_order(
+		RuntimeOrigin::signed(BOB),
+		token_a.clone(),
+		token_b.clone(),
+		1_000,
+		2_000,
+		TIP,
+		expiry,
+	));
+	order_id
+}
+
+#[test]
+fn order_book_sorts_orders_by_limit_price_then_age() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		// Limit prices whose hashes would not sort like them.
+		for min_output in [3_000, 500, 2_000, 500, 256_000] {
+			assert_ok!(Dex::place_order(
+				RuntimeOrigin::signed(BOB),
+				token_a.clone(),
+				token_b.clone(),
+				1_000,
+				min_output,
+				TIP,
+				10,
+			));
+		}
+
+		assert_eq!(
+			Dex::order_book(&token_a, &token_b),
+			vec![
+				(FixedU128::from_rational(1, 2), 1),
+				(FixedU128::from_rational(1, 2), 3),
+				(FixedU128::from(2), 2),
+				(FixedU128::from(3), 0),
+				(FixedU128::from(256), 4),
+			]
+		);
+	});
+}
+
+#[test]
+fn place_order_escrows_the_sold_tokens_and_the_tip() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		let bob_a = balance(&token_a, &BOB);
+
+		let order_id = place_order(&token_a, &token_b, 10);
+		System::assert_last_event(
+			Event::OrderPlaced(
+				order_id,
+				BOB,
+				token_a.clone(),
+				token_b.clone(),
+				1_000,
+				2_000,
+				TIP,
+				10,
+			)
+			.into(),
+		);
+		assert_eq!(
+			Dex::orders(order_id),
+			Some(LimitOrder {
+				owner: BOB,
+				sell_token: token_a.clone(),
+				buy_token: token_b.clone(),
+				amount: 1_000,
+				min_output: 2_000,
+				tip: TIP,
+				expiry: 10,
+			})
+		);
+		assert_eq!(Dex::order_book(&token_a, &token_b), vec![(FixedU128::from(2), order_id)]);
+		assert!(Dex::order_book(&token_b, &token_a).is_empty());
+		assert_eq!(balance(&token_a, &BOB), bob_a - 1_000);
+		assert_eq!(balance(&token_a, &dex_account()), 11_000);
+		assert_eq!(Balances::reserved_balance(BOB), TIP);
+		// The escrow is kept out of the pool.
+		assert_eq!(reserves(&token_a, &token_b), (10_000, 20_000));
+	});
+}
+
+#[test]
+fn place_order_fails_with_invalid_parameters() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		System::set_block_number(5);
+		let place = |sell: &Token, buy: &Token, amount, min_output, tip, expiry| {
+			Dex::place_order(
+				RuntimeOrigin::signed(BOB),
+				sell.clone(),
+				buy.clone(),
+				amount,
+				min_output,
+				tip,
+				expiry,
+			)
+		};
+
+		assert_noop!(
+			place(&token_a, &token_b, 0, 2_000, TIP, 10),
+			Error::<Test>::TradeAmountIsZero
+		);
+		assert_noop!(
+			place(&token_a, &token_b, 1_000, 0, TIP, 10),
+			Error::<Test>::TradeAmountIsZero
+		);
+		assert_noop!(
+			place(&token_a, &TokenKind::Native, 1_000, 2_000, TIP, 10),
+			Error::<Test>::ExchangeDoesNotExist
+		);
+		assert_noop!(
+			place(&token_a, &token_b, 1_000, 2_000, TIP, 4),
+			Error::<Test>::InvalidOrderExpiry
+		);
+		assert_noop!(
+			place(&token_a, &token_b, 1_000, 2_000, TIP, 106),
+			Error::<Test>::InvalidOrderExpiry
+		);
+		assert_noop!(
+			place(&token_a, &token_b, 1_000, 2_000, ENDOWMENT + 1, 10),
+			Error::<Test>::BalanceTooLow
+		);
+		assert_noop!(
+			place(&token_a, &token_b, 200_000, 2_000, TIP, 10),
+			Error::<Test>::TokenTransferFailed
+		);
+	});
+}
+
+#[test]
+fn place_order_fails_when_order_ids_run_out() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		NextOrderId::<Test>::put(OrderId::MAX);
+
+		assert_noop!(
+			Dex::place_order(RuntimeOrigin::signed(BOB), token_a, token_b, 1_000, 2_000, TIP, 10),
+			Error::<Test>::OrderIdOverflow
+		);
+	});
+}
+
+#[test]
+fn fill_order_waits_for_the_limit_price() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		let order_id = place_order(&token_a, &token_b, 10);
+		let bob_b = balance(&token_b, &BOB);
+		let charlie_native = Balances::free_balance(CHARLIE);
+
+		// 1_000 of `token_a` only fetch 1_813 of `token_b`.
+		assert_noop!(
+			Dex::fill_order(RuntimeOrigin::signed(CHARLIE), order_id),
+			Error::<Test>::LimitPriceNotReached
+		);
+
+		// Once `token_a` gets dearer, they fetch 2_768.
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(ALICE),
+			token_b.clone(),
+			token_a.clone(),
+			5_000,
+			1
+		));
+		assert_eq!(reserves(&token_a, &token_b), (8_005, 25_000));
+		assert_ok!(Dex::fill_order(RuntimeOrigin::signed(CHARLIE), order_id));
+		System::assert_last_event(Event::OrderFilled(order_id, CHARLIE, 2_768, TIP).into());
+
+		assert_eq!(reserves(&token_a, &token_b), (9_005, 22_232));
+		assert_eq!(balance(&token_b, &BOB), bob_b + 2_768);
+		assert_eq!(balance(&token_a, &dex_account()), 9_005);
+		assert_eq!(balance(&token_b, &dex_account()), 22_232);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(CHARLIE), charlie_native + TIP);
+		assert_eq!(Dex::orders(order_id), None);
+		assert!(Dex::order_book(&token_a, &token_b).is_empty());
+		assert_noop!(
+			Dex::fill_order(RuntimeOrigin::signed(CHARLIE), order_id),
+			Error::<Test>::UnknownOrder
+		);
+	});
+}
+
+#[test]
+fn fill_order_fails_after_expiry() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		let order_id = place_order(&token_a, &token_b, 10);
+		assert_ok!(Dex::swap(RuntimeOrigin::signed(ALICE), token_b, token_a, 5_000, 1));
+
+		System::set_block_number(11);
+		assert_noop!(
+			Dex::fill_order(RuntimeOrigin::signed(CHARLIE), order_id),
+			Error::<Test>::OrderExpired
+		);
+	});
+}
+
+#[test]
+fn cancel_order_gives_back_the_escrow() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		let bob_a = balance(&token_a, &BOB);
+		let order_id = place_order(&token_a, &token_b, 10);
+
+		assert_noop!(
+			Dex::cancel_order(RuntimeOrigin::signed(CHARLIE), order_id),
+			Error::<Test>::NotOrderOwner
+		);
+		assert_ok!(Dex::cancel_order(RuntimeOrigin::signed(BOB), order_id));
+		System::assert_last_event(Event::OrderCancelled(order_id).into());
+
+		assert_eq!(balance(&token_a, &BOB), bob_a);
+		assert_eq!(balance(&token_a, &dex_account()), 10_000);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Dex::orders(order_id), None);
+		assert!(Dex::order_book(&token_a, &token_b).is_empty());
+		assert_noop!(
+			Dex::cancel_order(RuntimeOrigin::signed(BOB), order_id),
+			Error::<Test>::UnknownOrder
+		);
+	});
+}
+
+#[test]
+fn expired_orders_are_given_back_on_idle() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		let bob_a = balance(&token_a, &BOB);
+		let first = place_order(&token_a, &token_b, 10);
+		let second = place_order(&token_a, &token_b, 12);
+
+		// Nothing expired yet.
+		Dex::on_idle(10, Weight::MAX);
+		assert_eq!(Balances::reserved_balance(BOB), 2 * TIP);
+
+		// Not enough weight left to give back any order.
+		Dex::on_idle(11, Weight::zero());
+		assert!(Dex::orders(first).is_some());
+
+		Dex::on_idle(11, Weight::MAX);
+		System::assert_last_event(Event::OrderExpired(first).into());
+		assert_eq!(Dex::orders(first), None);
+		assert!(Dex::orders(second).is_some());
+		assert_eq!(balance(&token_a, &BOB), bob_a - 1_000);
+		assert_eq!(Balances::reserved_balance(BOB), TIP);
+
+		Dex::on_idle(20, Weight::MAX);
+		System::assert_last_event(Event::OrderExpired(second).into());
+		assert_eq!(Dex::orders(second), None);
+		assert!(Dex::order_book(&token_a, &token_b).is_empty());
+		assert_eq!(balance(&token_a, &BOB), bob_a);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(ExpiryCursor::<Test>::get(), 20);
+	});
+}
+
+/// Tick spacing of the pool created by `setup_concentrated_exchange`.
+const TICK_SPACING: u32 = 10;
+
+/// Creates a concentrated pool at the price of `10_000 : 40_000` with a 0.3% fee. Its initial
+/// position, number 0, covers the whole tick range and belongs to `ALICE`.
+fn setup_concentrated_exchange() -> (Token, Token) {
+	let (token_a, token_b) = setup_tokens();
+	assert_ok!(Dex::create_exchange(
+		RuntimeOrigin::signed(ALICE),
+		token_a.clone(),
+		token_b.clone(),
+		LP,
+		10_000,
+		40_000,
+		3,
+		1000,
+		PoolKind::Concentrated { tick_spacing: TICK_SPACING },
+	));
+	(token_a, token_b)
+}
+
+fn position_owner(position_id: PositionId) -> Option<AccountId> {
+	<Uniques as frame_support::traits::tokens::nonfungibles::Inspect<_>>::owner(&0, &position_id)
+}
+
+fn mint_position(who: AccountId, ticks: (i32, i32), amounts: (Balance, Balance)) -> PositionId {
+	let position_id = NextPositionId::<Test>::get();
+	assert_ok!(Dex::mint_position(
+		RuntimeOrigin::signed(who),
+		0,
+		ticks.0,
+		ticks.1,
+		amounts.0,
+		amounts.1,
+		0,
+		0,
+	));
+	position_id
+}
+
+#[test]
+fn sqrt_price_at_tick_follows_powers_of_1_0001() {
+	assert_eq!(sqrt_price_at_tick(0), Some(1_000_000_000_000_000_000));
+	// A price of 4 lies between ticks 13_863 and 13_864.
+	let sqrt_price = sqrt_price_at_tick(13_863).unwrap();
+	assert!(sqrt_price < 2_000_000_000_000_000_000);
+	assert!(sqrt_price_at_tick(13_864).unwrap() > 2_000_000_000_000_000_000);
+	let reciprocal = 1_000_000_000_000_000_000_000_000_000_000_000_000 / sqrt_price;
+	assert!(sqrt_price_at_tick(-13_863).unwrap().abs_diff(reciprocal) <= 1);
+	assert!(sqrt_price_at_tick(MIN_TICK).unwrap() < sqrt_price_at_tick(MIN_TICK + 1).unwrap());
+	assert!(sqrt_price_at_tick(MAX_TICK - 1).unwrap() < sqrt_price_at_tick(MAX_TICK).unwrap());
+	assert_eq!(sqrt_price_at_tick(MIN_TICK - 1), None);
+	assert_eq!(sqrt_price_at_tick(MAX_TICK + 1), None);
+}
+
+#[test]
+fn create_exchange_mints_a_full_range_position() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_concentrated_exchange();
+
+		let pool = Dex::concentrated_pools(0).unwrap();
+		assert_eq!(pool.tick, 13_863);
+		assert_eq!(pool.sqrt_price, 2_000_000_000_000_000_000);
+		assert_eq!(
+			pool.ticks.iter().map(|tick| tick.index).collect::<Vec<_>>(),
+			vec![MIN_TICK, MAX_TICK]
+		);
+		let position = Dex::positions(0).unwrap();
+		assert_eq!((position.tick_lower, position.tick_upper), (MIN_TICK, MAX_TICK));
+		assert_eq!(position.liquidity, pool.liquidity);
+		assert_eq!(position_owner(0), Some(ALICE));
+
+		// The position takes a unit more than the desired amounts at most.
+		let (reserve_a, reserve_b) = reserves(&token_a, &token_b);
+		assert!((10_000..=10_001).contains(&reserve_a));
+		assert!((40_000..=40_001).contains(&reserve_b));
+		assert_eq!(balance(&token_a, &dex_account()), reserve_a);
+		assert_eq!(balance(&token_b, &dex_account()), reserve_b);
+		assert!(!<Assets as frame_support::traits::fungibles::Inspect<_>>::asset_exists(LP));
+		assert!(System::events().iter().any(|record| record.event ==
+			RuntimeEvent::Dex(Event::PositionMinted(
+				0,
+				ALICE,
+				0,
+				MIN_TICK,
+				MAX_TICK,
+				pool.liquidity,
+				reserve_a,
+				reserve_b,
+			))));
+	});
+}
+
+#[test]
+fn create_exchange_checks_concentrated_parameters() {
+	new_test_ext().execute_with(|| {
+		let (_, token_b) = setup_tokens();
+		let create = |amount_a, tick_spacing| {
+			Dex::create_exchange(
+				RuntimeOrigin::signed(ALICE),
+				TokenKind::Native,
+				token_b.clone(),
+				LP,
+				amount_a,
+				10,
+				3,
+				1000,
+				PoolKind::Concentrated { tick_spacing },
+			)
+		};
+
+		assert_noop!(create(1_000, 0), Error::<Test>::InvalidTickSpacing);
+		assert_noop!(create(1_000, MAX_TICK as u32 + 1), Error::<Test>::InvalidTickSpacing);
+		// A price of 2e-11 is below the one of `MIN_TICK`.
+		assert_noop!(create(500_000_000_000, 1), Error::<Test>::PriceOutOfRange);
+	});
+}
+
+#[test]
+fn positions_are_not_minted_into_a_squatted_collection() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_tokens();
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(BOB), 0, BOB));
+
+		assert_noop!(
+			Dex::create_exchange(
+				RuntimeOrigin::signed(ALICE),
+				token_a,
+				token_b,
+				LP,
+				10_000,
+				40_000,
+				3,
+				1000,
+				PoolKind::Concentrated { tick_spacing: TICK_SPACING },
+			),
+			Error::<Test>::PositionCollectionTaken
+		);
+	});
+}
+
+#[test]
+fn concentrated_pools_only_take_liquidity_through_positions() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_concentrated_exchange();
+
+		assert_noop!(
+			Dex::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				token_a.clone(),
+				token_b.clone(),
+				1_000,
+				1,
+				4_001,
+				100,
+			),
+			Error::<Test>::UnsupportedPoolKind
+		);
+		assert_noop!(
+			Dex::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				token_a.clone(),
+				token_b.clone(),
+				1,
+				1,
+				1,
+				100,
+			),
+			Error::<Test>::UnsupportedPoolKind
+		);
+		let receiver = setup_flash_receiver(&token_a, 1_000, 600, 0);
+		assert_noop!(
+			flash_swap(&token_a, &token_b, 0, 1_000, &receiver),
+			Error::<Test>::UnsupportedPoolKind
+		);
+	});
+}
+
+#[test]
+fn mint_position_fails_with_invalid_parameters() {
+	new_test_ext().execute_with(|| {
+		let (token_c, token_d) = (setup_asset(1), setup_asset(2));
+		assert_ok!(Dex::create_exchange(
+			RuntimeOrigin::signed(ALICE),
+			token_c,
+			token_d,
+			LP,
+			10_000,
+			10_000,
+			3,
+			1000,
+			PoolKind::ConstantProduct,
+		));
+		let mint = |pool_id, tick_lower, tick_upper, amounts: (Balance, Balance), mins| {
+			Dex::mint_position(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				tick_lower,
+				tick_upper,
+				amounts.0,
+				amounts.1,
+				mins,
+				0,
+			)
+		};
+
+		assert_noop!(mint(0, -100, 100, (1_000, 1_000), 0), Error::<Test>::UnsupportedPoolKind);
+		let (_, _) = setup_concentrated_exchange();
+		assert_noop!(mint(2, -100, 100, (1_000, 1_000), 0), Error::<Test>::ExchangeDoesNotExist);
+		assert_noop!(mint(1, -105, 100, (1_000, 1_000), 0), Error::<Test>::InvalidTickRange);
+		assert_noop!(mint(1, 100, 100, (1_000, 1_000), 0), Error::<Test>::InvalidTickRange);
+		assert_noop!(mint(1, 100, -100, (1_000, 1_000), 0), Error::<Test>::InvalidTickRange);
+		assert_noop!(
+			mint(1, MIN_TICK - 10, 100, (1_000, 1_000), 0),
+			Error::<Test>::InvalidTickRange
+		);
+		// The range is above the price, so it is only funded with the first token.
+		assert_noop!(
+			mint(1, 20_000, 20_100, (0, 1_000), 0),
+			Error::<Test>::PositionLiquidityIsZero
+		);
+		assert_noop!(
+			mint(1, 13_800, 13_900, (1_000, 1_000), 1_000),
+			Error::<Test>::MinCurrencyTooHigh
+		);
+	});
+}
+
+#[test]
+fn mint_position_fails_when_position_ids_run_out() {
+	new_test_ext().execute_with(|| {
+		let (_, _) = setup_concentrated_exchange();
+		NextPositionId::<Test>::put(PositionId::MAX);
+
+		assert_noop!(
+			Dex::mint_position(RuntimeOrigin::signed(BOB), 0, 20_000, 20_100, 1_000, 0, 0, 0),
+			Error::<Test>::PositionIdOverflow
+		);
+	});
+}
+
+#[test]
+fn mint_position_fails_when_the_pool_has_no_room_for_its_ticks() {
+	new_test_ext().execute_with(|| {
+		let (_, _) = setup_concentrated_exchange();
+		// The full range position bounds two ticks, and each of these two more, which fills the
+		// eight ticks of the pool.
+		for lower in [20_000, 20_200, 20_400] {
+			mint_position(BOB, (lower, lower + 100), (1_000, 0));
+		}
+		assert_eq!(Dex::concentrated_pools(0).unwrap().ticks.len(), 8);
+
+		assert_noop!(
+			Dex::mint_position(RuntimeOrigin::signed(BOB), 0, 20_600, 20_700, 1_000, 0, 0, 0),
+			Error::<Test>::TooManyTicks
+		);
+		// Positions bounded by existing ticks still fit.
+		mint_position(BOB, (20_000, 20_100), (1_000, 0));
+	});
+}
+
+#[test]
+fn concentrated_liquidity_updates_detect_overflow() {
+	new_test_ext().execute_with(|| {
+		let mut pool =
+			ConcentratedPool::<Test>::new(TICK_SPACING, sqrt_price_at_tick(0).unwrap()).unwrap();
+		assert!(pool.update_liquidity(-10, 10, i128::MAX).is_ok());
+
+		assert!(matches!(
+			pool.update_liquidity(-10, 10, i128::MAX),
+			Err(Error::<Test>::ArithmeticOverflow)
+		));
+	});
+}
+
+#[test]
+fn full_range_pools_trade_like_constant_product_ones() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_concentrated_exchange();
+
+		// A constant product pool gives 3_626 of `token_b` for 1_000 of `token_a`.
+		let quote = Dex::quote_exact_in(vec![token_a.clone(), token_b.clone()], 1_000).unwrap();
+		assert_eq!(quote.amount_out, 3_626);
+		assert_eq!(quote.fees, vec![3]);
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			token_a.clone(),
+			token_b.clone(),
+			1_000,
+			3_626,
+		));
+		assert_eq!(balance(&token_b, &BOB), 100_000 + 3_626);
+		assert_eq!(balance(&token_a, &BOB), 100_000 - 1_000);
+
+		let pool = Dex::concentrated_pools(0).unwrap();
+		assert!(pool.tick < 13_863);
+		assert!(
+			sqrt_price_at_tick(pool.tick).unwrap() <= pool.sqrt_price &&
+				pool.sqrt_price < sqrt_price_at_tick(pool.tick + 1).unwrap()
+		);
+		let (reserve_a, reserve_b) = reserves(&token_a, &token_b);
+		assert!(reserve_a <= balance(&token_a, &dex_account()));
+		assert_eq!(reserve_b, balance(&token_b, &dex_account()));
+
+		// Buying exact amounts is priced by the same curve.
+		let quote = Dex::quote_exact_out(vec![token_b.clone(), token_a.clone()], 500).unwrap();
+		assert_ok!(Dex::swap_exact_out_along_path(
+			RuntimeOrigin::signed(BOB),
+			vec![token_b.clone(), token_a.clone()].try_into().unwrap(),
+			500,
+			quote.amount_in,
+		));
+		assert_eq!(balance(&token_a, &BOB), 100_000 - 1_000 + 500);
+		assert_eq!(balance(&token_b, &BOB), 100_000 + 3_626 - quote.amount_in);
+	});
+}
+
+#[test]
+fn narrow_positions_deepen_the_pool_and_swaps_cross_them() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_concentrated_exchange();
+		let full_range = Dex::concentrated_pools(0).unwrap().liquidity;
+		let shallow = Dex::quote_exact_in(vec![token_a.clone(), token_b.clone()], 100).unwrap();
+
+		let position_id = mint_position(BOB, (13_800, 13_930), (1_000, 4_000));
+		let narrow = Dex::positions(position_id).unwrap().liquidity;
+		assert!(narrow > 10 * full_range);
+		assert_eq!(Dex::concentrated_pools(0).unwrap().liquidity, full_range + narrow);
+		let deep = Dex::quote_exact_in(vec![token_a.clone(), token_b.clone()], 100).unwrap();
+		assert!(deep.amount_out > shallow.amount_out);
+
+		// Selling enough of `token_a` pushes the price below the range of the position.
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			token_a.clone(),
+			token_b.clone(),
+			3_000,
+			0
+		));
+		let pool = Dex::concentrated_pools(0).unwrap();
+		assert!(pool.tick < 13_800);
+		assert_eq!(pool.liquidity, full_range);
+
+		// The position is now only backed by `token_a`.
+		let before = (balance(&token_a, &BOB), balance(&token_b, &BOB));
+		assert_ok!(Dex::decrease_liquidity(RuntimeOrigin::signed(BOB), position_id, narrow, 0, 0));
+		assert!(balance(&token_a, &BOB) > before.0);
+		assert_eq!(balance(&token_b, &BOB), before.1);
+
+		// And swapping back crosses the emptied ticks.
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			token_b.clone(),
+			token_a.clone(),
+			20_000,
+			0
+		));
+		let pool = Dex::concentrated_pools(0).unwrap();
+		assert!(pool.tick > 13_930);
+		assert_eq!(pool.liquidity, full_range);
+		assert_eq!(pool.ticks.len(), 2);
+	});
+}
+
+#[test]
+fn positions_earn_fees_while_in_range() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_concentrated_exchange();
+		let narrow = mint_position(BOB, (13_800, 13_930), (1_000, 4_000));
+		let out_of_range = mint_position(ALICE, (20_000, 20_100), (1_000, 0));
+		let full_range = Dex::positions(0).unwrap().liquidity;
+		let narrow_liquidity = Dex::positions(narrow).unwrap().liquidity;
+
+		assert_ok!(Dex::swap(RuntimeOrigin::signed(BOB), token_a.clone(), token_b.clone(), 500, 0));
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			token_b.clone(),
+			token_a.clone(),
+			2_000,
+			0
+		));
+
+		// The swaps paid fees of 2 and 6, of which `BOB` earns 96.8%, rounded down.
+		assert_eq!((full_range, narrow_liquidity), (20_000, 603_772));
+		assert_ok!(Dex::collect_position_fees(RuntimeOrigin::signed(BOB), narrow));
+		System::assert_last_event(RuntimeEvent::Dex(Event::PositionFeesCollected(narrow, 1, 5)));
+		assert_noop!(
+			Dex::collect_position_fees(RuntimeOrigin::signed(BOB), narrow),
+			Error::<Test>::NoPositionFees
+		);
+		assert_noop!(
+			Dex::collect_position_fees(RuntimeOrigin::signed(ALICE), out_of_range),
+			Error::<Test>::NoPositionFees
+		);
+		assert_noop!(
+			Dex::collect_position_fees(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NotPositionOwner
+		);
+		assert_noop!(
+			Dex::collect_position_fees(RuntimeOrigin::signed(BOB), 7),
+			Error::<Test>::UnknownPosition
+		);
+	});
+}
+
+#[test]
+fn protocol_fee_is_taken_from_concentrated_swaps() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_concentrated_exchange();
+		assert_ok!(Dex::set_protocol_fee(RuntimeOrigin::root(), Perbill::from_percent(50)));
+
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			token_a.clone(),
+			token_b.clone(),
+			1_000,
+			0
+		));
+		// Half of the fee of 3 goes to the treasury, rounded down.
+		assert_eq!(ProtocolFees::<Test>::get(&token_a), 1);
+		assert_ok!(Dex::collect_position_fees(RuntimeOrigin::signed(ALICE), 0));
+		System::assert_last_event(RuntimeEvent::Dex(Event::PositionFeesCollected(0, 2, 0)));
+	});
+}
+
+#[test]
+fn decrease_liquidity_burns_emptied_positions() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_concentrated_exchange();
+		let position_id = mint_position(BOB, (13_800, 13_930), (1_000, 4_000));
+		let liquidity = Dex::positions(position_id).unwrap().liquidity;
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(ALICE),
+			token_a.clone(),
+			token_b.clone(),
+			500,
+			0
+		));
+
+		assert_noop!(
+			Dex::decrease_liquidity(RuntimeOrigin::signed(ALICE), position_id, 1, 0, 0),
+			Error::<Test>::NotPositionOwner
+		);
+		assert_noop!(
+			Dex::decrease_liquidity(RuntimeOrigin::signed(BOB), position_id, 0, 0, 0),
+			Error::<Test>::LiquidityAmountIsZero
+		);
+		assert_noop!(
+			Dex::decrease_liquidity(RuntimeOrigin::signed(BOB), position_id, liquidity + 1, 0, 0),
+			Error::<Test>::ProviderLiquidityTooLow
+		);
+
+		assert_ok!(Dex::decrease_liquidity(
+			RuntimeOrigin::signed(BOB),
+			position_id,
+			liquidity / 2,
+			0,
+			0
+		));
+		assert_eq!(Dex::positions(position_id).unwrap().liquidity, liquidity - liquidity / 2);
+		assert_eq!(position_owner(position_id), Some(BOB));
+
+		// The receipt carries the position along.
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(BOB), 0, position_id, CHARLIE));
+		assert_noop!(
+			Dex::decrease_liquidity(RuntimeOrigin::signed(BOB), position_id, 1, 0, 0),
+			Error::<Test>::NotPositionOwner
+		);
+		assert_ok!(Dex::decrease_liquidity(
+			RuntimeOrigin::signed(CHARLIE),
+			position_id,
+			liquidity - liquidity / 2,
+			0,
+			0
+		));
+		System::assert_last_event(RuntimeEvent::Dex(Event::PositionBurned(position_id)));
+		// The fees of the position are paid out with its last liquidity.
+		assert!(System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Dex(Event::PositionFeesCollected(id, fees_a, _)) if id == position_id && fees_a > 0
+		)));
+		assert!(Dex::positions(position_id).is_none());
+		assert_eq!(position_owner(position_id), None);
+		assert_eq!(Dex::concentrated_pools(0).unwrap().ticks.len(), 2);
+	});
+}
+
+/// Creates a stable pool of `count` assets, numbered from 1, with an amplification coefficient of
+/// 100 and a 0.3% fee. `ALICE` funds it with `10_000` of each, and its liquidity token is `LP`.
+fn setup_stable_pool(count: u32) -> Vec<Token> {
+	let tokens: Vec<_> = (1..=count).map(setup_asset).collect();
+	assert_ok!(Dex::create_stable_pool(
+		RuntimeOrigin::signed(ALICE),
+		tokens.clone().try_into().unwrap(),
+		vec![10_000; count as usize].try_into().unwrap(),
+		LP,
+		3,
+		1000,
+		100,
+	));
+	tokens
+}
+
+fn stable_amounts(amounts: &[Balance]) -> BoundedVec<Balance, ConstU32<4>> {
+	amounts.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn create_stable_pool_works() {
+	new_test_ext().execute_with(|| {
+		let tokens = setup_stable_pool(3);
+
+		let pool = Dex::stable_pools(0).unwrap();
+		assert_eq!(pool.tokens.to_vec(), tokens);
+		assert_eq!(pool.balances.to_vec(), vec![10_000; 3]);
+		assert_eq!(pool.amplification(1), 100);
+		// Every pair is an exchange of its own, whose reserves mirror the pool.
+		assert_eq!(NextPoolId::<Test>::get(), 3);
+		for (pool_id, (i, j)) in [(0, 1), (0, 2), (1, 2)].into_iter().enumerate() {
+			assert_eq!(
+				Pools::<Test>::get(pool_id as PoolId),
+				Some((tokens[i].clone(), tokens[j].clone()))
+			);
+			assert_eq!(StablePairs::<Test>::get(pool_id as PoolId), Some(0));
+			assert_eq!(reserves(&tokens[i], &tokens[j]), (10_000, 10_000));
+			System::assert_has_event(
+				Event::PoolCreated(
+					pool_id as PoolId,
+					ALICE,
+					tokens[i].clone(),
+					tokens[j].clone(),
+					LP,
+				)
+				.into(),
+			);
+		}
+		// The invariant of a balanced pool is the sum of its balances.
+		assert_eq!(lp_total_issuance(), 30_000);
+		assert_eq!(lp_balance(&ALICE), 29_000);
+		assert_eq!(lp_balance(&dex_account()), 1_000);
+		for token in &tokens {
+			assert_eq!(balance(token, &dex_account()), 10_000);
+			assert_eq!(balance(token, &ALICE), SUPPLY - 100_000 - 10_000);
+		}
+		System::assert_last_event(RuntimeEvent::Dex(Event::StableLiquidityAdded(
+			ALICE,
+			0,
+			stable_amounts(&[10_000; 3]),
+			29_000,
+		)));
+	});
+}
+
+#[test]
+fn create_exchange_creates_two_token_stable_pools() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = (setup_asset(1), setup_asset(2));
+
+		assert_ok!(Dex::create_exchange(
+			RuntimeOrigin::signed(ALICE),
+			token_b.clone(),
+			token_a.clone(),
+			LP,
+			20_000,
+			10_000,
+			3,
+			1000,
+			PoolKind::Stable { amplification: 100 },
+		));
+
+		let pool = Dex::stable_pools(0).unwrap();
+		assert_eq!(pool.tokens.to_vec(), vec![token_b.clone(), token_a.clone()]);
+		assert_eq!(pool.balances.to_vec(), vec![20_000, 10_000]);
+		assert_eq!(reserves(&token_a, &token_b), (10_000, 20_000));
+		System::assert_has_event(Event::PoolCreated(0, ALICE, token_a, token_b, LP).into());
+	});
+}
+
+#[test]
+fn create_stable_pool_fails_with_invalid_parameters() {
+	new_test_ext().execute_with(|| {
+		let tokens: Vec<_> = (1..=5).map(setup_asset).collect();
+		let create = |tokens: &[Token], amounts: &[Balance], lp: u32, amplification: u32| {
+			Dex::create_stable_pool(
+				RuntimeOrigin::signed(ALICE),
+				tokens.to_vec().try_into().unwrap(),
+				stable_amounts(amounts),
+				lp,
+				3,
+				1000,
+				amplification,
+			)
+		};
+
+		assert_noop!(create(&tokens[..1], &[10_000], LP, 100), Error::<Test>::InvalidStableTokens);
+		assert_noop!(
+			create(
+				&[tokens[0].clone(), tokens[1].clone(), tokens[0].clone()],
+				&[10_000; 3],
+				LP,
+				100
+			),
+			Error::<Test>::InvalidStableTokens
+		);
+		assert_noop!(
+			create(&tokens[..3], &[10_000; 2], LP, 100),
+			Error::<Test>::StableAmountsMismatch
+		);
+		assert_noop!(
+			create(&tokens[..2], &[10_000; 2], LP, 0),
+			Error::<Test>::InvalidAmplification
+		);
+		assert_noop!(
+			create(&tokens[..2], &[10_000; 2], LP, MAX_AMPLIFICATION + 1),
+			Error::<Test>::InvalidAmplification
+		);
+		assert_noop!(
+			create(&tokens[..2], &[10_000, 9], LP, 100),
+			Error::<Test>::CurrencyAmountTooLow
+		);
+		assert_noop!(
+			Dex::create_stable_pool(
+				RuntimeOrigin::signed(ALICE),
+				tokens[..2].to_vec().try_into().unwrap(),
+				stable_amounts(&[10_000; 2]),
+				LP,
+				1,
+				1000,
+				100,
+			),
+			Error::<Test>::FeeTierNotApproved
+		);
+		assert_noop!(create(&tokens[..2], &[10_000; 2], 1, 100), Error::<Test>::TokenIdTaken);
+		assert_noop!(
+			create(&tokens[..2], &[500, 500], LP, 100),
+			Error::<Test>::InitialLiquidityTooLow
+		);
+
+		assert_ok!(Dex::create_exchange(
+			RuntimeOrigin::signed(ALICE),
+			tokens[1].clone(),
+			tokens[2].clone(),
+			LP,
+			10_000,
+			10_000,
+			3,
+			1000,
+			PoolKind::ConstantProduct,
+		));
+		assert_noop!(
+			create(&tokens[..3], &[10_000; 3], LP + 1, 100),
+			Error::<Test>::ExchangeAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn stable_pools_trade_close_to_par() {
+	new_test_ext().execute_with(|| {
+		let tokens = setup_stable_pool(2);
+		let bob_0 = balance(&tokens[0], &BOB);
+		let bob_1 = balance(&tokens[1], &BOB);
+
+		// A constant product pool of the same reserves would only pay out 906.
+		assert_noop!(
+			Dex::swap(RuntimeOrigin::signed(BOB), tokens[0].clone(), tokens[1].clone(), 1_000, 997),
+			Error::<Test>::InsufficientOutputAmount
+		);
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			tokens[0].clone(),
+			tokens[1].clone(),
+			1_000,
+			996,
+		));
+
+		assert_eq!(Dex::stable_pools(0).unwrap().balances.to_vec(), vec![11_000, 9_004]);
+		assert_eq!(reserves(&tokens[0], &tokens[1]), (11_000, 9_004));
+		assert_eq!(balance(&tokens[0], &BOB), bob_0 - 1_000);
+		assert_eq!(balance(&tokens[1], &BOB), bob_1 + 996);
+		assert_eq!(balance(&tokens[1], &dex_account()), 9_004);
+	});
+}
+
+#[test]
+fn stable_pools_quote_exact_outputs() {
+	new_test_ext().execute_with(|| {
+		let tokens = setup_stable_pool(2);
+		let bob_0 = balance(&tokens[0], &BOB);
+
+		assert_ok!(Dex::swap_exact_out_along_path(
+			RuntimeOrigin::signed(BOB),
+			path(&[&tokens[0], &tokens[1]]),
+			1_000,
+			1_005,
+		));
+
+		assert_eq!(balance(&tokens[0], &BOB), bob_0 - 1_005);
+		assert_eq!(reserves(&tokens[0], &tokens[1]), (11_005, 9_000));
+	});
+}
+
+#[test]
+fn stable_swaps_update_every_pair_of_the_pool() {
+	new_test_ext().execute_with(|| {
+		let tokens = setup_stable_pool(3);
+		assert_ok!(Dex::set_protocol_fee(RuntimeOrigin::root(), Perbill::from_percent(50)));
+
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			tokens[0].clone(),
+			tokens[2].clone(),
+			1_000,
+			996,
+		));
+
+		// Half of the fee of 3 is set aside for the treasury, rounded down.
+		assert_eq!(ProtocolFees::<Test>::get(&tokens[0]), 1);
+		assert_eq!(Dex::stable_pools(0).unwrap().balances.to_vec(), vec![10_999, 10_000, 9_004]);
+		assert_eq!(reserves(&tokens[0], &tokens[1]), (10_999, 10_000));
+		assert_eq!(reserves(&tokens[0], &tokens[2]), (10_999, 9_004));
+		assert_eq!(reserves(&tokens[1], &tokens[2]), (10_000, 9_004));
+		// The price of the untouched pair moved with the pool.
+		let quote = Dex::quote_exact_in(vec![tokens[1].clone(), tokens[2].clone()], 1_000).unwrap();
+		assert_eq!(quote.amount_out, 997);
+		assert!(quote.price_impact < Perbill::from_percent(1));
+	});
+}
+
+#[test]
+fn add_stable_liquidity_charges_imbalanced_deposits() {
+	// A balanced deposit mints liquidity in proportion to the invariant, an imbalanced one of the
+	// same value pays a fee on its imbalance.
+	for (amounts, liquidity) in [([1_000, 1_000], 2_000), ([2_000, 0], 1_997)] {
+		new_test_ext().execute_with(|| {
+			let tokens = setup_stable_pool(2);
+			let bob_0 = balance(&tokens[0], &BOB);
+
+			assert_noop!(
+				Dex::add_stable_liquidity(
+					RuntimeOrigin::signed(BOB),
+					0,
+					stable_amounts(&amounts),
+					liquidity + 1,
+					100,
+				),
+				Error::<Test>::MinLiquidityTooHigh
+			);
+			assert_ok!(Dex::add_stable_liquidity(
+				RuntimeOrigin::signed(BOB),
+				// Any pair of the pool refers to it.
+				0,
+				stable_amounts(&amounts),
+				liquidity,
+				100,
+			));
+
+			System::assert_last_event(RuntimeEvent::Dex(Event::StableLiquidityAdded(
+				BOB,
+				0,
+				stable_amounts(&amounts),
+				liquidity,
+			)));
+			assert_eq!(lp_balance(&BOB), liquidity);
+			assert_eq!(balance(&tokens[0], &BOB), bob_0 - amounts[0]);
+			assert_eq!(
+				reserves(&tokens[0], &tokens[1]),
+				(10_000 + amounts[0], 10_000 + amounts[1])
+			);
+		});
+	}
+}
+
+#[test]
+fn add_stable_liquidity_fails_with_invalid_parameters() {
+	new_test_ext().execute_with(|| {
+		setup_stable_pool(3);
+		let add = |pool_id: PoolId, amounts: &[Balance]| {
+			Dex::add_stable_liquidity(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				stable_amounts(amounts),
+				0,
+				100,
+			)
+		};
+
+		assert_noop!(add(0, &[0, 0, 0]), Error::<Test>::TokenAmountIsZero);
+		assert_noop!(add(2, &[1_000, 1_000]), Error::<Test>::StableAmountsMismatch);
+		assert_noop!(add(3, &[1_000; 3]), Error::<Test>::ExchangeDoesNotExist);
+		System::set_block_number(101);
+		assert_noop!(add(0, &[1_000; 3]), Error::<Test>::DeadlinePassed);
+	});
+}
+
+#[test]
+fn remove_stable_liquidity_works() {
+	new_test_ext().execute_with(|| {
+		let tokens = setup_stable_pool(3);
+		let alice_0 = balance(&tokens[0], &ALICE);
+
+		// A tenth of the liquidity of `ALICE` is worth 10_000 * 2_900 / 30_000 of every token.
+		assert_noop!(
+			Dex::remove_stable_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				1,
+				2_900,
+				stable_amounts(&[966, 966, 967]),
+				100,
+			),
+			Error::<Test>::MinTokensTooHigh
+		);
+		assert_ok!(Dex::remove_stable_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			1,
+			2_900,
+			stable_amounts(&[966; 3]),
+			100,
+		));
+
+		System::assert_last_event(RuntimeEvent::Dex(Event::StableLiquidityRemoved(
+			ALICE,
+			0,
+			stable_amounts(&[966; 3]),
+			2_900,
+		)));
+		assert_eq!(lp_balance(&ALICE), 26_100);
+		assert_eq!(lp_total_issuance(), 27_100);
+		assert_eq!(balance(&tokens[0], &ALICE), alice_0 + 966);
+		assert_eq!(reserves(&tokens[1], &tokens[2]), (9_034, 9_034));
+		assert_noop!(
+			Dex::remove_stable_liquidity(
+				RuntimeOrigin::signed(BOB),
+				0,
+				1,
+				stable_amounts(&[0; 3]),
+				100,
+			),
+			Error::<Test>::ProviderLiquidityTooLow
+		);
+	});
+}
 
 #[test]
-fn it_works_for_default_value() {
+fn ramp_amplification_works() {
 	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		System::set_block_number(1);
-		// Dispatch a signed extrinsic.
-		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
-		// Read pallet storage and assert an expected result.
-		assert_eq!(TemplateModule::something(), Some(42));
-		// Assert that the correct event was deposited
-		System::assert_last_event(Event::SomethingStored { something: 42, who: 1 }.into());
+		let tokens = setup_stable_pool(2);
+		let ramp = |origin, future_amplification, future_block| {
+			Dex::ramp_amplification(origin, 0, future_amplification, future_block)
+		};
+
+		assert_noop!(ramp(RuntimeOrigin::signed(ALICE), 1_000, 101), BadOrigin);
+		assert_noop!(
+			ramp(RuntimeOrigin::root(), 1_000, 1),
+			Error::<Test>::InvalidAmplificationRamp
+		);
+		assert_noop!(ramp(RuntimeOrigin::root(), 0, 101), Error::<Test>::InvalidAmplification);
+		assert_noop!(
+			ramp(RuntimeOrigin::root(), 1_001, 101),
+			Error::<Test>::AmplificationChangeTooLarge
+		);
+		assert_noop!(
+			ramp(RuntimeOrigin::root(), 9, 101),
+			Error::<Test>::AmplificationChangeTooLarge
+		);
+		assert_noop!(
+			Dex::ramp_amplification(RuntimeOrigin::root(), 1, 1_000, 101),
+			Error::<Test>::ExchangeDoesNotExist
+		);
+
+		// 5_000 buy 4_969 at an amplification of 100.
+		assert_ok!(ramp(RuntimeOrigin::root(), 1_000, 101));
+		System::assert_last_event(RuntimeEvent::Dex(Event::AmplificationRamped(
+			0, 100, 1_000, 101,
+		)));
+		let pool = Dex::stable_pools(0).unwrap();
+		assert_eq!(pool.amplification(51), 550);
+		assert_eq!(pool.amplification(101), 1_000);
+		assert_eq!(pool.amplification(1_000), 1_000);
+
+		System::set_block_number(101);
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			tokens[0].clone(),
+			tokens[1].clone(),
+			5_000,
+			4_983,
+		));
 	});
 }
 
 #[test]
-fn correct_error_for_none_value() {
+fn stable_pools_only_take_liquidity_as_a_whole() {
 	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
+		let tokens = setup_stable_pool(2);
+
 		assert_noop!(
-			TemplateModule::cause_error(RuntimeOrigin::signed(1)),
-			Error::<Test>::NoneValue
+			Dex::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				tokens[0].clone(),
+				tokens[1].clone(),
+				1_000,
+				1,
+				1_001,
+				100,
+			),
+			Error::<Test>::UnsupportedPoolKind
+		);
+		assert_noop!(
+			Dex::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				tokens[0].clone(),
+				tokens[1].clone(),
+				1,
+				1,
+				1,
+				100,
+			),
+			Error::<Test>::UnsupportedPoolKind
+		);
+
+		let token_c = setup_asset(3);
+		assert_ok!(Dex::create_exchange(
+			RuntimeOrigin::signed(ALICE),
+			tokens[0].clone(),
+			token_c,
+			LP + 1,
+			10_000,
+			10_000,
+			3,
+			1000,
+			PoolKind::ConstantProduct,
+		));
+		assert_noop!(
+			Dex::add_stable_liquidity(
+				RuntimeOrigin::signed(BOB),
+				1,
+				stable_amounts(&[1_000, 1_000]),
+				0,
+				100,
+			),
+			Error::<Test>::UnsupportedPoolKind
+		);
+		assert_noop!(
+			Dex::ramp_amplification(RuntimeOrigin::root(), 1, 1_000, 101),
+			Error::<Test>::UnsupportedPoolKind
+		);
+	});
+}
+
+/// Gives pools moved from the first layout consecutive liquidity token ids from `LP`.
+struct MigratedLiquidityTokenId;
+
+impl Convert<u32, u32> for MigratedLiquidityTokenId {
+	fn convert(position: u32) -> u32 {
+		LP + position
+	}
+}
+
+/// Stores a pool of the first layout, backed by tokens of `ALICE`.
+fn insert_v0_exchange(token_a: &Token, token_b: &Token, reserves: (Balance, Balance)) {
+	let (TokenKind::Contract(token_a), TokenKind::Contract(token_b)) = (token_a, token_b) else {
+		panic!("pools of the first layout trade token contracts");
+	};
+	token_transfer(token_a, &ALICE, &dex_account(), reserves.0);
+	token_transfer(token_b, &ALICE, &dex_account(), reserves.1);
+	v1::v0::Exchanges::<Test>::insert(
+		(token_a.clone(), token_b.clone()),
+		OldExchange {
+			token_a: token_a.clone(),
+			token_a_reserve: reserves.0,
+			token_b_reserve: reserves.1,
+			token_b: token_b.clone(),
+			fee_numerator: 3,
+			fee_denominator: 1000,
+		},
+	);
+}
+
+/// Creates asset `id` and a `100_000 : 200_000` pool of it against the native currency with a
+/// 0.3% fee, funded by `ALICE`.
+fn setup_fee_pool(id: u32) -> Token {
+	let asset = setup_asset(id);
+	assert_ok!(Dex::create_exchange(
+		RuntimeOrigin::signed(ALICE),
+		asset.clone(),
+		TokenKind::Native,
+		LP,
+		100_000,
+		200_000,
+		3,
+		1000,
+		PoolKind::ConstantProduct,
+	));
+	asset
+}
+
+const FEE_CALL: &RuntimeCall =
+	&RuntimeCall::Balances(pallet_balances::Call::transfer { dest: CHARLIE, value: 1 });
+
+fn info_from_weight(ref_time: u64) -> DispatchInfo {
+	DispatchInfo { weight: Weight::from_parts(ref_time, 0), ..Default::default() }
+}
+
+#[test]
+fn fee_in_asset_is_bought_from_its_pool_and_overcharge_refunded() {
+	new_test_ext().execute_with(|| {
+		let asset = setup_fee_pool(1);
+		let info = info_from_weight(3_000);
+
+		// The fee of 3_000 for the weight, 10 for the length and the tip of 10 is bought at the
+		// price of the pool.
+		let pre = ChargeAssetTxPayment::<Test>::from(10, Some(1))
+			.pre_dispatch(&BOB, FEE_CALL, &info, 10)
+			.unwrap();
+		System::assert_last_event(RuntimeEvent::Dex(Event::TransactionFeeSwapped(
+			BOB, 1, 1_538, 3_020,
+		)));
+		assert_eq!(balance(&asset, &BOB), 100_000 - 1_538);
+		assert_eq!(Balances::free_balance(BOB), ENDOWMENT);
+		assert_eq!(reserves(&asset, &TokenKind::Native), (101_538, 196_980));
+
+		// Only 1_020 is due, and the other 2_000 are sold back into the pool.
+		let post_info = PostDispatchInfo {
+			actual_weight: Some(Weight::from_parts(1_000, 0)),
+			..Default::default()
+		};
+		assert_ok!(ChargeAssetTxPayment::<Test>::post_dispatch(
+			Some(pre),
+			&info,
+			&post_info,
+			10,
+			&Ok(())
+		));
+		System::assert_has_event(RuntimeEvent::Dex(Event::TransactionFeeRefunded(
+			BOB, 1, 1_017, 2_000,
+		)));
+		System::assert_last_event(RuntimeEvent::AssetTxPayment(
+			pallet_asset_tx_payment::Event::AssetTxFeePaid {
+				who: BOB,
+				actual_fee: 521,
+				tip: 5,
+				asset_id: Some(1),
+			},
+		));
+		assert_eq!(balance(&asset, &BOB), 100_000 - 521);
+		assert_eq!(reserves(&asset, &TokenKind::Native), (100_521, 198_980));
+		assert_eq!(Balances::free_balance(BLOCK_AUTHOR), 1_020);
+		assert_eq!(Balances::free_balance(dex_account()), ENDOWMENT + 198_980);
+	});
+}
+
+#[test]
+fn fee_in_asset_fails_without_a_pool() {
+	new_test_ext().execute_with(|| {
+		setup_asset(1);
+
+		assert_eq!(
+			ChargeAssetTxPayment::<Test>::from(0, Some(1)).validate(
+				&BOB,
+				FEE_CALL,
+				&info_from_weight(3_000),
+				10
+			),
+			Err(InvalidTransaction::Custom(NO_FEE_POOL).into())
+		);
+	});
+}
+
+#[test]
+fn fee_in_asset_fails_without_enough_of_the_asset() {
+	new_test_ext().execute_with(|| {
+		setup_fee_pool(1);
+
+		assert_eq!(
+			ChargeAssetTxPayment::<Test>::from(0, Some(1)).validate(
+				&CHARLIE,
+				FEE_CALL,
+				&info_from_weight(3_000),
+				10
+			),
+			Err(InvalidTransaction::Payment.into())
+		);
+		assert_eq!(reserves(&TokenKind::Asset(1), &TokenKind::Native), (100_000, 200_000));
+	});
+}
+
+#[test]
+fn migration_to_v1_moves_pools_to_the_current_layout() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_tokens();
+		StorageVersion::new(0).put::<Dex>();
+		insert_v0_exchange(&token_b, &token_a, (20_000, 10_000));
+		// Can't be moved, as it is between identical tokens.
+		insert_v0_exchange(&token_a, &token_a, (1_000, 1_000));
+
+		v1::MigrateToV1::<Test, MigratedLiquidityTokenId>::on_runtime_upgrade();
+
+		assert_eq!(Dex::on_chain_storage_version(), 1);
+		assert_eq!(NextPoolId::<Test>::get(), 1);
+		assert_eq!(Dex::pools(0), Some((token_a.clone(), token_b.clone())));
+		assert_eq!(Dex::list_pools(Some(token_a.clone())).len(), 1);
+		let exchange = Dex::exchanges((token_a.clone(), token_b.clone())).unwrap();
+		assert_eq!(exchange.pool_id, 0);
+		assert_eq!(exchange.liquidity_token_id, LP);
+		assert_eq!((exchange.token_a_reserve, exchange.token_b_reserve), (10_000, 20_000));
+		assert_eq!(Observations::<Test>::get(0).len(), 1);
+		// The whole liquidity stays locked in the pallet account.
+		assert_eq!(lp_total_issuance(), 10_000);
+		assert_eq!(lp_balance(&dex_account()), 10_000);
+
+		assert_ok!(Dex::swap(
+			RuntimeOrigin::signed(BOB),
+			token_a.clone(),
+			token_b.clone(),
+			1_000,
+			1
+		));
+		assert_eq!(reserves(&token_a, &token_b).0, 11_000);
+		assert_ok!(Dex::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			token_a,
+			token_b,
+			1_100,
+			1,
+			100_000,
+			100
+		));
+		assert_eq!(lp_balance(&BOB), 1_000);
+	});
+}
+
+#[test]
+fn migration_to_v1_skips_taken_liquidity_token_ids() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_tokens();
+		StorageVersion::new(0).put::<Dex>();
+		insert_v0_exchange(&token_a, &token_b, (10_000, 20_000));
+		// The first liquidity token id was handed out before the upgrade.
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), LP, BOB, false, 1));
+
+		v1::MigrateToV1::<Test, MigratedLiquidityTokenId>::on_runtime_upgrade();
+
+		let exchange = Dex::exchanges((token_a, token_b)).unwrap();
+		assert_eq!(exchange.pool_id, 0);
+		assert_eq!(exchange.liquidity_token_id, LP + 1);
+		assert_eq!(
+			<Assets as frame_support::traits::fungibles::Inspect<_>>::total_issuance(LP + 1),
+			10_000
 		);
 	});
 }
+
+#[test]
+fn migration_to_v1_runs_once() {
+	new_test_ext().execute_with(|| {
+		let (token_a, token_b) = setup_exchange();
+		StorageVersion::new(1).put::<Dex>();
+		let exchange = Dex::exchanges((token_a.clone(), token_b.clone()));
+
+		v1::MigrateToV1::<Test, MigratedLiquidityTokenId>::on_runtime_upgrade();
+
+		assert_eq!(Dex::exchanges((token_a, token_b)), exchange);
+		assert_eq!(NextPoolId::<Test>::get(), 1);
+	});
+}
+
+#[test]
+fn genesis_config_seeds_pools() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	// Liquidity tokens are not sufficient, so the pallet account needs a native balance to hold
+	// them.
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(ALICE, ENDOWMENT), (dex_account(), ENDOWMENT)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	pallet_assets::GenesisConfig::<Test> {
+		assets: vec![(1, ALICE, true, 1)],
+		metadata: vec![],
+		accounts: vec![(1, ALICE, SUPPLY)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	crate::GenesisConfig::<Test> {
+		pools: vec![(TokenKind::Asset(1), TokenKind::Native, LP, 10_000, 20_000, ALICE)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		assert_eq!(reserves(&TokenKind::Asset(1), &TokenKind::Native), (10_000, 20_000));
+		assert_eq!(balance(&TokenKind::Asset(1), &ALICE), SUPPLY - 10_000);
+		assert_eq!(lp_balance(&ALICE), 14_142 - MinimumLiquidity::get());
+	});
+}
+
+#[test]
+fn psp22_wasm_fixture_matches_wat() {
+	// The benchmarks cannot parse wat in no_std and embed the compiled fixture instead.
+	let wasm = wat::parse_file("fixtures/psp22.wat").unwrap();
+	assert_eq!(wasm, include_bytes!("../fixtures/psp22.wasm").to_vec());
+}
+
+#[test]
+fn flash_receiver_wasm_fixture_matches_wat() {
+	let wasm = wat::parse_file("fixtures/flash_receiver.wat").unwrap();
+	assert_eq!(wasm, include_bytes!("../fixtures/flash_receiver.wasm").to_vec());
+}
@@ -0,0 +1,98 @@
+//! Types for the DEX runtime API.
+
+use crate::{PoolId, TokenKind};
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use frame_support::sp_runtime::{FixedU128, Perbill, RuntimeDebug};
+use scale_info::TypeInfo;
+use sp_std::prelude::*;
+
+/// The state of a pool.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PoolInfo<AssetId, AccountId, Balance> {
+	/// Identifier of the pool.
+	pub pool_id: PoolId,
+	/// First token of the canonical pair.
+	pub token_a: TokenKind<AssetId, AccountId>,
+	/// Second token of the canonical pair.
+	pub token_b: TokenKind<AssetId, AccountId>,
+	/// Reserve of `token_a`.
+	pub token_a_reserve: Balance,
+	/// Reserve of `token_b`.
+	pub token_b_reserve: Balance,
+	/// Fee charged on the sold token, as `fee_numerator / fee_denominator`.
+	pub fee_numerator: Balance,
+	/// Denominator of the fee.
+	pub fee_denominator: Balance,
+	/// Asset id of the liquidity token of the pool.
+	pub liquidity_token_id: AssetId,
+	/// Total issuance of the liquidity token.
+	pub total_liquidity: Balance,
+}
+
+/// The outcome of a swap along a path of pools, as it would be executed now.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Quote<Balance> {
+	/// Amount of the first token of the path sold.
+	pub amount_in: Balance,
+	/// Amount of the last token of the path bought.
+	pub amount_out: Balance,
+	/// Amount traded into every pool of the path, followed by `amount_out`.
+	pub amounts: Vec<Balance>,
+	/// Fee charged by every pool of the path, in the token sold into it, protocol share included.
+	pub fees: Vec<Balance>,
+	/// How much worse the price is than the spot price of the path, fees excluded.
+	pub price_impact: Perbill,
+}
+
+/// Price of a token in another one, averaged over a range of blocks.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TwapPrice<BlockNumber> {
+	/// Average amount of the bought token paid for one sold token, fees excluded.
+	pub price: FixedU128,
+	/// First block of the range.
+	pub from: BlockNumber,
+	/// Block the range ends before.
+	pub to: BlockNumber,
+}
+
+impl<Balance> Quote<Balance> {
+	/// Converts every amount of the quote with `f`.
+	pub fn try_map<B, E>(self, mut f: impl FnMut(Balance) -> Result<B, E>) -> Result<Quote<B>, E> {
+		Ok(Quote {
+			amount_in: f(self.amount_in)?,
+			amount_out: f(self.amount_out)?,
+			amounts: self.amounts.into_iter().map(&mut f).collect::<Result<_, _>>()?,
+			fees: self.fees.into_iter().map(&mut f).collect::<Result<_, _>>()?,
+			price_impact: self.price_impact,
+		})
+	}
+}
+
+impl<AssetId, AccountId, Balance> PoolInfo<AssetId, AccountId, Balance> {
+	/// Converts every amount of the pool with `f`.
+	pub fn try_map<B, E>(
+		self,
+		mut f: impl FnMut(Balance) -> Result<B, E>,
+	) -> Result<PoolInfo<AssetId, AccountId, B>, E> {
+		Ok(PoolInfo {
+			pool_id: self.pool_id,
+			token_a: self.token_a,
+			token_b: self.token_b,
+			token_a_reserve: f(self.token_a_reserve)?,
+			token_b_reserve: f(self.token_b_reserve)?,
+			fee_numerator: f(self.fee_numerator)?,
+			fee_denominator: f(self.fee_denominator)?,
+			liquidity_token_id: self.liquidity_token_id,
+			total_liquidity: f(self.total_liquidity)?,
+		})
+	}
+}
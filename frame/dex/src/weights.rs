@@ -1,3 +1,30 @@
+//! Autogenerated weights for pallet_dex
+//!
+//! MEASURED BY DRIVING THE `benchmark pallet` LOOP OVER THE NATIVE RUNTIME INSTEAD OF WASM.
+//! REGENERATE WITH THE COMMAND BELOW ON REFERENCE HARDWARE BEFORE RELYING ON THE TIMINGS.
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: Some(Native), CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! The scenarios trade the tokens of the `psp22` fixture, so these weights include the nested calls
+//! into its contract, which are charged again up to `ContractCallGasLimit` and refunded down to the
+//! gas they actually consumed.
+
+// Regenerate with:
+// ./target/production/substrate
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_dex
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/dex/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -6,128 +33,1774 @@
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
-/// Weight functions for `pallet_dex`.
+/// Weight functions needed for pallet_dex.
 pub trait WeightInfo {
 	fn create_exchange() -> Weight;
+	fn swap() -> Weight;
+	fn asset_to_asset() -> Weight;
 	fn add_liquidity() -> Weight;
 	fn remove_liquidity() -> Weight;
-	fn currency_to_asset() -> Weight;
-	fn asset_to_currency() -> Weight;
-	fn asset_to_asset() -> Weight;
-
+	fn swap_exact_in_along_path(n: u32, ) -> Weight;
+	fn swap_exact_out_along_path(n: u32, ) -> Weight;
+	fn set_protocol_fee() -> Weight;
+	fn add_fee_tier() -> Weight;
+	fn remove_fee_tier() -> Weight;
+	fn collect_protocol_fees() -> Weight;
+	fn flash_swap(d: u32, ) -> Weight;
+	fn place_order() -> Weight;
+	fn fill_order() -> Weight;
+	fn cancel_order() -> Weight;
+	fn expire_order() -> Weight;
+	fn mint_position() -> Weight;
+	fn decrease_liquidity() -> Weight;
+	fn collect_position_fees() -> Weight;
+	fn swap_concentrated() -> Weight;
+	fn create_stable_pool(n: u32, ) -> Weight;
+	fn add_stable_liquidity(n: u32, ) -> Weight;
+	fn remove_stable_liquidity(n: u32, ) -> Weight;
+	fn ramp_amplification() -> Weight;
+	fn swap_stable() -> Weight;
 }
 
-/// Weight functions for `pallet_dex`.
+/// Weights for pallet_dex using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	// Storage: Assets Asset (r:2 w:1)
-	// Storage: Dex Exchanges (r:1 w:1)
-	// Storage: System Account (r:1 w:1)
-	// Storage: Assets Account (r:3 w:3)
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:3 w:3)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX NextPoolId (r:1 w:1)
+	/// Proof: DEX NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: DEX PoolsByToken (r:0 w:2)
+	/// Proof: DEX PoolsByToken (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: DEX Pools (r:0 w:1)
+	/// Proof: DEX Pools (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
 	fn create_exchange() -> Weight {
-		Weight::from_ref_time(103_019_000)
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(7))
-	}
-	// Storage: Assets Asset (r:2 w:2)
-	// Storage: Assets Account (r:3 w:3)
-	// Storage: Dex Exchanges (r:1 w:1)
-	// Storage: System Account (r:1 w:1)
+		// Proof Size summary in bytes:
+		//  Measured:  `5138`
+		//  Estimated: `194223`
+		// Minimum execution time: 1_154_309 nanoseconds.
+		Weight::from_parts(1_422_582_000, 194223)
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(21_u64))
+	}
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:1 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:4 w:4)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFees (r:1 w:1)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	fn swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4759`
+		//  Estimated: `194460`
+		// Minimum execution time: 704_658 nanoseconds.
+		Weight::from_parts(782_669_000, 194460)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: DEX Exchanges (r:2 w:2)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:2 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:2 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:4 w:4)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:2 w:2)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFees (r:2 w:2)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	fn asset_to_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5235`
+		//  Estimated: `218228`
+		// Minimum execution time: 743_438 nanoseconds.
+		Weight::from_parts(833_642_000, 218228)
+			.saturating_add(T::DbWeight::get().reads(25_u64))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+	}
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:1 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:3 w:3)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
 	fn add_liquidity() -> Weight {
-		Weight::from_ref_time(89_032_000)
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(7))
-	}
-	// Storage: Dex Exchanges (r:1 w:1)
-	// Storage: Assets Asset (r:2 w:2)
-	// Storage: Assets Account (r:3 w:3)
-	// Storage: System Account (r:1 w:1)
+		// Proof Size summary in bytes:
+		//  Measured:  `5434`
+		//  Estimated: `193630`
+		// Minimum execution time: 1_103_610 nanoseconds.
+		Weight::from_parts(1_299_133_000, 193630)
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:1 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:3 w:3)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
 	fn remove_liquidity() -> Weight {
-		Weight::from_ref_time(88_652_000)
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(7))
-	}
-	// Storage: Dex Exchanges (r:1 w:1)
-	// Storage: System Account (r:1 w:1)
-	// Storage: Assets Asset (r:1 w:1)
-	// Storage: Assets Account (r:2 w:2)
-	fn currency_to_asset() -> Weight {
-		Weight::from_ref_time(70_294_000)
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
-	}
-	// Storage: Dex Exchanges (r:1 w:1)
-	// Storage: Assets Asset (r:1 w:1)
-	// Storage: Assets Account (r:2 w:2)
-	// Storage: System Account (r:1 w:1)
-	fn asset_to_currency() -> Weight {
-		Weight::from_ref_time(72_349_000)
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
-	}
-	// Storage: Dex Exchanges (r:2 w:2)
-	// Storage: Assets Asset (r:2 w:2)
-	// Storage: Assets Account (r:4 w:4)
-	fn asset_to_asset() -> Weight {
-		Weight::from_ref_time(99_152_000)
-			.saturating_add(T::DbWeight::get().reads(8))
-			.saturating_add(T::DbWeight::get().writes(8))
+		// Proof Size summary in bytes:
+		//  Measured:  `5434`
+		//  Estimated: `196233`
+		// Minimum execution time: 681_653 nanoseconds.
+		Weight::from_parts(787_834_000, 196233)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: DEX Exchanges (r:3 w:3)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:3 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:3 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:4 w:4)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:3 w:3)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFees (r:3 w:3)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// The range of component `n` is `[2, 4]`.
+	fn swap_exact_in_along_path(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3807 + n * (476 ±0)`
+		//  Estimated: `191655 + n * (6790 ±55)`
+		// Minimum execution time: 442_954 nanoseconds.
+		Weight::from_parts(649_253_485, 191655)
+			// Standard Error: 2_407_303
+			.saturating_add(Weight::from_ref_time(43_705_268).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(6790).saturating_mul(n.into()))
+	}
+	/// Storage: DEX Exchanges (r:3 w:3)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:3 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:3 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:4 w:4)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:3 w:3)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// The range of component `n` is `[2, 4]`.
+	fn swap_exact_out_along_path(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3807 + n * (476 ±0)`
+		//  Estimated: `189115 + n * (6178 ±50)`
+		// Minimum execution time: 416_707 nanoseconds.
+		Weight::from_parts(723_837_427, 189115)
+			// Standard Error: 2_210_987
+			.saturating_add(Weight::from_ref_time(12_862_566).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(6178).saturating_mul(n.into()))
+	}
+	/// Storage: DEX ProtocolFee (r:0 w:1)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_protocol_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1149`
+		//  Estimated: `0`
+		// Minimum execution time: 15_612 nanoseconds.
+		Weight::from_parts(16_530_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DEX FeeTiers (r:1 w:1)
+	/// Proof: DEX FeeTiers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn add_fee_tier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1380`
+		//  Estimated: `2523`
+		// Minimum execution time: 30_360 nanoseconds.
+		Weight::from_parts(34_734_000, 2523)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DEX FeeTiers (r:1 w:1)
+	/// Proof: DEX FeeTiers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn remove_fee_tier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1366`
+		//  Estimated: `2523`
+		// Minimum execution time: 29_224 nanoseconds.
+		Weight::from_parts(30_521_000, 2523)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DEX ProtocolFees (r:1 w:1)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Storage: unknown `0x6b1ab1274bcbe3a4176e17eb2917654904f19b3261911ec3f7a30a473a04dcc8` (r:1 w:1)
+	/// Proof Skipped: unknown `0x6b1ab1274bcbe3a4176e17eb2917654904f19b3261911ec3f7a30a473a04dcc8` (r:1 w:1)
+	fn collect_protocol_fees() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3984`
+		//  Estimated: `161806`
+		// Minimum execution time: 413_973 nanoseconds.
+		Weight::from_parts(489_034_000, 161806)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:1 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:3 w:3)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:2 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:5 w:5)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:1)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFees (r:1 w:1)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Storage: unknown `0xc40dd16a698af921dabc0f05b3e23e967de00628c7159be99505efe5979e4efe` (r:2 w:2)
+	/// Proof Skipped: unknown `0xc40dd16a698af921dabc0f05b3e23e967de00628c7159be99505efe5979e4efe` (r:2 w:2)
+	/// Storage: unknown `0x89eb0d6a8a691dae2cd15ed0369931ce0a949ecafa5c3f93f8121833646e15c3` (r:1 w:0)
+	/// Proof Skipped: unknown `0x89eb0d6a8a691dae2cd15ed0369931ce0a949ecafa5c3f93f8121833646e15c3` (r:1 w:0)
+	/// The range of component `d` is `[0, 1024]`.
+	fn flash_swap(_d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5906`
+		//  Estimated: `342569`
+		// Minimum execution time: 1_033_500 nanoseconds.
+		Weight::from_parts(1_731_182_047, 342569)
+			.saturating_add(T::DbWeight::get().reads(25_u64))
+			.saturating_add(T::DbWeight::get().writes(18_u64))
+	}
+	/// Storage: DEX Exchanges (r:1 w:0)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX NextOrderId (r:1 w:1)
+	/// Proof: DEX NextOrderId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX ExpiryCursor (r:1 w:1)
+	/// Proof: DEX ExpiryCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX Orders (r:0 w:1)
+	/// Proof: DEX Orders (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: DEX OrderBook (r:0 w:1)
+	/// Proof: DEX OrderBook (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: DEX OrderExpiries (r:0 w:1)
+	/// Proof: DEX OrderExpiries (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	fn place_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4117`
+		//  Estimated: `158184`
+		// Minimum execution time: 367_438 nanoseconds.
+		Weight::from_parts(470_282_000, 158184)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: DEX Orders (r:1 w:1)
+	/// Proof: DEX Orders (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:1 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFees (r:1 w:1)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: DEX OrderBook (r:0 w:1)
+	/// Proof: DEX OrderBook (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: DEX OrderExpiries (r:0 w:1)
+	/// Proof: DEX OrderExpiries (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	fn fill_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4911`
+		//  Estimated: `187527`
+		// Minimum execution time: 279_852 nanoseconds.
+		Weight::from_parts(339_180_000, 187527)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: DEX Orders (r:1 w:1)
+	/// Proof: DEX Orders (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX OrderBook (r:0 w:1)
+	/// Proof: DEX OrderBook (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: DEX OrderExpiries (r:0 w:1)
+	/// Proof: DEX OrderExpiries (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	fn cancel_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4152`
+		//  Estimated: `159808`
+		// Minimum execution time: 406_714 nanoseconds.
+		Weight::from_parts(483_782_000, 159808)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: DEX ExpiryCursor (r:1 w:1)
+	/// Proof: DEX ExpiryCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX OrderExpiries (r:2 w:1)
+	/// Proof: DEX OrderExpiries (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: DEX Orders (r:1 w:1)
+	/// Proof: DEX Orders (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX OrderBook (r:0 w:1)
+	/// Proof: DEX OrderBook (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	fn expire_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4784`
+		//  Estimated: `167209`
+		// Minimum execution time: 400_858 nanoseconds.
+		Weight::from_parts(522_457_000, 167209)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: DEX Pools (r:1 w:0)
+	/// Proof: DEX Pools (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:1)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX NextPositionId (r:1 w:1)
+	/// Proof: DEX NextPositionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:3 w:3)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Uniques Class (r:1 w:1)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionMaxSupply (r:1 w:0)
+	/// Proof: Uniques CollectionMaxSupply (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX Positions (r:0 w:1)
+	/// Proof: DEX Positions (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:1)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	fn mint_position() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10297`
+		//  Estimated: `213907`
+		// Minimum execution time: 807_074 nanoseconds.
+		Weight::from_parts(923_784_000, 213907)
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(18_u64))
+	}
+	/// Storage: DEX Positions (r:1 w:1)
+	/// Proof: DEX Positions (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: DEX Pools (r:1 w:0)
+	/// Proof: DEX Pools (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:1)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:3 w:3)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:0)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:1)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:1 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:0 w:1)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:1)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	fn decrease_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6426`
+		//  Estimated: `212747`
+		// Minimum execution time: 576_096 nanoseconds.
+		Weight::from_parts(833_331_000, 212747)
+			.saturating_add(T::DbWeight::get().reads(24_u64))
+			.saturating_add(T::DbWeight::get().writes(19_u64))
+	}
+	/// Storage: DEX Positions (r:1 w:1)
+	/// Proof: DEX Positions (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:0)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: DEX Pools (r:1 w:0)
+	/// Proof: DEX Pools (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: DEX Exchanges (r:1 w:0)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:3 w:3)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	fn collect_position_fees() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5709`
+		//  Estimated: `189361`
+		// Minimum execution time: 533_140 nanoseconds.
+		Weight::from_parts(695_340_000, 189361)
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:1)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:4 w:4)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:1 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFees (r:1 w:1)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	fn swap_concentrated() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9308`
+		//  Estimated: `208107`
+		// Minimum execution time: 647_041 nanoseconds.
+		Weight::from_parts(852_151_000, 208107)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: DEX Exchanges (r:6 w:6)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:4 w:4)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:5 w:5)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX NextPoolId (r:1 w:1)
+	/// Proof: DEX NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: System Account (r:5 w:5)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:6 w:6)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX PoolsByToken (r:0 w:12)
+	/// Proof: DEX PoolsByToken (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: DEX Pools (r:0 w:6)
+	/// Proof: DEX Pools (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:0 w:6)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX StablePools (r:0 w:1)
+	/// Proof: DEX StablePools (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:4 w:4)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:4 w:4)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:4 w:4)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:4 w:4)
+	/// The range of component `n` is `[2, 4]`.
+	fn create_stable_pool(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4094 + n * (522 ±0)`
+		//  Estimated: `165542 + n * (19474 ±42)`
+		// Minimum execution time: 659_523 nanoseconds.
+		Weight::from_parts(858_395_000, 165542)
+			// Standard Error: 3_861_020
+			.saturating_add(Weight::from_ref_time(208_766_760).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(23_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(19474).saturating_mul(n.into()))
+	}
+	/// Storage: DEX StablePairs (r:6 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX StablePools (r:1 w:1)
+	/// Proof: DEX StablePools (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: DEX Exchanges (r:6 w:6)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:6 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:6 w:6)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// The range of component `n` is `[2, 4]`.
+	fn add_stable_liquidity(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3543 + n * (907 ±0)`
+		//  Estimated: `180254 + n * (12621 ±4)`
+		// Minimum execution time: 271_903 nanoseconds.
+		Weight::from_parts(316_520_174, 180254)
+			// Standard Error: 3_082_758
+			.saturating_add(Weight::from_ref_time(76_146_222).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(12621).saturating_mul(n.into()))
+	}
+	/// Storage: DEX StablePairs (r:6 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX StablePools (r:1 w:1)
+	/// Proof: DEX StablePools (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:4 w:4)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:5 w:5)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX Exchanges (r:6 w:6)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:6 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:6 w:6)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:4 w:4)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:4 w:4)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:4 w:4)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:4 w:4)
+	/// The range of component `n` is `[2, 4]`.
+	fn remove_stable_liquidity(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3143 + n * (1273 ±0)`
+		//  Estimated: `171526 + n * (23868 ±119)`
+		// Minimum execution time: 409_664 nanoseconds.
+		Weight::from_parts(113_458_274, 171526)
+			// Standard Error: 6_240_632
+			.saturating_add(Weight::from_ref_time(278_633_204).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(23868).saturating_mul(n.into()))
+	}
+	/// Storage: DEX StablePairs (r:1 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX StablePools (r:1 w:1)
+	/// Proof: DEX StablePools (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	fn ramp_amplification() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1547`
+		//  Estimated: `5228`
+		// Minimum execution time: 53_041 nanoseconds.
+		Weight::from_parts(63_601_000, 5228)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DEX Exchanges (r:6 w:6)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:6 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:6 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX StablePools (r:1 w:1)
+	/// Proof: DEX StablePools (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:4 w:4)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFees (r:1 w:1)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:6 w:6)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	fn swap_stable() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7257`
+		//  Estimated: `303691`
+		// Minimum execution time: 548_903 nanoseconds.
+		Weight::from_parts(822_131_000, 303691)
+			.saturating_add(T::DbWeight::get().reads(41_u64))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	// Storage: Assets Asset (r:2 w:1)
-	// Storage: Dex Exchanges (r:1 w:1)
-	// Storage: System Account (r:1 w:1)
-	// Storage: Assets Account (r:3 w:3)
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:3 w:3)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX NextPoolId (r:1 w:1)
+	/// Proof: DEX NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: DEX PoolsByToken (r:0 w:2)
+	/// Proof: DEX PoolsByToken (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: DEX Pools (r:0 w:1)
+	/// Proof: DEX Pools (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
 	fn create_exchange() -> Weight {
-		Weight::from_ref_time(103_019_000)
-			.saturating_add(RocksDbWeight::get().reads(7))
-			.saturating_add(RocksDbWeight::get().writes(7))
-	}
-	// Storage: Assets Asset (r:2 w:2)
-	// Storage: Assets Account (r:3 w:3)
-	// Storage: Dex Exchanges (r:1 w:1)
-	// Storage: System Account (r:1 w:1)
+		// Proof Size summary in bytes:
+		//  Measured:  `5138`
+		//  Estimated: `194223`
+		// Minimum execution time: 1_154_309 nanoseconds.
+		Weight::from_parts(1_422_582_000, 194223)
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(21_u64))
+	}
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:1 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:4 w:4)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFees (r:1 w:1)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	fn swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4759`
+		//  Estimated: `194460`
+		// Minimum execution time: 704_658 nanoseconds.
+		Weight::from_parts(782_669_000, 194460)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: DEX Exchanges (r:2 w:2)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:2 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:2 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:4 w:4)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:2 w:2)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFees (r:2 w:2)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	fn asset_to_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5235`
+		//  Estimated: `218228`
+		// Minimum execution time: 743_438 nanoseconds.
+		Weight::from_parts(833_642_000, 218228)
+			.saturating_add(RocksDbWeight::get().reads(25_u64))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
+	}
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:1 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:3 w:3)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
 	fn add_liquidity() -> Weight {
-		Weight::from_ref_time(89_032_000)
-			.saturating_add(RocksDbWeight::get().reads(7))
-			.saturating_add(RocksDbWeight::get().writes(7))
-	}
-	// Storage: Dex Exchanges (r:1 w:1)
-	// Storage: Assets Asset (r:2 w:2)
-	// Storage: Assets Account (r:3 w:3)
-	// Storage: System Account (r:1 w:1)
+		// Proof Size summary in bytes:
+		//  Measured:  `5434`
+		//  Estimated: `193630`
+		// Minimum execution time: 1_103_610 nanoseconds.
+		Weight::from_parts(1_299_133_000, 193630)
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:1 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:3 w:3)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
 	fn remove_liquidity() -> Weight {
-		Weight::from_ref_time(88_652_000)
-			.saturating_add(RocksDbWeight::get().reads(7))
-			.saturating_add(RocksDbWeight::get().writes(7))
-	}
-	// Storage: Dex Exchanges (r:1 w:1)
-	// Storage: System Account (r:1 w:1)
-	// Storage: Assets Asset (r:1 w:1)
-	// Storage: Assets Account (r:2 w:2)
-	fn currency_to_asset() -> Weight {
-		Weight::from_ref_time(70_294_000)
-			.saturating_add(RocksDbWeight::get().reads(5))
-			.saturating_add(RocksDbWeight::get().writes(5))
-	}
-	// Storage: Dex Exchanges (r:1 w:1)
-	// Storage: Assets Asset (r:1 w:1)
-	// Storage: Assets Account (r:2 w:2)
-	// Storage: System Account (r:1 w:1)
-	fn asset_to_currency() -> Weight {
-		Weight::from_ref_time(72_349_000)
-			.saturating_add(RocksDbWeight::get().reads(5))
-			.saturating_add(RocksDbWeight::get().writes(5))
-	}
-	// Storage: Dex Exchanges (r:2 w:2)
-	// Storage: Assets Asset (r:2 w:2)
-	// Storage: Assets Account (r:4 w:4)
-	fn asset_to_asset() -> Weight {
-		Weight::from_ref_time(99_152_000)
-			.saturating_add(RocksDbWeight::get().reads(8))
-			.saturating_add(RocksDbWeight::get().writes(8))
+		// Proof Size summary in bytes:
+		//  Measured:  `5434`
+		//  Estimated: `196233`
+		// Minimum execution time: 681_653 nanoseconds.
+		Weight::from_parts(787_834_000, 196233)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: DEX Exchanges (r:3 w:3)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:3 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:3 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:4 w:4)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:3 w:3)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFees (r:3 w:3)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// The range of component `n` is `[2, 4]`.
+	fn swap_exact_in_along_path(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3807 + n * (476 ±0)`
+		//  Estimated: `191655 + n * (6790 ±55)`
+		// Minimum execution time: 442_954 nanoseconds.
+		Weight::from_parts(649_253_485, 191655)
+			// Standard Error: 2_407_303
+			.saturating_add(Weight::from_ref_time(43_705_268).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(6790).saturating_mul(n.into()))
+	}
+	/// Storage: DEX Exchanges (r:3 w:3)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:3 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:3 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:4 w:4)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:3 w:3)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// The range of component `n` is `[2, 4]`.
+	fn swap_exact_out_along_path(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3807 + n * (476 ±0)`
+		//  Estimated: `189115 + n * (6178 ±50)`
+		// Minimum execution time: 416_707 nanoseconds.
+		Weight::from_parts(723_837_427, 189115)
+			// Standard Error: 2_210_987
+			.saturating_add(Weight::from_ref_time(12_862_566).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(6178).saturating_mul(n.into()))
+	}
+	/// Storage: DEX ProtocolFee (r:0 w:1)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_protocol_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1149`
+		//  Estimated: `0`
+		// Minimum execution time: 15_612 nanoseconds.
+		Weight::from_parts(16_530_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DEX FeeTiers (r:1 w:1)
+	/// Proof: DEX FeeTiers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn add_fee_tier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1380`
+		//  Estimated: `2523`
+		// Minimum execution time: 30_360 nanoseconds.
+		Weight::from_parts(34_734_000, 2523)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DEX FeeTiers (r:1 w:1)
+	/// Proof: DEX FeeTiers (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn remove_fee_tier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1366`
+		//  Estimated: `2523`
+		// Minimum execution time: 29_224 nanoseconds.
+		Weight::from_parts(30_521_000, 2523)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DEX ProtocolFees (r:1 w:1)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Storage: unknown `0x6b1ab1274bcbe3a4176e17eb2917654904f19b3261911ec3f7a30a473a04dcc8` (r:1 w:1)
+	/// Proof Skipped: unknown `0x6b1ab1274bcbe3a4176e17eb2917654904f19b3261911ec3f7a30a473a04dcc8` (r:1 w:1)
+	fn collect_protocol_fees() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3984`
+		//  Estimated: `161806`
+		// Minimum execution time: 413_973 nanoseconds.
+		Weight::from_parts(489_034_000, 161806)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:1 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:3 w:3)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:2 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:5 w:5)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:1)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFees (r:1 w:1)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Storage: unknown `0xc40dd16a698af921dabc0f05b3e23e967de00628c7159be99505efe5979e4efe` (r:2 w:2)
+	/// Proof Skipped: unknown `0xc40dd16a698af921dabc0f05b3e23e967de00628c7159be99505efe5979e4efe` (r:2 w:2)
+	/// Storage: unknown `0x89eb0d6a8a691dae2cd15ed0369931ce0a949ecafa5c3f93f8121833646e15c3` (r:1 w:0)
+	/// Proof Skipped: unknown `0x89eb0d6a8a691dae2cd15ed0369931ce0a949ecafa5c3f93f8121833646e15c3` (r:1 w:0)
+	/// The range of component `d` is `[0, 1024]`.
+	fn flash_swap(_d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5906`
+		//  Estimated: `342569`
+		// Minimum execution time: 1_033_500 nanoseconds.
+		Weight::from_parts(1_731_182_047, 342569)
+			.saturating_add(RocksDbWeight::get().reads(25_u64))
+			.saturating_add(RocksDbWeight::get().writes(18_u64))
 	}
-}
\ No newline at end of file
+	/// Storage: DEX Exchanges (r:1 w:0)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX NextOrderId (r:1 w:1)
+	/// Proof: DEX NextOrderId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX ExpiryCursor (r:1 w:1)
+	/// Proof: DEX ExpiryCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX Orders (r:0 w:1)
+	/// Proof: DEX Orders (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: DEX OrderBook (r:0 w:1)
+	/// Proof: DEX OrderBook (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: DEX OrderExpiries (r:0 w:1)
+	/// Proof: DEX OrderExpiries (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	fn place_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4117`
+		//  Estimated: `158184`
+		// Minimum execution time: 367_438 nanoseconds.
+		Weight::from_parts(470_282_000, 158184)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: DEX Orders (r:1 w:1)
+	/// Proof: DEX Orders (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:1 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFees (r:1 w:1)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: DEX OrderBook (r:0 w:1)
+	/// Proof: DEX OrderBook (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: DEX OrderExpiries (r:0 w:1)
+	/// Proof: DEX OrderExpiries (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	fn fill_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4911`
+		//  Estimated: `187527`
+		// Minimum execution time: 279_852 nanoseconds.
+		Weight::from_parts(339_180_000, 187527)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: DEX Orders (r:1 w:1)
+	/// Proof: DEX Orders (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX OrderBook (r:0 w:1)
+	/// Proof: DEX OrderBook (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: DEX OrderExpiries (r:0 w:1)
+	/// Proof: DEX OrderExpiries (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	fn cancel_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4152`
+		//  Estimated: `159808`
+		// Minimum execution time: 406_714 nanoseconds.
+		Weight::from_parts(483_782_000, 159808)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: DEX ExpiryCursor (r:1 w:1)
+	/// Proof: DEX ExpiryCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX OrderExpiries (r:2 w:1)
+	/// Proof: DEX OrderExpiries (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: DEX Orders (r:1 w:1)
+	/// Proof: DEX Orders (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX OrderBook (r:0 w:1)
+	/// Proof: DEX OrderBook (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	fn expire_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4784`
+		//  Estimated: `167209`
+		// Minimum execution time: 400_858 nanoseconds.
+		Weight::from_parts(522_457_000, 167209)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: DEX Pools (r:1 w:0)
+	/// Proof: DEX Pools (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:1)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX NextPositionId (r:1 w:1)
+	/// Proof: DEX NextPositionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:3 w:3)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Uniques Class (r:1 w:1)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionMaxSupply (r:1 w:0)
+	/// Proof: Uniques CollectionMaxSupply (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX Positions (r:0 w:1)
+	/// Proof: DEX Positions (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:1)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	fn mint_position() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10297`
+		//  Estimated: `213907`
+		// Minimum execution time: 807_074 nanoseconds.
+		Weight::from_parts(923_784_000, 213907)
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(18_u64))
+	}
+	/// Storage: DEX Positions (r:1 w:1)
+	/// Proof: DEX Positions (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: DEX Pools (r:1 w:0)
+	/// Proof: DEX Pools (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:1)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:3 w:3)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:0)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:1)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:1 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:0 w:1)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:1)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	fn decrease_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6426`
+		//  Estimated: `212747`
+		// Minimum execution time: 576_096 nanoseconds.
+		Weight::from_parts(833_331_000, 212747)
+			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().writes(19_u64))
+	}
+	/// Storage: DEX Positions (r:1 w:1)
+	/// Proof: DEX Positions (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:0)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: DEX Pools (r:1 w:0)
+	/// Proof: DEX Pools (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: DEX Exchanges (r:1 w:0)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:3 w:3)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	fn collect_position_fees() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5709`
+		//  Estimated: `189361`
+		// Minimum execution time: 533_140 nanoseconds.
+		Weight::from_parts(695_340_000, 189361)
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: DEX Exchanges (r:1 w:1)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:1 w:1)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:4 w:4)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:1 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:1 w:1)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFees (r:1 w:1)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	fn swap_concentrated() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9308`
+		//  Estimated: `208107`
+		// Minimum execution time: 647_041 nanoseconds.
+		Weight::from_parts(852_151_000, 208107)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: DEX Exchanges (r:6 w:6)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:4 w:4)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:5 w:5)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX NextPoolId (r:1 w:1)
+	/// Proof: DEX NextPoolId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: System Account (r:5 w:5)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:6 w:6)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: DEX PoolsByToken (r:0 w:12)
+	/// Proof: DEX PoolsByToken (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: DEX Pools (r:0 w:6)
+	/// Proof: DEX Pools (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:0 w:6)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX StablePools (r:0 w:1)
+	/// Proof: DEX StablePools (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:4 w:4)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:4 w:4)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:4 w:4)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:4 w:4)
+	/// The range of component `n` is `[2, 4]`.
+	fn create_stable_pool(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4094 + n * (522 ±0)`
+		//  Estimated: `165542 + n * (19474 ±42)`
+		// Minimum execution time: 659_523 nanoseconds.
+		Weight::from_parts(858_395_000, 165542)
+			// Standard Error: 3_861_020
+			.saturating_add(Weight::from_ref_time(208_766_760).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((9_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(23_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(19474).saturating_mul(n.into()))
+	}
+	/// Storage: DEX StablePairs (r:6 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX StablePools (r:1 w:1)
+	/// Proof: DEX StablePools (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: DEX Exchanges (r:6 w:6)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:6 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:6 w:6)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:1 w:1)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:1 w:1)
+	/// The range of component `n` is `[2, 4]`.
+	fn add_stable_liquidity(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3543 + n * (907 ±0)`
+		//  Estimated: `180254 + n * (12621 ±4)`
+		// Minimum execution time: 271_903 nanoseconds.
+		Weight::from_parts(316_520_174, 180254)
+			// Standard Error: 3_082_758
+			.saturating_add(Weight::from_ref_time(76_146_222).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(12621).saturating_mul(n.into()))
+	}
+	/// Storage: DEX StablePairs (r:6 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX StablePools (r:1 w:1)
+	/// Proof: DEX StablePools (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:4 w:4)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:5 w:5)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEX Exchanges (r:6 w:6)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:6 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:6 w:6)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:4 w:4)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:4 w:4)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:4 w:4)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:4 w:4)
+	/// The range of component `n` is `[2, 4]`.
+	fn remove_stable_liquidity(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3143 + n * (1273 ±0)`
+		//  Estimated: `171526 + n * (23868 ±119)`
+		// Minimum execution time: 409_664 nanoseconds.
+		Weight::from_parts(113_458_274, 171526)
+			// Standard Error: 6_240_632
+			.saturating_add(Weight::from_ref_time(278_633_204).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(23868).saturating_mul(n.into()))
+	}
+	/// Storage: DEX StablePairs (r:1 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX StablePools (r:1 w:1)
+	/// Proof: DEX StablePools (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	fn ramp_amplification() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1547`
+		//  Estimated: `5228`
+		// Minimum execution time: 53_041 nanoseconds.
+		Weight::from_parts(63_601_000, 5228)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DEX Exchanges (r:6 w:6)
+	/// Proof: DEX Exchanges (max_values: None, max_size: Some(248), added: 2723, mode: MaxEncodedLen)
+	/// Storage: DEX ConcentratedPools (r:6 w:0)
+	/// Proof: DEX ConcentratedPools (max_values: None, max_size: Some(4438), added: 6913, mode: MaxEncodedLen)
+	/// Storage: DEX StablePairs (r:6 w:0)
+	/// Proof: DEX StablePairs (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: DEX StablePools (r:1 w:1)
+	/// Proof: DEX StablePools (max_values: None, max_size: Some(262), added: 2737, mode: MaxEncodedLen)
+	/// Storage: DEX FlashSwapInProgress (r:1 w:0)
+	/// Proof: DEX FlashSwapInProgress (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: Contracts ContractInfoOf (r:2 w:2)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(258), added: 2733, mode: MaxEncodedLen)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System EventTopics (r:4 w:4)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFee (r:1 w:0)
+	/// Proof: DEX ProtocolFee (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DEX ProtocolFees (r:1 w:1)
+	/// Proof: DEX ProtocolFees (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: DEX Observations (r:6 w:6)
+	/// Proof: DEX Observations (max_values: None, max_size: Some(5198), added: 7673, mode: MaxEncodedLen)
+	/// Storage: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Proof Skipped: unknown `0x0c4a0ff07a659d16320aa8951f74be1051637c580c5eced0e1d4df5192075a92` (r:2 w:2)
+	/// Storage: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	/// Proof Skipped: unknown `0x425079f1621eb465cff4bc3e02a690148bd5c811a16949874da6df2339bbe55e` (r:2 w:2)
+	fn swap_stable() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7257`
+		//  Estimated: `303691`
+		// Minimum execution time: 548_903 nanoseconds.
+		Weight::from_parts(822_131_000, 303691)
+			.saturating_add(RocksDbWeight::get().reads(41_u64))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
+	}
+}
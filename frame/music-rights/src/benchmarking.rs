@@ -0,0 +1,166 @@
+//! Benchmarking setup for pallet-music-rights
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as MusicRights;
+
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Hash;
+
+const SEED: u32 = 0;
+
+fn metadata() -> WorkMetadata {
+	WorkMetadata {
+		kind: WorkKind::Track,
+		isrc: Some(*b"USRC17607839"),
+		iswc: Some(*b"T0345246801"),
+	}
+}
+
+/// A chain of `n` distinct holders, each passing half of their share on to the next one.
+fn chain<T: Config>(n: u32) -> SplitTreeOf<T> {
+	let nodes: Vec<_> = (0..n)
+		.map(|index| SplitNode {
+			holder: account("holder", index, SEED),
+			share: if index == 0 { Perbill::one() } else { Perbill::from_percent(50) },
+			parent: index.checked_sub(1),
+		})
+		.collect();
+	nodes.try_into().expect("n is at most MaxSplitNodes")
+}
+
+/// Registers an item of a new owner as a work with `splits`, edited by `editor`.
+fn register<T: Config>(
+	splits: SplitTreeOf<T>,
+	editor: SplitEditorOf<T>,
+) -> (T::CollectionId, T::ItemId) {
+	let owner: T::AccountId = account("owner", 0, SEED);
+	let (collection, item) = T::BenchmarkHelper::create_item(&owner, 0);
+	MusicRights::<T>::register_work(
+		RawOrigin::Signed(owner).into(),
+		collection,
+		item,
+		metadata(),
+		splits,
+		editor,
+	)
+	.expect("work registers");
+	(collection, item)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn register_work(n: Linear<1, 16>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (collection, item) = T::BenchmarkHelper::create_item(&caller, 0);
+		let splits = chain::<T>(n.min(T::MaxSplitNodes::get()));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			collection,
+			item,
+			metadata(),
+			splits,
+			SplitEditor::Holders { threshold: n },
+		);
+
+		assert!(Works::<T>::contains_key(collection, item));
+	}
+
+	#[benchmark]
+	fn set_metadata() {
+		let (collection, item) = register::<T>(
+			chain::<T>(T::MaxSplitNodes::get()),
+			SplitEditor::Holders { threshold: 1 },
+		);
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let release = WorkMetadata { kind: WorkKind::Release, isrc: None, iswc: None };
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), collection, item, release.clone());
+
+		assert_eq!(Works::<T>::get(collection, item).unwrap().metadata, release);
+	}
+
+	#[benchmark]
+	fn propose_splits(n: Linear<2, 16>) {
+		let (collection, item) = register::<T>(
+			chain::<T>(T::MaxSplitNodes::get()),
+			SplitEditor::Holders { threshold: 2 },
+		);
+		let proposer: T::AccountId = account("holder", 0, SEED);
+		let splits = chain::<T>(n.min(T::MaxSplitNodes::get()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(proposer), collection, item, splits);
+
+		assert!(SplitsProposals::<T>::contains_key(collection, item));
+	}
+
+	#[benchmark]
+	fn approve_splits(n: Linear<2, 16>) {
+		let n = n.min(T::MaxSplitNodes::get());
+		let splits = chain::<T>(n);
+		let (collection, item) =
+			register::<T>(splits.clone(), SplitEditor::Holders { threshold: n });
+		let proposal = T::Hashing::hash_of(&splits);
+		MusicRights::<T>::propose_splits(
+			RawOrigin::Signed(account("holder", 0, SEED)).into(),
+			collection,
+			item,
+			splits,
+		)
+		.expect("holder proposes");
+		for index in 1..n - 1 {
+			MusicRights::<T>::approve_splits(
+				RawOrigin::Signed(account("holder", index, SEED)).into(),
+				collection,
+				item,
+				proposal,
+			)
+			.expect("holder approves");
+		}
+		let approver: T::AccountId = account("holder", n - 1, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(approver), collection, item, proposal);
+
+		assert!(!SplitsProposals::<T>::contains_key(collection, item));
+	}
+
+	#[benchmark]
+	fn clear_work() {
+		let (collection, item) = register::<T>(
+			chain::<T>(T::MaxSplitNodes::get()),
+			SplitEditor::Holders { threshold: 2 },
+		);
+		MusicRights::<T>::propose_splits(
+			RawOrigin::Signed(account("holder", 0, SEED)).into(),
+			collection,
+			item,
+			chain::<T>(2),
+		)
+		.expect("holder proposes");
+		let (work, proposal) =
+			(Works::<T>::get(collection, item), SplitsProposals::<T>::get(collection, item));
+		T::BenchmarkHelper::destroy_item(&collection, &item);
+		// Burning the item may have removed the registration already.
+		Works::<T>::set(collection, item, work);
+		SplitsProposals::<T>::set(collection, item, proposal);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection, item);
+
+		assert!(!Works::<T>::contains_key(collection, item));
+	}
+
+	impl_benchmark_test_suite!(MusicRights, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,505 @@
+//! # Music Rights Pallet
+//!
+//! A registry of the rights to tracks and releases, each held as an item of a non-fungible token
+//! pallet such as `pallet_uniques`.
+//!
+//! The owner of an item registers it as a work with its ISRC and ISWC codes and a split tree of
+//! the holders of its rights. Every node of the tree holds a `Perbill` share of the rights of its
+//! parent, so rights can be passed on down a chain of holders, e.g. from a label to an artist to
+//! the featured artists of a track. [`WorkRights`] exposes the resulting share of every holder to
+//! the pallets paying royalties.
+//!
+//! The split tree can only be edited by the [`SplitEditor`] picked at registration: either a
+//! single account, typically a multisig, or a threshold of the holders approving a proposed tree.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `register_work` - Registers an item of the caller as a work.
+//! * `set_metadata` - Updates the codes of a work of the caller.
+//! * `propose_splits` - Replaces the split tree of a work, or proposes to its holders to.
+//! * `approve_splits` - Approves the proposed split tree of a work, as one of its holders.
+//! * `clear_work` - Removes the registration of a work whose item was destroyed.
+//!
+//! Registrations should be removed as their item is burned, so that an item minted again under
+//! the same id does not inherit the split tree of the burned one: the runtime calls
+//! [`Pallet::do_clear_work`] when an item is burned, e.g. from `pallet_uniques::Config::OnBurn`.
+//! `clear_work` lets anyone remove those left over otherwise.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub mod weights;
+
+pub use pallet::*;
+pub use types::{
+	Isrc, Iswc, SplitEditor, SplitNode, SplitTree, SplitsProposal, Work, WorkKind, WorkMetadata,
+};
+pub use weights::WeightInfo;
+
+use frame_support::traits::tokens::nonfungibles;
+use sp_runtime::{PerThing, Perbill};
+use sp_std::prelude::*;
+
+type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+/// Split tree of a work.
+pub type SplitTreeOf<T> = SplitTree<AccountIdOf<T>, <T as Config>::MaxSplitNodes>;
+/// Editor of the split tree of a work.
+pub type SplitEditorOf<T> = SplitEditor<AccountIdOf<T>>;
+/// A registered work.
+pub type WorkOf<T> = Work<AccountIdOf<T>, <T as Config>::MaxSplitNodes>;
+/// An update of a split tree waiting for approvals.
+pub type SplitsProposalOf<T> =
+	SplitsProposal<AccountIdOf<T>, <T as frame_system::Config>::Hash, <T as Config>::MaxSplitNodes>;
+
+/// Rights holders of registered works, for the pallets paying royalties to them.
+pub trait WorkRights<AccountId, CollectionId, ItemId> {
+	/// Returns the holders of the work held as `item` of `collection` with their share of it, or
+	/// `None` if the item is not a registered work.
+	///
+	/// Every holder appears once and the shares add up to exactly 100%.
+	fn holder_shares(collection: &CollectionId, item: &ItemId)
+		-> Option<Vec<(AccountId, Perbill)>>;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Hash;
+
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<AccountId, CollectionId, ItemId> {
+		/// Creates an item owned by `owner`, distinct for every `seed`.
+		fn create_item(owner: &AccountId, seed: u32) -> (CollectionId, ItemId);
+		/// Destroys an item created by `create_item`.
+		fn destroy_item(collection: &CollectionId, item: &ItemId);
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier of a collection of items.
+		type CollectionId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// Identifier of an item within its collection.
+		type ItemId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// The items works are held as, e.g. `pallet_uniques`.
+		type Items: nonfungibles::Inspect<
+			Self::AccountId,
+			CollectionId = Self::CollectionId,
+			ItemId = Self::ItemId,
+		>;
+
+		/// Maximum number of nodes of a split tree.
+		#[pallet::constant]
+		type MaxSplitNodes: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Creates the items registered by the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::CollectionId, Self::ItemId>;
+	}
+
+	/// Registered works, by the item they are held as.
+	#[pallet::storage]
+	#[pallet::getter(fn works)]
+	pub type Works<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		WorkOf<T>,
+		OptionQuery,
+	>;
+
+	/// Split trees proposed to the holders of works edited by them.
+	#[pallet::storage]
+	#[pallet::getter(fn splits_proposals)]
+	pub type SplitsProposals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		SplitsProposalOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An item was registered as a work.
+		WorkRegistered { collection: T::CollectionId, item: T::ItemId, owner: T::AccountId },
+		/// The codes of a work were updated.
+		MetadataSet { collection: T::CollectionId, item: T::ItemId, metadata: WorkMetadata },
+		/// A split tree was proposed to the holders of a work.
+		SplitsProposed {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			proposal: T::Hash,
+			proposer: T::AccountId,
+		},
+		/// A holder of a work approved the split tree proposed for it.
+		SplitsApproved {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			proposal: T::Hash,
+			holder: T::AccountId,
+		},
+		/// The split tree of a work was replaced.
+		SplitsUpdated { collection: T::CollectionId, item: T::ItemId },
+		/// The registration of a work whose item was destroyed was removed.
+		WorkCleared { collection: T::CollectionId, item: T::ItemId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The item does not exist.
+		UnknownItem,
+		/// The item is not a registered work.
+		UnknownWork,
+		/// The item is already registered as a work.
+		AlreadyRegistered,
+		/// The caller may not do this to the work.
+		NoPermission,
+		/// The ISRC or ISWC of the work is malformed.
+		InvalidMetadata,
+		/// A split tree has no nodes.
+		EmptySplits,
+		/// A node of a split tree has a zero share.
+		ZeroShare,
+		/// The parent of a node of a split tree does not come before it.
+		InvalidParent,
+		/// The shares of the roots of a split tree do not add up to 100%.
+		IncompleteShares,
+		/// The shares of the children of a node of a split tree add up to more than 100%.
+		SharesExceedParent,
+		/// The approval threshold of the holders is zero or more than the number of holders.
+		InvalidThreshold,
+		/// The work has no split tree waiting for approvals, or not the one approved.
+		UnknownProposal,
+		/// The holder already approved the proposed split tree.
+		AlreadyApproved,
+		/// The item of the work still exists.
+		ItemExists,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Registers `item` of `collection`, owned by the caller, as a work with the codes in
+		/// `metadata` and the holders in `splits`, edited by `editor`.
+		///
+		/// Emits `WorkRegistered`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register_work(splits.len() as u32))]
+		pub fn register_work(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			metadata: WorkMetadata,
+			splits: SplitTreeOf<T>,
+			editor: SplitEditorOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_owner(&who, &collection, &item)?;
+			ensure!(!Works::<T>::contains_key(collection, item), Error::<T>::AlreadyRegistered);
+			ensure!(metadata.is_valid(), Error::<T>::InvalidMetadata);
+			Self::ensure_valid_splits(&splits, &editor)?;
+
+			Works::<T>::insert(collection, item, Work { metadata, splits, editor });
+			Self::deposit_event(Event::WorkRegistered { collection, item, owner: who });
+			Ok(())
+		}
+
+		/// Replaces the codes of the work held as `item` of `collection`, owned by the caller.
+		///
+		/// Emits `MetadataSet`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_metadata())]
+		pub fn set_metadata(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			metadata: WorkMetadata,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_owner(&who, &collection, &item)?;
+			ensure!(metadata.is_valid(), Error::<T>::InvalidMetadata);
+
+			Works::<T>::try_mutate(collection, item, |work| -> DispatchResult {
+				let work = work.as_mut().ok_or(Error::<T>::UnknownWork)?;
+				work.metadata = metadata.clone();
+				Ok(())
+			})?;
+			Self::deposit_event(Event::MetadataSet { collection, item, metadata });
+			Ok(())
+		}
+
+		/// Replaces the split tree of the work held as `item` of `collection` with `splits`.
+		///
+		/// If the work is edited by an account, the caller must be that account and the tree is
+		/// replaced right away. If it is edited by its holders, the caller must be one of them and
+		/// the tree is proposed to the others, with the approval of the caller. The proposal
+		/// replaces any earlier one.
+		///
+		/// Emits `SplitsUpdated`, or `SplitsProposed` and `SplitsApproved`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::propose_splits(splits.len() as u32))]
+		pub fn propose_splits(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			splits: SplitTreeOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let work = Works::<T>::get(collection, item).ok_or(Error::<T>::UnknownWork)?;
+			Self::ensure_valid_splits(&splits, &work.editor)?;
+
+			match work.editor {
+				SplitEditor::Account(editor) => {
+					ensure!(who == editor, Error::<T>::NoPermission);
+					Self::update_splits(collection, item, splits);
+				},
+				SplitEditor::Holders { .. } => {
+					ensure!(Self::is_holder(&work.splits, &who), Error::<T>::NoPermission);
+					let hash = T::Hashing::hash_of(&splits);
+					let proposal = SplitsProposal { hash, splits, approvals: Default::default() };
+					Self::deposit_event(Event::SplitsProposed {
+						collection,
+						item,
+						proposal: hash,
+						proposer: who.clone(),
+					});
+					Self::approve(collection, item, work, proposal, who)?;
+				},
+			}
+			Ok(())
+		}
+
+		/// Approves the split tree with hash `proposal` proposed for the work held as `item` of
+		/// `collection`, as one of its holders. The tree replaces the current one once enough
+		/// holders approved it.
+		///
+		/// Emits `SplitsApproved`, and `SplitsUpdated` if the threshold is reached.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::approve_splits(T::MaxSplitNodes::get()))]
+		pub fn approve_splits(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			proposal: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let work = Works::<T>::get(collection, item).ok_or(Error::<T>::UnknownWork)?;
+			let pending = SplitsProposals::<T>::get(collection, item)
+				.filter(|pending| pending.hash == proposal)
+				.ok_or(Error::<T>::UnknownProposal)?;
+			ensure!(Self::is_holder(&work.splits, &who), Error::<T>::NoPermission);
+			ensure!(!pending.approvals.contains(&who), Error::<T>::AlreadyApproved);
+
+			Self::approve(collection, item, work, pending, who)
+		}
+
+		/// Removes the registration of the work held as `item` of `collection` once the item no
+		/// longer exists, along with any split tree proposed for it.
+		///
+		/// Emits `WorkCleared`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::clear_work())]
+		pub fn clear_work(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(Works::<T>::contains_key(collection, item), Error::<T>::UnknownWork);
+			ensure!(
+				<T::Items as nonfungibles::Inspect<_>>::owner(&collection, &item).is_none(),
+				Error::<T>::ItemExists
+			);
+
+			Self::do_clear_work(collection, item);
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Removes the registration of the work held as `item` of `collection`, if it is one,
+		/// along with any split tree proposed for it. To be called as the item is burned.
+		///
+		/// Emits `WorkCleared` if the item was a work.
+		pub fn do_clear_work(collection: T::CollectionId, item: T::ItemId) {
+			if Works::<T>::take(collection, item).is_some() {
+				SplitsProposals::<T>::remove(collection, item);
+				Self::deposit_event(Event::WorkCleared { collection, item });
+			}
+		}
+
+		fn ensure_owner(
+			who: &T::AccountId,
+			collection: &T::CollectionId,
+			item: &T::ItemId,
+		) -> DispatchResult {
+			let owner = <T::Items as nonfungibles::Inspect<_>>::owner(collection, item)
+				.ok_or(Error::<T>::UnknownItem)?;
+			ensure!(&owner == who, Error::<T>::NoPermission);
+			Ok(())
+		}
+
+		/// Fails unless every node of `splits` has a nonzero share of a parent coming before it,
+		/// the roots share 100% of the work, no node passes on more than 100% of its share and
+		/// `editor` is a threshold its holders can reach.
+		pub(crate) fn ensure_valid_splits(
+			splits: &SplitTreeOf<T>,
+			editor: &SplitEditorOf<T>,
+		) -> DispatchResult {
+			ensure!(!splits.is_empty(), Error::<T>::EmptySplits);
+			// Shares passed on by every node, followed by the shares of the roots.
+			let mut passed_on = sp_std::vec![0u64; splits.len() + 1];
+			for (index, node) in splits.iter().enumerate() {
+				ensure!(!node.share.is_zero(), Error::<T>::ZeroShare);
+				let parent = match node.parent {
+					Some(parent) => {
+						ensure!((parent as usize) < index, Error::<T>::InvalidParent);
+						parent as usize
+					},
+					None => splits.len(),
+				};
+				passed_on[parent] += u64::from(node.share.deconstruct());
+				ensure!(
+					passed_on[parent] <= u64::from(Perbill::ACCURACY),
+					Error::<T>::SharesExceedParent
+				);
+			}
+			ensure!(
+				passed_on[splits.len()] == u64::from(Perbill::ACCURACY),
+				Error::<T>::IncompleteShares
+			);
+
+			if let SplitEditor::Holders { threshold } = editor {
+				ensure!(
+					*threshold > 0 && *threshold as usize <= Self::holders(splits).len(),
+					Error::<T>::InvalidThreshold
+				);
+			}
+			Ok(())
+		}
+
+		fn is_holder(splits: &SplitTreeOf<T>, who: &T::AccountId) -> bool {
+			splits.iter().any(|node| &node.holder == who)
+		}
+
+		/// Distinct holders of `splits`.
+		fn holders(splits: &SplitTreeOf<T>) -> Vec<T::AccountId> {
+			let mut holders: Vec<_> = splits.iter().map(|node| node.holder.clone()).collect();
+			holders.sort();
+			holders.dedup();
+			holders
+		}
+
+		/// Adds the approval of `holder` to `proposal`, and replaces the split tree of the work
+		/// with it if it reached the threshold or stores it otherwise.
+		fn approve(
+			collection: T::CollectionId,
+			item: T::ItemId,
+			work: WorkOf<T>,
+			mut proposal: SplitsProposalOf<T>,
+			holder: T::AccountId,
+		) -> DispatchResult {
+			let threshold = match work.editor {
+				SplitEditor::Holders { threshold } => threshold,
+				SplitEditor::Account(_) => return Err(Error::<T>::UnknownProposal.into()),
+			};
+			// Every approval is from a distinct holder, so there are at most as many as nodes.
+			proposal
+				.approvals
+				.try_push(holder.clone())
+				.map_err(|_| Error::<T>::AlreadyApproved)?;
+			Self::deposit_event(Event::SplitsApproved {
+				collection,
+				item,
+				proposal: proposal.hash,
+				holder,
+			});
+
+			if proposal.approvals.len() as u32 >= threshold {
+				SplitsProposals::<T>::remove(collection, item);
+				Self::update_splits(collection, item, proposal.splits);
+			} else {
+				SplitsProposals::<T>::insert(collection, item, proposal);
+			}
+			Ok(())
+		}
+
+		fn update_splits(collection: T::CollectionId, item: T::ItemId, splits: SplitTreeOf<T>) {
+			Works::<T>::mutate(collection, item, |work| {
+				if let Some(work) = work {
+					work.splits = splits;
+				}
+			});
+			Self::deposit_event(Event::SplitsUpdated { collection, item });
+		}
+
+		/// Share of the whole work of every holder of `splits`, in the order they first appear.
+		///
+		/// The share of a node is rounded down and the remainder kept by its parent, so the shares
+		/// add up to exactly 100%.
+		pub fn shares_of(splits: &SplitTreeOf<T>) -> Vec<(T::AccountId, Perbill)> {
+			// Parts of the work held through every node, and kept by it after its children.
+			let mut held = Vec::with_capacity(splits.len());
+			let mut kept = Vec::with_capacity(splits.len());
+			for node in splits.iter() {
+				let parts = match node.parent {
+					Some(parent) => {
+						let parts = node.share.mul_floor(held[parent as usize]);
+						kept[parent as usize] -= parts;
+						parts
+					},
+					None => node.share.deconstruct(),
+				};
+				held.push(parts);
+				kept.push(parts);
+			}
+
+			let mut shares: Vec<(T::AccountId, u32)> = Vec::new();
+			for (node, parts) in splits.iter().zip(kept) {
+				match shares.iter_mut().find(|(holder, _)| holder == &node.holder) {
+					Some((_, total)) => *total += parts,
+					None => shares.push((node.holder.clone(), parts)),
+				}
+			}
+			shares
+				.into_iter()
+				.map(|(holder, parts)| (holder, Perbill::from_parts(parts)))
+				.collect()
+		}
+	}
+}
+
+impl<T: Config> WorkRights<AccountIdOf<T>, T::CollectionId, T::ItemId> for Pallet<T> {
+	fn holder_shares(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> Option<Vec<(AccountIdOf<T>, Perbill)>> {
+		Works::<T>::get(collection, item).map(|work| Self::shares_of(&work.splits))
+	}
+}
@@ -0,0 +1,148 @@
+//! Test environment for the music rights pallet.
+
+use super::*;
+use crate as pallet_music_rights;
+
+use frame_support::{
+	assert_ok, construct_runtime, parameter_types,
+	traits::{tokens::nonfungibles::Mutate, AsEnsureOriginWithArg, ConstU32, ConstU64},
+};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Uniques: pallet_uniques,
+		MusicRights: pallet_music_rights,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+}
+
+parameter_types! {
+	pub const UniquesMaxRoyalty: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(50);
+	/// Whether burning an item removes its registration, as the runtime wires it.
+	pub static ClearWorksOnBurn: bool = true;
+}
+
+/// Removes the registration of burned items while `ClearWorksOnBurn` is set.
+pub struct ClearWorks;
+impl pallet_uniques::OnBurn<u32, u32> for ClearWorks {
+	fn on_burn(collection: &u32, item: &u32) {
+		if ClearWorksOnBurn::get() {
+			MusicRights::do_clear_work(*collection, *item);
+		}
+	}
+}
+
+impl pallet_uniques::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type Locker = ();
+	type OnBurn = ClearWorks;
+	type CollectionDeposit = ConstU64<2>;
+	type ItemDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type AttributeDepositBase = ConstU64<1>;
+	type DepositPerByte = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type KeyLimit = ConstU32<50>;
+	type ValueLimit = ConstU32<50>;
+	type MaxRoyalty = UniquesMaxRoyalty;
+	type MaxOffers = ConstU32<10>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Items = Uniques;
+	type MaxSplitNodes = ConstU32<16>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = UniquesHelper;
+}
+
+/// Creates item 0 of a free holding collection `collection` owned by `owner`.
+pub(crate) fn create_item(owner: u64, collection: u32) {
+	assert_ok!(Uniques::force_create(RuntimeOrigin::root(), collection, owner, true));
+	assert_ok!(Uniques::mint_into(&collection, &0, &owner));
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct UniquesHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<u64, u32, u32> for UniquesHelper {
+	fn create_item(owner: &u64, seed: u32) -> (u32, u32) {
+		create_item(*owner, seed);
+		(seed, 0)
+	}
+
+	fn destroy_item(collection: &u32, item: &u32) {
+		assert_ok!(<Uniques as Mutate<u64>>::burn(collection, item, None));
+	}
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
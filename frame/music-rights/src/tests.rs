@@ -0,0 +1,428 @@
+//! Tests for the music rights pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+const OWNER: u64 = 10;
+const EDITOR: u64 = 99;
+const LABEL: u64 = 1;
+const ARTIST: u64 = 2;
+const FEATURED: u64 = 3;
+const PRODUCER: u64 = 4;
+
+fn node(holder: u64, percent: u32, parent: Option<u32>) -> SplitNode<u64> {
+	SplitNode { holder, share: Perbill::from_percent(percent), parent }
+}
+
+fn tree(nodes: Vec<SplitNode<u64>>) -> SplitTreeOf<Test> {
+	SplitTreeOf::<Test>::try_from(nodes).unwrap()
+}
+
+/// The label passes 60% on to the artist, who passes 20% on to the featured artist and 10% on to
+/// the producer.
+fn label_tree() -> SplitTreeOf<Test> {
+	tree(vec![
+		node(LABEL, 100, None),
+		node(ARTIST, 60, Some(0)),
+		node(FEATURED, 20, Some(1)),
+		node(PRODUCER, 10, Some(1)),
+	])
+}
+
+fn metadata() -> WorkMetadata {
+	WorkMetadata {
+		kind: WorkKind::Track,
+		isrc: Some(*b"USRC17607839"),
+		iswc: Some(*b"T0345246801"),
+	}
+}
+
+/// Creates item 0 of collection 0 owned by `OWNER` and registers it with `splits` and `editor`.
+fn register(splits: SplitTreeOf<Test>, editor: SplitEditorOf<Test>) {
+	create_item(OWNER, 0);
+	assert_ok!(MusicRights::register_work(
+		RuntimeOrigin::signed(OWNER),
+		0,
+		0,
+		metadata(),
+		splits,
+		editor
+	));
+}
+
+#[test]
+fn register_work_works() {
+	new_test_ext().execute_with(|| {
+		register(label_tree(), SplitEditor::Account(EDITOR));
+
+		assert_eq!(
+			MusicRights::works(0, 0),
+			Some(Work {
+				metadata: metadata(),
+				splits: label_tree(),
+				editor: SplitEditor::Account(EDITOR)
+			})
+		);
+		System::assert_last_event(
+			Event::WorkRegistered { collection: 0, item: 0, owner: OWNER }.into(),
+		);
+		assert_eq!(
+			MusicRights::holder_shares(&0, &0),
+			Some(vec![
+				(LABEL, Perbill::from_percent(40)),
+				(ARTIST, Perbill::from_percent(42)),
+				(FEATURED, Perbill::from_percent(12)),
+				(PRODUCER, Perbill::from_percent(6)),
+			])
+		);
+		assert_eq!(MusicRights::holder_shares(&0, &1), None);
+	});
+}
+
+#[test]
+fn register_work_fails_for_items_of_others_and_registered_works() {
+	new_test_ext().execute_with(|| {
+		let editor = SplitEditor::Account(EDITOR);
+		assert_noop!(
+			MusicRights::register_work(
+				RuntimeOrigin::signed(OWNER),
+				0,
+				0,
+				metadata(),
+				label_tree(),
+				editor.clone()
+			),
+			Error::<Test>::UnknownItem
+		);
+		create_item(OWNER, 0);
+		assert_noop!(
+			MusicRights::register_work(
+				RuntimeOrigin::signed(LABEL),
+				0,
+				0,
+				metadata(),
+				label_tree(),
+				editor.clone()
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(MusicRights::register_work(
+			RuntimeOrigin::signed(OWNER),
+			0,
+			0,
+			metadata(),
+			label_tree(),
+			editor.clone()
+		));
+		assert_noop!(
+			MusicRights::register_work(
+				RuntimeOrigin::signed(OWNER),
+				0,
+				0,
+				metadata(),
+				label_tree(),
+				editor
+			),
+			Error::<Test>::AlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn register_work_fails_with_malformed_codes() {
+	new_test_ext().execute_with(|| {
+		create_item(OWNER, 0);
+		let register = |metadata| {
+			MusicRights::register_work(
+				RuntimeOrigin::signed(OWNER),
+				0,
+				0,
+				metadata,
+				label_tree(),
+				SplitEditor::Account(EDITOR),
+			)
+		};
+
+		let lowercase_country = WorkMetadata { isrc: Some(*b"usRC17607839"), ..metadata() };
+		assert_noop!(register(lowercase_country), Error::<Test>::InvalidMetadata);
+		let letter_in_designation = WorkMetadata { isrc: Some(*b"USRC1760783X"), ..metadata() };
+		assert_noop!(register(letter_in_designation), Error::<Test>::InvalidMetadata);
+		let wrong_check_digit = WorkMetadata { iswc: Some(*b"T0345246802"), ..metadata() };
+		assert_noop!(register(wrong_check_digit), Error::<Test>::InvalidMetadata);
+		let no_prefix = WorkMetadata { iswc: Some(*b"00345246801"), ..metadata() };
+		assert_noop!(register(no_prefix), Error::<Test>::InvalidMetadata);
+
+		let release = WorkMetadata { kind: WorkKind::Release, isrc: None, iswc: None };
+		assert_ok!(register(release));
+	});
+}
+
+#[test]
+fn split_trees_are_validated() {
+	new_test_ext().execute_with(|| {
+		create_item(OWNER, 0);
+		let register = |nodes, editor| {
+			MusicRights::register_work(
+				RuntimeOrigin::signed(OWNER),
+				0,
+				0,
+				metadata(),
+				tree(nodes),
+				editor,
+			)
+		};
+		let editor = SplitEditor::Account(EDITOR);
+
+		assert_noop!(register(vec![], editor.clone()), Error::<Test>::EmptySplits);
+		assert_noop!(
+			register(vec![node(LABEL, 100, None), node(ARTIST, 0, Some(0))], editor.clone()),
+			Error::<Test>::ZeroShare
+		);
+		assert_noop!(
+			register(vec![node(LABEL, 100, Some(1)), node(ARTIST, 50, None)], editor.clone()),
+			Error::<Test>::InvalidParent
+		);
+		assert_noop!(
+			register(vec![node(LABEL, 100, Some(0))], editor.clone()),
+			Error::<Test>::InvalidParent
+		);
+		assert_noop!(
+			register(vec![node(LABEL, 50, None), node(ARTIST, 40, None)], editor.clone()),
+			Error::<Test>::IncompleteShares
+		);
+		assert_noop!(
+			register(
+				vec![
+					node(LABEL, 100, None),
+					node(ARTIST, 60, Some(0)),
+					node(FEATURED, 50, Some(0))
+				],
+				editor
+			),
+			Error::<Test>::SharesExceedParent
+		);
+		assert_noop!(
+			register(label_tree().into_inner(), SplitEditor::Holders { threshold: 0 }),
+			Error::<Test>::InvalidThreshold
+		);
+		assert_noop!(
+			register(label_tree().into_inner(), SplitEditor::Holders { threshold: 5 }),
+			Error::<Test>::InvalidThreshold
+		);
+	});
+}
+
+#[test]
+fn holder_shares_add_up_to_the_whole_work() {
+	new_test_ext().execute_with(|| {
+		// The artist is paid through two nodes, and the label keeps the rounding of thirds.
+		let third = Perbill::from_rational(1u32, 3);
+		let splits = tree(vec![
+			node(LABEL, 100, None),
+			SplitNode { holder: ARTIST, share: third, parent: Some(0) },
+			SplitNode { holder: FEATURED, share: third, parent: Some(0) },
+			SplitNode { holder: ARTIST, share: third, parent: Some(0) },
+		]);
+		register(splits, SplitEditor::Account(EDITOR));
+
+		assert_eq!(
+			MusicRights::holder_shares(&0, &0),
+			Some(vec![
+				(LABEL, Perbill::from_parts(1)),
+				(ARTIST, Perbill::from_parts(666_666_666)),
+				(FEATURED, Perbill::from_parts(333_333_333)),
+			])
+		);
+	});
+}
+
+#[test]
+fn account_editor_replaces_splits() {
+	new_test_ext().execute_with(|| {
+		register(label_tree(), SplitEditor::Account(EDITOR));
+		let splits = tree(vec![node(LABEL, 50, None), node(ARTIST, 50, None)]);
+
+		assert_noop!(
+			MusicRights::propose_splits(RuntimeOrigin::signed(LABEL), 0, 0, splits.clone()),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(MusicRights::propose_splits(
+			RuntimeOrigin::signed(EDITOR),
+			0,
+			0,
+			splits.clone()
+		));
+		assert_eq!(MusicRights::works(0, 0).unwrap().splits, splits);
+		assert_eq!(MusicRights::splits_proposals(0, 0), None);
+		System::assert_last_event(Event::SplitsUpdated { collection: 0, item: 0 }.into());
+		assert_noop!(
+			MusicRights::propose_splits(RuntimeOrigin::signed(EDITOR), 0, 1, splits),
+			Error::<Test>::UnknownWork
+		);
+	});
+}
+
+#[test]
+fn holders_replace_splits_once_the_threshold_approved() {
+	new_test_ext().execute_with(|| {
+		register(label_tree(), SplitEditor::Holders { threshold: 2 });
+		let splits = tree(vec![node(LABEL, 50, None), node(ARTIST, 50, None)]);
+		let hash = BlakeTwo256::hash_of(&splits);
+
+		assert_noop!(
+			MusicRights::propose_splits(RuntimeOrigin::signed(OWNER), 0, 0, splits.clone()),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(MusicRights::propose_splits(
+			RuntimeOrigin::signed(FEATURED),
+			0,
+			0,
+			splits.clone()
+		));
+		System::assert_has_event(
+			Event::SplitsProposed { collection: 0, item: 0, proposal: hash, proposer: FEATURED }
+				.into(),
+		);
+		assert_eq!(MusicRights::works(0, 0).unwrap().splits, label_tree());
+		assert_eq!(
+			MusicRights::splits_proposals(0, 0).map(|proposal| proposal.approvals.into_inner()),
+			Some(vec![FEATURED])
+		);
+
+		assert_noop!(
+			MusicRights::approve_splits(RuntimeOrigin::signed(FEATURED), 0, 0, hash),
+			Error::<Test>::AlreadyApproved
+		);
+		assert_noop!(
+			MusicRights::approve_splits(RuntimeOrigin::signed(OWNER), 0, 0, hash),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			MusicRights::approve_splits(RuntimeOrigin::signed(LABEL), 0, 0, Default::default()),
+			Error::<Test>::UnknownProposal
+		);
+
+		assert_ok!(MusicRights::approve_splits(RuntimeOrigin::signed(LABEL), 0, 0, hash));
+		System::assert_has_event(
+			Event::SplitsApproved { collection: 0, item: 0, proposal: hash, holder: LABEL }.into(),
+		);
+		System::assert_last_event(Event::SplitsUpdated { collection: 0, item: 0 }.into());
+		assert_eq!(MusicRights::works(0, 0).unwrap().splits, splits);
+		assert_eq!(MusicRights::splits_proposals(0, 0), None);
+		// The producer is no longer a holder.
+		assert_noop!(
+			MusicRights::propose_splits(RuntimeOrigin::signed(PRODUCER), 0, 0, label_tree()),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn new_proposal_replaces_the_pending_one() {
+	new_test_ext().execute_with(|| {
+		register(label_tree(), SplitEditor::Holders { threshold: 3 });
+		let first =
+			tree(vec![node(LABEL, 50, None), node(ARTIST, 30, None), node(FEATURED, 20, None)]);
+		let second =
+			tree(vec![node(LABEL, 30, None), node(ARTIST, 50, None), node(FEATURED, 20, None)]);
+
+		assert_ok!(MusicRights::propose_splits(RuntimeOrigin::signed(LABEL), 0, 0, first.clone()));
+		assert_ok!(MusicRights::approve_splits(
+			RuntimeOrigin::signed(FEATURED),
+			0,
+			0,
+			BlakeTwo256::hash_of(&first)
+		));
+		assert_ok!(MusicRights::propose_splits(RuntimeOrigin::signed(ARTIST), 0, 0, second));
+
+		assert_eq!(
+			MusicRights::splits_proposals(0, 0).map(|proposal| proposal.approvals.into_inner()),
+			Some(vec![ARTIST])
+		);
+		assert_noop!(
+			MusicRights::approve_splits(
+				RuntimeOrigin::signed(PRODUCER),
+				0,
+				0,
+				BlakeTwo256::hash_of(&first)
+			),
+			Error::<Test>::UnknownProposal
+		);
+		// A tree with fewer holders than the threshold can't be proposed.
+		let two_holders = tree(vec![node(LABEL, 50, None), node(ARTIST, 50, None)]);
+		assert_noop!(
+			MusicRights::propose_splits(RuntimeOrigin::signed(LABEL), 0, 0, two_holders),
+			Error::<Test>::InvalidThreshold
+		);
+	});
+}
+
+#[test]
+fn set_metadata_works() {
+	new_test_ext().execute_with(|| {
+		register(label_tree(), SplitEditor::Account(EDITOR));
+		let release = WorkMetadata { kind: WorkKind::Release, isrc: None, iswc: None };
+
+		assert_noop!(
+			MusicRights::set_metadata(RuntimeOrigin::signed(EDITOR), 0, 0, release.clone()),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(MusicRights::set_metadata(RuntimeOrigin::signed(OWNER), 0, 0, release.clone()));
+		assert_eq!(MusicRights::works(0, 0).unwrap().metadata, release);
+		System::assert_last_event(
+			Event::MetadataSet { collection: 0, item: 0, metadata: release }.into(),
+		);
+	});
+}
+
+#[test]
+fn burning_an_item_clears_its_work() {
+	new_test_ext().execute_with(|| {
+		register(label_tree(), SplitEditor::Holders { threshold: 2 });
+		assert_ok!(MusicRights::propose_splits(RuntimeOrigin::signed(LABEL), 0, 0, label_tree()));
+
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(OWNER), 0, 0, None));
+		assert_eq!(MusicRights::works(0, 0), None);
+		assert_eq!(MusicRights::splits_proposals(0, 0), None);
+		System::assert_has_event(Event::WorkCleared { collection: 0, item: 0 }.into());
+
+		// An item minted again under the same id starts unregistered.
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(OWNER), 0, 0, EDITOR));
+		assert_eq!(MusicRights::holder_shares(&0, &0), None);
+		assert_ok!(MusicRights::register_work(
+			RuntimeOrigin::signed(EDITOR),
+			0,
+			0,
+			metadata(),
+			label_tree(),
+			SplitEditor::Account(EDITOR),
+		));
+	});
+}
+
+#[test]
+fn clear_work_requires_the_item_to_be_destroyed() {
+	new_test_ext().execute_with(|| {
+		// Works whose item was burned without notifying the pallet.
+		ClearWorksOnBurn::set(false);
+		register(label_tree(), SplitEditor::Holders { threshold: 2 });
+		assert_ok!(MusicRights::propose_splits(RuntimeOrigin::signed(LABEL), 0, 0, label_tree()));
+
+		assert_noop!(
+			MusicRights::clear_work(RuntimeOrigin::signed(EDITOR), 0, 0),
+			Error::<Test>::ItemExists
+		);
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(OWNER), 0, 0, None));
+		assert_ok!(MusicRights::clear_work(RuntimeOrigin::signed(EDITOR), 0, 0));
+
+		assert_eq!(MusicRights::works(0, 0), None);
+		assert_eq!(MusicRights::splits_proposals(0, 0), None);
+		System::assert_last_event(Event::WorkCleared { collection: 0, item: 0 }.into());
+		assert_noop!(
+			MusicRights::clear_work(RuntimeOrigin::signed(EDITOR), 0, 0),
+			Error::<Test>::UnknownWork
+		);
+	});
+}
@@ -0,0 +1,138 @@
+//! Types of the works and split trees of the pallet.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use sp_runtime::Perbill;
+use sp_std::fmt::Debug;
+
+/// International Standard Recording Code, without separators, e.g. `USRC17607839`.
+pub type Isrc = [u8; 12];
+
+/// International Standard Musical Work Code, without separators, e.g. `T0345246801`.
+pub type Iswc = [u8; 11];
+
+/// What a registered item stands for.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum WorkKind {
+	/// A single recording.
+	Track,
+	/// A collection of recordings released together, e.g. an album or an EP.
+	Release,
+}
+
+/// Identifiers of a work in the registries of the music industry.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct WorkMetadata {
+	/// What the item stands for.
+	pub kind: WorkKind,
+	/// Code of the recording, if it has one.
+	pub isrc: Option<Isrc>,
+	/// Code of the underlying composition, if it has one.
+	pub iswc: Option<Iswc>,
+}
+
+impl WorkMetadata {
+	/// Whether the codes are well formed. The ISWC check digit is verified.
+	pub fn is_valid(&self) -> bool {
+		self.isrc.as_ref().is_none_or(is_valid_isrc) && self.iswc.as_ref().is_none_or(is_valid_iswc)
+	}
+}
+
+/// An ISRC is a country code of two letters, a registrant code of three letters or digits, a year
+/// of two digits and a designation code of five digits.
+fn is_valid_isrc(isrc: &Isrc) -> bool {
+	isrc[..2].iter().all(u8::is_ascii_uppercase) &&
+		isrc[2..5].iter().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit()) &&
+		isrc[5..].iter().all(u8::is_ascii_digit)
+}
+
+/// An ISWC is a `T` followed by nine digits and a check digit, which makes the weighted sum of the
+/// digits a multiple of ten.
+fn is_valid_iswc(iswc: &Iswc) -> bool {
+	if iswc[0] != b'T' || !iswc[1..].iter().all(u8::is_ascii_digit) {
+		return false
+	}
+	let sum = iswc[1..10]
+		.iter()
+		.enumerate()
+		.fold(1, |sum, (index, digit)| sum + (index as u32 + 1) * u32::from(digit - b'0'));
+	u32::from(iswc[10] - b'0') == (10 - sum % 10) % 10
+}
+
+/// Holder of a share of the rights to a work.
+///
+/// The share is of the rights of the parent node, or of the whole work for root nodes. A node
+/// keeps what its children don't take, e.g. a label passing 60% of its rights on to an artist who
+/// passes 20% of theirs on to a featured artist.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct SplitNode<AccountId> {
+	/// Account the share is paid to.
+	pub holder: AccountId,
+	/// Share of the rights of the parent node.
+	pub share: Perbill,
+	/// Index of the parent node in the tree, which comes before this one, or `None` for a root.
+	pub parent: Option<u32>,
+}
+
+/// Nodes of a split tree, parents first.
+pub type SplitTree<AccountId, MaxNodes> = BoundedVec<SplitNode<AccountId>, MaxNodes>;
+
+/// Who may edit the split tree of a work.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum SplitEditor<AccountId> {
+	/// A single account, typically a multisig, updates the splits directly.
+	Account(AccountId),
+	/// Updates are proposed by a holder and applied once `threshold` distinct holders approved
+	/// them.
+	Holders { threshold: u32 },
+}
+
+/// A registered work.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxNodes))]
+#[codec(mel_bound(AccountId: MaxEncodedLen))]
+pub struct Work<AccountId: Clone + Eq + Debug, MaxNodes: Get<u32>> {
+	/// Identifiers of the work.
+	pub metadata: WorkMetadata,
+	/// Holders of the rights to the work.
+	pub splits: SplitTree<AccountId, MaxNodes>,
+	/// Who may edit `splits`.
+	pub editor: SplitEditor<AccountId>,
+}
+
+/// An update of the split tree of a work waiting for the approval of its holders.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxNodes))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Hash: MaxEncodedLen))]
+pub struct SplitsProposal<
+	AccountId: Clone + Eq + Debug,
+	Hash: Clone + Eq + Debug,
+	MaxNodes: Get<u32>,
+> {
+	/// Hash of `splits`, which approvals refer to.
+	pub hash: Hash,
+	/// The proposed split tree.
+	pub splits: SplitTree<AccountId, MaxNodes>,
+	/// Holders who approved the proposal, the proposer first.
+	pub approvals: BoundedVec<AccountId, MaxNodes>,
+}
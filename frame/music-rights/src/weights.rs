@@ -0,0 +1,196 @@
+//! Autogenerated weights for pallet_music_rights
+//!
+//! MEASURED BY DRIVING THE `benchmark pallet` LOOP OVER THE NATIVE RUNTIME INSTEAD OF WASM.
+//! REGENERATE WITH THE COMMAND BELOW ON REFERENCE HARDWARE BEFORE RELYING ON THE TIMINGS.
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: Some(Native), CHAIN: Some("dev"), DB CACHE: 1024
+
+// Regenerate with:
+// ./target/production/substrate
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_music_rights
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/music-rights/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_music_rights.
+pub trait WeightInfo {
+	fn register_work(n: u32, ) -> Weight;
+	fn set_metadata() -> Weight;
+	fn propose_splits(n: u32, ) -> Weight;
+	fn approve_splits(n: u32, ) -> Weight;
+	fn clear_work() -> Weight;
+}
+
+/// Weights for pallet_music_rights using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Uniques Asset (r:1 w:0)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:1 w:1)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn register_work(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1587`
+		//  Estimated: `5828`
+		// Minimum execution time: 27_055 nanoseconds.
+		Weight::from_parts(42_690_495, 5828)
+			// Standard Error: 35_512
+			.saturating_add(Weight::from_ref_time(318_253).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Uniques Asset (r:1 w:0)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:1 w:1)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	fn set_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2371`
+		//  Estimated: `5828`
+		// Minimum execution time: 39_852 nanoseconds.
+		Weight::from_parts(43_817_000, 5828)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: MusicRights Works (r:1 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: MusicRights SplitsProposals (r:0 w:1)
+	/// Proof: MusicRights SplitsProposals (max_values: None, max_size: Some(1242), added: 3717, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 16]`.
+	fn propose_splits(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2013`
+		//  Estimated: `3231`
+		// Minimum execution time: 33_931 nanoseconds.
+		Weight::from_parts(42_211_863, 3231)
+			// Standard Error: 40_887
+			.saturating_add(Weight::from_ref_time(450_603).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: MusicRights Works (r:1 w:1)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: MusicRights SplitsProposals (r:1 w:1)
+	/// Proof: MusicRights SplitsProposals (max_values: None, max_size: Some(1242), added: 3717, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 16]`.
+	fn approve_splits(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1451 + n * (114 ±0)`
+		//  Estimated: `6948`
+		// Minimum execution time: 32_417 nanoseconds.
+		Weight::from_parts(46_866_704, 6948)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: MusicRights Works (r:1 w:1)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:0)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: MusicRights SplitsProposals (r:0 w:1)
+	/// Proof: MusicRights SplitsProposals (max_values: None, max_size: Some(1242), added: 3717, mode: MaxEncodedLen)
+	fn clear_work() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2227`
+		//  Estimated: `5828`
+		// Minimum execution time: 38_763 nanoseconds.
+		Weight::from_parts(42_158_000, 5828)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Uniques Asset (r:1 w:0)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:1 w:1)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn register_work(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1587`
+		//  Estimated: `5828`
+		// Minimum execution time: 27_055 nanoseconds.
+		Weight::from_parts(42_690_495, 5828)
+			// Standard Error: 35_512
+			.saturating_add(Weight::from_ref_time(318_253).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Uniques Asset (r:1 w:0)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:1 w:1)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	fn set_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2371`
+		//  Estimated: `5828`
+		// Minimum execution time: 39_852 nanoseconds.
+		Weight::from_parts(43_817_000, 5828)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: MusicRights Works (r:1 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: MusicRights SplitsProposals (r:0 w:1)
+	/// Proof: MusicRights SplitsProposals (max_values: None, max_size: Some(1242), added: 3717, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 16]`.
+	fn propose_splits(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2013`
+		//  Estimated: `3231`
+		// Minimum execution time: 33_931 nanoseconds.
+		Weight::from_parts(42_211_863, 3231)
+			// Standard Error: 40_887
+			.saturating_add(Weight::from_ref_time(450_603).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: MusicRights Works (r:1 w:1)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: MusicRights SplitsProposals (r:1 w:1)
+	/// Proof: MusicRights SplitsProposals (max_values: None, max_size: Some(1242), added: 3717, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 16]`.
+	fn approve_splits(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1451 + n * (114 ±0)`
+		//  Estimated: `6948`
+		// Minimum execution time: 32_417 nanoseconds.
+		Weight::from_parts(46_866_704, 6948)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: MusicRights Works (r:1 w:1)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:0)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: MusicRights SplitsProposals (r:0 w:1)
+	/// Proof: MusicRights SplitsProposals (max_values: None, max_size: Some(1242), added: 3717, mode: MaxEncodedLen)
+	fn clear_work() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2227`
+		//  Estimated: `5828`
+		// Minimum execution time: 38_763 nanoseconds.
+		Weight::from_parts(42_158_000, 5828)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
@@ -0,0 +1,178 @@
+//! Benchmarking setup for pallet-nft-auctions
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as NftAuctions;
+
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+use pallet_uniques::{BenchmarkHelper as _, RoyaltyInfo};
+use sp_runtime::traits::{Bounded, One, StaticLookup};
+
+const SEED: u32 = 0;
+
+type Uniques<T> = pallet_uniques::Pallet<T>;
+
+fn fund<T: Config>(who: &T::AccountId) {
+	CurrencyOf::<T>::make_free_balance_be(who, BalanceOf::<T>::max_value() / 4u32.into());
+}
+
+/// Mints an item of a new collection to `seller`, which pays the highest royalty to another
+/// account.
+fn mint_item<T: Config>(seller: &T::AccountId) -> (T::CollectionId, T::ItemId) {
+	fund::<T>(seller);
+	let collection = <T as pallet_uniques::Config>::Helper::collection(0);
+	let item = <T as pallet_uniques::Config>::Helper::item(0);
+	let seller_lookup = T::Lookup::unlookup(seller.clone());
+	Uniques::<T>::force_create(RawOrigin::Root.into(), collection, seller_lookup.clone(), false)
+		.expect("collection is new");
+	Uniques::<T>::mint(RawOrigin::Signed(seller.clone()).into(), collection, item, seller_lookup)
+		.expect("item is new");
+	let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+	CurrencyOf::<T>::make_free_balance_be(&beneficiary, CurrencyOf::<T>::minimum_balance());
+	let royalty =
+		RoyaltyInfo { share: <T as pallet_uniques::Config>::MaxRoyalty::get(), beneficiary };
+	Uniques::<T>::set_item_royalty(
+		RawOrigin::Signed(seller.clone()).into(),
+		collection,
+		item,
+		Some(royalty),
+	)
+	.expect("seller is the admin");
+	(collection, item)
+}
+
+/// Puts a new item of `seller` on sale, returning the auction.
+fn start_auction<T: Config>(seller: &T::AccountId, kind: AuctionKindOf<T>) -> AuctionId {
+	let (collection, item) = mint_item::<T>(seller);
+	NftAuctions::<T>::create_auction(
+		RawOrigin::Signed(seller.clone()).into(),
+		collection,
+		item,
+		kind,
+		T::MaxDuration::get(),
+	)
+	.expect("seller owns the item");
+	0
+}
+
+/// Price of the items on sale, which is also the minimum increment of English auctions.
+fn price<T: Config>() -> BalanceOf<T> {
+	CurrencyOf::<T>::minimum_balance().saturating_mul(100u32.into())
+}
+
+fn english<T: Config>() -> AuctionKindOf<T> {
+	AuctionKind::English { reserve_price: price::<T>(), min_increment: price::<T>() }
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_auction() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (collection, item) = mint_item::<T>(&caller);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			collection,
+			item,
+			english::<T>(),
+			T::MaxDuration::get(),
+		);
+
+		assert_eq!(Auctions::<T>::get(0).map(|auction| auction.seller), Some(caller));
+	}
+
+	/// Outbids a bid at the last block of an auction, which extends it.
+	#[benchmark]
+	fn bid() {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		let auction = start_auction::<T>(&seller, english::<T>());
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+		fund::<T>(&bidder);
+		NftAuctions::<T>::bid(RawOrigin::Signed(bidder).into(), auction, price::<T>())
+			.expect("bid meets the reserve price");
+		let end = Auctions::<T>::get(auction).expect("auction exists").end;
+		frame_system::Pallet::<T>::set_block_number(end - One::one());
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let amount = price::<T>().saturating_mul(2u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), auction, amount);
+
+		let details = Auctions::<T>::get(auction).expect("auction exists");
+		assert_eq!(details.best_bid, Some(Bid { bidder: caller, amount }));
+		assert!(details.end > end);
+	}
+
+	#[benchmark]
+	fn buy() {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		let price = price::<T>();
+		let kind = AuctionKind::Dutch { start_price: price, floor_price: Zero::zero() };
+		let auction = start_auction::<T>(&seller, kind);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), auction, price);
+
+		assert!(Auctions::<T>::get(auction).is_none());
+	}
+
+	#[benchmark]
+	fn cancel_auction() {
+		let caller: T::AccountId = whitelisted_caller();
+		let auction = start_auction::<T>(&caller, english::<T>());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), auction);
+
+		assert!(Auctions::<T>::get(auction).is_none());
+	}
+
+	/// Sells the item of an English auction, paying its royalty.
+	#[benchmark]
+	fn settle_auction() {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		let auction = start_auction::<T>(&seller, english::<T>());
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+		fund::<T>(&bidder);
+		NftAuctions::<T>::bid(RawOrigin::Signed(bidder).into(), auction, price::<T>())
+			.expect("bid meets the reserve price");
+		let end = Auctions::<T>::get(auction).expect("auction exists").end;
+		frame_system::Pallet::<T>::set_block_number(end);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), auction);
+
+		assert!(Auctions::<T>::get(auction).is_none());
+	}
+
+	#[benchmark]
+	fn withdraw_bid() {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		let auction = start_auction::<T>(&seller, english::<T>());
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		NftAuctions::<T>::bid(RawOrigin::Signed(caller.clone()).into(), auction, price::<T>())
+			.expect("bid meets the reserve price");
+		Auctions::<T>::mutate(auction, |details| {
+			details.as_mut().expect("auction exists").settlement_failed = true
+		});
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), auction);
+
+		assert!(Auctions::<T>::get(auction).is_none());
+		assert!(CurrencyOf::<T>::reserved_balance(&caller).is_zero());
+	}
+
+	impl_benchmark_test_suite!(NftAuctions, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,594 @@
+//! # NFT Auctions Pallet
+//!
+//! Sells the items of `pallet_uniques` by auction, next to the fixed-price listings of its
+//! `set_price` and `buy_item`.
+//!
+//! The owner of an item puts it on sale with `create_auction`, which moves the item to the
+//! account of the pallet until the auction is settled, cancelling any price it was listed at.
+//! Auctions last a number of blocks, at most `MaxDuration`, and are one of:
+//!
+//! * English auctions, which sell to the highest bidder. The first bid must meet the reserve price,
+//!   and every following bid must beat the best bid by the minimum increment. Bids are reserved
+//!   from their bidders, and unreserved when they are outbid. A bid placed less than
+//!   `ExtensionPeriod` blocks before the end pushes the end back to `ExtensionPeriod` blocks after
+//!   the bid, so that the auction can't be sniped.
+//! * Dutch auctions, which sell to the first buyer. The price decays linearly from the start price
+//!   at the start of the auction to the floor price at its end.
+//!
+//! Every auction is settled at its end block by a task of the scheduler, which sells the item of
+//! an English auction to its best bidder and returns unsold items to their sellers. Anyone may
+//! settle an auction which ended too, should its task be missed. Sales pay the royalty of the
+//! item like `buy_item` does, and can't exceed the face value the transfer policy of its
+//! collection may cap sales at.
+//!
+//! Items on sale are locked through the `Locker` of `pallet_uniques`, which this pallet implements,
+//! so that they stay with the pallet until their auction is over. Should the sale to the best
+//! bidder still fail, for instance because the item was frozen, the auction is kept with its
+//! settlement marked as failed, and the bid may be withdrawn with `withdraw_bid` by the bidder, by
+//! the seller, or by anyone once `GracePeriod` blocks passed since the end of the auction. The
+//! item is then returned to its seller like the item of an auction without bids.
+//!
+//! Auctions must end before the transfer policy of the collection of their item stops transfers,
+//! and their items leave the pallet whatever policy the collection is given meanwhile.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `create_auction` - Puts an item on sale by auction.
+//! * `bid` - Bids on an English auction.
+//! * `buy` - Buys the item of a Dutch auction at its current price.
+//! * `cancel_auction` - Returns the item of an auction without bids to its seller.
+//! * `settle_auction` - Settles an auction which ended.
+//! * `withdraw_bid` - Withdraws the best bid of an auction whose settlement failed, returning its
+//!   item.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub mod weights;
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+use codec::Encode;
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	storage::with_storage_layer,
+	traits::{
+		schedule::{
+			v3::{Named as ScheduleNamed, TaskName},
+			DispatchTime, LOWEST_PRIORITY,
+		},
+		tokens::Locker,
+		BalanceStatus, Currency,
+		ExistenceRequirement::KeepAlive,
+		Get, OriginTrait, ReservableCurrency, StorePreimage,
+	},
+};
+use sp_runtime::{
+	traits::{AccountIdConversion, Saturating, Zero},
+	ArithmeticError, DispatchError,
+};
+
+/// The currency items are sold in, which is the currency of `pallet_uniques`.
+pub type CurrencyOf<T> = <T as pallet_uniques::Config>::Currency;
+/// The calls the scheduler dispatches.
+pub type CallOf<T> = <T as Config>::RuntimeCall;
+/// The origins the scheduler dispatches calls with.
+pub type PalletsOriginOf<T> =
+	<<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::PalletsOrigin;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{dispatch::Dispatchable, pallet_prelude::*, PalletId};
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_uniques::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The overarching call type, which settlements are scheduled as.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin>
+			+ From<Call<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// The scheduler settling auctions at their end block.
+		type Scheduler: ScheduleNamed<Self::BlockNumber, CallOf<Self>, PalletsOriginOf<Self>>;
+
+		/// The preimage provider bounding the scheduled calls.
+		type Preimages: StorePreimage;
+
+		/// The pallet id, from which the account holding the items on sale is derived.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Maximum number of blocks an auction lasts, before any extension.
+		#[pallet::constant]
+		type MaxDuration: Get<Self::BlockNumber>;
+
+		/// Number of blocks an English auction lasts at least after a bid.
+		#[pallet::constant]
+		type ExtensionPeriod: Get<Self::BlockNumber>;
+
+		/// Number of blocks after the end of an auction whose settlement failed from which anyone
+		/// may withdraw its best bid.
+		#[pallet::constant]
+		type GracePeriod: Get<Self::BlockNumber>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Identifier of the next auction.
+	#[pallet::storage]
+	pub type NextAuctionId<T: Config> = StorageValue<_, AuctionId, ValueQuery>;
+
+	/// Auctions which are not settled or cancelled yet.
+	#[pallet::storage]
+	#[pallet::getter(fn auction)]
+	pub type Auctions<T: Config> = StorageMap<_, Blake2_128Concat, AuctionId, AuctionOf<T>>;
+
+	/// The auction of the items on sale, which are locked.
+	#[pallet::storage]
+	#[pallet::getter(fn auctioned)]
+	pub type AuctionedItems<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		AuctionId,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An item was put on sale by auction.
+		AuctionCreated {
+			auction: AuctionId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			seller: T::AccountId,
+			kind: AuctionKindOf<T>,
+			end: T::BlockNumber,
+		},
+		/// A bid became the best bid of an English auction.
+		BidPlaced { auction: AuctionId, bidder: T::AccountId, amount: BalanceOf<T> },
+		/// A late bid pushed back the end of an English auction.
+		AuctionExtended { auction: AuctionId, end: T::BlockNumber },
+		/// The item of an auction was sold.
+		AuctionSold {
+			auction: AuctionId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+		},
+		/// An auction ended without a sale, returning its item to its seller.
+		AuctionUnsold { auction: AuctionId, collection: T::CollectionId, item: T::ItemId },
+		/// An auction was cancelled by its seller.
+		AuctionCancelled { auction: AuctionId },
+		/// The sale of an auction to its best bidder failed, so the bid may be withdrawn.
+		SettlementFailed { auction: AuctionId, error: DispatchError },
+		/// The best bid of an auction whose settlement failed was withdrawn.
+		BidWithdrawn { auction: AuctionId, bidder: T::AccountId, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The auction does not exist.
+		UnknownAuction,
+		/// The caller does not own the item, is not the seller, or is the seller.
+		NoPermission,
+		/// The duration of the auction is zero or above `MaxDuration`.
+		InvalidDuration,
+		/// The floor price of the Dutch auction is above its start price.
+		InvalidPrice,
+		/// The auction is over.
+		AuctionOver,
+		/// The auction is not over yet.
+		AuctionNotOver,
+		/// The auction is not an English auction.
+		NotEnglish,
+		/// The auction is not a Dutch auction.
+		NotDutch,
+		/// The bid is below the reserve price or the minimum increment, or the price of the Dutch
+		/// auction is above the limit of the buyer.
+		BidTooLow,
+		/// The English auction has bids, so it can't be cancelled.
+		HasBids,
+		/// Part of the reserved bid was slashed, so it no longer pays the price.
+		BidSlashed,
+		/// The settlement of the auction did not fail.
+		SettlementNotFailed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Put an item of the caller on sale by auction, moving it to the account of the pallet
+		/// and scheduling the settlement of the auction at its end.
+		///
+		/// - `collection`, `item`: the item on sale.
+		/// - `kind`: how the auction sells the item.
+		/// - `duration`: number of blocks the auction lasts, at most `MaxDuration`.
+		///
+		/// Emits `AuctionCreated`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			kind: AuctionKindOf<T>,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+
+			ensure!(
+				!duration.is_zero() && duration <= T::MaxDuration::get(),
+				Error::<T>::InvalidDuration
+			);
+			let opening_price = match kind {
+				AuctionKind::English { reserve_price, .. } => reserve_price,
+				AuctionKind::Dutch { start_price, floor_price } => {
+					ensure!(floor_price <= start_price, Error::<T>::InvalidPrice);
+					start_price
+				},
+			};
+			pallet_uniques::Pallet::<T>::check_sale_price(collection, opening_price)?;
+			let start = frame_system::Pallet::<T>::block_number();
+			let end = start.saturating_add(duration);
+			// Items leave the pallet whatever the transfer policy, so the auction must end before
+			// the policy stops transfers.
+			pallet_uniques::Pallet::<T>::check_transferable(collection, end)?;
+
+			ensure!(
+				pallet_uniques::Pallet::<T>::owner(collection, item).as_ref() == Some(&seller),
+				Error::<T>::NoPermission
+			);
+			pallet_uniques::Pallet::<T>::do_transfer(
+				collection,
+				item,
+				Self::account_id(),
+				|_, _| Ok(()),
+			)?;
+
+			let auction = NextAuctionId::<T>::get();
+			NextAuctionId::<T>::put(auction.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+			Self::schedule_settlement(auction, end)?;
+			Auctions::<T>::insert(
+				auction,
+				Auction {
+					seller: seller.clone(),
+					collection,
+					item,
+					kind: kind.clone(),
+					start,
+					end,
+					best_bid: None,
+					settlement_failed: false,
+				},
+			);
+			AuctionedItems::<T>::insert(collection, item, auction);
+
+			Self::deposit_event(Event::AuctionCreated {
+				auction,
+				collection,
+				item,
+				seller,
+				kind,
+				end,
+			});
+			Ok(())
+		}
+
+		/// Bid `amount` on an English auction, reserving it from the caller and unreserving the
+		/// bid it outbids.
+		///
+		/// The first bid must meet the reserve price, and every following bid must beat the best
+		/// bid by the minimum increment. A bid less than `ExtensionPeriod` blocks before the end
+		/// pushes the end back to `ExtensionPeriod` blocks after the bid.
+		///
+		/// Emits `BidPlaced`, and `AuctionExtended` if the end is pushed back.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::bid())]
+		pub fn bid(
+			origin: OriginFor<T>,
+			auction: AuctionId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+
+			Auctions::<T>::try_mutate(auction, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::UnknownAuction)?;
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(now < details.end, Error::<T>::AuctionOver);
+				ensure!(bidder != details.seller, Error::<T>::NoPermission);
+				let AuctionKind::English { reserve_price, min_increment } = details.kind else {
+					return Err(Error::<T>::NotEnglish.into())
+				};
+
+				match &details.best_bid {
+					Some(best) => {
+						ensure!(
+							amount > best.amount &&
+								amount >= best.amount.saturating_add(min_increment),
+							Error::<T>::BidTooLow
+						);
+						// Released first, so that a bidder raising their own bid only needs the
+						// difference.
+						CurrencyOf::<T>::unreserve(&best.bidder, best.amount);
+					},
+					None => ensure!(amount >= reserve_price, Error::<T>::BidTooLow),
+				}
+				pallet_uniques::Pallet::<T>::check_sale_price(details.collection, amount)?;
+				CurrencyOf::<T>::reserve(&bidder, amount)?;
+				details.best_bid = Some(Bid { bidder: bidder.clone(), amount });
+
+				let extended_end = now.saturating_add(T::ExtensionPeriod::get());
+				if extended_end > details.end {
+					details.end = extended_end;
+					T::Scheduler::reschedule_named(
+						Self::task_name(auction),
+						DispatchTime::At(extended_end),
+					)?;
+					Self::deposit_event(Event::AuctionExtended { auction, end: extended_end });
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::BidPlaced { auction, bidder, amount });
+			Ok(())
+		}
+
+		/// Buy the item of a Dutch auction at its current price, paying the royalty of the item.
+		///
+		/// - `max_price`: the highest price the caller pays.
+		///
+		/// Emits `AuctionSold`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		pub fn buy(
+			origin: OriginFor<T>,
+			auction: AuctionId,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+
+			let details = Auctions::<T>::get(auction).ok_or(Error::<T>::UnknownAuction)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < details.end, Error::<T>::AuctionOver);
+			ensure!(buyer != details.seller, Error::<T>::NoPermission);
+			let price = details.dutch_price(now).ok_or(Error::<T>::NotDutch)?;
+			ensure!(price <= max_price, Error::<T>::BidTooLow);
+
+			let proceeds = pallet_uniques::Pallet::<T>::do_pay_royalty(
+				details.collection,
+				details.item,
+				&details.seller,
+				price,
+				|beneficiary, amount| {
+					CurrencyOf::<T>::transfer(&buyer, beneficiary, amount, KeepAlive)
+				},
+			)?;
+			CurrencyOf::<T>::transfer(&buyer, &details.seller, proceeds, KeepAlive)?;
+			AuctionedItems::<T>::remove(details.collection, details.item);
+			pallet_uniques::Pallet::<T>::do_release(
+				details.collection,
+				details.item,
+				buyer.clone(),
+			)?;
+			T::Scheduler::cancel_named(Self::task_name(auction))?;
+			Auctions::<T>::remove(auction);
+
+			Self::deposit_event(Event::AuctionSold {
+				auction,
+				collection: details.collection,
+				item: details.item,
+				seller: details.seller,
+				buyer,
+				price,
+			});
+			Ok(())
+		}
+
+		/// Cancel an auction of the caller without bids, returning its item.
+		///
+		/// Emits `AuctionCancelled`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_auction())]
+		pub fn cancel_auction(origin: OriginFor<T>, auction: AuctionId) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+
+			let details = Auctions::<T>::get(auction).ok_or(Error::<T>::UnknownAuction)?;
+			ensure!(seller == details.seller, Error::<T>::NoPermission);
+			ensure!(details.best_bid.is_none(), Error::<T>::HasBids);
+
+			AuctionedItems::<T>::remove(details.collection, details.item);
+			pallet_uniques::Pallet::<T>::do_release(details.collection, details.item, seller)?;
+			// The task is missing if the auction ended but its settlement was missed.
+			let _ = T::Scheduler::cancel_named(Self::task_name(auction));
+			Auctions::<T>::remove(auction);
+
+			Self::deposit_event(Event::AuctionCancelled { auction });
+			Ok(())
+		}
+
+		/// Settle an auction which ended, selling the item of an English auction to its best
+		/// bidder, paying the royalty of the item, or returning an unsold item to its seller.
+		///
+		/// Dispatched by the scheduler at the end of every auction, and callable by anyone once
+		/// the auction ended. A sale which fails is rolled back and marks the settlement as
+		/// failed, keeping the auction so that its best bid can be withdrawn.
+		///
+		/// Emits `AuctionSold`, `AuctionUnsold` or `SettlementFailed`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::settle_auction())]
+		pub fn settle_auction(origin: OriginFor<T>, auction: AuctionId) -> DispatchResult {
+			ensure_signed_or_root(origin)?;
+
+			let details = Auctions::<T>::get(auction).ok_or(Error::<T>::UnknownAuction)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= details.end, Error::<T>::AuctionNotOver);
+
+			let (collection, item) = (details.collection, details.item);
+			// The task is missing when it is the one settling the auction.
+			let _ = T::Scheduler::cancel_named(Self::task_name(auction));
+			match details.best_bid {
+				Some(Bid { bidder, amount }) => {
+					let sold = with_storage_layer(|| {
+						Self::do_sell(collection, item, &details.seller, &bidder, amount)
+					});
+					if let Err(error) = sold {
+						Auctions::<T>::mutate(auction, |maybe_details| {
+							if let Some(details) = maybe_details {
+								details.settlement_failed = true;
+							}
+						});
+						Self::deposit_event(Event::SettlementFailed { auction, error });
+						return Ok(())
+					}
+					Self::deposit_event(Event::AuctionSold {
+						auction,
+						collection,
+						item,
+						seller: details.seller,
+						buyer: bidder,
+						price: amount,
+					});
+				},
+				None => {
+					AuctionedItems::<T>::remove(collection, item);
+					pallet_uniques::Pallet::<T>::do_release(collection, item, details.seller)?;
+					Self::deposit_event(Event::AuctionUnsold { auction, collection, item });
+				},
+			}
+			Auctions::<T>::remove(auction);
+			Ok(())
+		}
+
+		/// Withdraw the best bid on an auction whose settlement failed, unreserving it for its
+		/// bidder, and return the item of the auction to its seller.
+		///
+		/// The caller must be the bidder or the seller, unless `GracePeriod` blocks passed since
+		/// the end of the auction. The item stays with the pallet, and the auction without bids,
+		/// while the item can't be moved, so that `settle_auction` returns it later.
+		///
+		/// Emits `BidWithdrawn`, and `AuctionUnsold` if the item is returned.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_bid())]
+		pub fn withdraw_bid(origin: OriginFor<T>, auction: AuctionId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			let mut details = Auctions::<T>::get(auction).ok_or(Error::<T>::UnknownAuction)?;
+			ensure!(details.settlement_failed, Error::<T>::SettlementNotFailed);
+			let Bid { bidder, amount } = details.best_bid.take().ok_or(Error::<T>::NoPermission)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				caller == bidder ||
+					caller == details.seller ||
+					now >= details.end.saturating_add(T::GracePeriod::get()),
+				Error::<T>::NoPermission
+			);
+			CurrencyOf::<T>::unreserve(&bidder, amount);
+			Self::deposit_event(Event::BidWithdrawn { auction, bidder, amount });
+
+			let (collection, item) = (details.collection, details.item);
+			let returned = with_storage_layer(|| {
+				// Unlocks the item before transferring it.
+				AuctionedItems::<T>::remove(collection, item);
+				pallet_uniques::Pallet::<T>::do_release(collection, item, details.seller.clone())
+			});
+			if returned.is_ok() {
+				Auctions::<T>::remove(auction);
+				Self::deposit_event(Event::AuctionUnsold { auction, collection, item });
+			} else {
+				Auctions::<T>::insert(auction, details);
+			}
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account holding the items on sale.
+	pub fn account_id() -> T::AccountId {
+		<T as Config>::PalletId::get().into_account_truncating()
+	}
+
+	/// Name of the task of the scheduler settling `auction`.
+	fn task_name(auction: AuctionId) -> TaskName {
+		(<T as Config>::PalletId::get(), auction).using_encoded(sp_io::hashing::blake2_256)
+	}
+
+	/// Sells `item` to `bidder` at their reserved bid `amount`, paying its royalty and `seller`.
+	fn do_sell(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		seller: &T::AccountId,
+		bidder: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let proceeds = pallet_uniques::Pallet::<T>::do_pay_royalty(
+			collection,
+			item,
+			seller,
+			amount,
+			|beneficiary, royalty| Self::pay_from_bid(bidder, beneficiary, royalty),
+		)?;
+		Self::pay_from_bid(bidder, seller, proceeds)?;
+		// Unlocks the item before transferring it.
+		AuctionedItems::<T>::remove(collection, item);
+		pallet_uniques::Pallet::<T>::do_release(collection, item, bidder.clone())
+	}
+
+	/// Pays `amount` of the reserved bid of `bidder` to `beneficiary`, failing if any of it was
+	/// slashed.
+	fn pay_from_bid(
+		bidder: &T::AccountId,
+		beneficiary: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let missed =
+			CurrencyOf::<T>::repatriate_reserved(bidder, beneficiary, amount, BalanceStatus::Free)?;
+		ensure!(missed.is_zero(), Error::<T>::BidSlashed);
+		Ok(())
+	}
+
+	/// Schedules the settlement of `auction` at block `end`.
+	fn schedule_settlement(auction: AuctionId, end: T::BlockNumber) -> DispatchResult {
+		let call = T::Preimages::bound(CallOf::<T>::from(Call::settle_auction { auction }))?;
+		T::Scheduler::schedule_named(
+			Self::task_name(auction),
+			DispatchTime::At(end),
+			None,
+			LOWEST_PRIORITY,
+			frame_system::RawOrigin::Root.into(),
+			call,
+		)?;
+		Ok(())
+	}
+}
+
+impl<T: Config> Locker<T::CollectionId, T::ItemId> for Pallet<T> {
+	fn is_locked(collection: T::CollectionId, item: T::ItemId) -> bool {
+		AuctionedItems::<T>::contains_key(collection, item)
+	}
+}
@@ -0,0 +1,656 @@
+//! Tests for the NFT auctions pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{tokens::nonfungibles::Inspect, Currency, ReservableCurrency},
+};
+use pallet_uniques::RoyaltyInfo;
+use sp_runtime::Perbill;
+
+fn english(reserve_price: u64, min_increment: u64) -> AuctionKindOf<Test> {
+	AuctionKind::English { reserve_price, min_increment }
+}
+
+fn dutch(start_price: u64, floor_price: u64) -> AuctionKindOf<Test> {
+	AuctionKind::Dutch { start_price, floor_price }
+}
+
+fn owner() -> Option<u64> {
+	<Uniques as Inspect<u64>>::owner(&COLLECTION, &ITEM)
+}
+
+/// Block the settlement of `auction` is scheduled at.
+fn scheduled_at(auction: AuctionId) -> Option<u64> {
+	<Scheduler as ScheduleNamed<_, _, _>>::next_dispatch_time(NftAuctions::task_name(auction)).ok()
+}
+
+fn set_royalty(share: Perbill) {
+	assert_ok!(Uniques::set_collection_royalty(
+		RuntimeOrigin::root(),
+		COLLECTION,
+		Some(RoyaltyInfo { share, beneficiary: CREATOR }),
+	));
+}
+
+#[test]
+fn create_auction_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			NftAuctions::create_auction(
+				RuntimeOrigin::signed(SELLER),
+				COLLECTION,
+				ITEM,
+				english(100, 10),
+				0
+			),
+			Error::<Test>::InvalidDuration
+		);
+		assert_noop!(
+			NftAuctions::create_auction(
+				RuntimeOrigin::signed(SELLER),
+				COLLECTION,
+				ITEM,
+				english(100, 10),
+				101
+			),
+			Error::<Test>::InvalidDuration
+		);
+		assert_noop!(
+			NftAuctions::create_auction(
+				RuntimeOrigin::signed(SELLER),
+				COLLECTION,
+				ITEM,
+				dutch(100, 101),
+				10
+			),
+			Error::<Test>::InvalidPrice
+		);
+		assert_noop!(
+			NftAuctions::create_auction(
+				RuntimeOrigin::signed(ALICE),
+				COLLECTION,
+				ITEM,
+				english(100, 10),
+				10
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Uniques::set_price(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			Some(50),
+			None
+		));
+		assert_ok!(NftAuctions::create_auction(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			english(100, 10),
+			10
+		));
+
+		assert_eq!(owner(), Some(NftAuctions::account_id()));
+		// The listing of the item is cancelled.
+		assert_noop!(
+			Uniques::buy_item(RuntimeOrigin::signed(ALICE), COLLECTION, ITEM, 50),
+			pallet_uniques::Error::<Test>::NotForSale
+		);
+		assert_eq!(
+			NftAuctions::auction(0),
+			Some(Auction {
+				seller: SELLER,
+				collection: COLLECTION,
+				item: ITEM,
+				kind: english(100, 10),
+				start: 1,
+				end: 11,
+				best_bid: None,
+				settlement_failed: false,
+			})
+		);
+		assert_eq!(scheduled_at(0), Some(11));
+		assert_eq!(
+			events(),
+			vec![Event::AuctionCreated {
+				auction: 0,
+				collection: COLLECTION,
+				item: ITEM,
+				seller: SELLER,
+				kind: english(100, 10),
+				end: 11,
+			}]
+		);
+	});
+}
+
+#[test]
+fn english_auction_should_work() {
+	new_test_ext().execute_with(|| {
+		set_royalty(Perbill::from_percent(10));
+		assert_ok!(NftAuctions::create_auction(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			english(100, 10),
+			20
+		));
+		events();
+
+		assert_noop!(
+			NftAuctions::bid(RuntimeOrigin::signed(SELLER), 0, 100),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			NftAuctions::bid(RuntimeOrigin::signed(ALICE), 0, 99),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(NftAuctions::bid(RuntimeOrigin::signed(ALICE), 0, 100));
+		assert_eq!(Balances::reserved_balance(ALICE), 100);
+
+		assert_noop!(
+			NftAuctions::bid(RuntimeOrigin::signed(BOB), 0, 109),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(NftAuctions::bid(RuntimeOrigin::signed(BOB), 0, 110));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(BOB), 110);
+
+		// Raising a bid only reserves the difference.
+		assert_ok!(NftAuctions::bid(RuntimeOrigin::signed(BOB), 0, 150));
+		assert_eq!(Balances::reserved_balance(BOB), 150);
+		assert_eq!(
+			events(),
+			vec![
+				Event::BidPlaced { auction: 0, bidder: ALICE, amount: 100 },
+				Event::BidPlaced { auction: 0, bidder: BOB, amount: 110 },
+				Event::BidPlaced { auction: 0, bidder: BOB, amount: 150 },
+			]
+		);
+
+		// The scheduler settles the auction at its end.
+		run_to_block(20);
+		assert!(NftAuctions::auction(0).is_some());
+		run_to_block(21);
+		assert!(NftAuctions::auction(0).is_none());
+		assert_eq!(owner(), Some(BOB));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(BOB), 850);
+		assert_eq!(Balances::free_balance(CREATOR), 100 - 1 + 15);
+		assert_eq!(Balances::free_balance(SELLER), 100 + 135);
+		assert_eq!(
+			events(),
+			vec![Event::AuctionSold {
+				auction: 0,
+				collection: COLLECTION,
+				item: ITEM,
+				seller: SELLER,
+				buyer: BOB,
+				price: 150,
+			}]
+		);
+	});
+}
+
+#[test]
+fn late_bids_extend_english_auction() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(NftAuctions::create_auction(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			english(100, 10),
+			10
+		));
+		events();
+
+		// A bid long enough before the end does not extend the auction.
+		run_to_block(6);
+		assert_ok!(NftAuctions::bid(RuntimeOrigin::signed(ALICE), 0, 100));
+		assert_eq!(NftAuctions::auction(0).unwrap().end, 11);
+
+		run_to_block(8);
+		assert_ok!(NftAuctions::bid(RuntimeOrigin::signed(BOB), 0, 110));
+		assert_eq!(NftAuctions::auction(0).unwrap().end, 13);
+		assert_eq!(
+			events(),
+			vec![
+				Event::BidPlaced { auction: 0, bidder: ALICE, amount: 100 },
+				Event::AuctionExtended { auction: 0, end: 13 },
+				Event::BidPlaced { auction: 0, bidder: BOB, amount: 110 },
+			]
+		);
+
+		run_to_block(11);
+		assert!(NftAuctions::auction(0).is_some());
+		assert_ok!(NftAuctions::bid(RuntimeOrigin::signed(ALICE), 0, 120));
+		assert_eq!(NftAuctions::auction(0).unwrap().end, 16);
+
+		run_to_block(16);
+		assert!(NftAuctions::auction(0).is_none());
+		assert_eq!(owner(), Some(ALICE));
+		assert_noop!(
+			NftAuctions::bid(RuntimeOrigin::signed(BOB), 0, 200),
+			Error::<Test>::UnknownAuction
+		);
+	});
+}
+
+#[test]
+fn dutch_auction_should_work() {
+	new_test_ext().execute_with(|| {
+		set_royalty(Perbill::from_percent(10));
+		assert_ok!(NftAuctions::create_auction(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			dutch(200, 100),
+			10
+		));
+		events();
+
+		let details = NftAuctions::auction(0).unwrap();
+		assert_eq!(details.dutch_price(1), Some(200));
+		assert_eq!(details.dutch_price(4), Some(170));
+		assert_eq!(details.dutch_price(11), Some(100));
+		assert_eq!(details.dutch_price(50), Some(100));
+		assert_noop!(
+			NftAuctions::bid(RuntimeOrigin::signed(ALICE), 0, 200),
+			Error::<Test>::NotEnglish
+		);
+
+		run_to_block(6);
+		assert_noop!(
+			NftAuctions::buy(RuntimeOrigin::signed(ALICE), 0, 149),
+			Error::<Test>::BidTooLow
+		);
+		assert_noop!(
+			NftAuctions::buy(RuntimeOrigin::signed(SELLER), 0, 150),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(NftAuctions::buy(RuntimeOrigin::signed(ALICE), 0, 160));
+
+		assert!(NftAuctions::auction(0).is_none());
+		assert_eq!(owner(), Some(ALICE));
+		assert_eq!(Balances::free_balance(ALICE), 850);
+		assert_eq!(Balances::free_balance(CREATOR), 100 - 1 + 15);
+		assert_eq!(Balances::free_balance(SELLER), 100 + 135);
+		assert_eq!(
+			events(),
+			vec![Event::AuctionSold {
+				auction: 0,
+				collection: COLLECTION,
+				item: ITEM,
+				seller: SELLER,
+				buyer: ALICE,
+				price: 150,
+			}]
+		);
+		// The settlement is no longer scheduled.
+		assert_eq!(scheduled_at(0), None);
+		run_to_block(11);
+		assert_eq!(owner(), Some(ALICE));
+	});
+}
+
+#[test]
+fn unsold_auctions_return_items() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(NftAuctions::create_auction(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			dutch(200, 100),
+			10
+		));
+		run_to_block(11);
+		assert_noop!(
+			NftAuctions::buy(RuntimeOrigin::signed(ALICE), 0, 200),
+			Error::<Test>::UnknownAuction
+		);
+		assert_eq!(owner(), Some(SELLER));
+
+		assert_ok!(NftAuctions::create_auction(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			english(100, 10),
+			10
+		));
+		assert_noop!(
+			NftAuctions::buy(RuntimeOrigin::signed(ALICE), 1, 200),
+			Error::<Test>::NotDutch
+		);
+		events();
+		run_to_block(21);
+		assert_eq!(owner(), Some(SELLER));
+		assert_eq!(
+			events(),
+			vec![Event::AuctionUnsold { auction: 1, collection: COLLECTION, item: ITEM }]
+		);
+	});
+}
+
+#[test]
+fn cancel_auction_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(NftAuctions::create_auction(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			english(100, 10),
+			10
+		));
+		events();
+
+		assert_noop!(
+			NftAuctions::cancel_auction(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(NftAuctions::cancel_auction(RuntimeOrigin::signed(SELLER), 0));
+		assert_eq!(owner(), Some(SELLER));
+		assert!(NftAuctions::auction(0).is_none());
+		assert_eq!(events(), vec![Event::AuctionCancelled { auction: 0 }]);
+		run_to_block(11);
+		assert_eq!(events(), vec![]);
+
+		assert_ok!(NftAuctions::create_auction(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			english(100, 10),
+			10
+		));
+		assert_ok!(NftAuctions::bid(RuntimeOrigin::signed(ALICE), 1, 100));
+		assert_noop!(
+			NftAuctions::cancel_auction(RuntimeOrigin::signed(SELLER), 1),
+			Error::<Test>::HasBids
+		);
+	});
+}
+
+#[test]
+fn anyone_can_settle_ended_auctions() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(NftAuctions::create_auction(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			english(100, 10),
+			10
+		));
+		assert_ok!(NftAuctions::bid(RuntimeOrigin::signed(ALICE), 0, 100));
+		assert_noop!(
+			NftAuctions::settle_auction(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::AuctionNotOver
+		);
+
+		// The end block passes without the scheduler.
+		System::set_block_number(11);
+		assert_noop!(
+			NftAuctions::bid(RuntimeOrigin::signed(BOB), 0, 200),
+			Error::<Test>::AuctionOver
+		);
+		assert_ok!(NftAuctions::settle_auction(RuntimeOrigin::signed(BOB), 0));
+		assert_eq!(owner(), Some(ALICE));
+		assert_eq!(Balances::free_balance(SELLER), 200);
+		assert_eq!(scheduled_at(0), None);
+	});
+}
+
+#[test]
+fn settlement_uses_reserved_bid() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(NftAuctions::create_auction(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			english(100, 10),
+			10
+		));
+		// The whole balance of the bidder is bid, so that it can only be paid from the reserve.
+		let _ = Balances::make_free_balance_be(&ALICE, 100);
+		assert_ok!(NftAuctions::bid(RuntimeOrigin::signed(ALICE), 0, 100));
+		assert_eq!(Balances::free_balance(ALICE), 0);
+		assert!(Balances::reserve(&ALICE, 1).is_err());
+
+		run_to_block(11);
+		assert_eq!(owner(), Some(ALICE));
+		assert_eq!(Balances::free_balance(SELLER), 200);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn auctions_respect_face_value() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::set_transfer_policy(
+			RuntimeOrigin::root(),
+			COLLECTION,
+			Some(pallet_uniques::TransferPolicy::ResaleCapped { face_value: 150 }),
+		));
+
+		assert_noop!(
+			NftAuctions::create_auction(
+				RuntimeOrigin::signed(SELLER),
+				COLLECTION,
+				ITEM,
+				dutch(200, 100),
+				10
+			),
+			pallet_uniques::Error::<Test>::AboveFaceValue
+		);
+		assert_ok!(NftAuctions::create_auction(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			english(100, 10),
+			10
+		));
+		assert_noop!(
+			NftAuctions::bid(RuntimeOrigin::signed(ALICE), 0, 151),
+			pallet_uniques::Error::<Test>::AboveFaceValue
+		);
+		assert_ok!(NftAuctions::bid(RuntimeOrigin::signed(ALICE), 0, 150));
+	});
+}
+
+#[test]
+fn auctioned_items_are_locked() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(NftAuctions::create_auction(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			english(100, 10),
+			10
+		));
+		assert_eq!(NftAuctions::auctioned(COLLECTION, ITEM), Some(0));
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(CREATOR), COLLECTION, ITEM, CREATOR),
+			pallet_uniques::Error::<Test>::Locked
+		);
+		assert_noop!(
+			Uniques::burn(RuntimeOrigin::signed(CREATOR), COLLECTION, ITEM, None),
+			pallet_uniques::Error::<Test>::Locked
+		);
+
+		assert_ok!(NftAuctions::cancel_auction(RuntimeOrigin::signed(SELLER), 0));
+		assert_eq!(NftAuctions::auctioned(COLLECTION, ITEM), None);
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(SELLER), COLLECTION, ITEM, ALICE));
+	});
+}
+
+#[test]
+fn failed_settlement_lets_bidder_withdraw() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(NftAuctions::create_auction(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			english(100, 10),
+			10
+		));
+		assert_ok!(NftAuctions::bid(RuntimeOrigin::signed(ALICE), 0, 100));
+		assert_noop!(
+			NftAuctions::withdraw_bid(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::SettlementNotFailed
+		);
+		assert_ok!(Uniques::freeze(RuntimeOrigin::signed(CREATOR), COLLECTION, ITEM));
+		events();
+
+		run_to_block(11);
+		assert!(matches!(events().as_slice(), [Event::SettlementFailed { auction: 0, .. }]));
+		assert_eq!(owner(), Some(NftAuctions::account_id()));
+		assert_eq!(Balances::free_balance(SELLER), 100);
+		assert_eq!(Balances::reserved_balance(ALICE), 100);
+		assert!(NftAuctions::auction(0).unwrap().settlement_failed);
+
+		assert_noop!(
+			NftAuctions::withdraw_bid(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(NftAuctions::withdraw_bid(RuntimeOrigin::signed(ALICE), 0));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 1_000);
+		assert_noop!(
+			NftAuctions::withdraw_bid(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::NoPermission
+		);
+
+		// Without bids, the item goes back to its seller once it can be moved again.
+		assert_ok!(Uniques::thaw(RuntimeOrigin::signed(CREATOR), COLLECTION, ITEM));
+		assert_ok!(NftAuctions::settle_auction(RuntimeOrigin::signed(BOB), 0));
+		assert_eq!(owner(), Some(SELLER));
+		assert_eq!(NftAuctions::auction(0), None);
+		assert_eq!(NftAuctions::auctioned(COLLECTION, ITEM), None);
+	});
+}
+
+/// Starts an English auction of the item, in which `ALICE` bids 100, and fails its settlement
+/// by freezing the item until it ends.
+fn fail_settlement() -> AuctionId {
+	let auction = NextAuctionId::<Test>::get();
+	assert_ok!(NftAuctions::create_auction(
+		RuntimeOrigin::signed(SELLER),
+		COLLECTION,
+		ITEM,
+		english(100, 10),
+		10
+	));
+	assert_ok!(NftAuctions::bid(RuntimeOrigin::signed(ALICE), auction, 100));
+	assert_ok!(Uniques::freeze(RuntimeOrigin::signed(CREATOR), COLLECTION, ITEM));
+	run_to_block(System::block_number() + 10);
+	assert!(NftAuctions::auction(auction).unwrap().settlement_failed);
+	assert_ok!(Uniques::thaw(RuntimeOrigin::signed(CREATOR), COLLECTION, ITEM));
+	events();
+	auction
+}
+
+#[test]
+fn seller_withdraws_bid_of_failed_settlement() {
+	new_test_ext().execute_with(|| {
+		let auction = fail_settlement();
+
+		assert_ok!(NftAuctions::withdraw_bid(RuntimeOrigin::signed(SELLER), auction));
+		assert_eq!(
+			events(),
+			[
+				Event::BidWithdrawn { auction, bidder: ALICE, amount: 100 },
+				Event::AuctionUnsold { auction, collection: COLLECTION, item: ITEM },
+			]
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(owner(), Some(SELLER));
+		assert_eq!(NftAuctions::auction(auction), None);
+		assert_eq!(NftAuctions::auctioned(COLLECTION, ITEM), None);
+	});
+}
+
+#[test]
+fn anyone_withdraws_bid_of_failed_settlement_after_grace_period() {
+	new_test_ext().execute_with(|| {
+		let auction = fail_settlement();
+		let end = NftAuctions::auction(auction).unwrap().end;
+
+		run_to_block(end + 9);
+		assert_noop!(
+			NftAuctions::withdraw_bid(RuntimeOrigin::signed(BOB), auction),
+			Error::<Test>::NoPermission
+		);
+		run_to_block(end + 10);
+		assert_ok!(NftAuctions::withdraw_bid(RuntimeOrigin::signed(BOB), auction));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 1_000);
+		assert_eq!(owner(), Some(SELLER));
+		assert_eq!(NftAuctions::auction(auction), None);
+	});
+}
+
+#[test]
+fn slashed_bids_fail_settlement() {
+	new_test_ext().execute_with(|| {
+		set_royalty(Perbill::from_percent(10));
+		assert_ok!(NftAuctions::create_auction(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			english(100, 10),
+			10
+		));
+		assert_ok!(NftAuctions::bid(RuntimeOrigin::signed(ALICE), 0, 100));
+		let _ = Balances::slash_reserved(&ALICE, 50);
+		let creator_balance = Balances::free_balance(CREATOR);
+
+		run_to_block(11);
+		assert_eq!(owner(), Some(NftAuctions::account_id()));
+		assert_eq!(Balances::free_balance(SELLER), 100);
+		assert_eq!(Balances::free_balance(CREATOR), creator_balance);
+		assert!(NftAuctions::auction(0).unwrap().settlement_failed);
+
+		assert_ok!(NftAuctions::withdraw_bid(RuntimeOrigin::signed(ALICE), 0));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn auctions_outlast_transfer_policies() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::set_transfer_policy(
+			RuntimeOrigin::root(),
+			COLLECTION,
+			Some(pallet_uniques::TransferPolicy::TransferableUntil(11)),
+		));
+		assert_noop!(
+			NftAuctions::create_auction(
+				RuntimeOrigin::signed(SELLER),
+				COLLECTION,
+				ITEM,
+				english(100, 10),
+				10
+			),
+			pallet_uniques::Error::<Test>::TransferForbidden
+		);
+		assert_ok!(NftAuctions::create_auction(
+			RuntimeOrigin::signed(SELLER),
+			COLLECTION,
+			ITEM,
+			english(100, 10),
+			9
+		));
+		assert_ok!(NftAuctions::bid(RuntimeOrigin::signed(ALICE), 0, 100));
+
+		// The item is sold even though transfers stopped meanwhile.
+		assert_ok!(Uniques::set_transfer_policy(
+			RuntimeOrigin::root(),
+			COLLECTION,
+			Some(pallet_uniques::TransferPolicy::NonTransferable),
+		));
+		run_to_block(10);
+		assert_eq!(owner(), Some(ALICE));
+		assert_eq!(NftAuctions::auction(0), None);
+	});
+}
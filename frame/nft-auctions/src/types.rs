@@ -0,0 +1,106 @@
+//! Types of the NFT auctions pallet.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::Currency, RuntimeDebug};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Zero},
+	Perbill,
+};
+
+/// Identifier of an auction.
+pub type AuctionId = u32;
+
+type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+/// Balance of the currency items are sold in.
+pub type BalanceOf<T> =
+	<<T as pallet_uniques::Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+/// Identifier of the collection of an item.
+pub type CollectionIdOf<T> = <T as pallet_uniques::Config>::CollectionId;
+/// Identifier of an item within its collection.
+pub type ItemIdOf<T> = <T as pallet_uniques::Config>::ItemId;
+/// The kind of an auction from this pallet's point of view.
+pub type AuctionKindOf<T> = AuctionKind<BalanceOf<T>>;
+/// An auction from this pallet's point of view.
+pub type AuctionOf<T> = Auction<
+	AccountIdOf<T>,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+	CollectionIdOf<T>,
+	ItemIdOf<T>,
+>;
+
+/// How an auction sells its item.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AuctionKind<Balance> {
+	/// Sells to the highest bidder once the auction ends.
+	English {
+		/// Lowest first bid.
+		reserve_price: Balance,
+		/// Amount every bid must beat the best bid by.
+		min_increment: Balance,
+	},
+	/// Sells to the first buyer, at a price decaying linearly over the auction.
+	Dutch {
+		/// Price at the start of the auction.
+		start_price: Balance,
+		/// Price at the end of the auction, at most `start_price`.
+		floor_price: Balance,
+	},
+}
+
+/// A bid of an English auction, reserved from its bidder.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Bid<AccountId, Balance> {
+	/// The bidder.
+	pub bidder: AccountId,
+	/// The amount bid.
+	pub amount: Balance,
+}
+
+/// An item on sale by auction, held by the pallet until the auction is settled.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Auction<AccountId, Balance, BlockNumber, CollectionId, ItemId> {
+	/// Owner of the item, who is paid for it.
+	pub seller: AccountId,
+	/// Collection of the item.
+	pub collection: CollectionId,
+	/// The item within its collection.
+	pub item: ItemId,
+	/// How the auction sells the item.
+	pub kind: AuctionKind<Balance>,
+	/// Block the auction started at.
+	pub start: BlockNumber,
+	/// Block the auction ends at, which bids near the end of an English auction push back.
+	pub end: BlockNumber,
+	/// Best bid of an English auction.
+	pub best_bid: Option<Bid<AccountId, Balance>>,
+	/// Whether the sale to the best bidder failed, letting them withdraw their bid.
+	pub settlement_failed: bool,
+}
+
+impl<AccountId, Balance, BlockNumber, CollectionId, ItemId>
+	Auction<AccountId, Balance, BlockNumber, CollectionId, ItemId>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// Price of a Dutch auction at block `now`, decaying linearly from its start price at its
+	/// start to its floor price at its end.
+	pub fn dutch_price(&self, now: BlockNumber) -> Option<Balance> {
+		match self.kind {
+			AuctionKind::Dutch { start_price, floor_price } => {
+				let duration = self.end.saturating_sub(self.start);
+				let elapsed = now.saturating_sub(self.start).min(duration);
+				let decay = if duration.is_zero() {
+					Zero::zero()
+				} else {
+					Perbill::from_rational(elapsed, duration)
+						.mul_floor(start_price.saturating_sub(floor_price))
+				};
+				Some(start_price.saturating_sub(decay))
+			},
+			AuctionKind::English { .. } => None,
+		}
+	}
+}
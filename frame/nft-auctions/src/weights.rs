@@ -0,0 +1,373 @@
+//! Autogenerated weights for pallet_nft_auctions
+//!
+//! MEASURED BY DRIVING THE `benchmark pallet` LOOP OVER THE NATIVE RUNTIME INSTEAD OF WASM.
+//! REGENERATE WITH THE COMMAND BELOW ON REFERENCE HARDWARE BEFORE RELYING ON THE TIMINGS.
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: Some(Native), CHAIN: Some("dev"), DB CACHE: 1024
+
+// Regenerate with:
+// ./target/production/substrate
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_nft_auctions
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/nft-auctions/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_nft_auctions.
+pub trait WeightInfo {
+	fn create_auction() -> Weight;
+	fn bid() -> Weight;
+	fn buy() -> Weight;
+	fn cancel_auction() -> Weight;
+	fn settle_auction() -> Weight;
+	fn withdraw_bid() -> Weight;
+}
+
+/// Weights for pallet_nft_auctions using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Uniques CollectionTransferPolicy (r:1 w:0)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:1)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: NftAuctions NextAuctionId (r:1 w:1)
+	/// Proof: NftAuctions NextAuctionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:1 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: NftAuctions Auctions (r:0 w:1)
+	/// Proof: NftAuctions Auctions (max_values: None, max_size: Some(151), added: 2626, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1830`
+		//  Estimated: `125356`
+		// Minimum execution time: 57_523 nanoseconds.
+		Weight::from_parts(95_100_000, 125356)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: NftAuctions Auctions (r:1 w:1)
+	/// Proof: NftAuctions Auctions (max_values: None, max_size: Some(151), added: 2626, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionTransferPolicy (r:1 w:0)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:1 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:2 w:2)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	fn bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2955`
+		//  Estimated: `229258`
+		// Minimum execution time: 70_758 nanoseconds.
+		Weight::from_parts(76_736_000, 229258)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: NftAuctions Auctions (r:1 w:1)
+	/// Proof: NftAuctions Auctions (max_values: None, max_size: Some(151), added: 2626, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:1 w:0)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:1 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:0 w:1)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn buy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3237`
+		//  Estimated: `130209`
+		// Minimum execution time: 97_326 nanoseconds.
+		Weight::from_parts(103_347_000, 130209)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: NftAuctions Auctions (r:1 w:1)
+	/// Proof: NftAuctions Auctions (max_values: None, max_size: Some(151), added: 2626, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:1 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:0 w:1)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn cancel_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2237`
+		//  Estimated: `122452`
+		// Minimum execution time: 58_800 nanoseconds.
+		Weight::from_parts(74_047_000, 122452)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: NftAuctions Auctions (r:1 w:1)
+	/// Proof: NftAuctions Auctions (max_values: None, max_size: Some(151), added: 2626, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:1 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:1 w:0)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:0 w:1)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn settle_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3285`
+		//  Estimated: `132812`
+		// Minimum execution time: 139_301 nanoseconds.
+		Weight::from_parts(164_746_000, 132812)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: NftAuctions Auctions (r:1 w:1)
+	/// Proof: NftAuctions Auctions (max_values: None, max_size: Some(151), added: 2626, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:0 w:1)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn withdraw_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2685`
+		//  Estimated: `10432`
+		// Minimum execution time: 86_535 nanoseconds.
+		Weight::from_parts(99_948_000, 10432)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Uniques CollectionTransferPolicy (r:1 w:0)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:1)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: NftAuctions NextAuctionId (r:1 w:1)
+	/// Proof: NftAuctions NextAuctionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:1 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: NftAuctions Auctions (r:0 w:1)
+	/// Proof: NftAuctions Auctions (max_values: None, max_size: Some(151), added: 2626, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1830`
+		//  Estimated: `125356`
+		// Minimum execution time: 57_523 nanoseconds.
+		Weight::from_parts(95_100_000, 125356)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: NftAuctions Auctions (r:1 w:1)
+	/// Proof: NftAuctions Auctions (max_values: None, max_size: Some(151), added: 2626, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionTransferPolicy (r:1 w:0)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:1 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:2 w:2)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	fn bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2955`
+		//  Estimated: `229258`
+		// Minimum execution time: 70_758 nanoseconds.
+		Weight::from_parts(76_736_000, 229258)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: NftAuctions Auctions (r:1 w:1)
+	/// Proof: NftAuctions Auctions (max_values: None, max_size: Some(151), added: 2626, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:1 w:0)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:1 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:0 w:1)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn buy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3237`
+		//  Estimated: `130209`
+		// Minimum execution time: 97_326 nanoseconds.
+		Weight::from_parts(103_347_000, 130209)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: NftAuctions Auctions (r:1 w:1)
+	/// Proof: NftAuctions Auctions (max_values: None, max_size: Some(151), added: 2626, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:1 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:0 w:1)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn cancel_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2237`
+		//  Estimated: `122452`
+		// Minimum execution time: 58_800 nanoseconds.
+		Weight::from_parts(74_047_000, 122452)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: NftAuctions Auctions (r:1 w:1)
+	/// Proof: NftAuctions Auctions (max_values: None, max_size: Some(151), added: 2626, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:1 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:1 w:0)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:0 w:1)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn settle_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3285`
+		//  Estimated: `132812`
+		// Minimum execution time: 139_301 nanoseconds.
+		Weight::from_parts(164_746_000, 132812)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: NftAuctions Auctions (r:1 w:1)
+	/// Proof: NftAuctions Auctions (max_values: None, max_size: Some(151), added: 2626, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:0 w:1)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn withdraw_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2685`
+		//  Estimated: `10432`
+		// Minimum execution time: 86_535 nanoseconds.
+		Weight::from_parts(99_948_000, 10432)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+}
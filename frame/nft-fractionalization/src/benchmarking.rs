@@ -0,0 +1,130 @@
+//! Benchmarking setup for pallet-nft-fractionalization
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as NftFractionalization;
+
+use frame_benchmarking::v2::*;
+use frame_support::traits::Currency;
+use frame_system::RawOrigin;
+use pallet_uniques::BenchmarkHelper as _;
+use sp_runtime::traits::{Bounded, StaticLookup};
+
+const SEED: u32 = 0;
+
+type Uniques<T> = pallet_uniques::Pallet<T>;
+
+fn fund<T: Config>(who: &T::AccountId) {
+	CurrencyOf::<T>::make_free_balance_be(who, BalanceOf::<T>::max_value() / 4u32.into());
+}
+
+/// Mints an item of a new collection to `owner`.
+fn mint_item<T: Config>(owner: &T::AccountId) -> (T::CollectionId, T::ItemId) {
+	fund::<T>(owner);
+	let collection = <T as pallet_uniques::Config>::Helper::collection(0);
+	let item = <T as pallet_uniques::Config>::Helper::item(0);
+	let owner_lookup = T::Lookup::unlookup(owner.clone());
+	Uniques::<T>::force_create(RawOrigin::Root.into(), collection, owner_lookup.clone(), false)
+		.expect("collection is new");
+	Uniques::<T>::mint(RawOrigin::Signed(owner.clone()).into(), collection, item, owner_lookup)
+		.expect("item is new");
+	(collection, item)
+}
+
+/// Number of shares items are fractionalized into.
+fn shares<T: Config>() -> BalanceOf<T> {
+	100u32.into()
+}
+
+/// Fractionalizes a new item of `owner`, returning the asset class of its shares.
+fn fractionalize_item<T: Config>(owner: &T::AccountId) -> T::AssetId {
+	let (collection, item) = mint_item::<T>(owner);
+	let asset = T::BenchmarkHelper::asset(0);
+	NftFractionalization::<T>::fractionalize(
+		RawOrigin::Signed(owner.clone()).into(),
+		collection,
+		item,
+		asset,
+		shares::<T>(),
+	)
+	.expect("owner owns the item");
+	asset
+}
+
+/// Gives half the shares of `asset` of `owner` to a new buyer, who offers a buyout of the other
+/// half.
+fn offer_buyout_of_half<T: Config>(owner: &T::AccountId, asset: T::AssetId) -> T::AccountId {
+	let buyer: T::AccountId = account("buyer", 0, SEED);
+	fund::<T>(&buyer);
+	T::Assets::transfer(asset, owner, &buyer, shares::<T>() / 2u32.into(), false)
+		.expect("owner holds every share");
+	NftFractionalization::<T>::offer_buyout(
+		RawOrigin::Signed(buyer.clone()).into(),
+		asset,
+		CurrencyOf::<T>::minimum_balance(),
+	)
+	.expect("buyer holds shares");
+	buyer
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn fractionalize() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (collection, item) = mint_item::<T>(&caller);
+		let asset = T::BenchmarkHelper::asset(0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection, item, asset, shares::<T>());
+
+		assert!(Fractions::<T>::contains_key(asset));
+	}
+
+	/// Buys out an item at once, the caller holding every share.
+	#[benchmark]
+	fn offer_buyout() {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = fractionalize_item::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), asset, CurrencyOf::<T>::minimum_balance());
+
+		assert!(!Fractions::<T>::contains_key(asset));
+	}
+
+	/// Redeems the last shares the buyer doesn't hold, which completes the buyout.
+	#[benchmark]
+	fn redeem() {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = fractionalize_item::<T>(&caller);
+		offer_buyout_of_half::<T>(&caller, asset);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), asset);
+
+		assert!(!Fractions::<T>::contains_key(asset));
+	}
+
+	#[benchmark]
+	fn cancel_buyout() {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let asset = fractionalize_item::<T>(&owner);
+		let caller = offer_buyout_of_half::<T>(&owner, asset);
+		whitelist_account!(caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), asset);
+
+		assert_eq!(Fractions::<T>::get(asset).and_then(|fraction| fraction.buyout), None);
+	}
+
+	impl_benchmark_test_suite!(
+		NftFractionalization,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	);
+}
@@ -0,0 +1,375 @@
+//! # NFT Fractionalization Pallet
+//!
+//! Splits the items of `pallet_uniques` into fungible shares, so that several accounts can co-own
+//! an item.
+//!
+//! The owner of an item fractionalizes it with `fractionalize`, which moves the item to the
+//! account of the pallet and mints a new asset class of shares to the owner. The class is created
+//! by the pallet, so its supply can't change until the item is bought out. The pallet is the
+//! [`Locker`] of `pallet_uniques`, so that fractionalized items can't be transferred or burned,
+//! even by the admin of their collection, until they are bought out.
+//!
+//! Any holder of shares may offer to buy out every other share of the item with `offer_buyout`,
+//! at a reserve price per share, which is reserved from them for the shares they don't hold. Every
+//! other holder then redeems their shares for that price with `redeem`, the shares going to the
+//! buyer. Once the buyer holds every share, the shares are burned and the item is transferred to
+//! the buyer. A holder of every share buys out the item at once. A single buyout is in progress
+//! per item at a time, until it completes, its buyer cancels it, or a holder outbids it with a
+//! higher price per share. The shares redeemed to an outbid buyer stay with them.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `fractionalize` - Locks an item of the caller and mints its shares.
+//! * `offer_buyout` - Offers to buy every other share of an item.
+//! * `redeem` - Sells the shares of the caller to the buyout of an item.
+//! * `cancel_buyout` - Cancels the buyout of the caller, unreserving what is left of its price.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub mod weights;
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{
+		tokens::{
+			fungibles::{Create, Inspect, Mutate, Transfer},
+			Locker,
+		},
+		BalanceStatus, Get, ReservableCurrency,
+	},
+};
+use sp_runtime::{
+	traits::{AccountIdConversion, CheckedMul, One, Saturating, Zero},
+	ArithmeticError,
+};
+
+/// The currency buyouts are paid in, which is the currency of `pallet_uniques`.
+pub type CurrencyOf<T> = <T as pallet_uniques::Config>::Currency;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, PalletId};
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<AssetId> {
+		fn asset(i: u32) -> AssetId;
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	impl<AssetId: From<u32>> BenchmarkHelper<AssetId> for () {
+		fn asset(i: u32) -> AssetId {
+			i.into()
+		}
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_uniques::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier of an asset class of shares.
+		type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// The assets the shares of items are minted as, counted in the currency of buyouts.
+		type Assets: Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = BalanceOf<Self>>
+			+ Create<Self::AccountId>
+			+ Mutate<Self::AccountId>
+			+ Transfer<Self::AccountId>;
+
+		/// The pallet id, from which the account holding fractionalized items and administering
+		/// their shares is derived.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		/// A set of helper functions for benchmarking.
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+	}
+
+	/// Fractionalized items, by the asset class of their shares.
+	#[pallet::storage]
+	#[pallet::getter(fn fraction)]
+	pub type Fractions<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, FractionOf<T>>;
+
+	/// The asset class of the shares of fractionalized items, which are locked.
+	#[pallet::storage]
+	#[pallet::getter(fn fractionalized)]
+	pub type FractionalizedItems<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		T::AssetId,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An item was fractionalized into `shares` of `asset`, minted to its owner.
+		Fractionalized {
+			asset: T::AssetId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			owner: T::AccountId,
+			shares: BalanceOf<T>,
+		},
+		/// A holder offered to buy every other share of `asset` at `price` per share.
+		BuyoutOffered { asset: T::AssetId, buyer: T::AccountId, price: BalanceOf<T> },
+		/// A holder sold their `shares` of `asset` to the buyout for `payment`.
+		SharesRedeemed {
+			asset: T::AssetId,
+			holder: T::AccountId,
+			shares: BalanceOf<T>,
+			payment: BalanceOf<T>,
+		},
+		/// Every share of `asset` was redeemed and burned, and its item went to the buyer.
+		BuyoutCompleted {
+			asset: T::AssetId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			buyer: T::AccountId,
+		},
+		/// The buyout of `asset` was cancelled by its buyer.
+		BuyoutCancelled { asset: T::AssetId },
+		/// The buyout of `asset` by `buyer` was replaced by a buyout at a higher price.
+		BuyoutOutbid { asset: T::AssetId, buyer: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No item is fractionalized into the asset.
+		UnknownFraction,
+		/// The caller does not own the item, is not the buyer, or is the buyer.
+		NoPermission,
+		/// The item is fractionalized into zero shares.
+		NoShares,
+		/// The caller holds no share of the item.
+		NotHolder,
+		/// A buyout of the item at a price per share at least as high is in progress.
+		BuyoutTooLow,
+		/// No buyout of the item is in progress.
+		NoBuyout,
+		/// What is left reserved from the buyer does not pay for the shares, and the buyer can't
+		/// reserve the price of the shares they gave away since the offer.
+		InsufficientReserve,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Fractionalize an item of the caller into `shares` of a new asset class `asset`,
+		/// moving the item to the account of the pallet, which locks it.
+		///
+		/// - `collection`, `item`: the item to fractionalize.
+		/// - `asset`: the asset class of the shares, which must not exist yet.
+		/// - `shares`: the number of shares minted to the caller.
+		///
+		/// Emits `Fractionalized`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::fractionalize())]
+		pub fn fractionalize(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			asset: T::AssetId,
+			shares: BalanceOf<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			ensure!(!shares.is_zero(), Error::<T>::NoShares);
+			ensure!(
+				pallet_uniques::Pallet::<T>::owner(collection, item).as_ref() == Some(&owner),
+				Error::<T>::NoPermission
+			);
+			pallet_uniques::Pallet::<T>::do_transfer(
+				collection,
+				item,
+				Self::account_id(),
+				|_, _| Ok(()),
+			)?;
+
+			T::Assets::create(asset, Self::account_id(), false, One::one())?;
+			T::Assets::mint_into(asset, &owner, shares)?;
+			Fractions::<T>::insert(asset, Fraction { collection, item, buyout: None });
+			FractionalizedItems::<T>::insert(collection, item, asset);
+
+			Self::deposit_event(Event::Fractionalized { asset, collection, item, owner, shares });
+			Ok(())
+		}
+
+		/// Offer to buy every share of `asset` the caller doesn't hold at `price` per share,
+		/// reserving the price of those shares from the caller.
+		///
+		/// The caller must hold shares, and gets the item once every other share is redeemed, or
+		/// at once if the caller holds every share. The offer replaces the buyout in progress if
+		/// its price per share is higher, unreserving what is left of the price of that buyout.
+		///
+		/// Emits `BuyoutOffered`, `BuyoutOutbid` if it replaces a buyout, and `BuyoutCompleted` if
+		/// the caller holds every share.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::offer_buyout())]
+		pub fn offer_buyout(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+
+			let mut fraction = Fractions::<T>::get(asset).ok_or(Error::<T>::UnknownFraction)?;
+			ensure!(
+				fraction.buyout.as_ref().is_none_or(|current| price > current.price),
+				Error::<T>::BuyoutTooLow
+			);
+			let held = T::Assets::balance(asset, &buyer);
+			ensure!(!held.is_zero(), Error::<T>::NotHolder);
+
+			let reserved = price
+				.checked_mul(&T::Assets::total_issuance(asset).saturating_sub(held))
+				.ok_or(ArithmeticError::Overflow)?;
+			let outbid = fraction.buyout.replace(Buyout { buyer: buyer.clone(), price, reserved });
+			if let Some(outbid) = &outbid {
+				CurrencyOf::<T>::unreserve(&outbid.buyer, outbid.reserved);
+			}
+			CurrencyOf::<T>::reserve(&buyer, reserved)?;
+
+			if let Some(outbid) = outbid {
+				Self::deposit_event(Event::BuyoutOutbid { asset, buyer: outbid.buyer });
+			}
+			Self::deposit_event(Event::BuyoutOffered { asset, buyer, price });
+			Self::update_buyout(asset, fraction)
+		}
+
+		/// Sell every share of `asset` of the caller to its buyout, for the price per share
+		/// reserved from the buyer.
+		///
+		/// The price of the shares the buyer gave away since the offer is reserved from the buyer
+		/// first, so that every share the buyer doesn't hold is paid for.
+		///
+		/// Completes the buyout if these were the last shares the buyer didn't hold, burning
+		/// every share and transferring the item to the buyer.
+		///
+		/// Emits `SharesRedeemed`, and `BuyoutCompleted` if the buyout completes.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::redeem())]
+		pub fn redeem(origin: OriginFor<T>, asset: T::AssetId) -> DispatchResult {
+			let holder = ensure_signed(origin)?;
+
+			let mut fraction = Fractions::<T>::get(asset).ok_or(Error::<T>::UnknownFraction)?;
+			let buyout = fraction.buyout.as_mut().ok_or(Error::<T>::NoBuyout)?;
+			ensure!(holder != buyout.buyer, Error::<T>::NoPermission);
+			let shares = T::Assets::balance(asset, &holder);
+			ensure!(!shares.is_zero(), Error::<T>::NotHolder);
+
+			let outstanding = T::Assets::total_issuance(asset)
+				.saturating_sub(T::Assets::balance(asset, &buyout.buyer));
+			let owed = buyout.price.checked_mul(&outstanding).ok_or(ArithmeticError::Overflow)?;
+			if owed > buyout.reserved {
+				CurrencyOf::<T>::reserve(&buyout.buyer, owed - buyout.reserved)
+					.map_err(|_| Error::<T>::InsufficientReserve)?;
+				buyout.reserved = owed;
+			}
+			// The caller's shares are part of the outstanding ones.
+			let payment = buyout.price.saturating_mul(shares);
+			// Only what was slashed from the reserve since the offer is missed.
+			let missed = CurrencyOf::<T>::repatriate_reserved(
+				&buyout.buyer,
+				&holder,
+				payment,
+				BalanceStatus::Free,
+			)?;
+			ensure!(missed.is_zero(), Error::<T>::InsufficientReserve);
+			buyout.reserved.saturating_reduce(payment);
+			T::Assets::transfer(asset, &holder, &buyout.buyer, shares, false)?;
+
+			Self::deposit_event(Event::SharesRedeemed { asset, holder, shares, payment });
+			Self::update_buyout(asset, fraction)
+		}
+
+		/// Cancel the buyout of `asset` of the caller, unreserving what is left of its price.
+		///
+		/// The shares redeemed so far stay with the caller.
+		///
+		/// Emits `BuyoutCancelled`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_buyout())]
+		pub fn cancel_buyout(origin: OriginFor<T>, asset: T::AssetId) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+
+			Fractions::<T>::try_mutate(asset, |maybe_fraction| -> DispatchResult {
+				let fraction = maybe_fraction.as_mut().ok_or(Error::<T>::UnknownFraction)?;
+				let buyout = fraction.buyout.take().ok_or(Error::<T>::NoBuyout)?;
+				ensure!(buyout.buyer == buyer, Error::<T>::NoPermission);
+				CurrencyOf::<T>::unreserve(&buyer, buyout.reserved);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::BuyoutCancelled { asset });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account holding fractionalized items and administering their shares.
+	pub fn account_id() -> T::AccountId {
+		<T as Config>::PalletId::get().into_account_truncating()
+	}
+
+	/// Stores `fraction` of `asset`, or completes its buyout if the buyer holds every share,
+	/// burning the shares and transferring the item to the buyer.
+	fn update_buyout(asset: T::AssetId, fraction: FractionOf<T>) -> DispatchResult {
+		let Some(buyout) = &fraction.buyout else {
+			Fractions::<T>::insert(asset, fraction);
+			return Ok(())
+		};
+		let supply = T::Assets::total_issuance(asset);
+		if T::Assets::balance(asset, &buyout.buyer) < supply {
+			Fractions::<T>::insert(asset, fraction);
+			return Ok(())
+		}
+
+		T::Assets::burn_from(asset, &buyout.buyer, supply)?;
+		CurrencyOf::<T>::unreserve(&buyout.buyer, buyout.reserved);
+		let (collection, item) = (fraction.collection, fraction.item);
+		Fractions::<T>::remove(asset);
+		// Unlocks the item before transferring it.
+		FractionalizedItems::<T>::remove(collection, item);
+		pallet_uniques::Pallet::<T>::do_release(collection, item, buyout.buyer.clone())?;
+
+		Self::deposit_event(Event::BuyoutCompleted {
+			asset,
+			collection,
+			item,
+			buyer: buyout.buyer.clone(),
+		});
+		Ok(())
+	}
+}
+
+impl<T: Config> Locker<T::CollectionId, T::ItemId> for Pallet<T> {
+	fn is_locked(collection: T::CollectionId, item: T::ItemId) -> bool {
+		FractionalizedItems::<T>::contains_key(collection, item)
+	}
+}
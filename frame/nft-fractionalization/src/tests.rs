@@ -0,0 +1,349 @@
+//! Tests for the NFT fractionalization pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{tokens::nonfungibles, Currency},
+};
+
+fn owner() -> Option<u64> {
+	<Uniques as nonfungibles::Inspect<u64>>::owner(&COLLECTION, &ITEM)
+}
+
+fn shares(who: u64) -> u64 {
+	<Assets as Inspect<u64>>::balance(ASSET, &who)
+}
+
+/// Fractionalizes the item into 100 shares, of which `OWNER` gives 30 to `ALICE` and 20 to
+/// `BOB`.
+fn fractionalize() {
+	assert_ok!(NftFractionalization::fractionalize(
+		RuntimeOrigin::signed(OWNER),
+		COLLECTION,
+		ITEM,
+		ASSET,
+		100
+	));
+	assert_ok!(Assets::transfer(RuntimeOrigin::signed(OWNER), ASSET, ALICE, 30));
+	assert_ok!(Assets::transfer(RuntimeOrigin::signed(OWNER), ASSET, BOB, 20));
+	events();
+}
+
+#[test]
+fn fractionalize_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			NftFractionalization::fractionalize(
+				RuntimeOrigin::signed(OWNER),
+				COLLECTION,
+				ITEM,
+				ASSET,
+				0
+			),
+			Error::<Test>::NoShares
+		);
+		assert_noop!(
+			NftFractionalization::fractionalize(
+				RuntimeOrigin::signed(ALICE),
+				COLLECTION,
+				ITEM,
+				ASSET,
+				100
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(NftFractionalization::fractionalize(
+			RuntimeOrigin::signed(OWNER),
+			COLLECTION,
+			ITEM,
+			ASSET,
+			100
+		));
+		assert_eq!(
+			events(),
+			[Event::<Test>::Fractionalized {
+				asset: ASSET,
+				collection: COLLECTION,
+				item: ITEM,
+				owner: OWNER,
+				shares: 100,
+			}]
+		);
+		assert_eq!(owner(), Some(NftFractionalization::account_id()));
+		assert_eq!(shares(OWNER), 100);
+		assert_eq!(
+			NftFractionalization::fraction(ASSET),
+			Some(Fraction { collection: COLLECTION, item: ITEM, buyout: None })
+		);
+		assert_eq!(NftFractionalization::fractionalized(COLLECTION, ITEM), Some(ASSET));
+		// the item is locked, even for the admin of its collection, and only the pallet mints
+		// shares
+		assert_noop!(
+			Uniques::transfer(
+				RuntimeOrigin::signed(NftFractionalization::account_id()),
+				COLLECTION,
+				ITEM,
+				OWNER
+			),
+			pallet_uniques::Error::<Test>::Locked
+		);
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(CREATOR), COLLECTION, ITEM, CREATOR),
+			pallet_uniques::Error::<Test>::Locked
+		);
+		assert_noop!(
+			Uniques::burn(RuntimeOrigin::signed(CREATOR), COLLECTION, ITEM, None),
+			pallet_uniques::Error::<Test>::Locked
+		);
+		assert_noop!(
+			Assets::mint(RuntimeOrigin::signed(OWNER), ASSET, OWNER, 1),
+			pallet_assets::Error::<Test>::NoPermission
+		);
+
+		// the shares of every item are a new asset class
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(CREATOR), COLLECTION, ITEM + 1, OWNER));
+		assert_noop!(
+			NftFractionalization::fractionalize(
+				RuntimeOrigin::signed(OWNER),
+				COLLECTION,
+				ITEM + 1,
+				ASSET,
+				100
+			),
+			pallet_assets::Error::<Test>::InUse
+		);
+	});
+}
+
+#[test]
+fn buyout_should_work() {
+	new_test_ext().execute_with(|| {
+		fractionalize();
+
+		assert_noop!(
+			NftFractionalization::offer_buyout(RuntimeOrigin::signed(ALICE), ASSET + 1, 2),
+			Error::<Test>::UnknownFraction
+		);
+		assert_noop!(
+			NftFractionalization::offer_buyout(RuntimeOrigin::signed(CREATOR), ASSET, 2),
+			Error::<Test>::NotHolder
+		);
+		assert_noop!(
+			NftFractionalization::redeem(RuntimeOrigin::signed(OWNER), ASSET),
+			Error::<Test>::NoBuyout
+		);
+
+		// the 70 shares `ALICE` doesn't hold are paid 2 each
+		assert_ok!(NftFractionalization::offer_buyout(RuntimeOrigin::signed(ALICE), ASSET, 2));
+		assert_eq!(Balances::reserved_balance(ALICE), 140);
+		assert_eq!(
+			events(),
+			[Event::<Test>::BuyoutOffered { asset: ASSET, buyer: ALICE, price: 2 }]
+		);
+		assert_noop!(
+			NftFractionalization::offer_buyout(RuntimeOrigin::signed(BOB), ASSET, 2),
+			Error::<Test>::BuyoutTooLow
+		);
+		assert_noop!(
+			NftFractionalization::redeem(RuntimeOrigin::signed(ALICE), ASSET),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			NftFractionalization::redeem(RuntimeOrigin::signed(CREATOR), ASSET),
+			Error::<Test>::NotHolder
+		);
+
+		assert_ok!(NftFractionalization::redeem(RuntimeOrigin::signed(OWNER), ASSET));
+		assert_eq!(
+			events(),
+			[Event::<Test>::SharesRedeemed {
+				asset: ASSET,
+				holder: OWNER,
+				shares: 50,
+				payment: 100
+			}]
+		);
+		assert_eq!((shares(OWNER), shares(ALICE)), (0, 80));
+		assert_eq!(Balances::free_balance(OWNER), 200);
+		assert_eq!(owner(), Some(NftFractionalization::account_id()));
+
+		// the last shares complete the buyout
+		assert_ok!(NftFractionalization::redeem(RuntimeOrigin::signed(BOB), ASSET));
+		assert_eq!(
+			events(),
+			[
+				Event::<Test>::SharesRedeemed {
+					asset: ASSET,
+					holder: BOB,
+					shares: 20,
+					payment: 40
+				},
+				Event::<Test>::BuyoutCompleted {
+					asset: ASSET,
+					collection: COLLECTION,
+					item: ITEM,
+					buyer: ALICE,
+				},
+			]
+		);
+		assert_eq!(owner(), Some(ALICE));
+		assert_eq!(<Assets as Inspect<u64>>::total_issuance(ASSET), 0);
+		assert_eq!(NftFractionalization::fraction(ASSET), None);
+		assert_eq!(NftFractionalization::fractionalized(COLLECTION, ITEM), None);
+		assert_eq!(Balances::free_balance(BOB), 1_040);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 860);
+		// the item is unlocked
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(ALICE), COLLECTION, ITEM, BOB));
+	});
+}
+
+#[test]
+fn holder_of_every_share_buys_out_at_once() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(NftFractionalization::fractionalize(
+			RuntimeOrigin::signed(OWNER),
+			COLLECTION,
+			ITEM,
+			ASSET,
+			100
+		));
+		events();
+
+		assert_ok!(NftFractionalization::offer_buyout(RuntimeOrigin::signed(OWNER), ASSET, 5));
+		assert_eq!(
+			events(),
+			[
+				Event::<Test>::BuyoutOffered { asset: ASSET, buyer: OWNER, price: 5 },
+				Event::<Test>::BuyoutCompleted {
+					asset: ASSET,
+					collection: COLLECTION,
+					item: ITEM,
+					buyer: OWNER,
+				},
+			]
+		);
+		assert_eq!(owner(), Some(OWNER));
+		assert_eq!(shares(OWNER), 0);
+		assert_eq!(Balances::free_balance(OWNER), 100);
+	});
+}
+
+#[test]
+fn cancel_buyout_should_work() {
+	new_test_ext().execute_with(|| {
+		fractionalize();
+		assert_ok!(NftFractionalization::offer_buyout(RuntimeOrigin::signed(ALICE), ASSET, 2));
+		assert_ok!(NftFractionalization::redeem(RuntimeOrigin::signed(OWNER), ASSET));
+		events();
+
+		assert_noop!(
+			NftFractionalization::cancel_buyout(RuntimeOrigin::signed(BOB), ASSET),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(NftFractionalization::cancel_buyout(RuntimeOrigin::signed(ALICE), ASSET));
+		assert_eq!(events(), [Event::<Test>::BuyoutCancelled { asset: ASSET }]);
+		assert_noop!(
+			NftFractionalization::cancel_buyout(RuntimeOrigin::signed(ALICE), ASSET),
+			Error::<Test>::NoBuyout
+		);
+		// the redeemed shares stay with the buyer
+		assert_eq!(shares(ALICE), 80);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 900);
+
+		// another holder may offer a buyout then
+		assert_ok!(NftFractionalization::offer_buyout(RuntimeOrigin::signed(BOB), ASSET, 1));
+		assert_eq!(Balances::reserved_balance(BOB), 80);
+	});
+}
+
+#[test]
+fn higher_buyout_replaces_buyout_in_progress() {
+	new_test_ext().execute_with(|| {
+		fractionalize();
+		Balances::make_free_balance_be(&OWNER, 1_000);
+		assert_ok!(NftFractionalization::offer_buyout(RuntimeOrigin::signed(BOB), ASSET, 1));
+		assert_ok!(NftFractionalization::redeem(RuntimeOrigin::signed(ALICE), ASSET));
+		events();
+
+		assert_noop!(
+			NftFractionalization::offer_buyout(RuntimeOrigin::signed(OWNER), ASSET, 1),
+			Error::<Test>::BuyoutTooLow
+		);
+		// `ALICE` sold her shares to `BOB`, who holds 50 of them now
+		assert_noop!(
+			NftFractionalization::offer_buyout(RuntimeOrigin::signed(ALICE), ASSET, 2),
+			Error::<Test>::NotHolder
+		);
+		assert_ok!(NftFractionalization::offer_buyout(RuntimeOrigin::signed(OWNER), ASSET, 2));
+		assert_eq!(
+			events(),
+			[
+				Event::<Test>::BuyoutOutbid { asset: ASSET, buyer: BOB },
+				Event::<Test>::BuyoutOffered { asset: ASSET, buyer: OWNER, price: 2 },
+			]
+		);
+		// what is left of the outbid buyout is unreserved, the redeemed shares stay with `BOB`
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(BOB), 970);
+		assert_eq!(Balances::reserved_balance(OWNER), 100);
+
+		assert_ok!(NftFractionalization::redeem(RuntimeOrigin::signed(BOB), ASSET));
+		assert_eq!(owner(), Some(OWNER));
+		assert_eq!(Balances::free_balance(BOB), 1_070);
+		assert_eq!(Balances::reserved_balance(OWNER), 0);
+		assert_eq!(Balances::free_balance(OWNER), 900);
+	});
+}
+
+#[test]
+fn redeem_reserves_the_price_of_shares_given_away_by_the_buyer() {
+	new_test_ext().execute_with(|| {
+		fractionalize();
+		assert_ok!(NftFractionalization::offer_buyout(RuntimeOrigin::signed(ALICE), ASSET, 2));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(ALICE), ASSET, BOB, 10));
+
+		// the 10 shares given away by the buyer are paid for out of their free balance
+		let free = Balances::free_balance(ALICE);
+		Balances::make_free_balance_be(&ALICE, 19);
+		assert_noop!(
+			NftFractionalization::redeem(RuntimeOrigin::signed(OWNER), ASSET),
+			Error::<Test>::InsufficientReserve
+		);
+		Balances::make_free_balance_be(&ALICE, free);
+
+		assert_ok!(NftFractionalization::redeem(RuntimeOrigin::signed(OWNER), ASSET));
+		assert_eq!(
+			NftFractionalization::fraction(ASSET).and_then(|fraction| fraction.buyout),
+			Some(Buyout { buyer: ALICE, price: 2, reserved: 60 })
+		);
+		assert_eq!(Balances::free_balance(ALICE), free - 20);
+
+		events();
+		assert_ok!(NftFractionalization::redeem(RuntimeOrigin::signed(BOB), ASSET));
+		assert_eq!(
+			events(),
+			[
+				Event::<Test>::SharesRedeemed {
+					asset: ASSET,
+					holder: BOB,
+					shares: 30,
+					payment: 60
+				},
+				Event::<Test>::BuyoutCompleted {
+					asset: ASSET,
+					collection: COLLECTION,
+					item: ITEM,
+					buyer: ALICE,
+				},
+			]
+		);
+		assert_eq!(owner(), Some(ALICE));
+		assert_eq!(Balances::free_balance(BOB), 1_060);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), free - 20);
+	});
+}
@@ -0,0 +1,38 @@
+//! Types of the NFT fractionalization pallet.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::Currency, RuntimeDebug};
+use scale_info::TypeInfo;
+
+type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+/// Balance of the currency buyouts are paid in, which also counts shares.
+pub type BalanceOf<T> =
+	<<T as pallet_uniques::Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+/// Identifier of the collection of an item.
+pub type CollectionIdOf<T> = <T as pallet_uniques::Config>::CollectionId;
+/// Identifier of an item within its collection.
+pub type ItemIdOf<T> = <T as pallet_uniques::Config>::ItemId;
+/// A fractionalized item from this pallet's point of view.
+pub type FractionOf<T> = Fraction<AccountIdOf<T>, BalanceOf<T>, CollectionIdOf<T>, ItemIdOf<T>>;
+
+/// An offer of a holder to buy every other share of a fractionalized item.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Buyout<AccountId, Balance> {
+	/// The holder buying the shares, who gets the item.
+	pub buyer: AccountId,
+	/// The price paid for every share.
+	pub price: Balance,
+	/// What is left reserved from the buyer to pay for the shares not redeemed yet.
+	pub reserved: Balance,
+}
+
+/// An item held and frozen by the pallet, whose shares are an asset class.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Fraction<AccountId, Balance, CollectionId, ItemId> {
+	/// Collection of the item.
+	pub collection: CollectionId,
+	/// The item within its collection.
+	pub item: ItemId,
+	/// The buyout in progress, if any.
+	pub buyout: Option<Buyout<AccountId, Balance>>,
+}
@@ -0,0 +1,239 @@
+//! Autogenerated weights for pallet_nft_fractionalization
+//!
+//! MEASURED BY DRIVING THE `benchmark pallet` LOOP OVER THE NATIVE RUNTIME INSTEAD OF WASM.
+//! REGENERATE WITH THE COMMAND BELOW ON REFERENCE HARDWARE BEFORE RELYING ON THE TIMINGS.
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: Some(Native), CHAIN: Some("dev"), DB CACHE: 1024
+
+// Regenerate with:
+// ./target/production/substrate
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_nft_fractionalization
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/nft-fractionalization/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_nft_fractionalization.
+pub trait WeightInfo {
+	fn fractionalize() -> Weight;
+	fn offer_buyout() -> Weight;
+	fn redeem() -> Weight;
+	fn cancel_buyout() -> Weight;
+}
+
+/// Weights for pallet_nft_fractionalization using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:1)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:0)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionTransferPolicy (r:1 w:0)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization Fractions (r:0 w:1)
+	/// Proof: NftFractionalization Fractions (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn fractionalize() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2854`
+		//  Estimated: `18099`
+		// Minimum execution time: 72_035 nanoseconds.
+		Weight::from_parts(76_105_000, 18099)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: NftFractionalization Fractions (r:1 w:1)
+	/// Proof: NftFractionalization Fractions (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:0)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:0 w:1)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn offer_buyout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3209`
+		//  Estimated: `15636`
+		// Minimum execution time: 80_713 nanoseconds.
+		Weight::from_parts(87_342_000, 15636)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: NftFractionalization Fractions (r:1 w:1)
+	/// Proof: NftFractionalization Fractions (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:0)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:0 w:1)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3590`
+		//  Estimated: `20816`
+		// Minimum execution time: 118_912 nanoseconds.
+		Weight::from_parts(181_019_000, 20816)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: NftFractionalization Fractions (r:1 w:1)
+	/// Proof: NftFractionalization Fractions (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn cancel_buyout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1935`
+		//  Estimated: `2568`
+		// Minimum execution time: 38_395 nanoseconds.
+		Weight::from_parts(54_216_000, 2568)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:1)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:0)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionTransferPolicy (r:1 w:0)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization Fractions (r:0 w:1)
+	/// Proof: NftFractionalization Fractions (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn fractionalize() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2854`
+		//  Estimated: `18099`
+		// Minimum execution time: 72_035 nanoseconds.
+		Weight::from_parts(76_105_000, 18099)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: NftFractionalization Fractions (r:1 w:1)
+	/// Proof: NftFractionalization Fractions (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:0)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:0 w:1)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn offer_buyout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3209`
+		//  Estimated: `15636`
+		// Minimum execution time: 80_713 nanoseconds.
+		Weight::from_parts(87_342_000, 15636)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: NftFractionalization Fractions (r:1 w:1)
+	/// Proof: NftFractionalization Fractions (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:0)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:0 w:1)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3590`
+		//  Estimated: `20816`
+		// Minimum execution time: 118_912 nanoseconds.
+		Weight::from_parts(181_019_000, 20816)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: NftFractionalization Fractions (r:1 w:1)
+	/// Proof: NftFractionalization Fractions (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	fn cancel_buyout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1935`
+		//  Estimated: `2568`
+		// Minimum execution time: 38_395 nanoseconds.
+		Weight::from_parts(54_216_000, 2568)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
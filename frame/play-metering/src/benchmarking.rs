@@ -0,0 +1,300 @@
+//! Benchmarking setup for pallet-play-metering
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as PlayMetering;
+
+use frame_benchmarking::v2::*;
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+use sp_core::sr25519;
+use sp_runtime::traits::{Bounded, One};
+
+const SEED: u32 = 0;
+
+/// Endows `who`, the pot and the royalties account, so that payouts keep them alive and royalties
+/// below the existential deposit can be paid in.
+fn fund<T: Config>(who: &T::AccountId) {
+	<T as Config>::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 4u32.into());
+	<T as Config>::Currency::make_free_balance_be(
+		&PlayMetering::<T>::pot_account(),
+		BalanceOf::<T>::max_value() / 4u32.into(),
+	);
+	<T as pallet_royalties::Config>::Currency::make_free_balance_be(
+		&pallet_royalties::Pallet::<T>::account_id(),
+		<T as pallet_royalties::Config>::Currency::minimum_balance(),
+	);
+}
+
+/// Registers `n` tracks with a play each.
+fn tracks<T: Config>(n: u32) -> TrackPlaysOf<T> {
+	let tracks: Vec<_> = (0..n)
+		.map(|seed| {
+			let (collection, item) = <T as Config>::BenchmarkHelper::register_track(seed);
+			(collection, item, 1)
+		})
+		.collect();
+	tracks.try_into().expect("at most `MaxTracks` tracks")
+}
+
+/// A receipt of a play of `track` sent to `aggregator`, which is not signed.
+fn receipt<T: Config>(
+	aggregator: &T::AccountId,
+	(collection, item): (CollectionIdOf<T>, ItemIdOf<T>),
+	played_at: T::BlockNumber,
+) -> SignedReceiptOf<T> {
+	SignedReceipt {
+		receipt: ListenReceipt {
+			listener: sr25519::Public::from_raw([1; 32]),
+			aggregator: aggregator.clone(),
+			collection,
+			item,
+			played_at,
+			nonce: 0,
+		},
+		signature: sr25519::Signature::from_raw([0; 64]),
+	}
+}
+
+/// Arbitrary hashes of the inner nodes of a tree of `u32::MAX` leaves, from the bottom up, above
+/// the leaves 0 and 1.
+fn inner_nodes() -> Vec<H256> {
+	(1..MAX_PROOF_DEPTH).map(|level| H256::repeat_byte(level as u8)).collect()
+}
+
+/// Posts a batch of `u32::MAX` plays of one track from `aggregator` with `leaves` as its first
+/// receipts, returning the proofs of these receipts, which are as deep as proofs get.
+fn submit_deepest_batch<T: Config>(
+	aggregator: &T::AccountId,
+	track: (CollectionIdOf<T>, ItemIdOf<T>),
+	leaves: [H256; 2],
+) -> [Vec<H256>; 2] {
+	let proofs =
+		[[vec![leaves[1]], inner_nodes()].concat(), [vec![leaves[0]], inner_nodes()].concat()];
+	let root = merkle::root_from_proof(u32::MAX, 0, leaves[0], &proofs[0]).expect("fits the tree");
+	let tracks = vec![(track.0, track.1, u32::MAX)].try_into().expect("one track");
+	let now = frame_system::Pallet::<T>::block_number();
+	PlayMetering::<T>::submit_batch(
+		RawOrigin::Signed(aggregator.clone()).into(),
+		root,
+		tracks,
+		now,
+		now,
+	)
+	.expect("batch is valid");
+	proofs
+}
+
+/// Requests the reveal of the `r` receipts of `batch` from index `first` on, from a requester
+/// each.
+fn request_reveals<T: Config>(batch: BatchId, first: u32, r: u32) {
+	for index in first..first + r {
+		let requester: T::AccountId = account("requester", index, SEED);
+		fund::<T>(&requester);
+		PlayMetering::<T>::request_reveal(RawOrigin::Signed(requester).into(), batch, index)
+			.expect("batch is in its challenge period");
+	}
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn submit_batch(n: Linear<1, 16>) {
+		let n = n.min(T::MaxTracks::get());
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let tracks = tracks::<T>(n);
+		frame_system::Pallet::<T>::set_block_number(One::one());
+		let first_play = frame_system::Pallet::<T>::block_number();
+		// A previous batch of the caller ends before the new one.
+		LastPlay::<T>::insert(&caller, T::BlockNumber::zero());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), H256::zero(), tracks, first_play, first_play);
+
+		assert_eq!(LastPlay::<T>::get(&caller), Some(first_play));
+	}
+
+	/// Proves the signature of a receipt as deep as receipts get invalid, which checks the most,
+	/// returning the deposits of `r` pending reveals.
+	#[benchmark]
+	fn challenge_batch(r: Linear<0, 16>) {
+		let r = r.min(T::MaxReveals::get());
+		let aggregator: T::AccountId = account("aggregator", 0, SEED);
+		fund::<T>(&aggregator);
+		let track = <T as Config>::BenchmarkHelper::register_track(0);
+		let now = frame_system::Pallet::<T>::block_number();
+		let forged = receipt::<T>(&aggregator, track, now);
+		let [proof, _] =
+			submit_deepest_batch::<T>(&aggregator, track, [forged.leaf_hash(), H256::zero()]);
+		request_reveals::<T>(0, 2, r);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			0,
+			0,
+			Box::new(forged),
+			proof,
+			Box::new(Fraud::InvalidSignature),
+		);
+
+		assert!(Batches::<T>::get(0).is_none());
+	}
+
+	#[benchmark]
+	fn challenge_duplicate(r: Linear<0, 16>) {
+		let r = r.min(T::MaxReveals::get());
+		let aggregator: T::AccountId = account("aggregator", 0, SEED);
+		fund::<T>(&aggregator);
+		let track = <T as Config>::BenchmarkHelper::register_track(0);
+		let now = frame_system::Pallet::<T>::block_number();
+		let (first, second) = (
+			receipt::<T>(&aggregator, track, now),
+			receipt::<T>(&aggregator, track, now + One::one()),
+		);
+		let [first_proof, second_proof] =
+			submit_deepest_batch::<T>(&aggregator, track, [first.leaf_hash(), second.leaf_hash()]);
+		request_reveals::<T>(0, 2, r);
+		let caller: T::AccountId = whitelisted_caller();
+		let fraud = Fraud::Duplicate { index: 1, receipt: second, proof: second_proof };
+
+		#[extrinsic_call]
+		challenge_batch(
+			RawOrigin::Signed(caller),
+			0,
+			0,
+			Box::new(first),
+			first_proof,
+			Box::new(fraud),
+		);
+
+		assert!(Batches::<T>::get(0).is_none());
+	}
+
+	#[benchmark]
+	fn settle_batch(n: Linear<1, 16>) {
+		let n = n.min(T::MaxTracks::get());
+		let aggregator: T::AccountId = account("aggregator", 0, SEED);
+		fund::<T>(&aggregator);
+		let tracks = tracks::<T>(n);
+		let now = frame_system::Pallet::<T>::block_number();
+		PlayMetering::<T>::submit_batch(
+			RawOrigin::Signed(aggregator).into(),
+			H256::zero(),
+			tracks,
+			now,
+			now,
+		)
+		.expect("batch is valid");
+		frame_system::Pallet::<T>::set_block_number(now + T::ChallengePeriod::get());
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0);
+
+		assert!(Batches::<T>::get(0).is_none());
+	}
+
+	#[benchmark]
+	fn register_listener() {
+		let origin = T::ListenerOrigin::try_successful_origin()
+			.expect("ListenerOrigin has a successful origin");
+		let listener = sr25519::Public::from_raw([1; 32]);
+
+		#[block]
+		{
+			PlayMetering::<T>::register_listener(origin, listener, One::one())
+				.expect("ListenerOrigin may register listeners");
+		}
+
+		assert!(Listeners::<T>::contains_key(listener));
+	}
+
+	#[benchmark]
+	fn deregister_listener() {
+		let origin = T::ListenerOrigin::try_successful_origin()
+			.expect("ListenerOrigin has a successful origin");
+		let listener = sr25519::Public::from_raw([1; 32]);
+		Listeners::<T>::insert(listener, T::BlockNumber::one());
+
+		#[block]
+		{
+			PlayMetering::<T>::deregister_listener(origin, listener)
+				.expect("ListenerOrigin may deregister listeners");
+		}
+
+		assert!(!Listeners::<T>::contains_key(listener));
+	}
+
+	#[benchmark]
+	fn request_reveal() {
+		let aggregator: T::AccountId = account("aggregator", 0, SEED);
+		fund::<T>(&aggregator);
+		let track = <T as Config>::BenchmarkHelper::register_track(0);
+		submit_deepest_batch::<T>(&aggregator, track, [H256::zero(), H256::zero()]);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0, 0);
+
+		assert!(RevealRequests::<T>::contains_key(0, 0));
+	}
+
+	/// Reveals a receipt as deep as receipts get.
+	#[benchmark]
+	fn reveal_receipt() {
+		let aggregator: T::AccountId = account("aggregator", 0, SEED);
+		fund::<T>(&aggregator);
+		let track = <T as Config>::BenchmarkHelper::register_track(0);
+		let now = frame_system::Pallet::<T>::block_number();
+		let revealed = receipt::<T>(&aggregator, track, now);
+		let [proof, _] =
+			submit_deepest_batch::<T>(&aggregator, track, [revealed.leaf_hash(), H256::zero()]);
+		request_reveals::<T>(0, 0, 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(aggregator), 0, 0, Box::new(revealed), proof);
+
+		assert!(!RevealRequests::<T>::contains_key(0, 0));
+	}
+
+	/// Rejects a batch with `r` pending reveals, whose deposits are returned.
+	#[benchmark]
+	fn reject_unrevealed(r: Linear<1, 16>) {
+		let r = r.min(T::MaxReveals::get());
+		let aggregator: T::AccountId = account("aggregator", 0, SEED);
+		fund::<T>(&aggregator);
+		let track = <T as Config>::BenchmarkHelper::register_track(0);
+		submit_deepest_batch::<T>(&aggregator, track, [H256::zero(), H256::zero()]);
+		request_reveals::<T>(0, 0, r);
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + T::RevealPeriod::get());
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0, 0);
+
+		assert!(Batches::<T>::get(0).is_none());
+	}
+
+	#[benchmark]
+	fn pay_unpaid_plays() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let (collection, item) = <T as Config>::BenchmarkHelper::register_track(0);
+		UnpaidPlays::<T>::insert(collection, item, 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection, item);
+
+		assert_eq!(UnpaidPlays::<T>::get(collection, item), 0);
+	}
+
+	impl_benchmark_test_suite!(PlayMetering, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,717 @@
+//! # Play Metering Pallet
+//!
+//! Meters streamed plays of the works of `pallet_music_rights` without an extrinsic per play, and
+//! pays royalties for them through `pallet_royalties`.
+//!
+//! Players sign a [`ListenReceipt`] for every play with an sr25519 key of their listener, naming
+//! the aggregator they send it to. Only the keys of listeners registered by `ListenerOrigin`, e.g.
+//! the service collecting subscriptions, are paid for, until the end of their subscription.
+//! Aggregators post the receipts they collect in batches with `submit_batch`: the root of the
+//! [`merkle`] tree of the receipts, ordered by track, the number of plays of every track in that
+//! order, and the blocks of the first and last play. The windows of plays of the batches of an
+//! aggregator never overlap, so that a receipt can't be counted by two batches without one of them
+//! being provably fraudulent.
+//!
+//! Every batch reserves a deposit from its aggregator. Until its challenge period ends, anyone
+//! may prove a receipt of the batch fraudulent with `challenge_batch` and the Merkle proof of the
+//! receipt, which rejects the batch and pays the deposit to the challenger. The fraud proofs are:
+//!
+//! * the receipt is not signed by its listener,
+//! * the listener of the receipt was not registered when it was played,
+//! * the receipt names another aggregator,
+//! * the receipt lies among the receipts of another track, so that the counts are wrong,
+//! * the receipt was played outside the window of the batch,
+//! * another receipt of the batch is of the same play of the same listener.
+//!
+//! As only the root of the receipts is posted, anyone may also ask the aggregator to reveal the
+//! receipt at an index of the batch with `request_reveal`, reserving `RevealDeposit`. The receipt
+//! must then be revealed with `reveal_receipt` within `RevealPeriod`, which pays the deposit of the
+//! request to the aggregator and leaves `RevealPeriod` to challenge the receipt. Otherwise anyone
+//! may reject the batch with `reject_unrevealed`, paying its deposit to the requester.
+//!
+//! Once the challenge period is over and no reveal is pending, anyone may settle the batch with
+//! `settle_batch`, which returns the deposit and adds the plays of every track to its settled
+//! plays. Every settled play pays `PayoutPerPlay` of royalties for its track from the pot of the
+//! pallet, funded by whoever collects the revenue of streaming, e.g. subscriptions. Plays of tracks
+//! which are no longer registered works are counted but not paid. Plays the pot can't pay for, e.g.
+//! as it ran short, don't hold up the batch: they are carried over and paid with the next settled
+//! plays of their track, or by anyone with `pay_unpaid_plays` once the pot is funded again.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `submit_batch` - Posts a batch of plays.
+//! * `challenge_batch` - Proves a receipt of a batch fraudulent, rejecting it.
+//! * `settle_batch` - Settles a batch whose challenge period is over, paying its royalties.
+//! * `register_listener` - Registers the key of a listener until the end of its subscription.
+//! * `deregister_listener` - Removes the key of a listener.
+//! * `request_reveal` - Asks the aggregator of a batch to reveal one of its receipts.
+//! * `reveal_receipt` - Reveals a receipt of a batch whose reveal was requested.
+//! * `reject_unrevealed` - Rejects a batch which didn't reveal a receipt in time.
+//! * `pay_unpaid_plays` - Pays the royalties of the plays of a track carried over by settlements.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod merkle;
+mod types;
+pub mod weights;
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	storage::with_storage_layer,
+	traits::{BalanceStatus, Currency, ExistenceRequirement::KeepAlive, Get, ReservableCurrency},
+};
+use pallet_music_rights::WorkRights;
+use pallet_royalties::RoyaltyToken;
+use sp_core::{sr25519, H256};
+use sp_runtime::{
+	traits::{AccountIdConversion, SaturatedConversion, Saturating, Zero},
+	ArithmeticError, DispatchError,
+};
+use sp_std::prelude::*;
+
+/// Balance of the native currency, in which deposits and royalties are paid.
+pub type BalanceOf<T> = pallet_royalties::BalanceOf<T>;
+
+/// Maximum depth of the tree of a batch, which has at most `u32::MAX` receipts.
+pub const MAX_PROOF_DEPTH: u32 = 32;
+
+// The `Call` dispatch generated for `DispatchResultWithPostInfo` calls converts into its own type.
+#[allow(clippy::useless_conversion)]
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, PalletId};
+	use frame_system::pallet_prelude::*;
+
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<CollectionId, ItemId> {
+		/// Registers a work as a track, distinct for every `seed`.
+		fn register_track(seed: u32) -> (CollectionId, ItemId);
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_royalties::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The currency deposits are reserved in, which royalties are paid in.
+		type Currency: ReservableCurrency<Self::AccountId, Balance = BalanceOf<Self>>;
+
+		/// The pallet id, from which the account of the pot paying royalties is derived.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Deposit reserved from the aggregator of a batch until it is settled.
+		#[pallet::constant]
+		type BatchDeposit: Get<BalanceOf<Self>>;
+
+		/// Number of blocks after its submission during which a batch can be challenged.
+		#[pallet::constant]
+		type ChallengePeriod: Get<Self::BlockNumber>;
+
+		/// Royalties paid for a settled play.
+		#[pallet::constant]
+		type PayoutPerPlay: Get<BalanceOf<Self>>;
+
+		/// Maximum number of tracks of a batch.
+		#[pallet::constant]
+		type MaxTracks: Get<u32>;
+
+		/// Origin registering the keys of the listeners whose plays are paid for.
+		type ListenerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Deposit reserved from whoever asks for the reveal of a receipt, paid to the aggregator
+		/// if it is revealed.
+		#[pallet::constant]
+		type RevealDeposit: Get<BalanceOf<Self>>;
+
+		/// Number of blocks to reveal a requested receipt, and then to challenge it.
+		#[pallet::constant]
+		type RevealPeriod: Get<Self::BlockNumber>;
+
+		/// Maximum number of pending reveals of a batch.
+		#[pallet::constant]
+		type MaxReveals: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Registers the tracks played by the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::CollectionId, Self::ItemId>;
+	}
+
+	/// Identifier of the next batch.
+	#[pallet::storage]
+	pub type NextBatchId<T: Config> = StorageValue<_, BatchId, ValueQuery>;
+
+	/// Batches which are not settled or rejected yet.
+	#[pallet::storage]
+	#[pallet::getter(fn batch)]
+	pub type Batches<T: Config> = StorageMap<_, Blake2_128Concat, BatchId, Batch<T>>;
+
+	/// Block of the last play of the last batch of aggregators.
+	#[pallet::storage]
+	#[pallet::getter(fn last_play)]
+	pub type LastPlay<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+	/// Keys of registered listeners, with the block of the end of their subscription.
+	#[pallet::storage]
+	#[pallet::getter(fn listener)]
+	pub type Listeners<T: Config> =
+		StorageMap<_, Blake2_128Concat, sr25519::Public, T::BlockNumber>;
+
+	/// Pending reveals of receipts of batches, by batch and index of the receipt.
+	#[pallet::storage]
+	#[pallet::getter(fn reveal_request)]
+	pub type RevealRequests<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, BatchId, Twox64Concat, u32, RevealRequestOf<T>>;
+
+	/// Settled plays of tracks.
+	#[pallet::storage]
+	#[pallet::getter(fn settled_plays)]
+	pub type SettledPlays<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		u64,
+		ValueQuery,
+	>;
+
+	/// Settled plays of tracks whose royalties the pot could not pay yet.
+	#[pallet::storage]
+	#[pallet::getter(fn unpaid_plays)]
+	pub type UnpaidPlays<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		u64,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A batch of plays was posted.
+		BatchSubmitted { batch: BatchId, aggregator: T::AccountId, root: H256, plays: u32 },
+		/// A batch was proven fraudulent and its deposit paid to the challenger.
+		BatchRejected { batch: BatchId, challenger: T::AccountId, fraud: FraudKind },
+		/// The plays of a track in a batch were settled, paying `royalties` for them.
+		PlaysSettled {
+			batch: BatchId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			plays: u32,
+			royalties: BalanceOf<T>,
+		},
+		/// The royalties of the unpaid plays of a track, including those of `batch`, could not be
+		/// paid and were carried over.
+		PlaysCarriedOver {
+			batch: BatchId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			plays: u64,
+		},
+		/// A batch was settled.
+		BatchSettled { batch: BatchId },
+		/// The unpaid plays of a track were paid `royalties`.
+		UnpaidPlaysPaid {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			plays: u64,
+			royalties: BalanceOf<T>,
+		},
+		/// The key of a listener was registered until the end of its subscription.
+		ListenerRegistered { listener: sr25519::Public, until: T::BlockNumber },
+		/// The key of a listener was removed.
+		ListenerDeregistered { listener: sr25519::Public },
+		/// The aggregator of a batch was asked to reveal a receipt before `deadline`.
+		RevealRequested {
+			batch: BatchId,
+			index: u32,
+			requester: T::AccountId,
+			deadline: T::BlockNumber,
+		},
+		/// A requested receipt of a batch was revealed.
+		ReceiptRevealed { batch: BatchId, index: u32, receipt: SignedReceiptOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The batch does not exist.
+		UnknownBatch,
+		/// The batch has no tracks, or a track without plays.
+		NoPlays,
+		/// The batch has more than `u32::MAX` plays.
+		TooManyPlays,
+		/// A track of the batch is not a registered work.
+		UnknownTrack,
+		/// The first play of the batch is after its last play, or its last play is in the future.
+		InvalidWindow,
+		/// The window of the batch starts before the end of the previous batch of the aggregator.
+		OverlappingWindow,
+		/// The challenge period of the batch is over.
+		ChallengePeriodOver,
+		/// The challenge period of the batch is not over yet.
+		ChallengePeriodNotOver,
+		/// The proof is not the proof of the receipt in the batch.
+		InvalidProof,
+		/// The receipt is not fraudulent in the claimed way.
+		NotFraudulent,
+		/// The batch has no receipt at the index.
+		UnknownReceipt,
+		/// The reveal of the receipt was already requested.
+		RevealAlreadyRequested,
+		/// The batch has `MaxReveals` pending reveals already.
+		TooManyReveals,
+		/// The reveal of the receipt was not requested.
+		UnknownRevealRequest,
+		/// The receipt can still be revealed.
+		RevealPeriodNotOver,
+		/// The batch has pending reveals.
+		RevealsPending,
+		/// The listener is not registered.
+		UnknownListener,
+		/// The track has no unpaid plays.
+		NoUnpaidPlays,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Post a batch of plays, reserving `BatchDeposit` from the caller.
+		///
+		/// - `root`: root of the tree of the receipts of the batch, ordered by track.
+		/// - `tracks`: tracks of the batch with their number of plays, in the order of their
+		///   receipts.
+		/// - `first_play`, `last_play`: blocks of the first and last play of the batch, after the
+		///   last play of the previous batch of the caller.
+		///
+		/// Emits `BatchSubmitted`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::submit_batch(tracks.len() as u32))]
+		pub fn submit_batch(
+			origin: OriginFor<T>,
+			root: H256,
+			tracks: TrackPlaysOf<T>,
+			first_play: T::BlockNumber,
+			last_play: T::BlockNumber,
+		) -> DispatchResult {
+			let aggregator = ensure_signed(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(first_play <= last_play && last_play <= now, Error::<T>::InvalidWindow);
+			ensure!(
+				LastPlay::<T>::get(&aggregator).is_none_or(|last| first_play > last),
+				Error::<T>::OverlappingWindow
+			);
+			ensure!(!tracks.is_empty(), Error::<T>::NoPlays);
+			let mut plays = 0u32;
+			for (collection, item, track_plays) in tracks.iter() {
+				ensure!(*track_plays > 0, Error::<T>::NoPlays);
+				ensure!(
+					T::Rights::holder_shares(collection, item).is_some(),
+					Error::<T>::UnknownTrack
+				);
+				plays = plays.checked_add(*track_plays).ok_or(Error::<T>::TooManyPlays)?;
+			}
+
+			let deposit = T::BatchDeposit::get();
+			<T as Config>::Currency::reserve(&aggregator, deposit)?;
+			let batch = NextBatchId::<T>::get();
+			NextBatchId::<T>::put(batch.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+			Batches::<T>::insert(
+				batch,
+				Batch {
+					aggregator: aggregator.clone(),
+					root,
+					plays,
+					tracks,
+					first_play,
+					last_play,
+					submitted_at: now,
+					challenge_end: now.saturating_add(T::ChallengePeriod::get()),
+					pending_reveals: 0,
+					deposit,
+				},
+			);
+			LastPlay::<T>::insert(&aggregator, last_play);
+
+			Self::deposit_event(Event::BatchSubmitted { batch, aggregator, root, plays });
+			Ok(())
+		}
+
+		/// Prove the receipt at `index` of a batch in its challenge period fraudulent, rejecting
+		/// the batch and paying its deposit to the caller.
+		///
+		/// - `receipt`: the receipt at `index`.
+		/// - `proof`: the Merkle proof of the receipt in the tree of the batch.
+		/// - `fraud`: why the receipt is fraudulent.
+		///
+		/// Emits `BatchRejected`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(match **fraud {
+			Fraud::Duplicate { .. } =>
+				<T as Config>::WeightInfo::challenge_duplicate(T::MaxReveals::get()),
+			_ => <T as Config>::WeightInfo::challenge_batch(T::MaxReveals::get()),
+		})]
+		pub fn challenge_batch(
+			origin: OriginFor<T>,
+			batch: BatchId,
+			index: u32,
+			receipt: Box<SignedReceiptOf<T>>,
+			proof: Vec<H256>,
+			fraud: Box<FraudOf<T>>,
+		) -> DispatchResult {
+			let challenger = ensure_signed(origin)?;
+
+			let details = Batches::<T>::get(batch).ok_or(Error::<T>::UnknownBatch)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < details.challenge_end, Error::<T>::ChallengePeriodOver);
+			Self::ensure_in_batch(&details, index, &receipt, &proof)?;
+
+			let kind = Self::check_fraud(&details, index, &receipt, *fraud)?;
+
+			Self::reject(batch, details, challenger, kind);
+			Ok(())
+		}
+
+		/// Settle a batch whose challenge period is over, returning the deposit of its aggregator
+		/// and paying royalties for its plays from the pot, along with the unpaid plays of its
+		/// tracks.
+		///
+		/// The plays of a track whose royalties can't be paid, e.g. as the pot ran short, are
+		/// carried over without failing the settlement of the others.
+		///
+		/// Emits `PlaysSettled` for every track, preceded by `PlaysCarriedOver` if its plays were
+		/// carried over, and `BatchSettled`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::settle_batch(T::MaxTracks::get()))]
+		pub fn settle_batch(origin: OriginFor<T>, batch: BatchId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let details = Batches::<T>::get(batch).ok_or(Error::<T>::UnknownBatch)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= details.challenge_end, Error::<T>::ChallengePeriodNotOver);
+			ensure!(details.pending_reveals == 0, Error::<T>::RevealsPending);
+
+			for (collection, item, plays) in details.tracks.iter().copied() {
+				SettledPlays::<T>::mutate(collection, item, |settled| {
+					*settled = settled.saturating_add(plays as u64)
+				});
+				let royalties = if T::Rights::holder_shares(&collection, &item).is_none() {
+					Zero::zero()
+				} else {
+					let unpaid =
+						UnpaidPlays::<T>::take(collection, item).saturating_add(plays as u64);
+					match with_storage_layer(|| Self::pay_royalties(collection, item, unpaid)) {
+						Ok(royalties) => royalties,
+						Err(_) => {
+							UnpaidPlays::<T>::insert(collection, item, unpaid);
+							Self::deposit_event(Event::PlaysCarriedOver {
+								batch,
+								collection,
+								item,
+								plays: unpaid,
+							});
+							Zero::zero()
+						},
+					}
+				};
+				Self::deposit_event(Event::PlaysSettled {
+					batch,
+					collection,
+					item,
+					plays,
+					royalties,
+				});
+			}
+
+			<T as Config>::Currency::unreserve(&details.aggregator, details.deposit);
+			Batches::<T>::remove(batch);
+
+			Self::deposit_event(Event::BatchSettled { batch });
+			Ok(Some(<T as Config>::WeightInfo::settle_batch(details.tracks.len() as u32)).into())
+		}
+
+		/// Register the key of a listener, whose plays until `until` are paid for.
+		///
+		/// Origin must be `ListenerOrigin`. Registering a key again replaces the end of its
+		/// subscription.
+		///
+		/// Emits `ListenerRegistered`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_listener())]
+		pub fn register_listener(
+			origin: OriginFor<T>,
+			listener: sr25519::Public,
+			until: T::BlockNumber,
+		) -> DispatchResult {
+			T::ListenerOrigin::ensure_origin(origin)?;
+
+			Listeners::<T>::insert(listener, until);
+
+			Self::deposit_event(Event::ListenerRegistered { listener, until });
+			Ok(())
+		}
+
+		/// Remove the key of a listener, whose receipts are fraudulent from then on.
+		///
+		/// Origin must be `ListenerOrigin`. The plays of the listener should be settled first.
+		///
+		/// Emits `ListenerDeregistered`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::deregister_listener())]
+		pub fn deregister_listener(
+			origin: OriginFor<T>,
+			listener: sr25519::Public,
+		) -> DispatchResult {
+			T::ListenerOrigin::ensure_origin(origin)?;
+
+			ensure!(Listeners::<T>::take(listener).is_some(), Error::<T>::UnknownListener);
+
+			Self::deposit_event(Event::ListenerDeregistered { listener });
+			Ok(())
+		}
+
+		/// Ask the aggregator of a batch in its challenge period to reveal the receipt at
+		/// `index` within `RevealPeriod`, reserving `RevealDeposit` from the caller.
+		///
+		/// Emits `RevealRequested`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::request_reveal())]
+		pub fn request_reveal(origin: OriginFor<T>, batch: BatchId, index: u32) -> DispatchResult {
+			let requester = ensure_signed(origin)?;
+
+			let mut details = Batches::<T>::get(batch).ok_or(Error::<T>::UnknownBatch)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < details.challenge_end, Error::<T>::ChallengePeriodOver);
+			ensure!(index < details.plays, Error::<T>::UnknownReceipt);
+			ensure!(
+				!RevealRequests::<T>::contains_key(batch, index),
+				Error::<T>::RevealAlreadyRequested
+			);
+			ensure!(details.pending_reveals < T::MaxReveals::get(), Error::<T>::TooManyReveals);
+
+			let deposit = T::RevealDeposit::get();
+			<T as Config>::Currency::reserve(&requester, deposit)?;
+			let deadline = now.saturating_add(T::RevealPeriod::get());
+			RevealRequests::<T>::insert(
+				batch,
+				index,
+				RevealRequest { requester: requester.clone(), deadline, deposit },
+			);
+			details.pending_reveals.saturating_inc();
+			Batches::<T>::insert(batch, details);
+
+			Self::deposit_event(Event::RevealRequested { batch, index, requester, deadline });
+			Ok(())
+		}
+
+		/// Reveal the receipt at `index` of a batch, whose reveal was requested, with its Merkle
+		/// proof. The deposit of the request is paid to the aggregator of the batch, and the
+		/// receipt can be challenged for `RevealPeriod` from then on.
+		///
+		/// Emits `ReceiptRevealed`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::reveal_receipt())]
+		pub fn reveal_receipt(
+			origin: OriginFor<T>,
+			batch: BatchId,
+			index: u32,
+			receipt: Box<SignedReceiptOf<T>>,
+			proof: Vec<H256>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let mut details = Batches::<T>::get(batch).ok_or(Error::<T>::UnknownBatch)?;
+			let request =
+				RevealRequests::<T>::get(batch, index).ok_or(Error::<T>::UnknownRevealRequest)?;
+			Self::ensure_in_batch(&details, index, &receipt, &proof)?;
+
+			RevealRequests::<T>::remove(batch, index);
+			let now = frame_system::Pallet::<T>::block_number();
+			details.pending_reveals.saturating_dec();
+			details.challenge_end =
+				details.challenge_end.max(now.saturating_add(T::RevealPeriod::get()));
+			// A requester whose reserve was slashed since pays the aggregator what is left.
+			let _ = <T as Config>::Currency::repatriate_reserved(
+				&request.requester,
+				&details.aggregator,
+				request.deposit,
+				BalanceStatus::Free,
+			);
+			Batches::<T>::insert(batch, details);
+
+			Self::deposit_event(Event::ReceiptRevealed { batch, index, receipt: *receipt });
+			Ok(())
+		}
+
+		/// Reject a batch whose receipt at `index` was not revealed in time, paying its deposit
+		/// to the requester of the reveal.
+		///
+		/// Emits `BatchRejected`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::reject_unrevealed(T::MaxReveals::get()))]
+		pub fn reject_unrevealed(
+			origin: OriginFor<T>,
+			batch: BatchId,
+			index: u32,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let details = Batches::<T>::get(batch).ok_or(Error::<T>::UnknownBatch)?;
+			let request =
+				RevealRequests::<T>::get(batch, index).ok_or(Error::<T>::UnknownRevealRequest)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= request.deadline, Error::<T>::RevealPeriodNotOver);
+
+			Self::reject(batch, details, request.requester, FraudKind::Unrevealed);
+			Ok(())
+		}
+
+		/// Pay the royalties of the plays of a track which settlements carried over, from the pot.
+		///
+		/// Emits `UnpaidPlaysPaid`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::pay_unpaid_plays())]
+		pub fn pay_unpaid_plays(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let plays = UnpaidPlays::<T>::take(collection, item);
+			ensure!(plays > 0, Error::<T>::NoUnpaidPlays);
+			let royalties = Self::pay_royalties(collection, item, plays)?;
+
+			Self::deposit_event(Event::UnpaidPlaysPaid { collection, item, plays, royalties });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account of the pot paying royalties for settled plays.
+	///
+	/// It must hold the existential deposit, as payouts keep it alive.
+	pub fn pot_account() -> T::AccountId {
+		<T as Config>::PalletId::get().into_account_truncating()
+	}
+
+	/// Funds the pot with `amount` from `who`.
+	pub fn fund_pot(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		<T as Config>::Currency::transfer(who, &Self::pot_account(), amount, KeepAlive)
+	}
+
+	/// Pays the royalties of `plays` plays of the work held as `item` of `collection` from the
+	/// pot, returning them.
+	fn pay_royalties(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		plays: u64,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let royalties = T::PayoutPerPlay::get().saturating_mul(plays.saturated_into());
+		if !royalties.is_zero() {
+			pallet_royalties::Pallet::<T>::do_deposit(
+				&Self::pot_account(),
+				collection,
+				item,
+				RoyaltyToken::Native,
+				royalties,
+			)?;
+		}
+		Ok(royalties)
+	}
+
+	/// Rejects `batch`, paying the deposit of its aggregator to `challenger` and returning the
+	/// deposits of its pending reveals.
+	fn reject(batch: BatchId, details: Batch<T>, challenger: T::AccountId, kind: FraudKind) {
+		Batches::<T>::remove(batch);
+		for (_, request) in RevealRequests::<T>::drain_prefix(batch) {
+			<T as Config>::Currency::unreserve(&request.requester, request.deposit);
+		}
+		// The challenger gets whatever is left of the deposit if the aggregator was slashed since,
+		// which doesn't make the batch any less fraudulent.
+		let _ = <T as Config>::Currency::repatriate_reserved(
+			&details.aggregator,
+			&challenger,
+			details.deposit,
+			BalanceStatus::Free,
+		);
+
+		Self::deposit_event(Event::BatchRejected { batch, challenger, fraud: kind });
+	}
+
+	fn ensure_in_batch(
+		batch: &Batch<T>,
+		index: u32,
+		receipt: &SignedReceiptOf<T>,
+		proof: &[H256],
+	) -> DispatchResult {
+		ensure!(proof.len() as u32 <= MAX_PROOF_DEPTH, Error::<T>::InvalidProof);
+		ensure!(
+			merkle::verify_proof(&batch.root, batch.plays, index, receipt.leaf_hash(), proof),
+			Error::<T>::InvalidProof
+		);
+		Ok(())
+	}
+
+	/// Checks that the receipt at `index` of `batch` is fraudulent as claimed by `fraud`.
+	fn check_fraud(
+		batch: &Batch<T>,
+		index: u32,
+		receipt: &SignedReceiptOf<T>,
+		fraud: FraudOf<T>,
+	) -> Result<FraudKind, DispatchError> {
+		let play = &receipt.receipt;
+		let (kind, fraudulent) = match fraud {
+			Fraud::InvalidSignature => (FraudKind::InvalidSignature, !receipt.verify()),
+			Fraud::UnknownListener => (
+				FraudKind::UnknownListener,
+				Listeners::<T>::get(play.listener).is_none_or(|until| play.played_at > until),
+			),
+			Fraud::WrongAggregator =>
+				(FraudKind::WrongAggregator, play.aggregator != batch.aggregator),
+			Fraud::WrongTrack =>
+				(FraudKind::WrongTrack, batch.track_at(index) != Some((play.collection, play.item))),
+			Fraud::OutOfWindow => (
+				FraudKind::OutOfWindow,
+				play.played_at < batch.first_play || play.played_at > batch.last_play,
+			),
+			Fraud::Duplicate { index: other_index, receipt: other, proof } => {
+				Self::ensure_in_batch(batch, other_index, &other, &proof)?;
+				let other = &other.receipt;
+				(
+					FraudKind::Duplicate,
+					other_index != index &&
+						other.listener == play.listener &&
+						other.nonce == play.nonce,
+				)
+			},
+		};
+		ensure!(fraudulent, Error::<T>::NotFraudulent);
+		Ok(kind)
+	}
+}
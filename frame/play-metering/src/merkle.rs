@@ -0,0 +1,94 @@
+//! Ordered binary Merkle trees of receipts.
+//!
+//! Unlike the trees of `binary-merkle-tree`, which sort the hashes of every pair, a node hashes its
+//! children in order, so that a proof also proves the position of its leaf, which fraud proofs
+//! rely on. The last node of a layer with an odd number of nodes is promoted to the next layer as
+//! is.
+//!
+//! Aggregators build the trees of their batches with [`merkle_root`] and challengers the proofs of
+//! receipts with [`merkle_proof`], from the [`leaf_hash`] of every receipt.
+
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+use sp_std::prelude::*;
+
+/// Hash of a leaf holding `data`.
+pub fn leaf_hash(data: &[u8]) -> H256 {
+	blake2_256(data).into()
+}
+
+fn hash_pair(left: &H256, right: &H256) -> H256 {
+	let mut combined = [0u8; 64];
+	combined[..32].copy_from_slice(left.as_bytes());
+	combined[32..].copy_from_slice(right.as_bytes());
+	blake2_256(&combined).into()
+}
+
+fn next_layer(layer: &[H256]) -> Vec<H256> {
+	layer
+		.chunks(2)
+		.map(|pair| pair.get(1).map_or(pair[0], |right| hash_pair(&pair[0], right)))
+		.collect()
+}
+
+/// Root of the tree of the leaves with hashes `leaves`, or zero if there are none.
+pub fn merkle_root(leaves: &[H256]) -> H256 {
+	let mut layer = leaves.to_vec();
+	while layer.len() > 1 {
+		layer = next_layer(&layer);
+	}
+	layer.first().copied().unwrap_or_default()
+}
+
+/// Proof of the leaf at `index` of the tree of the leaves with hashes `leaves`: the siblings of
+/// the nodes on its path to the root, from the bottom up.
+pub fn merkle_proof(leaves: &[H256], index: usize) -> Vec<H256> {
+	let mut proof = Vec::new();
+	let mut layer = leaves.to_vec();
+	let mut position = index;
+	while layer.len() > 1 {
+		if let Some(sibling) = layer.get(position ^ 1) {
+			proof.push(*sibling);
+		}
+		layer = next_layer(&layer);
+		position /= 2;
+	}
+	proof
+}
+
+/// Root of a tree of `number_of_leaves` leaves whose leaf at `index` has hash `leaf` and `proof`,
+/// or `None` if the proof does not fit the shape of the tree.
+pub fn root_from_proof(
+	number_of_leaves: u32,
+	index: u32,
+	leaf: H256,
+	proof: &[H256],
+) -> Option<H256> {
+	if index >= number_of_leaves {
+		return None
+	}
+	let mut siblings = proof.iter();
+	let (mut hash, mut position, mut width) = (leaf, index, number_of_leaves);
+	while width > 1 {
+		if position % 2 == 1 {
+			hash = hash_pair(siblings.next()?, &hash);
+		} else if position + 1 < width {
+			hash = hash_pair(&hash, siblings.next()?);
+		}
+		position /= 2;
+		width = width.div_ceil(2);
+	}
+	siblings.next().is_none().then_some(hash)
+}
+
+/// Whether `proof` proves that the leaf at `index` of the tree of `number_of_leaves` leaves with
+/// root `root` has hash `leaf`.
+pub fn verify_proof(
+	root: &H256,
+	number_of_leaves: u32,
+	index: u32,
+	leaf: H256,
+	proof: &[H256],
+) -> bool {
+	root_from_proof(number_of_leaves, index, leaf, proof).as_ref() == Some(root)
+}
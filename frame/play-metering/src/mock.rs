@@ -0,0 +1,284 @@
+//! Test environment for the play metering pallet.
+
+use super::*;
+use crate as pallet_play_metering;
+
+use frame_support::{
+	assert_ok, construct_runtime, parameter_types,
+	traits::{tokens::nonfungibles::Mutate, AsEnsureOriginWithArg, ConstU32, ConstU64},
+	PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use pallet_music_rights::{SplitEditor, SplitNode, SplitTreeOf, WorkKind, WorkMetadata};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	PerThing, Perbill,
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		Uniques: pallet_uniques,
+		MusicRights: pallet_music_rights,
+		Royalties: pallet_royalties,
+		PlayMetering: pallet_play_metering,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type WeightInfo = ();
+	type CallbackHandle = ();
+	type Extra = ();
+	type RemoveItemsLimit = ConstU32<5>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub const UniquesMaxRoyalty: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(50);
+}
+
+impl pallet_uniques::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type Locker = ();
+	type OnBurn = ();
+	type CollectionDeposit = ConstU64<2>;
+	type ItemDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type AttributeDepositBase = ConstU64<1>;
+	type DepositPerByte = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type KeyLimit = ConstU32<50>;
+	type ValueLimit = ConstU32<50>;
+	type MaxRoyalty = UniquesMaxRoyalty;
+	type MaxOffers = ConstU32<10>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
+impl pallet_music_rights::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Items = Uniques;
+	type MaxSplitNodes = ConstU32<16>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MusicRightsHelper;
+}
+
+parameter_types! {
+	pub const RoyaltiesPalletId: PalletId = PalletId(*b"py/roylt");
+	pub const PlayMeteringPalletId: PalletId = PalletId(*b"py/plays");
+}
+
+impl pallet_royalties::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Rights = MusicRights;
+	type Currency = Balances;
+	type Assets = Assets;
+	type PalletId = RoyaltiesPalletId;
+	type MaxHolders = ConstU32<16>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MusicRightsHelper;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type PalletId = PlayMeteringPalletId;
+	type BatchDeposit = ConstU64<100>;
+	type ChallengePeriod = ConstU64<10>;
+	type PayoutPerPlay = ConstU64<2>;
+	type MaxTracks = ConstU32<16>;
+	type ListenerOrigin = EnsureRoot<u64>;
+	type RevealDeposit = ConstU64<10>;
+	type RevealPeriod = ConstU64<5>;
+	type MaxReveals = ConstU32<2>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MusicRightsHelper;
+}
+
+/// Owner of the items works are registered as.
+pub(crate) const OWNER: u64 = 100;
+/// Aggregator posting batches.
+pub(crate) const AGGREGATOR: u64 = 1;
+/// Account challenging and settling batches.
+pub(crate) const WATCHER: u64 = 2;
+/// Account funding the pot.
+pub(crate) const FUNDER: u64 = 50;
+/// Holders of the works registered with `register_track`.
+pub(crate) const HOLDERS: [u64; 2] = [10, 11];
+
+/// The split tree sharing a work equally between `holders`, the first one keeping the remainder.
+pub(crate) fn equal_splits(holders: &[u64]) -> SplitTreeOf<Test> {
+	let share = Perbill::from_rational(1, holders.len() as u32);
+	let first =
+		Perbill::from_parts(Perbill::ACCURACY - share.deconstruct() * (holders.len() as u32 - 1));
+	let nodes: Vec<_> = holders
+		.iter()
+		.enumerate()
+		.map(|(index, holder)| SplitNode {
+			holder: *holder,
+			share: if index == 0 { first } else { share },
+			parent: None,
+		})
+		.collect();
+	nodes.try_into().unwrap()
+}
+
+/// Registers item 0 of a new collection `collection` as a work with `splits`.
+pub(crate) fn register_work(collection: u32, splits: SplitTreeOf<Test>) {
+	assert_ok!(Uniques::force_create(RuntimeOrigin::root(), collection, OWNER, true));
+	assert_ok!(Uniques::mint_into(&collection, &0, &OWNER));
+	assert_ok!(MusicRights::register_work(
+		RuntimeOrigin::signed(OWNER),
+		collection,
+		0,
+		WorkMetadata { kind: WorkKind::Track, isrc: None, iswc: None },
+		splits,
+		SplitEditor::Account(OWNER),
+	));
+}
+
+/// Registers item 0 of a new collection `collection` as a track shared equally by `HOLDERS`.
+pub(crate) fn register_track(collection: u32) {
+	register_work(collection, equal_splits(&HOLDERS));
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MusicRightsHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<u32, u32> for MusicRightsHelper {
+	fn register_track(seed: u32) -> (u32, u32) {
+		Balances::make_free_balance_be(&OWNER, 1_000_000);
+		register_track(seed);
+		(seed, 0)
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_royalties::BenchmarkHelper<u64, u32, u32> for MusicRightsHelper {
+	fn register_work(holders: &[u64]) -> (u32, u32) {
+		Balances::make_free_balance_be(&OWNER, 1_000);
+		register_work(0, equal_splits(holders));
+		(0, 0)
+	}
+
+	fn set_holders(collection: &u32, _item: &u32, holders: &[u64]) {
+		assert_ok!(MusicRights::propose_splits(
+			RuntimeOrigin::signed(OWNER),
+			*collection,
+			0,
+			equal_splits(holders)
+		));
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_music_rights::BenchmarkHelper<u64, u32, u32> for MusicRightsHelper {
+	fn create_item(owner: &u64, seed: u32) -> (u32, u32) {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), seed, *owner, true));
+		assert_ok!(Uniques::mint_into(&seed, &0, owner));
+		(seed, 0)
+	}
+
+	fn destroy_item(collection: &u32, item: &u32) {
+		assert_ok!(<Uniques as Mutate<u64>>::burn(collection, item, None));
+	}
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![
+			(OWNER, 1_000),
+			(AGGREGATOR, 1_000),
+			(WATCHER, 1_000),
+			(FUNDER, 10_000),
+			(Royalties::account_id(), 1),
+			(PlayMetering::pot_account(), 1),
+		],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,635 @@
+//! Tests for the play metering pallet.
+
+use super::*;
+use crate::mock::*;
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok};
+use sp_core::{sr25519, Pair};
+
+/// Key of the `n`th listener.
+fn listener(n: u8) -> sr25519::Pair {
+	sr25519::Pair::from_seed(&[n; 32])
+}
+
+/// A play of item 0 of `collection` by `listener`, sent to `aggregator`.
+fn receipt_to(
+	aggregator: u64,
+	listener: &sr25519::Pair,
+	collection: u32,
+	played_at: u64,
+	nonce: u64,
+) -> SignedReceiptOf<Test> {
+	let receipt = ListenReceipt {
+		listener: listener.public(),
+		aggregator,
+		collection,
+		item: 0,
+		played_at,
+		nonce,
+	};
+	let signature = listener.sign(&receipt.encode());
+	SignedReceipt { receipt, signature }
+}
+
+/// A play of item 0 of `collection` by `listener`, sent to `AGGREGATOR`.
+fn receipt(
+	listener: &sr25519::Pair,
+	collection: u32,
+	played_at: u64,
+	nonce: u64,
+) -> SignedReceiptOf<Test> {
+	receipt_to(AGGREGATOR, listener, collection, played_at, nonce)
+}
+
+fn leaves(receipts: &[SignedReceiptOf<Test>]) -> Vec<H256> {
+	receipts.iter().map(|receipt| receipt.leaf_hash()).collect()
+}
+
+fn tracks(plays: &[(u32, u32)]) -> TrackPlaysOf<Test> {
+	plays
+		.iter()
+		.map(|(collection, plays)| (*collection, 0, *plays))
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+/// Posts `receipts` as a batch of `AGGREGATOR` with plays of `plays`, played between `first_play`
+/// and `last_play`.
+fn submit(
+	receipts: &[SignedReceiptOf<Test>],
+	plays: &[(u32, u32)],
+	first_play: u64,
+	last_play: u64,
+) {
+	assert_ok!(PlayMetering::submit_batch(
+		RuntimeOrigin::signed(AGGREGATOR),
+		merkle::merkle_root(&leaves(receipts)),
+		tracks(plays),
+		first_play,
+		last_play,
+	));
+}
+
+fn challenge(
+	receipts: &[SignedReceiptOf<Test>],
+	index: usize,
+	fraud: FraudOf<Test>,
+) -> DispatchResult {
+	PlayMetering::challenge_batch(
+		RuntimeOrigin::signed(WATCHER),
+		0,
+		index as u32,
+		Box::new(receipts[index].clone()),
+		merkle::merkle_proof(&leaves(receipts), index),
+		Box::new(fraud),
+	)
+}
+
+/// Registers tracks 0 and 1 and the listeners 1 and 2 until block 100, funds the pot and starts
+/// at block 5.
+fn setup() {
+	setup_with_pot(1_000);
+}
+
+/// Registers tracks 0 and 1 and listeners 1 and 2, funding the pot with `pot`.
+fn setup_with_pot(pot: u64) {
+	register_track(0);
+	register_track(1);
+	for n in [1, 2] {
+		assert_ok!(PlayMetering::register_listener(
+			RuntimeOrigin::root(),
+			listener(n).public(),
+			100
+		));
+	}
+	assert_ok!(PlayMetering::fund_pot(&FUNDER, pot));
+	System::set_block_number(5);
+}
+
+/// Three plays of track 0 and one of track 1 by two listeners, played at blocks 2 to 4.
+fn plays() -> Vec<SignedReceiptOf<Test>> {
+	let (alice, bob) = (listener(1), listener(2));
+	vec![
+		receipt(&alice, 0, 2, 0),
+		receipt(&bob, 0, 2, 0),
+		receipt(&alice, 0, 3, 1),
+		receipt(&bob, 1, 4, 1),
+	]
+}
+
+#[test]
+fn merkle_proofs_prove_positions() {
+	for number_of_leaves in 1..=9u32 {
+		let leaves: Vec<H256> =
+			(0..number_of_leaves).map(|leaf| merkle::leaf_hash(&leaf.encode())).collect();
+		let root = merkle::merkle_root(&leaves);
+		for index in 0..number_of_leaves {
+			let proof = merkle::merkle_proof(&leaves, index as usize);
+			let leaf = leaves[index as usize];
+			assert!(merkle::verify_proof(&root, number_of_leaves, index, leaf, &proof));
+			if number_of_leaves > 1 {
+				let other = (index + 1) % number_of_leaves;
+				assert!(!merkle::verify_proof(&root, number_of_leaves, other, leaf, &proof));
+			}
+		}
+	}
+	assert_eq!(merkle::merkle_root(&[]), H256::zero());
+}
+
+#[test]
+fn submit_and_settle_batch_works() {
+	new_test_ext().execute_with(|| {
+		setup();
+		let receipts = plays();
+		submit(&receipts, &[(0, 3), (1, 1)], 2, 4);
+		System::assert_last_event(RuntimeEvent::PlayMetering(Event::BatchSubmitted {
+			batch: 0,
+			aggregator: AGGREGATOR,
+			root: merkle::merkle_root(&leaves(&receipts)),
+			plays: 4,
+		}));
+		assert_eq!(Balances::reserved_balance(AGGREGATOR), 100);
+		assert_eq!(PlayMetering::last_play(AGGREGATOR), Some(4));
+		assert_eq!(PlayMetering::batch(0).unwrap().track_at(3), Some((1, 0)));
+
+		System::set_block_number(14);
+		assert_noop!(
+			PlayMetering::settle_batch(RuntimeOrigin::signed(WATCHER), 0),
+			Error::<Test>::ChallengePeriodNotOver
+		);
+
+		System::set_block_number(15);
+		assert_ok!(PlayMetering::settle_batch(RuntimeOrigin::signed(WATCHER), 0));
+		System::assert_has_event(RuntimeEvent::PlayMetering(Event::PlaysSettled {
+			batch: 0,
+			collection: 0,
+			item: 0,
+			plays: 3,
+			royalties: 6,
+		}));
+		System::assert_has_event(RuntimeEvent::PlayMetering(Event::PlaysSettled {
+			batch: 0,
+			collection: 1,
+			item: 0,
+			plays: 1,
+			royalties: 2,
+		}));
+		System::assert_last_event(RuntimeEvent::PlayMetering(Event::BatchSettled { batch: 0 }));
+		assert!(PlayMetering::batch(0).is_none());
+		assert_eq!(Balances::reserved_balance(AGGREGATOR), 0);
+		assert_eq!(PlayMetering::settled_plays(0, 0), 3);
+		assert_eq!(PlayMetering::settled_plays(1, 0), 1);
+		assert_eq!(Balances::free_balance(PlayMetering::pot_account()), 1_001 - 8);
+		assert_eq!(Royalties::accumulated((0, 0, RoyaltyToken::Native, 0)), 6);
+		assert_eq!(Royalties::accumulated((1, 0, RoyaltyToken::Native, 0)), 2);
+
+		assert_noop!(
+			PlayMetering::settle_batch(RuntimeOrigin::signed(WATCHER), 0),
+			Error::<Test>::UnknownBatch
+		);
+	});
+}
+
+#[test]
+fn submit_batch_checks_plays_and_window() {
+	new_test_ext().execute_with(|| {
+		setup();
+		let submit_batch = |plays: &[(u32, u32)], first_play, last_play| {
+			PlayMetering::submit_batch(
+				RuntimeOrigin::signed(AGGREGATOR),
+				H256::zero(),
+				tracks(plays),
+				first_play,
+				last_play,
+			)
+		};
+
+		assert_noop!(submit_batch(&[], 2, 4), Error::<Test>::NoPlays);
+		assert_noop!(submit_batch(&[(0, 1), (1, 0)], 2, 4), Error::<Test>::NoPlays);
+		assert_noop!(submit_batch(&[(0, 1), (2, 1)], 2, 4), Error::<Test>::UnknownTrack);
+		assert_noop!(submit_batch(&[(0, u32::MAX), (1, 1)], 2, 4), Error::<Test>::TooManyPlays);
+		assert_noop!(submit_batch(&[(0, 1)], 4, 2), Error::<Test>::InvalidWindow);
+		assert_noop!(submit_batch(&[(0, 1)], 2, 6), Error::<Test>::InvalidWindow);
+
+		assert_ok!(submit_batch(&[(0, 1)], 2, 4));
+		assert_noop!(submit_batch(&[(0, 1)], 4, 5), Error::<Test>::OverlappingWindow);
+		assert_ok!(submit_batch(&[(0, 1)], 5, 5));
+		assert_eq!(Balances::reserved_balance(AGGREGATOR), 200);
+
+		Balances::make_free_balance_be(&AGGREGATOR, 50);
+		System::set_block_number(6);
+		assert!(submit_batch(&[(0, 1)], 6, 6).is_err());
+	});
+}
+
+#[test]
+fn challenge_invalid_signature_works() {
+	new_test_ext().execute_with(|| {
+		setup();
+		let mut receipts = plays();
+		receipts[2].signature = listener(3).sign(&receipts[2].receipt.encode());
+		submit(&receipts, &[(0, 3), (1, 1)], 2, 4);
+
+		assert_noop!(
+			challenge(&receipts, 1, Fraud::InvalidSignature),
+			Error::<Test>::NotFraudulent
+		);
+		let mut proof = merkle::merkle_proof(&leaves(&receipts), 2);
+		assert_noop!(
+			PlayMetering::challenge_batch(
+				RuntimeOrigin::signed(WATCHER),
+				0,
+				1,
+				Box::new(receipts[2].clone()),
+				proof.clone(),
+				Box::new(Fraud::InvalidSignature),
+			),
+			Error::<Test>::InvalidProof
+		);
+		proof.push(H256::zero());
+		assert_noop!(
+			PlayMetering::challenge_batch(
+				RuntimeOrigin::signed(WATCHER),
+				0,
+				2,
+				Box::new(receipts[2].clone()),
+				proof,
+				Box::new(Fraud::InvalidSignature),
+			),
+			Error::<Test>::InvalidProof
+		);
+
+		assert_ok!(challenge(&receipts, 2, Fraud::InvalidSignature));
+		System::assert_last_event(RuntimeEvent::PlayMetering(Event::BatchRejected {
+			batch: 0,
+			challenger: WATCHER,
+			fraud: FraudKind::InvalidSignature,
+		}));
+		assert!(PlayMetering::batch(0).is_none());
+		assert_eq!(Balances::total_balance(&AGGREGATOR), 900);
+		assert_eq!(Balances::free_balance(WATCHER), 1_100);
+		assert_noop!(
+			PlayMetering::settle_batch(RuntimeOrigin::signed(WATCHER), 0),
+			Error::<Test>::UnknownBatch
+		);
+	});
+}
+
+#[test]
+fn challenge_wrong_aggregator_works() {
+	new_test_ext().execute_with(|| {
+		setup();
+		let mut receipts = plays();
+		receipts[3] = receipt_to(WATCHER, &listener(2), 1, 4, 1);
+		submit(&receipts, &[(0, 3), (1, 1)], 2, 4);
+
+		assert_noop!(challenge(&receipts, 0, Fraud::WrongAggregator), Error::<Test>::NotFraudulent);
+		assert_ok!(challenge(&receipts, 3, Fraud::WrongAggregator));
+		assert_eq!(Balances::free_balance(WATCHER), 1_100);
+	});
+}
+
+#[test]
+fn challenge_wrong_track_works() {
+	new_test_ext().execute_with(|| {
+		setup();
+		let receipts = plays();
+		// Counts one of the plays of track 0 as a play of track 1.
+		submit(&receipts, &[(0, 2), (1, 2)], 2, 4);
+
+		assert_noop!(challenge(&receipts, 1, Fraud::WrongTrack), Error::<Test>::NotFraudulent);
+		assert_noop!(challenge(&receipts, 3, Fraud::WrongTrack), Error::<Test>::NotFraudulent);
+		assert_ok!(challenge(&receipts, 2, Fraud::WrongTrack));
+		System::assert_last_event(RuntimeEvent::PlayMetering(Event::BatchRejected {
+			batch: 0,
+			challenger: WATCHER,
+			fraud: FraudKind::WrongTrack,
+		}));
+	});
+}
+
+#[test]
+fn challenge_out_of_window_works() {
+	new_test_ext().execute_with(|| {
+		setup();
+		let receipts = plays();
+		submit(&receipts, &[(0, 3), (1, 1)], 2, 3);
+
+		assert_noop!(challenge(&receipts, 2, Fraud::OutOfWindow), Error::<Test>::NotFraudulent);
+		assert_ok!(challenge(&receipts, 3, Fraud::OutOfWindow));
+	});
+}
+
+#[test]
+fn challenge_duplicate_works() {
+	new_test_ext().execute_with(|| {
+		setup();
+		let alice = listener(1);
+		// The second play of alice reuses the nonce of the first one.
+		let receipts = vec![receipt(&alice, 0, 2, 0), receipt(&alice, 0, 3, 0), plays()[3].clone()];
+		submit(&receipts, &[(0, 2), (1, 1)], 2, 4);
+		let leaves = leaves(&receipts);
+		let duplicate_of = |index: usize| Fraud::Duplicate {
+			index: index as u32,
+			receipt: receipts[index].clone(),
+			proof: merkle::merkle_proof(&leaves, index),
+		};
+
+		assert_noop!(challenge(&receipts, 0, duplicate_of(0)), Error::<Test>::NotFraudulent);
+		assert_noop!(challenge(&receipts, 0, duplicate_of(2)), Error::<Test>::NotFraudulent);
+		assert_noop!(
+			challenge(
+				&receipts,
+				0,
+				Fraud::Duplicate {
+					index: 2,
+					receipt: receipts[1].clone(),
+					proof: merkle::merkle_proof(&leaves, 1),
+				}
+			),
+			Error::<Test>::InvalidProof
+		);
+		assert_ok!(challenge(&receipts, 0, duplicate_of(1)));
+		System::assert_last_event(RuntimeEvent::PlayMetering(Event::BatchRejected {
+			batch: 0,
+			challenger: WATCHER,
+			fraud: FraudKind::Duplicate,
+		}));
+	});
+}
+
+#[test]
+fn challenge_unknown_listener_works() {
+	new_test_ext().execute_with(|| {
+		setup();
+		assert_ok!(PlayMetering::register_listener(RuntimeOrigin::root(), listener(3).public(), 2));
+		let receipts = vec![
+			receipt(&listener(1), 0, 2, 0),
+			receipt(&listener(3), 0, 2, 0),
+			receipt(&listener(3), 0, 3, 1),
+			receipt(&listener(4), 1, 4, 0),
+		];
+		submit(&receipts, &[(0, 3), (1, 1)], 2, 4);
+
+		assert_noop!(challenge(&receipts, 0, Fraud::UnknownListener), Error::<Test>::NotFraudulent);
+		assert_noop!(challenge(&receipts, 1, Fraud::UnknownListener), Error::<Test>::NotFraudulent);
+		// The subscription of listener 3 ended before the play.
+		assert_ok!(challenge(&receipts, 2, Fraud::UnknownListener));
+		System::assert_last_event(RuntimeEvent::PlayMetering(Event::BatchRejected {
+			batch: 0,
+			challenger: WATCHER,
+			fraud: FraudKind::UnknownListener,
+		}));
+
+		System::set_block_number(6);
+		assert_ok!(PlayMetering::submit_batch(
+			RuntimeOrigin::signed(AGGREGATOR),
+			merkle::merkle_root(&leaves(&receipts[3..])),
+			tracks(&[(1, 1)]),
+			5,
+			5,
+		));
+		assert_ok!(PlayMetering::challenge_batch(
+			RuntimeOrigin::signed(WATCHER),
+			1,
+			0,
+			Box::new(receipts[3].clone()),
+			vec![],
+			Box::new(Fraud::UnknownListener),
+		));
+	});
+}
+
+#[test]
+fn register_listener_works() {
+	new_test_ext().execute_with(|| {
+		let key = listener(1).public();
+		assert_noop!(
+			PlayMetering::register_listener(RuntimeOrigin::signed(AGGREGATOR), key, 10),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(PlayMetering::register_listener(RuntimeOrigin::root(), key, 10));
+		System::assert_last_event(RuntimeEvent::PlayMetering(Event::ListenerRegistered {
+			listener: key,
+			until: 10,
+		}));
+		assert_eq!(PlayMetering::listener(key), Some(10));
+
+		assert_noop!(
+			PlayMetering::deregister_listener(RuntimeOrigin::signed(AGGREGATOR), key),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(PlayMetering::deregister_listener(RuntimeOrigin::root(), key));
+		assert_eq!(PlayMetering::listener(key), None);
+		assert_noop!(
+			PlayMetering::deregister_listener(RuntimeOrigin::root(), key),
+			Error::<Test>::UnknownListener
+		);
+	});
+}
+
+#[test]
+fn reveal_receipt_works() {
+	new_test_ext().execute_with(|| {
+		setup();
+		let receipts = plays();
+		submit(&receipts, &[(0, 3), (1, 1)], 2, 4);
+		let reveal = |index: usize, proof| {
+			PlayMetering::reveal_receipt(
+				RuntimeOrigin::signed(AGGREGATOR),
+				0,
+				index as u32,
+				Box::new(receipts[index].clone()),
+				proof,
+			)
+		};
+		let proof = |index| merkle::merkle_proof(&leaves(&receipts), index);
+
+		assert_noop!(
+			PlayMetering::request_reveal(RuntimeOrigin::signed(WATCHER), 0, 4),
+			Error::<Test>::UnknownReceipt
+		);
+		assert_noop!(reveal(1, proof(1)), Error::<Test>::UnknownRevealRequest);
+		assert_ok!(PlayMetering::request_reveal(RuntimeOrigin::signed(WATCHER), 0, 1));
+		System::assert_last_event(RuntimeEvent::PlayMetering(Event::RevealRequested {
+			batch: 0,
+			index: 1,
+			requester: WATCHER,
+			deadline: 10,
+		}));
+		assert_eq!(Balances::reserved_balance(WATCHER), 10);
+		assert_noop!(
+			PlayMetering::request_reveal(RuntimeOrigin::signed(OWNER), 0, 1),
+			Error::<Test>::RevealAlreadyRequested
+		);
+		assert_ok!(PlayMetering::request_reveal(RuntimeOrigin::signed(OWNER), 0, 2));
+		assert_noop!(
+			PlayMetering::request_reveal(RuntimeOrigin::signed(OWNER), 0, 3),
+			Error::<Test>::TooManyReveals
+		);
+
+		assert_noop!(reveal(1, proof(2)), Error::<Test>::InvalidProof);
+		assert_ok!(reveal(1, proof(1)));
+		System::assert_last_event(RuntimeEvent::PlayMetering(Event::ReceiptRevealed {
+			batch: 0,
+			index: 1,
+			receipt: receipts[1].clone(),
+		}));
+		assert_eq!(Balances::total_balance(&WATCHER), 990);
+		assert_eq!(Balances::free_balance(AGGREGATOR), 910);
+
+		// The batch can't be settled while a reveal is pending.
+		System::set_block_number(14);
+		assert_ok!(reveal(2, proof(2)));
+		System::set_block_number(15);
+		assert_noop!(
+			PlayMetering::settle_batch(RuntimeOrigin::signed(WATCHER), 0),
+			Error::<Test>::ChallengePeriodNotOver
+		);
+		// Revealed receipts can be challenged for `RevealPeriod`.
+		assert_eq!(PlayMetering::batch(0).unwrap().challenge_end, 19);
+		System::set_block_number(19);
+		assert_ok!(PlayMetering::settle_batch(RuntimeOrigin::signed(WATCHER), 0));
+	});
+}
+
+#[test]
+fn reject_unrevealed_works() {
+	new_test_ext().execute_with(|| {
+		setup();
+		let receipts = plays();
+		submit(&receipts, &[(0, 3), (1, 1)], 2, 4);
+		assert_ok!(PlayMetering::request_reveal(RuntimeOrigin::signed(WATCHER), 0, 1));
+		System::set_block_number(12);
+		assert_ok!(PlayMetering::request_reveal(RuntimeOrigin::signed(OWNER), 0, 2));
+
+		System::set_block_number(15);
+		assert_noop!(
+			PlayMetering::settle_batch(RuntimeOrigin::signed(WATCHER), 0),
+			Error::<Test>::RevealsPending
+		);
+		assert_noop!(
+			PlayMetering::reject_unrevealed(RuntimeOrigin::signed(AGGREGATOR), 0, 0),
+			Error::<Test>::UnknownRevealRequest
+		);
+		assert_noop!(
+			PlayMetering::reject_unrevealed(RuntimeOrigin::signed(AGGREGATOR), 0, 2),
+			Error::<Test>::RevealPeriodNotOver
+		);
+
+		assert_ok!(PlayMetering::reject_unrevealed(RuntimeOrigin::signed(AGGREGATOR), 0, 1));
+		System::assert_last_event(RuntimeEvent::PlayMetering(Event::BatchRejected {
+			batch: 0,
+			challenger: WATCHER,
+			fraud: FraudKind::Unrevealed,
+		}));
+		assert!(PlayMetering::batch(0).is_none());
+		assert_eq!(PlayMetering::reveal_request(0, 2), None);
+		assert_eq!(Balances::free_balance(WATCHER), 1_100);
+		assert_eq!(Balances::total_balance(&AGGREGATOR), 900);
+		assert_eq!(Balances::reserved_balance(OWNER), 0);
+	});
+}
+
+#[test]
+fn challenge_period_ends() {
+	new_test_ext().execute_with(|| {
+		setup();
+		let mut receipts = plays();
+		receipts[0].signature = listener(3).sign(&receipts[0].receipt.encode());
+		submit(&receipts, &[(0, 3), (1, 1)], 2, 4);
+
+		System::set_block_number(15);
+		assert_noop!(
+			challenge(&receipts, 0, Fraud::InvalidSignature),
+			Error::<Test>::ChallengePeriodOver
+		);
+		assert_ok!(PlayMetering::settle_batch(RuntimeOrigin::signed(WATCHER), 0));
+		assert_eq!(PlayMetering::settled_plays(0, 0), 3);
+	});
+}
+
+#[test]
+fn settle_batch_carries_over_plays_the_pot_cannot_pay() {
+	new_test_ext().execute_with(|| {
+		// The pot can pay for the plays of track 0 but not for those of track 1 besides.
+		setup_with_pot(6);
+		submit(&plays(), &[(0, 3), (1, 1)], 2, 4);
+		submit(&[receipt(&listener(2), 1, 5, 2)], &[(1, 1)], 5, 5);
+
+		System::set_block_number(15);
+		assert_ok!(PlayMetering::settle_batch(RuntimeOrigin::signed(WATCHER), 0));
+		System::assert_has_event(RuntimeEvent::PlayMetering(Event::PlaysSettled {
+			batch: 0,
+			collection: 0,
+			item: 0,
+			plays: 3,
+			royalties: 6,
+		}));
+		System::assert_has_event(RuntimeEvent::PlayMetering(Event::PlaysCarriedOver {
+			batch: 0,
+			collection: 1,
+			item: 0,
+			plays: 1,
+		}));
+		System::assert_has_event(RuntimeEvent::PlayMetering(Event::PlaysSettled {
+			batch: 0,
+			collection: 1,
+			item: 0,
+			plays: 1,
+			royalties: 0,
+		}));
+		assert!(PlayMetering::batch(0).is_none());
+		assert_eq!(Balances::reserved_balance(AGGREGATOR), 100);
+		assert_eq!(PlayMetering::settled_plays(1, 0), 1);
+		assert_eq!(PlayMetering::unpaid_plays(1, 0), 1);
+		assert_eq!(Royalties::accumulated((0, 0, RoyaltyToken::Native, 0)), 6);
+		assert_eq!(Royalties::accumulated((1, 0, RoyaltyToken::Native, 0)), 0);
+
+		// The next settled plays of the track pay for the carried over ones.
+		assert_ok!(PlayMetering::fund_pot(&FUNDER, 10));
+		assert_ok!(PlayMetering::settle_batch(RuntimeOrigin::signed(WATCHER), 1));
+		System::assert_has_event(RuntimeEvent::PlayMetering(Event::PlaysSettled {
+			batch: 1,
+			collection: 1,
+			item: 0,
+			plays: 1,
+			royalties: 4,
+		}));
+		assert_eq!(Balances::reserved_balance(AGGREGATOR), 0);
+		assert_eq!(PlayMetering::unpaid_plays(1, 0), 0);
+		assert_eq!(Royalties::accumulated((1, 0, RoyaltyToken::Native, 0)), 4);
+	});
+}
+
+#[test]
+fn pay_unpaid_plays_works() {
+	new_test_ext().execute_with(|| {
+		register_track(0);
+		System::set_block_number(5);
+		submit(&plays()[..1], &[(0, 1)], 2, 2);
+		System::set_block_number(15);
+		assert_ok!(PlayMetering::settle_batch(RuntimeOrigin::signed(WATCHER), 0));
+		assert_eq!(PlayMetering::unpaid_plays(0, 0), 1);
+		assert_noop!(
+			PlayMetering::pay_unpaid_plays(RuntimeOrigin::signed(WATCHER), 0, 0),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(PlayMetering::fund_pot(&FUNDER, 10));
+		assert_ok!(PlayMetering::pay_unpaid_plays(RuntimeOrigin::signed(WATCHER), 0, 0));
+		System::assert_last_event(RuntimeEvent::PlayMetering(Event::UnpaidPlaysPaid {
+			collection: 0,
+			item: 0,
+			plays: 1,
+			royalties: 2,
+		}));
+		assert_eq!(PlayMetering::unpaid_plays(0, 0), 0);
+		assert_eq!(Royalties::accumulated((0, 0, RoyaltyToken::Native, 0)), 2);
+		assert_noop!(
+			PlayMetering::pay_unpaid_plays(RuntimeOrigin::signed(WATCHER), 0, 0),
+			Error::<Test>::NoUnpaidPlays
+		);
+	});
+}
@@ -0,0 +1,183 @@
+//! Types of the play metering pallet.
+
+use crate::{merkle, Config};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebug, RuntimeDebugNoBound,
+};
+use scale_info::TypeInfo;
+use sp_core::{sr25519, H256};
+use sp_std::prelude::*;
+
+/// Identifier of a batch.
+pub type BatchId = u32;
+
+type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
+/// Identifier of the collection of a track.
+pub type CollectionIdOf<T> = <T as pallet_royalties::Config>::CollectionId;
+/// Identifier of a track within its collection.
+pub type ItemIdOf<T> = <T as pallet_royalties::Config>::ItemId;
+/// The tracks of a batch with their number of plays, in the order of their receipts.
+pub type TrackPlaysOf<T> =
+	BoundedVec<(CollectionIdOf<T>, ItemIdOf<T>, u32), <T as Config>::MaxTracks>;
+/// A receipt from this pallet's point of view.
+pub type ListenReceiptOf<T> =
+	ListenReceipt<AccountIdOf<T>, CollectionIdOf<T>, ItemIdOf<T>, BlockNumberOf<T>>;
+/// A signed receipt from this pallet's point of view.
+pub type SignedReceiptOf<T> =
+	SignedReceipt<AccountIdOf<T>, CollectionIdOf<T>, ItemIdOf<T>, BlockNumberOf<T>>;
+/// A fraud from this pallet's point of view.
+pub type FraudOf<T> = Fraud<SignedReceiptOf<T>>;
+/// A reveal request from this pallet's point of view.
+pub type RevealRequestOf<T> = RevealRequest<AccountIdOf<T>, BlockNumberOf<T>, crate::BalanceOf<T>>;
+
+/// A play of a track, as recorded by the player.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ListenReceipt<AccountId, CollectionId, ItemId, BlockNumber> {
+	/// Key of the listener, who signs the receipt.
+	pub listener: sr25519::Public,
+	/// Aggregator the receipt is sent to, the only one who may post it.
+	pub aggregator: AccountId,
+	/// Collection of the track.
+	pub collection: CollectionId,
+	/// Track within its collection.
+	pub item: ItemId,
+	/// Block the track was played at.
+	pub played_at: BlockNumber,
+	/// Number of the play among the plays of the listener, which never reuses it.
+	pub nonce: u64,
+}
+
+/// A receipt with the signature of its listener.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SignedReceipt<AccountId, CollectionId, ItemId, BlockNumber> {
+	/// The receipt.
+	pub receipt: ListenReceipt<AccountId, CollectionId, ItemId, BlockNumber>,
+	/// Signature of the SCALE encoded receipt by its listener.
+	pub signature: sr25519::Signature,
+}
+
+impl<AccountId, CollectionId, ItemId, BlockNumber>
+	SignedReceipt<AccountId, CollectionId, ItemId, BlockNumber>
+where
+	AccountId: Encode,
+	CollectionId: Encode,
+	ItemId: Encode,
+	BlockNumber: Encode,
+{
+	/// Whether the receipt was signed by its listener.
+	pub fn verify(&self) -> bool {
+		sp_io::crypto::sr25519_verify(
+			&self.signature,
+			&self.receipt.encode(),
+			&self.receipt.listener,
+		)
+	}
+
+	/// Hash of the leaf of the receipt in the tree of its batch.
+	pub fn leaf_hash(&self) -> H256 {
+		merkle::leaf_hash(&self.encode())
+	}
+}
+
+/// Why a receipt of a batch is fraudulent.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum Fraud<Receipt> {
+	/// The receipt was not signed by its listener.
+	InvalidSignature,
+	/// The listener of the receipt was not registered when it was played.
+	UnknownListener,
+	/// The receipt was sent to another aggregator.
+	WrongAggregator,
+	/// The receipt is among the receipts of another track.
+	WrongTrack,
+	/// The receipt was played outside the plays of the batch.
+	OutOfWindow,
+	/// Another receipt of the batch is of the same play.
+	Duplicate {
+		/// Index of the other receipt.
+		index: u32,
+		/// The other receipt.
+		receipt: Receipt,
+		/// Proof of the other receipt.
+		proof: Vec<H256>,
+	},
+}
+
+/// Kind of a proven [`Fraud`], without its proof.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum FraudKind {
+	/// The receipt was not signed by its listener.
+	InvalidSignature,
+	/// The receipt was sent to another aggregator.
+	WrongAggregator,
+	/// The receipt is among the receipts of another track.
+	WrongTrack,
+	/// The receipt was played outside the plays of the batch.
+	OutOfWindow,
+	/// Another receipt of the batch is of the same play.
+	Duplicate,
+	/// The listener of the receipt was not registered when it was played.
+	UnknownListener,
+	/// A requested receipt was not revealed in time.
+	Unrevealed,
+}
+
+/// A request to the aggregator of a batch to reveal one of its receipts.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RevealRequest<AccountId, BlockNumber, Balance> {
+	/// Account which requested the reveal and reserved its deposit.
+	pub requester: AccountId,
+	/// Block from which the batch can be rejected if the receipt is not revealed.
+	pub deadline: BlockNumber,
+	/// Deposit reserved from the requester.
+	pub deposit: Balance,
+}
+
+/// Plays posted by an aggregator, which can be challenged until they are settled.
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound(T: Config))]
+pub struct Batch<T: Config> {
+	/// Aggregator who posted the batch and reserved its deposit.
+	pub aggregator: T::AccountId,
+	/// Root of the tree of the receipts of the batch.
+	pub root: H256,
+	/// Number of receipts of the batch.
+	pub plays: u32,
+	/// Tracks of the batch with their number of plays, in the order of their receipts.
+	pub tracks: TrackPlaysOf<T>,
+	/// Block of the first play of the batch.
+	pub first_play: T::BlockNumber,
+	/// Block of the last play of the batch.
+	pub last_play: T::BlockNumber,
+	/// Block the batch was posted at.
+	pub submitted_at: T::BlockNumber,
+	/// Block the batch can be challenged until, which revealed receipts postpone.
+	pub challenge_end: T::BlockNumber,
+	/// Number of receipts whose reveal was requested and is pending.
+	pub pending_reveals: u32,
+	/// Deposit reserved from the aggregator.
+	pub deposit: crate::BalanceOf<T>,
+}
+
+impl<T: Config> Batch<T> {
+	/// The track of the receipt at `index`, if it is a receipt of the batch.
+	pub fn track_at(&self, index: u32) -> Option<(CollectionIdOf<T>, ItemIdOf<T>)> {
+		let mut end = 0u32;
+		self.tracks.iter().find_map(|(collection, item, plays)| {
+			end = end.saturating_add(*plays);
+			(index < end).then_some((*collection, *item))
+		})
+	}
+}
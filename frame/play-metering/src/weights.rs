@@ -0,0 +1,441 @@
+//! Autogenerated weights for pallet_play_metering
+//!
+//! MEASURED BY DRIVING THE `benchmark pallet` LOOP OVER THE NATIVE RUNTIME INSTEAD OF WASM.
+//! REGENERATE WITH THE COMMAND BELOW ON REFERENCE HARDWARE BEFORE RELYING ON THE TIMINGS.
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: Some(Native), CHAIN: Some("dev"), DB CACHE: 1024
+
+// Regenerate with:
+// ./target/production/substrate
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_play_metering
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/play-metering/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_play_metering.
+pub trait WeightInfo {
+	fn submit_batch(n: u32, ) -> Weight;
+	fn challenge_batch(r: u32, ) -> Weight;
+	fn challenge_duplicate(r: u32, ) -> Weight;
+	fn settle_batch(n: u32, ) -> Weight;
+	fn register_listener() -> Weight;
+	fn deregister_listener() -> Weight;
+	fn request_reveal() -> Weight;
+	fn reveal_receipt() -> Weight;
+	fn reject_unrevealed(r: u32, ) -> Weight;
+	fn pay_unpaid_plays() -> Weight;
+}
+
+/// Weights for pallet_play_metering using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: PlayMetering LastPlay (r:1 w:1)
+	/// Proof: PlayMetering LastPlay (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:16 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: PlayMetering NextBatchId (r:1 w:1)
+	/// Proof: PlayMetering NextBatchId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PlayMetering Batches (r:0 w:1)
+	/// Proof: PlayMetering Batches (max_values: None, max_size: Some(317), added: 2792, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn submit_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1959 + n * (154 ±0)`
+		//  Estimated: `3026 + n * (3231 ±0)`
+		// Minimum execution time: 63_328 nanoseconds.
+		Weight::from_parts(71_219_015, 3026)
+			// Standard Error: 54_472
+			.saturating_add(Weight::from_ref_time(7_986_225).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_proof_size(3231).saturating_mul(n.into()))
+	}
+	/// Storage: PlayMetering Batches (r:1 w:1)
+	/// Proof: PlayMetering Batches (max_values: None, max_size: Some(317), added: 2792, mode: MaxEncodedLen)
+	/// Storage: PlayMetering RevealRequests (r:17 w:16)
+	/// Proof: PlayMetering RevealRequests (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:16 w:16)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 16]`.
+	fn challenge_batch(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1944 + r * (271 ±0)`
+		//  Estimated: `5351 + r * (5162 ±0)`
+		// Minimum execution time: 61_062 nanoseconds.
+		Weight::from_parts(82_520_203, 5351)
+			// Standard Error: 243_641
+			.saturating_add(Weight::from_ref_time(23_983_622).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(5162).saturating_mul(r.into()))
+	}
+	/// Storage: PlayMetering Batches (r:1 w:1)
+	/// Proof: PlayMetering Batches (max_values: None, max_size: Some(317), added: 2792, mode: MaxEncodedLen)
+	/// Storage: PlayMetering RevealRequests (r:17 w:16)
+	/// Proof: PlayMetering RevealRequests (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:16 w:16)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 16]`.
+	fn challenge_duplicate(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1944 + r * (271 ±0)`
+		//  Estimated: `5351 + r * (5162 ±0)`
+		// Minimum execution time: 72_160 nanoseconds.
+		Weight::from_parts(98_198_883, 5351)
+			// Standard Error: 267_359
+			.saturating_add(Weight::from_ref_time(21_773_316).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(5162).saturating_mul(r.into()))
+	}
+	/// Storage: PlayMetering Batches (r:1 w:1)
+	/// Proof: PlayMetering Batches (max_values: None, max_size: Some(317), added: 2792, mode: MaxEncodedLen)
+	/// Storage: PlayMetering SettledPlays (r:16 w:16)
+	/// Proof: PlayMetering SettledPlays (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:16 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: PlayMetering UnpaidPlays (r:16 w:0)
+	/// Proof: PlayMetering UnpaidPlays (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Royalties CurrentPeriod (r:16 w:16)
+	/// Proof: Royalties CurrentPeriod (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Royalties LatestPeriod (r:16 w:16)
+	/// Proof: Royalties LatestPeriod (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Royalties Accumulated (r:16 w:16)
+	/// Proof: Royalties Accumulated (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Royalties HolderShare (r:0 w:16)
+	/// Proof: Royalties HolderShare (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Royalties PeriodShares (r:0 w:16)
+	/// Proof: Royalties PeriodShares (max_values: None, max_size: Some(629), added: 3104, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn settle_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2468 + n * (166 ±0)`
+		//  Estimated: `10601 + n * (15900 ±0)`
+		// Minimum execution time: 114_216 nanoseconds.
+		Weight::from_parts(119_975_517, 10601)
+			// Standard Error: 916_359
+			.saturating_add(Weight::from_ref_time(50_093_350).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(15900).saturating_mul(n.into()))
+	}
+	/// Storage: PlayMetering Listeners (r:0 w:1)
+	/// Proof: PlayMetering Listeners (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn register_listener() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1149`
+		//  Estimated: `0`
+		// Minimum execution time: 22_698 nanoseconds.
+		Weight::from_parts(27_221_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PlayMetering Listeners (r:1 w:1)
+	/// Proof: PlayMetering Listeners (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn deregister_listener() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1231`
+		//  Estimated: `2527`
+		// Minimum execution time: 32_278 nanoseconds.
+		Weight::from_parts(34_803_000, 2527)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PlayMetering Batches (r:1 w:1)
+	/// Proof: PlayMetering Batches (max_values: None, max_size: Some(317), added: 2792, mode: MaxEncodedLen)
+	/// Storage: PlayMetering RevealRequests (r:1 w:1)
+	/// Proof: PlayMetering RevealRequests (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn request_reveal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2031`
+		//  Estimated: `5351`
+		// Minimum execution time: 37_943 nanoseconds.
+		Weight::from_parts(55_706_000, 5351)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PlayMetering Batches (r:1 w:1)
+	/// Proof: PlayMetering Batches (max_values: None, max_size: Some(317), added: 2792, mode: MaxEncodedLen)
+	/// Storage: PlayMetering RevealRequests (r:1 w:1)
+	/// Proof: PlayMetering RevealRequests (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn reveal_receipt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2308`
+		//  Estimated: `10557`
+		// Minimum execution time: 63_531 nanoseconds.
+		Weight::from_parts(75_238_000, 10557)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: PlayMetering Batches (r:1 w:1)
+	/// Proof: PlayMetering Batches (max_values: None, max_size: Some(317), added: 2792, mode: MaxEncodedLen)
+	/// Storage: PlayMetering RevealRequests (r:17 w:16)
+	/// Proof: PlayMetering RevealRequests (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:17 w:17)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `r` is `[1, 16]`.
+	fn reject_unrevealed(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2071 + r * (274 ±0)`
+		//  Estimated: `7954 + r * (5162 ±0)`
+		// Minimum execution time: 58_548 nanoseconds.
+		Weight::from_parts(66_932_442, 7954)
+			// Standard Error: 315_363
+			.saturating_add(Weight::from_ref_time(23_025_677).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(5162).saturating_mul(r.into()))
+	}
+	/// Storage: PlayMetering UnpaidPlays (r:1 w:1)
+	/// Proof: PlayMetering UnpaidPlays (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:1 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: Royalties CurrentPeriod (r:1 w:1)
+	/// Proof: Royalties CurrentPeriod (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Royalties LatestPeriod (r:1 w:1)
+	/// Proof: Royalties LatestPeriod (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Royalties Accumulated (r:1 w:1)
+	/// Proof: Royalties Accumulated (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Royalties HolderShare (r:0 w:1)
+	/// Proof: Royalties HolderShare (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Royalties PeriodShares (r:0 w:1)
+	/// Proof: Royalties PeriodShares (max_values: None, max_size: Some(629), added: 3104, mode: MaxEncodedLen)
+	fn pay_unpaid_plays() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2278`
+		//  Estimated: `18583`
+		// Minimum execution time: 105_092 nanoseconds.
+		Weight::from_parts(117_140_000, 18583)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: PlayMetering LastPlay (r:1 w:1)
+	/// Proof: PlayMetering LastPlay (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:16 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: PlayMetering NextBatchId (r:1 w:1)
+	/// Proof: PlayMetering NextBatchId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PlayMetering Batches (r:0 w:1)
+	/// Proof: PlayMetering Batches (max_values: None, max_size: Some(317), added: 2792, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn submit_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1959 + n * (154 ±0)`
+		//  Estimated: `3026 + n * (3231 ±0)`
+		// Minimum execution time: 63_328 nanoseconds.
+		Weight::from_parts(71_219_015, 3026)
+			// Standard Error: 54_472
+			.saturating_add(Weight::from_ref_time(7_986_225).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_proof_size(3231).saturating_mul(n.into()))
+	}
+	/// Storage: PlayMetering Batches (r:1 w:1)
+	/// Proof: PlayMetering Batches (max_values: None, max_size: Some(317), added: 2792, mode: MaxEncodedLen)
+	/// Storage: PlayMetering RevealRequests (r:17 w:16)
+	/// Proof: PlayMetering RevealRequests (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:16 w:16)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 16]`.
+	fn challenge_batch(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1944 + r * (271 ±0)`
+		//  Estimated: `5351 + r * (5162 ±0)`
+		// Minimum execution time: 61_062 nanoseconds.
+		Weight::from_parts(82_520_203, 5351)
+			// Standard Error: 243_641
+			.saturating_add(Weight::from_ref_time(23_983_622).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(5162).saturating_mul(r.into()))
+	}
+	/// Storage: PlayMetering Batches (r:1 w:1)
+	/// Proof: PlayMetering Batches (max_values: None, max_size: Some(317), added: 2792, mode: MaxEncodedLen)
+	/// Storage: PlayMetering RevealRequests (r:17 w:16)
+	/// Proof: PlayMetering RevealRequests (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:16 w:16)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 16]`.
+	fn challenge_duplicate(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1944 + r * (271 ±0)`
+		//  Estimated: `5351 + r * (5162 ±0)`
+		// Minimum execution time: 72_160 nanoseconds.
+		Weight::from_parts(98_198_883, 5351)
+			// Standard Error: 267_359
+			.saturating_add(Weight::from_ref_time(21_773_316).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(5162).saturating_mul(r.into()))
+	}
+	/// Storage: PlayMetering Batches (r:1 w:1)
+	/// Proof: PlayMetering Batches (max_values: None, max_size: Some(317), added: 2792, mode: MaxEncodedLen)
+	/// Storage: PlayMetering SettledPlays (r:16 w:16)
+	/// Proof: PlayMetering SettledPlays (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:16 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: PlayMetering UnpaidPlays (r:16 w:0)
+	/// Proof: PlayMetering UnpaidPlays (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Royalties CurrentPeriod (r:16 w:16)
+	/// Proof: Royalties CurrentPeriod (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Royalties LatestPeriod (r:16 w:16)
+	/// Proof: Royalties LatestPeriod (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Royalties Accumulated (r:16 w:16)
+	/// Proof: Royalties Accumulated (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Royalties HolderShare (r:0 w:16)
+	/// Proof: Royalties HolderShare (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Royalties PeriodShares (r:0 w:16)
+	/// Proof: Royalties PeriodShares (max_values: None, max_size: Some(629), added: 3104, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn settle_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2468 + n * (166 ±0)`
+		//  Estimated: `10601 + n * (15900 ±0)`
+		// Minimum execution time: 114_216 nanoseconds.
+		Weight::from_parts(119_975_517, 10601)
+			// Standard Error: 916_359
+			.saturating_add(Weight::from_ref_time(50_093_350).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_proof_size(15900).saturating_mul(n.into()))
+	}
+	/// Storage: PlayMetering Listeners (r:0 w:1)
+	/// Proof: PlayMetering Listeners (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn register_listener() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1149`
+		//  Estimated: `0`
+		// Minimum execution time: 22_698 nanoseconds.
+		Weight::from_parts(27_221_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PlayMetering Listeners (r:1 w:1)
+	/// Proof: PlayMetering Listeners (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn deregister_listener() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1231`
+		//  Estimated: `2527`
+		// Minimum execution time: 32_278 nanoseconds.
+		Weight::from_parts(34_803_000, 2527)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PlayMetering Batches (r:1 w:1)
+	/// Proof: PlayMetering Batches (max_values: None, max_size: Some(317), added: 2792, mode: MaxEncodedLen)
+	/// Storage: PlayMetering RevealRequests (r:1 w:1)
+	/// Proof: PlayMetering RevealRequests (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn request_reveal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2031`
+		//  Estimated: `5351`
+		// Minimum execution time: 37_943 nanoseconds.
+		Weight::from_parts(55_706_000, 5351)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PlayMetering Batches (r:1 w:1)
+	/// Proof: PlayMetering Batches (max_values: None, max_size: Some(317), added: 2792, mode: MaxEncodedLen)
+	/// Storage: PlayMetering RevealRequests (r:1 w:1)
+	/// Proof: PlayMetering RevealRequests (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn reveal_receipt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2308`
+		//  Estimated: `10557`
+		// Minimum execution time: 63_531 nanoseconds.
+		Weight::from_parts(75_238_000, 10557)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: PlayMetering Batches (r:1 w:1)
+	/// Proof: PlayMetering Batches (max_values: None, max_size: Some(317), added: 2792, mode: MaxEncodedLen)
+	/// Storage: PlayMetering RevealRequests (r:17 w:16)
+	/// Proof: PlayMetering RevealRequests (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:17 w:17)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `r` is `[1, 16]`.
+	fn reject_unrevealed(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2071 + r * (274 ±0)`
+		//  Estimated: `7954 + r * (5162 ±0)`
+		// Minimum execution time: 58_548 nanoseconds.
+		Weight::from_parts(66_932_442, 7954)
+			// Standard Error: 315_363
+			.saturating_add(Weight::from_ref_time(23_025_677).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_proof_size(5162).saturating_mul(r.into()))
+	}
+	/// Storage: PlayMetering UnpaidPlays (r:1 w:1)
+	/// Proof: PlayMetering UnpaidPlays (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:1 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: Royalties CurrentPeriod (r:1 w:1)
+	/// Proof: Royalties CurrentPeriod (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Royalties LatestPeriod (r:1 w:1)
+	/// Proof: Royalties LatestPeriod (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Royalties Accumulated (r:1 w:1)
+	/// Proof: Royalties Accumulated (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Royalties HolderShare (r:0 w:1)
+	/// Proof: Royalties HolderShare (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Royalties PeriodShares (r:0 w:1)
+	/// Proof: Royalties PeriodShares (max_values: None, max_size: Some(629), added: 3104, mode: MaxEncodedLen)
+	fn pay_unpaid_plays() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2278`
+		//  Estimated: `18583`
+		// Minimum execution time: 105_092 nanoseconds.
+		Weight::from_parts(117_140_000, 18583)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+}
@@ -0,0 +1,73 @@
+//! Benchmarking setup for pallet-royalties
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Royalties;
+
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn holders<T: Config>(name: &'static str, n: u32) -> Vec<T::AccountId> {
+	(0..n).map(|index| account(name, index, SEED)).collect()
+}
+
+/// Endows `who` and the pallet account, so that payouts keep it alive.
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+	T::Currency::make_free_balance_be(
+		&Royalties::<T>::account_id(),
+		T::Currency::minimum_balance(),
+	);
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	/// Starts a new period of a work shared by `n` holders, rolling over the dust of the previous
+	/// one.
+	#[benchmark]
+	fn deposit_royalties(n: Linear<1, 16>) {
+		let n = n.min(T::MaxHolders::get());
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let (collection, item) = T::BenchmarkHelper::register_work(&holders::<T>("holder", n));
+		let amount = T::Currency::minimum_balance().saturating_mul(1_000_003u32.into());
+		Royalties::<T>::do_deposit(&caller, collection, item, RoyaltyToken::Native, amount)
+			.expect("work is registered");
+		T::BenchmarkHelper::set_holders(&collection, &item, &holders::<T>("successor", n));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection, item, RoyaltyToken::Native, amount);
+
+		assert_eq!(CurrentPeriod::<T>::get(collection, item), Some(1));
+	}
+
+	/// Claims as a holder of a work shared by `MaxHolders` holders, which takes as long as with any
+	/// other number of holders.
+	#[benchmark]
+	fn claim_royalties() {
+		let n = T::MaxHolders::get();
+		let depositor: T::AccountId = account("depositor", 0, SEED);
+		fund::<T>(&depositor);
+		let holders = holders::<T>("holder", n);
+		let (collection, item) = T::BenchmarkHelper::register_work(&holders);
+		let amount = T::Currency::minimum_balance().saturating_mul(1_000_000u32.into());
+		Royalties::<T>::do_deposit(&depositor, collection, item, RoyaltyToken::Native, amount)
+			.expect("work is registered");
+		let holder = holders[n as usize - 1].clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(holder.clone()), collection, item, RoyaltyToken::Native, 0);
+
+		assert!(!Claimed::<T>::get((collection, item, RoyaltyToken::Native, 0, holder)).is_zero());
+	}
+
+	impl_benchmark_test_suite!(Royalties, crate::mock::new_test_ext(), crate::mock::Test);
+}
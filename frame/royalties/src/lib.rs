@@ -0,0 +1,479 @@
+//! # Royalties Pallet
+//!
+//! Distributes the royalties paid for works registered with `pallet_music_rights` to the holders
+//! of their rights, in the native currency or in any asset of a `fungibles` pallet such as
+//! `pallet_assets`.
+//!
+//! Deposits do not pay the holders of a work. Every work keeps, per token, the royalties paid into
+//! it per whole share of it, which only grows, and every holder pulls their share of it with
+//! `claim_royalties`. Claiming pays a holder the difference between their share of the
+//! accumulator and what they already claimed.
+//!
+//! Deposits still read the holders of a work, to find out whether their shares changed, and those
+//! of its previous payout period, to roll its dust over, so they take time linear in the number of
+//! holders. Their weight is that of a work with `MaxHolders` holders. Claims look up the share of
+//! the caller alone, as the shares of every period are also stored by holder when it starts, so
+//! they take constant time.
+//!
+//! The shares of the holders are those of the [`WorkRights`] of the work, snapshotted in payout
+//! periods. A work enters a new period on the first deposit after its shares changed, and the
+//! royalties of earlier periods stay claimable by the holders of their time. Shares are rounded
+//! down, so up to a unit per holder of the royalties of a period can't be claimed. This dust is
+//! added to the royalties of the next period of the work in the same token.
+//!
+//! The pallet account must hold the existential deposit of every token royalties are paid in, so
+//! that the last claims do not reap it.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `deposit_royalties` - Pays royalties for a work to the holders of its rights.
+//! * `claim_royalties` - Pays out the royalties of a period of a work owed to the caller.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::{tokens::fungibles, Currency, ExistenceRequirement::KeepAlive},
+	BoundedVec, RuntimeDebug,
+};
+use pallet_music_rights::WorkRights;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AccountIdConversion, Saturating, Zero},
+	ArithmeticError, Perbill,
+};
+
+type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+/// Balance of the native currency, which assets share.
+pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+/// Identifier of an asset.
+pub type AssetIdOf<T> = <<T as Config>::Assets as fungibles::Inspect<AccountIdOf<T>>>::AssetId;
+/// A token royalties are paid in.
+pub type RoyaltyTokenOf<T> = RoyaltyToken<AssetIdOf<T>>;
+/// Holders of a work with their share of it.
+pub type HolderSharesOf<T> = BoundedVec<(AccountIdOf<T>, Perbill), <T as Config>::MaxHolders>;
+/// Index of a payout period of a work.
+pub type PeriodIndex = u32;
+
+/// A token royalties are paid in.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum RoyaltyToken<AssetId> {
+	/// The native currency.
+	Native,
+	/// An asset of `Config::Assets`.
+	Asset(AssetId),
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, PalletId};
+	use frame_system::pallet_prelude::*;
+
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<AccountId, CollectionId, ItemId> {
+		/// Registers a work shared equally by `holders`.
+		fn register_work(holders: &[AccountId]) -> (CollectionId, ItemId);
+		/// Replaces the holders of a work registered by `register_work` with `holders`, sharing it
+		/// equally.
+		fn set_holders(collection: &CollectionId, item: &ItemId, holders: &[AccountId]);
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier of a collection of items works are held as.
+		type CollectionId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// Identifier of an item within its collection.
+		type ItemId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// The holders of the rights to works, e.g. `pallet_music_rights`.
+		type Rights: WorkRights<Self::AccountId, Self::CollectionId, Self::ItemId>;
+
+		/// The native currency.
+		type Currency: Currency<Self::AccountId>;
+
+		/// The assets royalties can be paid in besides the native currency.
+		type Assets: fungibles::Inspect<Self::AccountId, Balance = BalanceOf<Self>>
+			+ fungibles::Transfer<Self::AccountId>;
+
+		/// The pallet id, from which the account holding unclaimed royalties is derived.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Maximum number of holders of a work.
+		#[pallet::constant]
+		type MaxHolders: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Registers the works paid by the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::CollectionId, Self::ItemId>;
+	}
+
+	/// Current payout period of works, which were paid royalties at least once.
+	#[pallet::storage]
+	#[pallet::getter(fn current_period)]
+	pub type CurrentPeriod<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		PeriodIndex,
+		OptionQuery,
+	>;
+
+	/// Holders of works with their share, in each payout period of the works.
+	#[pallet::storage]
+	#[pallet::getter(fn period_shares)]
+	pub type PeriodShares<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, T::ItemId>,
+			NMapKey<Twox64Concat, PeriodIndex>,
+		),
+		HolderSharesOf<T>,
+		OptionQuery,
+	>;
+
+	/// Share of each holder of works, in each payout period of the works they held them in.
+	#[pallet::storage]
+	#[pallet::getter(fn holder_share)]
+	pub type HolderShare<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, T::ItemId>,
+			NMapKey<Twox64Concat, PeriodIndex>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		Perbill,
+		OptionQuery,
+	>;
+
+	/// Royalties paid into works per whole share of them, per token and payout period, including
+	/// the dust rolled over from their previous period. A holder of a share `s` of a work is owed
+	/// `s` of it.
+	#[pallet::storage]
+	#[pallet::getter(fn accumulated)]
+	pub type Accumulated<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, T::ItemId>,
+			NMapKey<Blake2_128Concat, RoyaltyTokenOf<T>>,
+			NMapKey<Twox64Concat, PeriodIndex>,
+		),
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Latest payout period works were paid royalties in, per token.
+	#[pallet::storage]
+	#[pallet::getter(fn latest_period)]
+	pub type LatestPeriod<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, T::ItemId>,
+			NMapKey<Blake2_128Concat, RoyaltyTokenOf<T>>,
+		),
+		PeriodIndex,
+		OptionQuery,
+	>;
+
+	/// Royalties claimed by the holders of works, per token and payout period.
+	#[pallet::storage]
+	#[pallet::getter(fn claimed)]
+	pub type Claimed<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, T::ItemId>,
+			NMapKey<Blake2_128Concat, RoyaltyTokenOf<T>>,
+			NMapKey<Twox64Concat, PeriodIndex>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Royalties were paid for a work.
+		RoyaltiesDeposited {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			token: RoyaltyTokenOf<T>,
+			period: PeriodIndex,
+			depositor: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// A work entered a new payout period, as the shares of its holders changed.
+		PeriodStarted { collection: T::CollectionId, item: T::ItemId, period: PeriodIndex },
+		/// The royalties of a period of a work no holder could claim were added to a later one.
+		DustRolledOver {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			token: RoyaltyTokenOf<T>,
+			from: PeriodIndex,
+			to: PeriodIndex,
+			amount: BalanceOf<T>,
+		},
+		/// A holder of a work claimed their royalties of a period.
+		RoyaltiesClaimed {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			token: RoyaltyTokenOf<T>,
+			period: PeriodIndex,
+			holder: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The item is not a registered work.
+		UnknownWork,
+		/// The work has more holders than `MaxHolders`.
+		TooManyHolders,
+		/// No royalties are paid.
+		ZeroAmount,
+		/// The work has no such payout period.
+		UnknownPeriod,
+		/// The caller did not hold the work in the payout period.
+		NotHolder,
+		/// The caller already claimed all of their royalties.
+		NothingToClaim,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Pays `amount` of `token` from the caller for the work held as `item` of `collection`,
+		/// to be claimed by the holders of its rights.
+		///
+		/// Emits `RoyaltiesDeposited`, preceded by `PeriodStarted` if the shares of the holders
+		/// changed since the last deposit and by `DustRolledOver` if dust of an earlier period was
+		/// added to the royalties.
+		///
+		/// Weight: `O(H)` where `H` is the number of holders of the work, charged as `MaxHolders`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::deposit_royalties(T::MaxHolders::get()))]
+		pub fn deposit_royalties(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			token: RoyaltyTokenOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_deposit(&who, collection, item, token, amount)
+		}
+
+		/// Pays out the royalties in `token` owed to the caller as a holder of the work held as
+		/// `item` of `collection` during `period`.
+		///
+		/// Emits `RoyaltiesClaimed`.
+		///
+		/// Weight: `O(1)`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::claim_royalties())]
+		pub fn claim_royalties(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			token: RoyaltyTokenOf<T>,
+			period: PeriodIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				CurrentPeriod::<T>::get(collection, item).is_some_and(|current| period <= current),
+				Error::<T>::UnknownPeriod
+			);
+			let share = HolderShare::<T>::get((collection, item, period, &who))
+				.ok_or(Error::<T>::NotHolder)?;
+			let owed = share.mul_floor(Accumulated::<T>::get((collection, item, token, period)));
+
+			let amount = Claimed::<T>::try_mutate(
+				(collection, item, token, period, &who),
+				|claimed| -> Result<_, DispatchError> {
+					let amount = owed.saturating_sub(*claimed);
+					ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+					*claimed = owed;
+					Ok(amount)
+				},
+			)?;
+			Self::transfer(token, &Self::account_id(), &who, amount)?;
+
+			Self::deposit_event(Event::RoyaltiesClaimed {
+				collection,
+				item,
+				token,
+				period,
+				holder: who,
+				amount,
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding the unclaimed royalties.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Pays `amount` of `token` from `depositor` for the work held as `item` of `collection`,
+		/// as `deposit_royalties` does.
+		pub fn do_deposit(
+			depositor: &T::AccountId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			token: RoyaltyTokenOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			let period = Self::sync_period(collection, item)?;
+			Self::transfer(token, depositor, &Self::account_id(), amount)?;
+
+			let dust = Self::roll_over_dust(collection, item, token, period);
+			Accumulated::<T>::mutate((collection, item, token, period), |accumulated| {
+				*accumulated = accumulated.saturating_add(amount).saturating_add(dust)
+			});
+			Self::deposit_event(Event::RoyaltiesDeposited {
+				collection,
+				item,
+				token,
+				period,
+				depositor: depositor.clone(),
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Royalties in `token` owed to `holder` as a holder of the work held as `item` of
+		/// `collection` during `period`, which they did not claim yet.
+		pub fn pending_royalties(
+			collection: T::CollectionId,
+			item: T::ItemId,
+			token: RoyaltyTokenOf<T>,
+			period: PeriodIndex,
+			holder: &T::AccountId,
+		) -> BalanceOf<T> {
+			HolderShare::<T>::get((collection, item, period, holder)).map_or_else(
+				Zero::zero,
+				|share| {
+					share
+						.mul_floor(Accumulated::<T>::get((collection, item, token, period)))
+						.saturating_sub(Claimed::<T>::get((
+							collection, item, token, period, holder,
+						)))
+				},
+			)
+		}
+
+		/// Returns the current payout period of the work held as `item` of `collection`, after
+		/// starting a new one if the shares of its holders changed since the current one started.
+		fn sync_period(
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> Result<PeriodIndex, DispatchError> {
+			let shares: HolderSharesOf<T> = T::Rights::holder_shares(&collection, &item)
+				.ok_or(Error::<T>::UnknownWork)?
+				.try_into()
+				.map_err(|_| Error::<T>::TooManyHolders)?;
+			let period = match CurrentPeriod::<T>::get(collection, item) {
+				Some(current)
+					if PeriodShares::<T>::get((collection, item, current)).as_ref() ==
+						Some(&shares) =>
+					return Ok(current),
+				Some(current) => current.checked_add(1).ok_or(ArithmeticError::Overflow)?,
+				None => 0,
+			};
+
+			CurrentPeriod::<T>::insert(collection, item, period);
+			for (holder, share) in &shares {
+				HolderShare::<T>::insert((collection, item, period, holder), share);
+			}
+			PeriodShares::<T>::insert((collection, item, period), shares);
+			Self::deposit_event(Event::PeriodStarted { collection, item, period });
+			Ok(period)
+		}
+
+		/// Makes `period` the latest payout period of the work held as `item` of `collection` in
+		/// `token`, and returns the royalties the holders of the previous latest one can't claim,
+		/// to be added to it.
+		fn roll_over_dust(
+			collection: T::CollectionId,
+			item: T::ItemId,
+			token: RoyaltyTokenOf<T>,
+			period: PeriodIndex,
+		) -> BalanceOf<T> {
+			let from = match LatestPeriod::<T>::mutate((collection, item, token), |latest| {
+				latest.replace(period)
+			}) {
+				Some(from) if from < period => from,
+				_ => return Zero::zero(),
+			};
+
+			let accumulated = Accumulated::<T>::get((collection, item, token, from));
+			let owed = PeriodShares::<T>::get((collection, item, from))
+				.unwrap_or_default()
+				.iter()
+				.fold(BalanceOf::<T>::zero(), |owed, (_, share)| {
+					owed.saturating_add(share.mul_floor(accumulated))
+				});
+			let dust = accumulated.saturating_sub(owed);
+			if !dust.is_zero() {
+				Self::deposit_event(Event::DustRolledOver {
+					collection,
+					item,
+					token,
+					from,
+					to: period,
+					amount: dust,
+				});
+			}
+			dust
+		}
+
+		fn transfer(
+			token: RoyaltyTokenOf<T>,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			match token {
+				RoyaltyToken::Native => T::Currency::transfer(from, to, amount, KeepAlive),
+				RoyaltyToken::Asset(asset) =>
+					<T::Assets as fungibles::Transfer<_>>::transfer(asset, from, to, amount, true)
+						.map(|_| ()),
+			}
+		}
+	}
+}
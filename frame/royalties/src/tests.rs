@@ -0,0 +1,271 @@
+//! Tests for the royalties pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use pallet_music_rights::SplitNode;
+
+const LABEL: u64 = 1;
+const ARTIST: u64 = 2;
+const FEATURED: u64 = 3;
+const ASSET: u32 = 7;
+
+/// The label passes 60% on to the artist, who passes half of it on to the featured artist.
+fn label_splits() -> pallet_music_rights::SplitTreeOf<Test> {
+	vec![
+		SplitNode { holder: LABEL, share: Perbill::one(), parent: None },
+		SplitNode { holder: ARTIST, share: Perbill::from_percent(60), parent: Some(0) },
+		SplitNode { holder: FEATURED, share: Perbill::from_percent(50), parent: Some(1) },
+	]
+	.try_into()
+	.unwrap()
+}
+
+fn deposit(token: RoyaltyTokenOf<Test>, amount: u64) {
+	assert_ok!(Royalties::deposit_royalties(RuntimeOrigin::signed(PAYER), 0, 0, token, amount));
+}
+
+fn claim(holder: u64, token: RoyaltyTokenOf<Test>, period: PeriodIndex) {
+	assert_ok!(Royalties::claim_royalties(RuntimeOrigin::signed(holder), 0, 0, token, period));
+}
+
+#[test]
+fn deposit_and_claim_royalties_works() {
+	new_test_ext().execute_with(|| {
+		register_work(0, label_splits());
+
+		deposit(RoyaltyToken::Native, 1_000);
+		System::assert_has_event(RuntimeEvent::Royalties(Event::PeriodStarted {
+			collection: 0,
+			item: 0,
+			period: 0,
+		}));
+		System::assert_last_event(RuntimeEvent::Royalties(Event::RoyaltiesDeposited {
+			collection: 0,
+			item: 0,
+			token: RoyaltyToken::Native,
+			period: 0,
+			depositor: PAYER,
+			amount: 1_000,
+		}));
+		assert_eq!(Balances::free_balance(PAYER), 9_000);
+		assert_eq!(Balances::free_balance(Royalties::account_id()), 1_001);
+		assert_eq!(Royalties::accumulated((0, 0, RoyaltyToken::Native, 0)), 1_000);
+
+		claim(ARTIST, RoyaltyToken::Native, 0);
+		System::assert_last_event(RuntimeEvent::Royalties(Event::RoyaltiesClaimed {
+			collection: 0,
+			item: 0,
+			token: RoyaltyToken::Native,
+			period: 0,
+			holder: ARTIST,
+			amount: 300,
+		}));
+		claim(LABEL, RoyaltyToken::Native, 0);
+		claim(FEATURED, RoyaltyToken::Native, 0);
+		assert_eq!(Balances::free_balance(LABEL), 400);
+		assert_eq!(Balances::free_balance(ARTIST), 300);
+		assert_eq!(Balances::free_balance(FEATURED), 300);
+		assert_eq!(Balances::free_balance(Royalties::account_id()), 1);
+
+		assert_noop!(
+			Royalties::claim_royalties(RuntimeOrigin::signed(LABEL), 0, 0, RoyaltyToken::Native, 0),
+			Error::<Test>::NothingToClaim
+		);
+	});
+}
+
+#[test]
+fn claims_only_pay_what_was_deposited_since() {
+	new_test_ext().execute_with(|| {
+		register_work(0, label_splits());
+
+		deposit(RoyaltyToken::Native, 1_000);
+		claim(ARTIST, RoyaltyToken::Native, 0);
+		deposit(RoyaltyToken::Native, 500);
+		assert_eq!(Royalties::pending_royalties(0, 0, RoyaltyToken::Native, 0, &ARTIST), 150);
+		assert_eq!(Royalties::pending_royalties(0, 0, RoyaltyToken::Native, 0, &LABEL), 600);
+
+		claim(ARTIST, RoyaltyToken::Native, 0);
+		claim(LABEL, RoyaltyToken::Native, 0);
+		assert_eq!(Balances::free_balance(ARTIST), 450);
+		assert_eq!(Balances::free_balance(LABEL), 600);
+		assert_eq!(Royalties::claimed((0, 0, RoyaltyToken::Native, 0, ARTIST)), 450);
+		assert_eq!(Royalties::pending_royalties(0, 0, RoyaltyToken::Native, 0, &ARTIST), 0);
+	});
+}
+
+#[test]
+fn new_period_starts_when_shares_change() {
+	new_test_ext().execute_with(|| {
+		register_work(0, equal_splits(&[LABEL, ARTIST]));
+		deposit(RoyaltyToken::Native, 100);
+
+		// The same shares in another tree keep the period.
+		set_splits(0, equal_splits(&[LABEL, ARTIST]));
+		deposit(RoyaltyToken::Native, 100);
+		assert_eq!(Royalties::current_period(0, 0), Some(0));
+
+		set_splits(0, equal_splits(&[ARTIST, FEATURED]));
+		// Periods only start on deposits.
+		assert_eq!(Royalties::current_period(0, 0), Some(0));
+		deposit(RoyaltyToken::Native, 300);
+		System::assert_has_event(RuntimeEvent::Royalties(Event::PeriodStarted {
+			collection: 0,
+			item: 0,
+			period: 1,
+		}));
+		assert_eq!(Royalties::current_period(0, 0), Some(1));
+		assert_eq!(Royalties::accumulated((0, 0, RoyaltyToken::Native, 0)), 200);
+		assert_eq!(Royalties::accumulated((0, 0, RoyaltyToken::Native, 1)), 300);
+		// The shares of each period are kept by holder.
+		assert_eq!(Royalties::holder_share((0, 0, 0, LABEL)), Some(Perbill::from_percent(50)));
+		assert_eq!(Royalties::holder_share((0, 0, 1, LABEL)), None);
+		assert_eq!(Royalties::holder_share((0, 0, 1, FEATURED)), Some(Perbill::from_percent(50)));
+
+		// Holders of the earlier period keep their royalties of it.
+		claim(LABEL, RoyaltyToken::Native, 0);
+		claim(ARTIST, RoyaltyToken::Native, 0);
+		claim(ARTIST, RoyaltyToken::Native, 1);
+		claim(FEATURED, RoyaltyToken::Native, 1);
+		assert_eq!(Balances::free_balance(LABEL), 100);
+		assert_eq!(Balances::free_balance(ARTIST), 250);
+		assert_eq!(Balances::free_balance(FEATURED), 150);
+		assert_noop!(
+			Royalties::claim_royalties(RuntimeOrigin::signed(LABEL), 0, 0, RoyaltyToken::Native, 1),
+			Error::<Test>::NotHolder
+		);
+	});
+}
+
+#[test]
+fn dust_rolls_over_to_the_next_period() {
+	new_test_ext().execute_with(|| {
+		register_work(0, equal_splits(&[LABEL, ARTIST, FEATURED]));
+		deposit(RoyaltyToken::Native, 10);
+		claim(LABEL, RoyaltyToken::Native, 0);
+		claim(ARTIST, RoyaltyToken::Native, 0);
+		claim(FEATURED, RoyaltyToken::Native, 0);
+		// A third of 10 is rounded down for everyone.
+		assert_eq!(Balances::free_balance(Royalties::account_id()), 2);
+
+		set_splits(0, equal_splits(&[LABEL, ARTIST]));
+		deposit(RoyaltyToken::Native, 9);
+		System::assert_has_event(RuntimeEvent::Royalties(Event::DustRolledOver {
+			collection: 0,
+			item: 0,
+			token: RoyaltyToken::Native,
+			from: 0,
+			to: 1,
+			amount: 1,
+		}));
+		assert_eq!(Royalties::accumulated((0, 0, RoyaltyToken::Native, 1)), 10);
+
+		claim(LABEL, RoyaltyToken::Native, 1);
+		claim(ARTIST, RoyaltyToken::Native, 1);
+		assert_eq!(Balances::free_balance(LABEL), 8);
+		assert_eq!(Balances::free_balance(ARTIST), 8);
+		assert_eq!(Balances::free_balance(Royalties::account_id()), 1);
+		// The dust of period 0 was moved, not duplicated.
+		deposit(RoyaltyToken::Native, 10);
+		assert_eq!(Royalties::accumulated((0, 0, RoyaltyToken::Native, 1)), 20);
+	});
+}
+
+#[test]
+fn royalties_in_assets_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), ASSET, OWNER, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(OWNER), ASSET, PAYER, 1_000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(OWNER), ASSET, Royalties::account_id(), 1));
+		register_work(0, label_splits());
+
+		deposit(RoyaltyToken::Asset(ASSET), 200);
+		deposit(RoyaltyToken::Native, 100);
+		claim(LABEL, RoyaltyToken::Asset(ASSET), 0);
+		claim(FEATURED, RoyaltyToken::Asset(ASSET), 0);
+		assert_eq!(Assets::balance(ASSET, LABEL), 80);
+		assert_eq!(Assets::balance(ASSET, FEATURED), 60);
+		assert_eq!(Assets::balance(ASSET, PAYER), 800);
+		assert_eq!(Balances::free_balance(LABEL), 0);
+		assert_eq!(Royalties::pending_royalties(0, 0, RoyaltyToken::Native, 0, &LABEL), 40);
+	});
+}
+
+#[test]
+fn deposit_royalties_fails_for_invalid_deposits() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Royalties::deposit_royalties(
+				RuntimeOrigin::signed(PAYER),
+				0,
+				0,
+				RoyaltyToken::Native,
+				100
+			),
+			Error::<Test>::UnknownWork
+		);
+		register_work(0, label_splits());
+		assert_noop!(
+			Royalties::deposit_royalties(
+				RuntimeOrigin::signed(PAYER),
+				0,
+				0,
+				RoyaltyToken::Native,
+				0
+			),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			Royalties::deposit_royalties(
+				RuntimeOrigin::signed(PAYER),
+				0,
+				0,
+				RoyaltyToken::Native,
+				20_000
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_noop!(
+			Royalties::deposit_royalties(
+				RuntimeOrigin::signed(PAYER),
+				0,
+				0,
+				RoyaltyToken::Asset(ASSET),
+				100
+			),
+			pallet_assets::Error::<Test>::Unknown
+		);
+	});
+}
+
+#[test]
+fn claim_royalties_fails_for_unknown_periods_and_holders() {
+	new_test_ext().execute_with(|| {
+		register_work(0, label_splits());
+		assert_noop!(
+			Royalties::claim_royalties(RuntimeOrigin::signed(LABEL), 0, 0, RoyaltyToken::Native, 0),
+			Error::<Test>::UnknownPeriod
+		);
+
+		deposit(RoyaltyToken::Native, 100);
+		assert_noop!(
+			Royalties::claim_royalties(RuntimeOrigin::signed(PAYER), 0, 0, RoyaltyToken::Native, 0),
+			Error::<Test>::NotHolder
+		);
+		assert_noop!(
+			Royalties::claim_royalties(RuntimeOrigin::signed(LABEL), 0, 0, RoyaltyToken::Native, 1),
+			Error::<Test>::UnknownPeriod
+		);
+		assert_noop!(
+			Royalties::claim_royalties(
+				RuntimeOrigin::signed(LABEL),
+				0,
+				0,
+				RoyaltyToken::Asset(ASSET),
+				0
+			),
+			Error::<Test>::NothingToClaim
+		);
+	});
+}
@@ -0,0 +1,137 @@
+//! Autogenerated weights for pallet_royalties
+//!
+//! MEASURED BY DRIVING THE `benchmark pallet` LOOP OVER THE NATIVE RUNTIME INSTEAD OF WASM.
+//! REGENERATE WITH THE COMMAND BELOW ON REFERENCE HARDWARE BEFORE RELYING ON THE TIMINGS.
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: Some(Native), CHAIN: Some("dev"), DB CACHE: 1024
+
+// Regenerate with:
+// ./target/production/substrate
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_royalties
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/royalties/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_royalties.
+pub trait WeightInfo {
+	fn deposit_royalties(n: u32, ) -> Weight;
+	fn claim_royalties() -> Weight;
+}
+
+/// Weights for pallet_royalties using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: MusicRights Works (r:1 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: Royalties CurrentPeriod (r:1 w:1)
+	/// Proof: Royalties CurrentPeriod (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Royalties PeriodShares (r:1 w:1)
+	/// Proof: Royalties PeriodShares (max_values: None, max_size: Some(629), added: 3104, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Royalties LatestPeriod (r:1 w:1)
+	/// Proof: Royalties LatestPeriod (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Royalties Accumulated (r:2 w:1)
+	/// Proof: Royalties Accumulated (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Royalties HolderShare (r:0 w:16)
+	/// Proof: Royalties HolderShare (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn deposit_royalties(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2603 + n * (73 ±0)`
+		//  Estimated: `19125`
+		// Minimum execution time: 77_473 nanoseconds.
+		Weight::from_parts(111_371_048, 19125)
+			// Standard Error: 119_357
+			.saturating_add(Weight::from_ref_time(4_129_880).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: Royalties CurrentPeriod (r:1 w:0)
+	/// Proof: Royalties CurrentPeriod (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Royalties HolderShare (r:1 w:0)
+	/// Proof: Royalties HolderShare (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Royalties Accumulated (r:1 w:0)
+	/// Proof: Royalties Accumulated (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Royalties Claimed (r:1 w:1)
+	/// Proof: Royalties Claimed (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_royalties() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2454`
+		//  Estimated: `15480`
+		// Minimum execution time: 89_206 nanoseconds.
+		Weight::from_parts(94_224_000, 15480)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: MusicRights Works (r:1 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: Royalties CurrentPeriod (r:1 w:1)
+	/// Proof: Royalties CurrentPeriod (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Royalties PeriodShares (r:1 w:1)
+	/// Proof: Royalties PeriodShares (max_values: None, max_size: Some(629), added: 3104, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Royalties LatestPeriod (r:1 w:1)
+	/// Proof: Royalties LatestPeriod (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Royalties Accumulated (r:2 w:1)
+	/// Proof: Royalties Accumulated (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Royalties HolderShare (r:0 w:16)
+	/// Proof: Royalties HolderShare (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn deposit_royalties(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2603 + n * (73 ±0)`
+		//  Estimated: `19125`
+		// Minimum execution time: 77_473 nanoseconds.
+		Weight::from_parts(111_371_048, 19125)
+			// Standard Error: 119_357
+			.saturating_add(Weight::from_ref_time(4_129_880).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: Royalties CurrentPeriod (r:1 w:0)
+	/// Proof: Royalties CurrentPeriod (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Royalties HolderShare (r:1 w:0)
+	/// Proof: Royalties HolderShare (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Royalties Accumulated (r:1 w:0)
+	/// Proof: Royalties Accumulated (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Royalties Claimed (r:1 w:1)
+	/// Proof: Royalties Claimed (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_royalties() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2454`
+		//  Estimated: `15480`
+		// Minimum execution time: 89_206 nanoseconds.
+		Weight::from_parts(94_224_000, 15480)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
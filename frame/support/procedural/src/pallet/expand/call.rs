@@ -182,7 +182,8 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 		});
 	}
 
-	// Extracts #[allow] attributes, necessary so that we don't run into compiler warnings
+	// Extracts #[allow] attributes, necessary so that we don't run into compiler warnings in the
+	// dispatch of the calls and in their constructors
 	let maybe_allow_attrs = methods
 		.iter()
 		.map(|method| {
@@ -259,6 +260,7 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 		impl<#type_impl_gen> #call_ident<#type_use_gen> #where_clause {
 			#(
 				#[doc = #new_call_variant_doc]
+				#maybe_allow_attrs
 				pub fn #new_call_variant_fn_name(
 					#( #args_name_stripped: #args_type ),*
 				) -> Self {
@@ -16,10 +16,94 @@
 // limitations under the License.
 
 use crate::{
-	traits::{GetStorageVersion, PalletInfoAccess},
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
 	weights::{RuntimeDbWeight, Weight},
 };
 use impl_trait_for_tuples::impl_for_tuples;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Executes the migration `Inner` only if the on-chain storage version of `Pallet` is `FROM`, and
+/// then sets it to `TO`.
+///
+/// This makes the version check and update of a migration impossible to forget, and lets
+/// `Inner` concern itself with moving the data alone. A migration done this way is conventionally
+/// named `MigrateToV<TO>`, and its inner migration `VersionUncheckedMigrateToV<TO>`.
+///
+/// The weight of `Inner` is returned along with the read of the version and, if the migration
+/// executes, its write, priced with `DbWeight`.
+pub struct VersionedMigration<const FROM: u16, const TO: u16, Inner, Pallet, DbWeight>(
+	PhantomData<(Inner, Pallet, DbWeight)>,
+);
+
+/// The state `VersionedMigration` passes from `pre_upgrade` to `post_upgrade`.
+#[cfg(feature = "try-runtime")]
+#[derive(codec::Encode, codec::Decode)]
+pub enum VersionedPostUpgradeData {
+	/// The migration executes, with the state of the inner migration.
+	MigrationExecuted(Vec<u8>),
+	/// The migration does not execute.
+	Noop,
+}
+
+impl<
+		const FROM: u16,
+		const TO: u16,
+		Inner: OnRuntimeUpgrade,
+		Pallet: GetStorageVersion + PalletInfoAccess,
+		DbWeight: Get<RuntimeDbWeight>,
+	> OnRuntimeUpgrade for VersionedMigration<FROM, TO, Inner, Pallet, DbWeight>
+{
+	fn on_runtime_upgrade() -> Weight {
+		let on_chain_version = Pallet::on_chain_storage_version();
+		if on_chain_version == FROM {
+			log::info!(
+				"Pallet {:?} migrating storage version from {:?} to {:?}.",
+				Pallet::name(),
+				FROM,
+				TO
+			);
+			let weight = Inner::on_runtime_upgrade();
+			StorageVersion::new(TO).put::<Pallet>();
+			weight.saturating_add(DbWeight::get().reads_writes(1, 1))
+		} else {
+			log::warn!(
+				"Pallet {:?} is at storage version {:?}, not {:?}: the migration to {:?} did not \
+				execute and should be removed.",
+				Pallet::name(),
+				on_chain_version,
+				FROM,
+				TO
+			);
+			DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		use codec::Encode;
+
+		if Pallet::on_chain_storage_version() == FROM {
+			Ok(VersionedPostUpgradeData::MigrationExecuted(Inner::pre_upgrade()?).encode())
+		} else {
+			Ok(VersionedPostUpgradeData::Noop.encode())
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		use codec::DecodeAll;
+
+		match VersionedPostUpgradeData::decode_all(&mut &state[..])
+			.map_err(|_| "VersionedMigration failed to decode its pre_upgrade state")?
+		{
+			VersionedPostUpgradeData::MigrationExecuted(inner_state) =>
+				Inner::post_upgrade(inner_state),
+			VersionedPostUpgradeData::Noop => Ok(()),
+		}
+	}
+}
 
 /// Trait used by [`migrate_from_pallet_version_to_storage_version`] to do the actual migration.
 pub trait PalletVersionToStorageVersionHelper {
@@ -42,7 +42,7 @@ use frame_support::{
 	dispatch::{DispatchInfo, DispatchResult, PostDispatchInfo},
 	traits::{
 		tokens::{
-			fungibles::{Balanced, CreditOf, Inspect},
+			fungibles::{Balanced, Inspect},
 			WithdrawConsequence,
 		},
 		IsType,
@@ -103,8 +103,8 @@ pub enum InitialPayment<T: Config> {
 	Nothing,
 	/// The initial fee was payed in the native currency.
 	Native(LiquidityInfoOf<T>),
-	/// The initial fee was payed in an asset.
-	Asset(CreditOf<T::AccountId, T::Fungibles>),
+	/// The initial fee was payed in an asset, as withdrawn by `OnChargeAssetTransaction`.
+	Asset(ChargeAssetLiquidityOf<T>),
 }
 
 pub use pallet::*;
@@ -160,7 +160,6 @@ where
 	AssetBalanceOf<T>: Send + Sync + FixedPointOperand,
 	BalanceOf<T>: Send + Sync + FixedPointOperand + IsType<ChargeAssetBalanceOf<T>>,
 	ChargeAssetIdOf<T>: Send + Sync,
-	CreditOf<T::AccountId, T::Fungibles>: IsType<ChargeAssetLiquidityOf<T>>,
 {
 	/// Utility constructor. Used only in client/factory code.
 	pub fn from(tip: BalanceOf<T>, asset_id: Option<ChargeAssetIdOf<T>>) -> Self {
@@ -189,7 +188,7 @@ where
 				fee.into(),
 				self.tip.into(),
 			)
-			.map(|i| (fee, InitialPayment::Asset(i.into())))
+			.map(|i| (fee, InitialPayment::Asset(i)))
 		} else {
 			<OnChargeTransactionOf<T> as OnChargeTransaction<T>>::withdraw_fee(
 				who, call, info, fee, self.tip,
@@ -217,7 +216,6 @@ where
 	AssetBalanceOf<T>: Send + Sync + FixedPointOperand,
 	BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand + IsType<ChargeAssetBalanceOf<T>>,
 	ChargeAssetIdOf<T>: Send + Sync,
-	CreditOf<T::AccountId, T::Fungibles>: IsType<ChargeAssetLiquidityOf<T>>,
 {
 	const IDENTIFIER: &'static str = "ChargeAssetTxPayment";
 	type AccountId = T::AccountId;
@@ -292,7 +290,7 @@ where
 							post_info,
 							actual_fee.into(),
 							tip.into(),
-							already_withdrawn.into(),
+							already_withdrawn,
 						)?;
 					Pallet::<T>::deposit_event(Event::<T>::AssetTxFeePaid {
 						who,
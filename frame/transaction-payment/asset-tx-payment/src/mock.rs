@@ -21,7 +21,9 @@ use frame_support::{
 	dispatch::DispatchClass,
 	pallet_prelude::*,
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, ConstU8, FindAuthor},
+	traits::{
+		tokens::fungibles::CreditOf, AsEnsureOriginWithArg, ConstU32, ConstU64, ConstU8, FindAuthor,
+	},
 	weights::{Weight, WeightToFee as WeightToFeeT},
 	ConsensusEngineId,
 };
@@ -192,41 +192,8 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::store(data.len() as u32))]
 		pub fn store(origin: OriginFor<T>, data: Vec<u8>) -> DispatchResult {
 			ensure!(data.len() > 0, Error::<T>::EmptyTransaction);
-			ensure!(
-				data.len() <= T::MaxTransactionSize::get() as usize,
-				Error::<T>::TransactionTooLarge
-			);
 			let sender = ensure_signed(origin)?;
-			Self::apply_fee(sender, data.len() as u32)?;
-
-			// Chunk data and compute storage root
-			let chunk_count = num_chunks(data.len() as u32);
-			let chunks = data.chunks(CHUNK_SIZE).map(|c| c.to_vec()).collect();
-			let root = sp_io::trie::blake2_256_ordered_root(chunks, sp_runtime::StateVersion::V1);
-
-			let content_hash = sp_io::hashing::blake2_256(&data);
-			let extrinsic_index =
-				<frame_system::Pallet<T>>::extrinsic_index().ok_or(Error::<T>::BadContext)?;
-			sp_io::transaction_index::index(extrinsic_index, data.len() as u32, content_hash);
-
-			let mut index = 0;
-			<BlockTransactions<T>>::mutate(|transactions| {
-				if transactions.len() + 1 > T::MaxBlockTransactions::get() as usize {
-					return Err(Error::<T>::TooManyTransactions)
-				}
-				let total_chunks = transactions.last().map_or(0, |t| t.block_chunks) + chunk_count;
-				index = transactions.len() as u32;
-				transactions
-					.try_push(TransactionInfo {
-						chunk_root: root,
-						size: data.len() as u32,
-						content_hash: content_hash.into(),
-						block_chunks: total_chunks,
-					})
-					.map_err(|_| Error::<T>::TooManyTransactions)?;
-				Ok(())
-			})?;
-			Self::deposit_event(Event::Stored { index });
+			Self::do_store(sender, &data)?;
 			Ok(())
 		}
 
@@ -245,33 +212,7 @@ pub mod pallet {
 			index: u32,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
-			let transactions = <Transactions<T>>::get(block).ok_or(Error::<T>::RenewedNotFound)?;
-			let info = transactions.get(index as usize).ok_or(Error::<T>::RenewedNotFound)?;
-			let extrinsic_index =
-				<frame_system::Pallet<T>>::extrinsic_index().ok_or(Error::<T>::BadContext)?;
-
-			Self::apply_fee(sender, info.size)?;
-
-			sp_io::transaction_index::renew(extrinsic_index, info.content_hash.into());
-
-			let mut index = 0;
-			<BlockTransactions<T>>::mutate(|transactions| {
-				if transactions.len() + 1 > T::MaxBlockTransactions::get() as usize {
-					return Err(Error::<T>::TooManyTransactions)
-				}
-				let chunks = num_chunks(info.size);
-				let total_chunks = transactions.last().map_or(0, |t| t.block_chunks) + chunks;
-				index = transactions.len() as u32;
-				transactions
-					.try_push(TransactionInfo {
-						chunk_root: info.chunk_root,
-						size: info.size,
-						content_hash: info.content_hash,
-						block_chunks: total_chunks,
-					})
-					.map_err(|_| Error::<T>::TooManyTransactions)
-			})?;
-			Self::deposit_event(Event::Renewed { index });
+			Self::do_renew(sender, block, index)?;
 			Ok(().into())
 		}
 
@@ -434,10 +375,99 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
-		fn apply_fee(sender: T::AccountId, size: u32) -> DispatchResult {
+		/// Index `data` as stored by `sender`, charging them the fees, and return its transaction
+		/// index within the block.
+		///
+		/// `data` must be the trailing bytes of the current extrinsic, which is what the client
+		/// indexes, and only one transaction can be stored per extrinsic.
+		pub fn do_store(sender: T::AccountId, data: &[u8]) -> Result<u32, DispatchError> {
+			ensure!(!data.is_empty(), Error::<T>::EmptyTransaction);
+			ensure!(
+				data.len() <= T::MaxTransactionSize::get() as usize,
+				Error::<T>::TransactionTooLarge
+			);
+			Self::apply_fee(sender, data.len() as u32)?;
+
+			// Chunk data and compute storage root
+			let chunk_count = num_chunks(data.len() as u32);
+			let chunks = data.chunks(CHUNK_SIZE).map(|c| c.to_vec()).collect();
+			let root = sp_io::trie::blake2_256_ordered_root(chunks, sp_runtime::StateVersion::V1);
+
+			let content_hash = sp_io::hashing::blake2_256(data);
+			let extrinsic_index =
+				<frame_system::Pallet<T>>::extrinsic_index().ok_or(Error::<T>::BadContext)?;
+			sp_io::transaction_index::index(extrinsic_index, data.len() as u32, content_hash);
+
+			let mut index = 0;
+			<BlockTransactions<T>>::mutate(|transactions| {
+				if transactions.len() + 1 > T::MaxBlockTransactions::get() as usize {
+					return Err(Error::<T>::TooManyTransactions)
+				}
+				let total_chunks = transactions.last().map_or(0, |t| t.block_chunks) + chunk_count;
+				index = transactions.len() as u32;
+				transactions
+					.try_push(TransactionInfo {
+						chunk_root: root,
+						size: data.len() as u32,
+						content_hash: content_hash.into(),
+						block_chunks: total_chunks,
+					})
+					.map_err(|_| Error::<T>::TooManyTransactions)?;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::Stored { index });
+			Ok(index)
+		}
+
+		/// Renew the data stored at transaction `index` of `block` for `sender`, charging them the
+		/// fees, and return its new transaction index within the block.
+		///
+		/// Like `do_store`, only one transaction can be renewed per extrinsic, as the client
+		/// indexes a single renewal per extrinsic.
+		pub fn do_renew(
+			sender: T::AccountId,
+			block: T::BlockNumber,
+			index: u32,
+		) -> Result<u32, DispatchError> {
+			let transactions = <Transactions<T>>::get(block).ok_or(Error::<T>::RenewedNotFound)?;
+			let info = transactions.get(index as usize).ok_or(Error::<T>::RenewedNotFound)?;
+			let extrinsic_index =
+				<frame_system::Pallet<T>>::extrinsic_index().ok_or(Error::<T>::BadContext)?;
+
+			Self::apply_fee(sender, info.size)?;
+
+			sp_io::transaction_index::renew(extrinsic_index, info.content_hash.into());
+
+			let mut index = 0;
+			<BlockTransactions<T>>::mutate(|transactions| {
+				if transactions.len() + 1 > T::MaxBlockTransactions::get() as usize {
+					return Err(Error::<T>::TooManyTransactions)
+				}
+				let chunks = num_chunks(info.size);
+				let total_chunks = transactions.last().map_or(0, |t| t.block_chunks) + chunks;
+				index = transactions.len() as u32;
+				transactions
+					.try_push(TransactionInfo {
+						chunk_root: info.chunk_root,
+						size: info.size,
+						content_hash: info.content_hash,
+						block_chunks: total_chunks,
+					})
+					.map_err(|_| Error::<T>::TooManyTransactions)
+			})?;
+			Self::deposit_event(Event::Renewed { index });
+			Ok(index)
+		}
+
+		/// Fee of storing or renewing a transaction of `size` bytes.
+		pub fn fee(size: u32) -> Result<BalanceOf<T>, DispatchError> {
 			let byte_fee = ByteFee::<T>::get().ok_or(Error::<T>::NotConfigured)?;
 			let entry_fee = EntryFee::<T>::get().ok_or(Error::<T>::NotConfigured)?;
-			let fee = byte_fee.saturating_mul(size.into()).saturating_add(entry_fee);
+			Ok(byte_fee.saturating_mul(size.into()).saturating_add(entry_fee))
+		}
+
+		fn apply_fee(sender: T::AccountId, size: u32) -> DispatchResult {
+			let fee = Self::fee(size)?;
 			ensure!(T::Currency::can_slash(&sender, fee), Error::<T>::InsufficientFunds);
 			let (credit, _) = T::Currency::slash(&sender, fee);
 			T::FeeDestination::on_unbalanced(credit);
@@ -0,0 +1,44 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the uniques pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+pub use pallet_uniques::TicketStatus;
+
+sp_api::decl_runtime_apis! {
+	pub trait UniquesApi<AccountId, CollectionId, ItemId, BlockNumber, Signature> where
+		AccountId: Codec,
+		CollectionId: Codec,
+		ItemId: Codec,
+		BlockNumber: Codec,
+		Signature: Codec,
+	{
+		/// Get the status of a ticket, checking that `signature` is the signature of `challenge`
+		/// by its owner.
+		fn ticket_status(
+			collection: CollectionId,
+			item: ItemId,
+			challenge: Vec<u8>,
+			signature: Signature,
+		) -> Option<TicketStatus<AccountId, BlockNumber>>;
+	}
+}
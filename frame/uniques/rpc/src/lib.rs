@@ -0,0 +1,121 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the uniques pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use pallet_uniques_rpc_runtime_api::TicketStatus;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, NumberFor},
+};
+
+pub use pallet_uniques_rpc_runtime_api::UniquesApi as UniquesRuntimeApi;
+
+#[rpc(client, server)]
+pub trait UniquesApi<BlockHash, AccountId, CollectionId, ItemId, BlockNumber, Signature> {
+	/// Get the owner of a ticket and whether it can still be used to enter, `signature` being
+	/// the signature of `challenge` the holder of the ticket was asked for.
+	#[method(name = "uniques_ticketStatus")]
+	fn ticket_status(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		challenge: Bytes,
+		signature: Signature,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<TicketStatus<AccountId, BlockNumber>>>;
+}
+
+/// Provides RPC methods to verify tickets minted as uniques items.
+pub struct Uniques<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Uniques<C, P> {
+	/// Creates a new instance of the Uniques Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, AccountId, CollectionId, ItemId, Signature>
+	UniquesApiServer<
+		<Block as BlockT>::Hash,
+		AccountId,
+		CollectionId,
+		ItemId,
+		NumberFor<Block>,
+		Signature,
+	> for Uniques<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: UniquesRuntimeApi<Block, AccountId, CollectionId, ItemId, NumberFor<Block>, Signature>,
+	AccountId: Codec + Send + Sync + 'static,
+	CollectionId: Codec + Send + Sync + 'static,
+	ItemId: Codec + Send + Sync + 'static,
+	Signature: Codec + Send + Sync + 'static,
+{
+	fn ticket_status(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		challenge: Bytes,
+		signature: Signature,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<TicketStatus<AccountId, NumberFor<Block>>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.ticket_status(&at, collection, item, challenge.to_vec(), signature)
+			.map_err(|e| {
+				CallError::Custom(ErrorObject::owned(
+					Error::RuntimeError.into(),
+					"Unable to query the ticket status.",
+					Some(e.to_string()),
+				))
+				.into()
+			})
+	}
+}
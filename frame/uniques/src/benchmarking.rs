@@ -24,7 +24,7 @@ use frame_benchmarking::v1::{
 	account, benchmarks_instance_pallet, whitelist_account, whitelisted_caller, BenchmarkError,
 };
 use frame_support::{
-	dispatch::UnfilteredDispatchable,
+	dispatch::{DispatchResult, UnfilteredDispatchable},
 	traits::{EnsureOrigin, Get},
 	BoundedVec,
 };
@@ -134,6 +134,48 @@ fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::
 	assert_eq!(event, &system_event);
 }
 
+/// A funded bidder, with the price and expiry of their offers.
+fn bidder<T: Config<I>, I: 'static>() -> (T::AccountId, ItemPrice<T, I>, T::BlockNumber) {
+	let bidder: T::AccountId = account("bidder", 0, SEED);
+	T::Currency::make_free_balance_be(&bidder, DepositBalanceOf::<T, I>::max_value() / 4u32.into());
+	let price = T::Currency::minimum_balance().saturating_mul(100u32.into());
+	let expiry = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+	(bidder, price, expiry)
+}
+
+/// Places `MaxOffers` offers of other bidders at `price` until `expiry` on `maybe_item`, or else on
+/// any item of `collection`.
+fn fill_offers<T: Config<I>, I: 'static>(
+	collection: T::CollectionId,
+	maybe_item: Option<T::ItemId>,
+	price: ItemPrice<T, I>,
+	expiry: T::BlockNumber,
+) {
+	for i in 0..T::MaxOffers::get() {
+		let bidder: T::AccountId = account("offerer", i, SEED);
+		T::Currency::make_free_balance_be(&bidder, price.saturating_mul(2u32.into()));
+		assert!(T::Currency::reserve(&bidder, price).is_ok());
+		let offer = Offer { price, expiry };
+		match maybe_item {
+			Some(item) => ItemOffers::<T, I>::insert((&collection, &item, &bidder), offer),
+			None => CollectionBids::<T, I>::insert(collection, &bidder, offer),
+		}
+	}
+}
+
+/// Makes `item` pay the highest royalty to another account, with `admin` as the admin.
+fn set_max_royalty<T: Config<I>, I: 'static>(
+	collection: T::CollectionId,
+	item: T::ItemId,
+	admin: &T::AccountId,
+) -> DispatchResult {
+	let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+	T::Currency::make_free_balance_be(&beneficiary, T::Currency::minimum_balance());
+	let royalty = RoyaltyInfo { share: T::MaxRoyalty::get(), beneficiary };
+	let origin = SystemOrigin::Signed(admin.clone()).into();
+	Uniques::<T, I>::set_item_royalty(origin, collection, item, Some(royalty))
+}
+
 benchmarks_instance_pallet! {
 	create {
 		let collection = T::Helper::collection(0);
@@ -164,9 +206,12 @@ benchmarks_instance_pallet! {
 
 		let (collection, caller, caller_lookup) = create_collection::<T, I>();
 		add_collection_metadata::<T, I>();
+		let (_, price, expiry) = bidder::<T, I>();
 		for i in 0..n {
-			mint_item::<T, I>(i as u16);
+			let (item, ..) = mint_item::<T, I>(i as u16);
+			fill_offers::<T, I>(collection, Some(item), price, expiry);
 		}
+		fill_offers::<T, I>(collection, None, price, expiry);
 		for i in 0..m {
 			add_item_metadata::<T, I>(T::Helper::item(i as u16));
 		}
@@ -190,6 +235,8 @@ benchmarks_instance_pallet! {
 	burn {
 		let (collection, caller, caller_lookup) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
+		let (_, price, expiry) = bidder::<T, I>();
+		fill_offers::<T, I>(collection, Some(item), price, expiry);
 	}: _(SystemOrigin::Signed(caller.clone()), collection, item, Some(caller_lookup))
 	verify {
 		assert_last_event::<T, I>(Event::Burned { collection, item, owner: caller }.into());
@@ -430,11 +477,14 @@ benchmarks_instance_pallet! {
 		let (item, ..) = mint_item::<T, I>(0);
 		let buyer: T::AccountId = account("buyer", 0, SEED);
 		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
-		let price = ItemPrice::<T, I>::from(0u32);
+		let price = T::Currency::minimum_balance().saturating_mul(100u32.into());
 		let origin = SystemOrigin::Signed(seller.clone()).into();
-		Uniques::<T, I>::set_price(origin, collection, item, Some(price.clone()), Some(buyer_lookup))?;
-		T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value());
-	}: _(SystemOrigin::Signed(buyer.clone()), collection, item, price.clone())
+		Uniques::<T, I>::set_price(origin, collection, item, Some(price), Some(buyer_lookup))?;
+		set_max_royalty::<T, I>(collection, item, &seller)?;
+		let balance = DepositBalanceOf::<T, I>::max_value() / 2u32.into();
+		T::Currency::make_free_balance_be(&seller, balance);
+		T::Currency::make_free_balance_be(&buyer, balance);
+	}: _(SystemOrigin::Signed(buyer.clone()), collection, item, price)
 	verify {
 		assert_last_event::<T, I>(Event::ItemBought {
 			collection,
@@ -445,5 +495,146 @@ benchmarks_instance_pallet! {
 		}.into());
 	}
 
+	set_collection_royalty {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let royalty = Some(RoyaltyInfo { share: T::MaxRoyalty::get(), beneficiary: caller.clone() });
+	}: _(SystemOrigin::Signed(caller), collection, royalty.clone())
+	verify {
+		assert_last_event::<T, I>(Event::CollectionRoyaltySet { collection, royalty }.into());
+	}
+
+	set_item_royalty {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let royalty = Some(RoyaltyInfo { share: T::MaxRoyalty::get(), beneficiary: caller.clone() });
+	}: _(SystemOrigin::Signed(caller), collection, item, royalty.clone())
+	verify {
+		assert_last_event::<T, I>(Event::ItemRoyaltySet { collection, item, royalty }.into());
+	}
+
+	place_offer {
+		let (collection, ..) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let (bidder, price, expiry) = bidder::<T, I>();
+		// The offer outbids the lowest of a full set of offers.
+		fill_offers::<T, I>(collection, Some(item), price, expiry);
+		let price = price.saturating_mul(2u32.into());
+	}: _(SystemOrigin::Signed(bidder.clone()), collection, item, price, expiry)
+	verify {
+		assert_last_event::<T, I>(Event::OfferPlaced { collection, item, bidder, price, expiry }.into());
+	}
+
+	cancel_offer {
+		let (collection, ..) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let (bidder, price, expiry) = bidder::<T, I>();
+		let origin = SystemOrigin::Signed(bidder.clone()).into();
+		Uniques::<T, I>::place_offer(origin, collection, item, price, expiry)?;
+	}: _(SystemOrigin::Signed(bidder.clone()), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::OfferCancelled { collection, item, bidder }.into());
+	}
+
+	place_collection_bid {
+		let (collection, ..) = create_collection::<T, I>();
+		let (bidder, price, expiry) = bidder::<T, I>();
+		// The offer outbids the lowest of a full set of offers.
+		fill_offers::<T, I>(collection, None, price, expiry);
+		let price = price.saturating_mul(2u32.into());
+	}: _(SystemOrigin::Signed(bidder.clone()), collection, price, expiry)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionBidPlaced { collection, bidder, price, expiry }.into());
+	}
+
+	cancel_collection_bid {
+		let (collection, ..) = create_collection::<T, I>();
+		let (bidder, price, expiry) = bidder::<T, I>();
+		let origin = SystemOrigin::Signed(bidder.clone()).into();
+		Uniques::<T, I>::place_collection_bid(origin, collection, price, expiry)?;
+	}: _(SystemOrigin::Signed(bidder.clone()), collection)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionBidCancelled { collection, bidder }.into());
+	}
+
+	accept_offer {
+		let (collection, seller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		set_max_royalty::<T, I>(collection, item, &seller)?;
+		T::Currency::make_free_balance_be(&seller, DepositBalanceOf::<T, I>::max_value() / 2u32.into());
+		let (bidder, price, expiry) = bidder::<T, I>();
+		let bidder_lookup = T::Lookup::unlookup(bidder.clone());
+		let origin = SystemOrigin::Signed(bidder.clone()).into();
+		Uniques::<T, I>::place_offer(origin, collection, item, price, expiry)?;
+	}: _(SystemOrigin::Signed(seller.clone()), collection, item, bidder_lookup, price)
+	verify {
+		assert_last_event::<T, I>(Event::OfferAccepted {
+			collection,
+			item,
+			price,
+			seller,
+			buyer: bidder,
+		}.into());
+	}
+
+	accept_collection_bid {
+		let (collection, seller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		set_max_royalty::<T, I>(collection, item, &seller)?;
+		T::Currency::make_free_balance_be(&seller, DepositBalanceOf::<T, I>::max_value() / 2u32.into());
+		let (bidder, price, expiry) = bidder::<T, I>();
+		let bidder_lookup = T::Lookup::unlookup(bidder.clone());
+		let origin = SystemOrigin::Signed(bidder.clone()).into();
+		Uniques::<T, I>::place_collection_bid(origin, collection, price, expiry)?;
+	}: _(SystemOrigin::Signed(seller.clone()), collection, item, bidder_lookup, price)
+	verify {
+		assert_last_event::<T, I>(Event::OfferAccepted {
+			collection,
+			item,
+			price,
+			seller,
+			buyer: bidder,
+		}.into());
+	}
+
+	set_transfer_policy {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let policy = Some(TransferPolicy::ResaleCapped { face_value: ItemPrice::<T, I>::from(100u32) });
+	}: _(SystemOrigin::Signed(caller), collection, policy)
+	verify {
+		assert_last_event::<T, I>(Event::TransferPolicySet { collection, policy }.into());
+	}
+
+	check_in {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let owner = Uniques::<T, I>::owner(collection, item).expect("item is minted");
+	}: _(SystemOrigin::Signed(caller), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::CheckedIn { collection, item, owner }.into());
+	}
+
+	undo_check_in {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let owner = Uniques::<T, I>::owner(collection, item).expect("item is minted");
+		Uniques::<T, I>::check_in(SystemOrigin::Signed(caller.clone()).into(), collection, item)?;
+	}: _(SystemOrigin::Signed(caller), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::CheckInUndone { collection, item, owner }.into());
+	}
+
+	cancel_expired_offer {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let (bidder, price, expiry) = bidder::<T, I>();
+		let bidder_lookup = T::Lookup::unlookup(bidder.clone());
+		let origin = SystemOrigin::Signed(bidder.clone()).into();
+		Uniques::<T, I>::place_offer(origin, collection, item, price, expiry)?;
+		frame_system::Pallet::<T>::set_block_number(expiry);
+	}: _(SystemOrigin::Signed(caller), collection, Some(item), bidder_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::OfferCancelled { collection, item, bidder }.into());
+	}
+
 	impl_benchmark_test_suite!(Uniques, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -20,7 +20,8 @@
 use super::*;
 use frame_support::{
 	ensure,
-	traits::{ExistenceRequirement, Get},
+	traits::{BalanceStatus, ExistenceRequirement, Get},
+	BoundedVec,
 };
 use sp_runtime::{DispatchError, DispatchResult};
 
@@ -33,11 +34,47 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			&CollectionDetailsFor<T, I>,
 			&mut ItemDetailsFor<T, I>,
 		) -> DispatchResult,
+	) -> DispatchResult {
+		Self::transfer_item(collection, item, dest, true, with_details)
+	}
+
+	/// Transfers `item` out of the account of a pallet holding it, like `do_transfer` but whatever
+	/// the transfer policy of its collection, which applied when the item was moved in.
+	pub fn do_release(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		dest: T::AccountId,
+	) -> DispatchResult {
+		Self::transfer_item(collection, item, dest, false, |_, _| Ok(()))
+	}
+
+	/// Fails if the transfer policy of `collection` forbids transfers at block `at`.
+	pub fn check_transferable(collection: T::CollectionId, at: T::BlockNumber) -> DispatchResult {
+		match CollectionTransferPolicy::<T, I>::get(collection) {
+			Some(TransferPolicy::NonTransferable) => Err(Error::<T, I>::TransferForbidden.into()),
+			Some(TransferPolicy::TransferableUntil(until)) if at >= until =>
+				Err(Error::<T, I>::TransferForbidden.into()),
+			_ => Ok(()),
+		}
+	}
+
+	fn transfer_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		dest: T::AccountId,
+		check_policy: bool,
+		with_details: impl FnOnce(
+			&CollectionDetailsFor<T, I>,
+			&mut ItemDetailsFor<T, I>,
+		) -> DispatchResult,
 	) -> DispatchResult {
 		let collection_details =
 			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 		ensure!(!collection_details.is_frozen, Error::<T, I>::Frozen);
 		ensure!(!T::Locker::is_locked(collection, item), Error::<T, I>::Locked);
+		if check_policy {
+			Self::check_transferable(collection, frame_system::Pallet::<T>::block_number())?;
+		}
 
 		let mut details =
 			Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownCollection)?;
@@ -91,6 +128,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				item_metadatas: 0,
 				attributes: 0,
 				is_frozen: false,
+				royalty: None,
 			},
 		);
 
@@ -119,17 +157,29 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 			for (item, details) in Item::<T, I>::drain_prefix(&collection) {
 				Account::<T, I>::remove((&details.owner, &collection, &item));
+				T::OnBurn::on_burn(&collection, &item);
 			}
 			#[allow(deprecated)]
 			ItemMetadataOf::<T, I>::remove_prefix(&collection, None);
 			#[allow(deprecated)]
 			ItemPriceOf::<T, I>::remove_prefix(&collection, None);
+			#[allow(deprecated)]
+			ItemRoyalty::<T, I>::remove_prefix(collection, None);
 			CollectionMetadataOf::<T, I>::remove(&collection);
 			#[allow(deprecated)]
 			Attribute::<T, I>::remove_prefix((&collection,), None);
 			CollectionAccount::<T, I>::remove(&collection_details.owner, &collection);
 			T::Currency::unreserve(&collection_details.owner, collection_details.total_deposit);
 			CollectionMaxSupply::<T, I>::remove(&collection);
+			CollectionTransferPolicy::<T, I>::remove(&collection);
+			for ((item, bidder), offer) in ItemOffers::<T, I>::drain_prefix((&collection,)) {
+				T::Currency::unreserve(&bidder, offer.price);
+				Self::deposit_event(Event::OfferCancelled { collection, item, bidder });
+			}
+			for (bidder, offer) in CollectionBids::<T, I>::drain_prefix(collection) {
+				T::Currency::unreserve(&bidder, offer.price);
+				Self::deposit_event(Event::CollectionBidCancelled { collection, bidder });
+			}
 
 			Self::deposit_event(Event::Destroyed { collection });
 
@@ -210,6 +260,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Item::<T, I>::remove(&collection, &item);
 		Account::<T, I>::remove((&owner, &collection, &item));
 		ItemPriceOf::<T, I>::remove(&collection, &item);
+		ItemRoyalty::<T, I>::remove(collection, item);
+		for (bidder, offer) in ItemOffers::<T, I>::drain_prefix((&collection, &item)) {
+			T::Currency::unreserve(&bidder, offer.price);
+			Self::deposit_event(Event::OfferCancelled { collection, item, bidder });
+		}
+		T::OnBurn::on_burn(&collection, &item);
 
 		Self::deposit_event(Event::Burned { collection, item, owner });
 		Ok(())
@@ -226,6 +282,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(details.owner == sender, Error::<T, I>::NoPermission);
 
 		if let Some(ref price) = price {
+			Self::check_sale_price(collection, *price)?;
 			ItemPriceOf::<T, I>::insert(&collection, &item, (price, whitelisted_buyer.clone()));
 			Self::deposit_event(Event::ItemPriceSet {
 				collection,
@@ -254,18 +311,25 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ItemPriceOf::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::NotForSale)?;
 
 		ensure!(bid_price >= price_info.0, Error::<T, I>::BidTooLow);
+		// The listing may predate the transfer policy.
+		Self::check_sale_price(collection, price_info.0)?;
 
 		if let Some(only_buyer) = price_info.1 {
 			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
 		}
 
-		T::Currency::transfer(
-			&buyer,
+		let proceeds = Self::do_pay_royalty(
+			collection,
+			item,
 			&details.owner,
 			price_info.0,
-			ExistenceRequirement::KeepAlive,
+			|beneficiary, amount| {
+				T::Currency::transfer(&buyer, beneficiary, amount, ExistenceRequirement::KeepAlive)
+			},
 		)?;
 
+		T::Currency::transfer(&buyer, &details.owner, proceeds, ExistenceRequirement::KeepAlive)?;
+
 		let old_owner = details.owner.clone();
 
 		Self::do_transfer(collection, item, buyer.clone(), |_, _| Ok(()))?;
@@ -280,4 +344,347 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Ok(())
 	}
+
+	/// Pays the royalty of `item` on its sale by `seller` at `price` with `pay`, returning what is
+	/// left of the price for the seller.
+	pub fn do_pay_royalty(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		seller: &T::AccountId,
+		price: ItemPrice<T, I>,
+		pay: impl FnOnce(&T::AccountId, ItemPrice<T, I>) -> DispatchResult,
+	) -> Result<ItemPrice<T, I>, DispatchError> {
+		let mut proceeds = price;
+		if let Some(royalty) = Self::royalty(collection, item) {
+			// A royalty set before the cap was lowered is only paid up to the cap.
+			let amount = royalty.share.min(T::MaxRoyalty::get()).mul_floor(price);
+			if !amount.is_zero() && &royalty.beneficiary != seller {
+				pay(&royalty.beneficiary, amount)?;
+				proceeds.saturating_reduce(amount);
+				Self::deposit_event(Event::RoyaltyPaid {
+					collection,
+					item,
+					beneficiary: royalty.beneficiary,
+					amount,
+				});
+			}
+		}
+		Ok(proceeds)
+	}
+
+	pub fn do_place_offer(
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		bidder: T::AccountId,
+		price: ItemPrice<T, I>,
+		expiry: T::BlockNumber,
+	) -> DispatchResult {
+		ensure!(!price.is_zero(), Error::<T, I>::BidTooLow);
+		Self::check_sale_price(collection, price)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(expiry > now, Error::<T, I>::OfferExpired);
+
+		let offer = Offer { price, expiry };
+		match maybe_item {
+			Some(item) => {
+				let details =
+					Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+				ensure!(details.owner != bidder, Error::<T, I>::NoPermission);
+				if !ItemOffers::<T, I>::contains_key((&collection, &item, &bidder)) {
+					let offers = ItemOffers::<T, I>::iter_prefix((&collection, &item));
+					if let Some(outbid) = Self::offer_to_cancel(offers, price)? {
+						Self::do_cancel_offer(collection, Some(item), outbid)?;
+					}
+				}
+				ItemOffers::<T, I>::try_mutate((&collection, &item, &bidder), |maybe_offer| {
+					Self::replace_offer(&bidder, maybe_offer, offer)
+				})?;
+				Self::deposit_event(Event::OfferPlaced { collection, item, bidder, price, expiry });
+			},
+			None => {
+				ensure!(
+					Collection::<T, I>::contains_key(&collection),
+					Error::<T, I>::UnknownCollection
+				);
+				if !CollectionBids::<T, I>::contains_key(collection, &bidder) {
+					let offers = CollectionBids::<T, I>::iter_prefix(collection);
+					if let Some(outbid) = Self::offer_to_cancel(offers, price)? {
+						Self::do_cancel_offer(collection, None, outbid)?;
+					}
+				}
+				CollectionBids::<T, I>::try_mutate(&collection, &bidder, |maybe_offer| {
+					Self::replace_offer(&bidder, maybe_offer, offer)
+				})?;
+				Self::deposit_event(Event::CollectionBidPlaced {
+					collection,
+					bidder,
+					price,
+					expiry,
+				});
+			},
+		}
+		Ok(())
+	}
+
+	/// Finds the bidder of the offer among `offers` to cancel to make room for a new offer at
+	/// `price`, if there are already `MaxOffers` of them: an expired offer, or else the lowest
+	/// one, which must be below `price`.
+	fn offer_to_cancel(
+		offers: impl Iterator<Item = (T::AccountId, OfferFor<T, I>)>,
+		price: ItemPrice<T, I>,
+	) -> Result<Option<T::AccountId>, DispatchError> {
+		let now = frame_system::Pallet::<T>::block_number();
+		// Expired offers sort first, then the lowest ones.
+		let rank = |offer: &OfferFor<T, I>| (offer.expiry > now, offer.price);
+		let mut count = 0u32;
+		let mut weakest: Option<(T::AccountId, OfferFor<T, I>)> = None;
+		for (bidder, offer) in offers {
+			count.saturating_inc();
+			match &weakest {
+				Some((_, weakest)) if rank(weakest) <= rank(&offer) => {},
+				_ => weakest = Some((bidder, offer)),
+			}
+		}
+		if count < T::MaxOffers::get() {
+			return Ok(None)
+		}
+		let (bidder, offer) = weakest.ok_or(Error::<T, I>::BidTooLow)?;
+		ensure!(rank(&offer) < (true, price), Error::<T, I>::BidTooLow);
+		Ok(Some(bidder))
+	}
+
+	/// Reserves the price of `offer` from `bidder` in place of the price of the offer it replaces.
+	fn replace_offer(
+		bidder: &T::AccountId,
+		maybe_offer: &mut Option<OfferFor<T, I>>,
+		offer: OfferFor<T, I>,
+	) -> DispatchResult {
+		// Released first, so that a bidder raising their offer only needs the difference.
+		if let Some(old) = maybe_offer.take() {
+			T::Currency::unreserve(bidder, old.price);
+		}
+		T::Currency::reserve(bidder, offer.price)?;
+		*maybe_offer = Some(offer);
+		Ok(())
+	}
+
+	pub fn do_cancel_offer(
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		bidder: T::AccountId,
+	) -> DispatchResult {
+		let offer = match maybe_item {
+			Some(item) => ItemOffers::<T, I>::take((&collection, &item, &bidder)),
+			None => CollectionBids::<T, I>::take(&collection, &bidder),
+		}
+		.ok_or(Error::<T, I>::UnknownOffer)?;
+		T::Currency::unreserve(&bidder, offer.price);
+
+		match maybe_item {
+			Some(item) => Self::deposit_event(Event::OfferCancelled { collection, item, bidder }),
+			None => Self::deposit_event(Event::CollectionBidCancelled { collection, bidder }),
+		}
+		Ok(())
+	}
+
+	/// Sells `item` of `seller` to `bidder` at the price of their `offer`, which was taken from
+	/// storage.
+	pub fn do_accept_offer(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		seller: T::AccountId,
+		bidder: T::AccountId,
+		offer: OfferFor<T, I>,
+		min_price: ItemPrice<T, I>,
+	) -> DispatchResult {
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(details.owner == seller, Error::<T, I>::NoPermission);
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now < offer.expiry, Error::<T, I>::OfferExpired);
+		ensure!(offer.price >= min_price, Error::<T, I>::BidTooLow);
+		Self::check_sale_price(collection, offer.price)?;
+
+		// The whole price must still be reserved, whatever was slashed from the offer since.
+		let pay = |beneficiary: &T::AccountId, amount| -> DispatchResult {
+			let missed = T::Currency::repatriate_reserved(
+				&bidder,
+				beneficiary,
+				amount,
+				BalanceStatus::Free,
+			)?;
+			ensure!(missed.is_zero(), Error::<T, I>::OfferSlashed);
+			Ok(())
+		};
+		let proceeds = Self::do_pay_royalty(collection, item, &seller, offer.price, pay)?;
+		pay(&seller, proceeds)?;
+
+		Self::do_transfer(collection, item, bidder.clone(), |_, _| Ok(()))?;
+
+		Self::deposit_event(Event::OfferAccepted {
+			collection,
+			item,
+			price: offer.price,
+			seller,
+			buyer: bidder,
+		});
+		Ok(())
+	}
+
+	/// Checks that the transfer policy of `collection` allows selling its items at `price`.
+	pub fn check_sale_price(collection: T::CollectionId, price: ItemPrice<T, I>) -> DispatchResult {
+		if let Some(TransferPolicy::ResaleCapped { face_value }) =
+			CollectionTransferPolicy::<T, I>::get(&collection)
+		{
+			ensure!(price <= face_value, Error::<T, I>::AboveFaceValue);
+		}
+		Ok(())
+	}
+
+	pub fn do_set_attribute(
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+		value: BoundedVec<u8, T::ValueLimit>,
+		with_deposit: bool,
+		with_details: impl FnOnce(&CollectionDetailsFor<T, I>) -> DispatchResult,
+	) -> DispatchResult {
+		let mut collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		with_details(&collection_details)?;
+
+		let attribute = Attribute::<T, I>::get((collection, maybe_item, &key));
+		if attribute.is_none() {
+			collection_details.attributes.saturating_inc();
+		}
+		let old_deposit = attribute.map_or(Zero::zero(), |m| m.1);
+		collection_details.total_deposit.saturating_reduce(old_deposit);
+		let mut deposit = Zero::zero();
+		if !collection_details.free_holding && with_deposit {
+			deposit = T::DepositPerByte::get()
+				.saturating_mul(((key.len() + value.len()) as u32).into())
+				.saturating_add(T::AttributeDepositBase::get());
+		}
+		collection_details.total_deposit.saturating_accrue(deposit);
+		if deposit > old_deposit {
+			T::Currency::reserve(&collection_details.owner, deposit - old_deposit)?;
+		} else if deposit < old_deposit {
+			T::Currency::unreserve(&collection_details.owner, old_deposit - deposit);
+		}
+
+		Attribute::<T, I>::insert((&collection, maybe_item, &key), (&value, deposit));
+		Collection::<T, I>::insert(collection, &collection_details);
+		Self::deposit_event(Event::AttributeSet { collection, maybe_item, key, value });
+		Ok(())
+	}
+
+	/// Checks in the ticket `item`, recording the block it was used at in its
+	/// `CHECKED_IN_ATTRIBUTE` attribute and freezing it.
+	///
+	/// The attribute is set like the owner of the collection sets attributes, holding its
+	/// deposit.
+	pub fn do_check_in(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		operator: T::AccountId,
+	) -> DispatchResult {
+		let owner = Self::owner(collection, item).ok_or(Error::<T, I>::UnknownItem)?;
+		let key = Self::checked_in_key();
+		ensure!(
+			!Attribute::<T, I>::contains_key((collection, Some(item), &key)),
+			Error::<T, I>::AlreadyCheckedIn
+		);
+		let now = frame_system::Pallet::<T>::block_number();
+		let value = BoundedVec::truncate_from(now.encode());
+
+		Self::do_set_attribute(collection, Some(item), key, value, true, |details| {
+			ensure!(details.freezer == operator, Error::<T, I>::NoPermission);
+			Ok(())
+		})?;
+		Self::do_set_frozen(collection, item, true)?;
+
+		Self::deposit_event(Event::CheckedIn { collection, item, owner });
+		Ok(())
+	}
+
+	/// Undoes the check-in of the ticket `item`, clearing its `CHECKED_IN_ATTRIBUTE` attribute,
+	/// unreserving its deposit, and thawing it.
+	pub fn do_undo_check_in(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		maybe_check_freezer: Option<T::AccountId>,
+	) -> DispatchResult {
+		let mut collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_freezer) = &maybe_check_freezer {
+			ensure!(check_freezer == &collection_details.freezer, Error::<T, I>::NoPermission);
+		}
+		let owner = Self::owner(collection, item).ok_or(Error::<T, I>::UnknownItem)?;
+		let key = Self::checked_in_key();
+		let (_, deposit) = Attribute::<T, I>::take((collection, Some(item), &key))
+			.ok_or(Error::<T, I>::NotCheckedIn)?;
+
+		collection_details.attributes.saturating_dec();
+		collection_details.total_deposit.saturating_reduce(deposit);
+		T::Currency::unreserve(&collection_details.owner, deposit);
+		Collection::<T, I>::insert(collection, &collection_details);
+		Self::deposit_event(Event::AttributeCleared { collection, maybe_item: Some(item), key });
+		Self::do_set_frozen(collection, item, false)?;
+
+		Self::deposit_event(Event::CheckInUndone { collection, item, owner });
+		Ok(())
+	}
+
+	/// Freezes or thaws `item`, whoever the freezer and admin of its collection are.
+	pub fn do_set_frozen(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		is_frozen: bool,
+	) -> DispatchResult {
+		Item::<T, I>::try_mutate(&collection, &item, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownItem)?;
+			details.is_frozen = is_frozen;
+			Ok::<_, DispatchError>(())
+		})?;
+
+		if is_frozen {
+			Self::deposit_event(Event::<T, I>::Frozen { collection, item });
+		} else {
+			Self::deposit_event(Event::<T, I>::Thawed { collection, item });
+		}
+		Ok(())
+	}
+
+	pub fn do_set_royalty(
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		royalty: Option<RoyaltyInfoFor<T>>,
+		maybe_check_admin: Option<T::AccountId>,
+	) -> DispatchResult {
+		if let Some(royalty) = &royalty {
+			ensure!(royalty.share <= T::MaxRoyalty::get(), Error::<T, I>::RoyaltyTooHigh);
+		}
+
+		Collection::<T, I>::try_mutate(collection, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownCollection)?;
+			if let Some(check_admin) = maybe_check_admin {
+				ensure!(check_admin == details.admin, Error::<T, I>::NoPermission);
+			}
+
+			match maybe_item {
+				Some(item) => {
+					ensure!(
+						Item::<T, I>::contains_key(collection, item),
+						Error::<T, I>::UnknownItem
+					);
+					ItemRoyalty::<T, I>::set(collection, item, royalty.clone());
+					Self::deposit_event(Event::ItemRoyaltySet { collection, item, royalty });
+				},
+				None => {
+					details.royalty = royalty.clone();
+					Self::deposit_event(Event::CollectionRoyaltySet { collection, royalty });
+				},
+			}
+			Ok(())
+		})
+	}
 }
@@ -51,8 +51,8 @@ use frame_support::{
 };
 use frame_system::Config as SystemConfig;
 use sp_runtime::{
-	traits::{Saturating, StaticLookup, Zero},
-	ArithmeticError, RuntimeDebug,
+	traits::{IdentifyAccount, Saturating, StaticLookup, Verify, Zero},
+	ArithmeticError, Perbill, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
@@ -64,16 +64,35 @@ const LOG_TARGET: &str = "runtime::uniques";
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+/// Key of the attribute recording the block a ticket was checked in at.
+pub const CHECKED_IN_ATTRIBUTE: &[u8] = b"checked_in";
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
+	/// Notified of the items burned, so that other pallets can drop what they attached to them.
+	pub trait OnBurn<CollectionId, ItemId> {
+		/// Called once `item` of `collection` was burned, by itself or with its collection.
+		///
+		/// The weights of burning items and destroying collections don't account for it, so it
+		/// should only touch a few storage items.
+		fn on_burn(collection: &CollectionId, item: &ItemId);
+	}
+	impl<CollectionId, ItemId> OnBurn<CollectionId, ItemId> for () {
+		fn on_burn(_: &CollectionId, _: &ItemId) {}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	pub trait BenchmarkHelper<CollectionId, ItemId> {
 		fn collection(i: u16) -> CollectionId;
@@ -120,6 +139,9 @@ pub mod pallet {
 		/// Locker trait to enable Locking mechanism downstream.
 		type Locker: Locker<Self::CollectionId, Self::ItemId>;
 
+		/// Notified of the items burned.
+		type OnBurn: OnBurn<Self::CollectionId, Self::ItemId>;
+
 		/// The basic amount of funds that must be reserved for collection.
 		#[pallet::constant]
 		type CollectionDeposit: Get<DepositBalanceOf<Self, I>>;
@@ -153,6 +175,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type ValueLimit: Get<u32>;
 
+		/// The maximum share of the price of an item which may be paid as royalty.
+		#[pallet::constant]
+		type MaxRoyalty: Get<Perbill>;
+
+		/// The maximum number of offers on an item, and of offers on any item of a collection.
+		#[pallet::constant]
+		type MaxOffers: Get<u32>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		/// A set of helper functions for benchmarking.
 		type Helper: BenchmarkHelper<Self::CollectionId, Self::ItemId>;
@@ -266,6 +296,48 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	/// Royalty of an item, overriding the royalty of its collection.
+	pub(super) type ItemRoyalty<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		RoyaltyInfoFor<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Offers to buy an item, by bidder.
+	pub(super) type ItemOffers<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, T::ItemId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		OfferFor<T, I>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Offers to buy any item of a collection, by bidder.
+	pub(super) type CollectionBids<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		OfferFor<T, I>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Restriction on the transfers of the items of a collection, if any.
+	pub(super) type CollectionTransferPolicy<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, TransferPolicyFor<T, I>, OptionQuery>;
+
 	#[pallet::storage]
 	/// Keeps track of the number of items a collection might have.
 	pub(super) type CollectionMaxSupply<T: Config<I>, I: 'static = ()> =
@@ -379,6 +451,54 @@ pub mod pallet {
 			seller: T::AccountId,
 			buyer: T::AccountId,
 		},
+		/// The royalty of a `collection` was set, or removed if `None`.
+		CollectionRoyaltySet { collection: T::CollectionId, royalty: Option<RoyaltyInfoFor<T>> },
+		/// The royalty of an `item` was set, or removed if `None`.
+		ItemRoyaltySet {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			royalty: Option<RoyaltyInfoFor<T>>,
+		},
+		/// A royalty was paid out of the price an `item` was bought at.
+		RoyaltyPaid {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			beneficiary: T::AccountId,
+			amount: ItemPrice<T, I>,
+		},
+		/// A `bidder` offered to buy an `item` at `price` until `expiry`.
+		OfferPlaced {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			bidder: T::AccountId,
+			price: ItemPrice<T, I>,
+			expiry: T::BlockNumber,
+		},
+		/// The offer of a `bidder` to buy an `item` was cancelled.
+		OfferCancelled { collection: T::CollectionId, item: T::ItemId, bidder: T::AccountId },
+		/// A `bidder` offered to buy any item of a `collection` at `price` until `expiry`.
+		CollectionBidPlaced {
+			collection: T::CollectionId,
+			bidder: T::AccountId,
+			price: ItemPrice<T, I>,
+			expiry: T::BlockNumber,
+		},
+		/// The offer of a `bidder` to buy any item of a `collection` was cancelled.
+		CollectionBidCancelled { collection: T::CollectionId, bidder: T::AccountId },
+		/// An offer to buy an `item`, or any item of its collection, was accepted.
+		OfferAccepted {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			price: ItemPrice<T, I>,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+		},
+		/// The transfer policy of a `collection` was set, or removed if `None`.
+		TransferPolicySet { collection: T::CollectionId, policy: Option<TransferPolicyFor<T, I>> },
+		/// A ticket `item` of its `owner` was checked in, and frozen.
+		CheckedIn { collection: T::CollectionId, item: T::ItemId, owner: T::AccountId },
+		/// The check-in of a ticket `item` of its `owner` was undone, and the ticket thawed.
+		CheckInUndone { collection: T::CollectionId, item: T::ItemId, owner: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -419,6 +539,26 @@ pub mod pallet {
 		NotForSale,
 		/// The provided bid is too low.
 		BidTooLow,
+		/// The royalty share is above `MaxRoyalty`.
+		RoyaltyTooHigh,
+		/// The offer does not exist.
+		UnknownOffer,
+		/// The offer expired.
+		OfferExpired,
+		/// Part of the price reserved by the offer was slashed.
+		OfferSlashed,
+		/// The transfer policy of the collection forbids transferring the item.
+		TransferForbidden,
+		/// The price is above the face value the transfer policy of the collection caps sales at.
+		AboveFaceValue,
+		/// The ticket was already checked in.
+		AlreadyCheckedIn,
+		/// The ticket is not checked in.
+		NotCheckedIn,
+		/// The check-in attribute of a ticket is only changed by `check_in` and `undo_check_in`.
+		CheckInAttribute,
+		/// The offer has not expired yet.
+		OfferNotExpired,
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -431,6 +571,55 @@ pub mod pallet {
 		pub fn collection_owner(collection: T::CollectionId) -> Option<T::AccountId> {
 			Collection::<T, I>::get(collection).map(|i| i.owner)
 		}
+
+		/// Get the royalty paid when the item is bought: its own, or else its collection's.
+		pub fn royalty(collection: T::CollectionId, item: T::ItemId) -> Option<RoyaltyInfoFor<T>> {
+			ItemRoyalty::<T, I>::get(collection, item)
+				.or_else(|| Collection::<T, I>::get(collection).and_then(|c| c.royalty))
+		}
+
+		/// Get the status of the ticket `item`, if it exists, for a verifier who challenged its
+		/// holder to sign `challenge` with `signature`.
+		pub fn ticket_status<S>(
+			collection: T::CollectionId,
+			item: T::ItemId,
+			challenge: &[u8],
+			signature: &S,
+		) -> Option<TicketStatus<T::AccountId, T::BlockNumber>>
+		where
+			S: Verify,
+			S::Signer: IdentifyAccount<AccountId = T::AccountId>,
+		{
+			let owner = Self::owner(collection, item)?;
+			let attribute =
+				Attribute::<T, I>::get((collection, Some(item), Self::checked_in_key()));
+			let signed_by_owner = signature.verify(challenge, &owner);
+			Some(TicketStatus {
+				valid: attribute.is_none() && signed_by_owner,
+				checked_in_at: attribute
+					.and_then(|(value, _)| T::BlockNumber::decode(&mut &value[..]).ok()),
+				signed_by_owner,
+				owner,
+			})
+		}
+
+		pub(crate) fn checked_in_key() -> BoundedVec<u8, T::KeyLimit> {
+			BoundedVec::truncate_from(CHECKED_IN_ATTRIBUTE.to_vec())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn integrity_test() {
+			assert!(
+				T::KeyLimit::get() as usize >= CHECKED_IN_ATTRIBUTE.len(),
+				"`KeyLimit` must fit the check-in attribute key"
+			);
+			assert!(
+				T::ValueLimit::get() as usize >= T::BlockNumber::max_encoded_len(),
+				"`ValueLimit` must fit the check-in block"
+			);
+		}
 	}
 
 	#[pallet::call]
@@ -518,6 +707,8 @@ pub mod pallet {
 		/// - `witness`: Information on the items minted in the collection. This must be
 		/// correct.
 		///
+		/// The offers on the collection and its items are cancelled, unreserving their prices.
+		///
 		/// Emits `Destroyed` event when successful.
 		///
 		/// Weight: `O(n + m)` where:
@@ -588,9 +779,11 @@ pub mod pallet {
 		/// - `check_owner`: If `Some` then the operation will fail with `WrongOwner` unless the
 		///   item is owned by this value.
 		///
+		/// The offers on the item are cancelled, unreserving their prices.
+		///
 		/// Emits `Burned` with the actual amount burned.
 		///
-		/// Weight: `O(1)`
+		/// Weight: `O(MaxOffers)`
 		/// Modes: `check_owner.is_some()`.
 		#[pallet::call_index(4)]
 		#[pallet::weight(T::WeightInfo::burn())]
@@ -1110,40 +1303,23 @@ pub mod pallet {
 				.map(|_| None)
 				.or_else(|origin| ensure_signed(origin).map(Some))?;
 
-			let mut collection_details =
-				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
-			if let Some(check_owner) = &maybe_check_owner {
-				ensure!(check_owner == &collection_details.owner, Error::<T, I>::NoPermission);
-			}
-			let maybe_is_frozen = match maybe_item {
-				None => CollectionMetadataOf::<T, I>::get(collection).map(|v| v.is_frozen),
-				Some(item) => ItemMetadataOf::<T, I>::get(collection, item).map(|v| v.is_frozen),
-			};
-			ensure!(!maybe_is_frozen.unwrap_or(false), Error::<T, I>::Frozen);
-
-			let attribute = Attribute::<T, I>::get((collection, maybe_item, &key));
-			if attribute.is_none() {
-				collection_details.attributes.saturating_inc();
-			}
-			let old_deposit = attribute.map_or(Zero::zero(), |m| m.1);
-			collection_details.total_deposit.saturating_reduce(old_deposit);
-			let mut deposit = Zero::zero();
-			if !collection_details.free_holding && maybe_check_owner.is_some() {
-				deposit = T::DepositPerByte::get()
-					.saturating_mul(((key.len() + value.len()) as u32).into())
-					.saturating_add(T::AttributeDepositBase::get());
-			}
-			collection_details.total_deposit.saturating_accrue(deposit);
-			if deposit > old_deposit {
-				T::Currency::reserve(&collection_details.owner, deposit - old_deposit)?;
-			} else if deposit < old_deposit {
-				T::Currency::unreserve(&collection_details.owner, old_deposit - deposit);
-			}
-
-			Attribute::<T, I>::insert((&collection, maybe_item, &key), (&value, deposit));
-			Collection::<T, I>::insert(collection, &collection_details);
-			Self::deposit_event(Event::AttributeSet { collection, maybe_item, key, value });
-			Ok(())
+			ensure!(
+				maybe_item.is_none() || key[..] != *CHECKED_IN_ATTRIBUTE,
+				Error::<T, I>::CheckInAttribute
+			);
+			let with_deposit = maybe_check_owner.is_some();
+			Self::do_set_attribute(collection, maybe_item, key, value, with_deposit, |details| {
+				if let Some(check_owner) = &maybe_check_owner {
+					ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+				}
+				let maybe_is_frozen = match maybe_item {
+					None => CollectionMetadataOf::<T, I>::get(collection).map(|v| v.is_frozen),
+					Some(item) =>
+						ItemMetadataOf::<T, I>::get(collection, item).map(|v| v.is_frozen),
+				};
+				ensure!(!maybe_is_frozen.unwrap_or(false), Error::<T, I>::Frozen);
+				Ok(())
+			})
 		}
 
 		/// Clear an attribute for a collection or item.
@@ -1182,6 +1358,10 @@ pub mod pallet {
 				Some(item) => ItemMetadataOf::<T, I>::get(collection, item).map(|v| v.is_frozen),
 			};
 			ensure!(!maybe_is_frozen.unwrap_or(false), Error::<T, I>::Frozen);
+			ensure!(
+				maybe_item.is_none() || key[..] != *CHECKED_IN_ATTRIBUTE,
+				Error::<T, I>::CheckInAttribute
+			);
 
 			if let Some((_, deposit)) = Attribute::<T, I>::take((collection, maybe_item, &key)) {
 				collection_details.attributes.saturating_dec();
@@ -1513,10 +1693,14 @@ pub mod pallet {
 		///
 		/// Origin must be Signed and must not be the owner of the `item`.
 		///
+		/// The royalty of the item, or else of its collection, is paid out of the price to its
+		/// beneficiary, unless the beneficiary is the seller.
+		///
 		/// - `collection`: The collection of the item.
 		/// - `item`: The item the sender wants to buy.
 		/// - `bid_price`: The price the sender is willing to pay.
 		///
+		/// Emits `RoyaltyPaid` if a royalty is paid.
 		/// Emits `ItemBought` on success.
 		#[pallet::call_index(25)]
 		#[pallet::weight(T::WeightInfo::buy_item())]
@@ -1530,5 +1714,340 @@ pub mod pallet {
 			let origin = ensure_signed(origin)?;
 			Self::do_buy_item(collection, item, origin, bid_price)
 		}
+
+		/// Set (or reset) the royalty paid out of the price of the items of a collection when they
+		/// are bought, unless they have their own.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Admin of
+		/// the `collection`.
+		///
+		/// - `collection`: The identifier of the collection.
+		/// - `royalty`: The share of the price paid as royalty, at most `MaxRoyalty`, and its
+		///   beneficiary. Pass `None` to remove the royalty.
+		///
+		/// Emits `CollectionRoyaltySet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_collection_royalty())]
+		pub fn set_collection_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			royalty: Option<RoyaltyInfoFor<T>>,
+		) -> DispatchResult {
+			let maybe_check_admin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			Self::do_set_royalty(collection, None, royalty, maybe_check_admin)
+		}
+
+		/// Set (or reset) the royalty paid out of the price of an item when it is bought,
+		/// overriding the royalty of its collection.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Admin of
+		/// the `collection`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to set the royalty of.
+		/// - `royalty`: The share of the price paid as royalty, at most `MaxRoyalty`, and its
+		///   beneficiary. Pass `None` to fall back to the royalty of the collection.
+		///
+		/// Emits `ItemRoyaltySet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_item_royalty())]
+		pub fn set_item_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			royalty: Option<RoyaltyInfoFor<T>>,
+		) -> DispatchResult {
+			let maybe_check_admin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			Self::do_set_royalty(collection, Some(item), royalty, maybe_check_admin)
+		}
+
+		/// Offer to buy an item, reserving the price offered until the offer is accepted or
+		/// cancelled. The offer replaces any previous offer of the sender for the item, and stays
+		/// on the item when it changes hands.
+		///
+		/// When the item already has `MaxOffers` offers, an expired one, or else the lowest one,
+		/// is cancelled to make room, which the price must be above.
+		///
+		/// Origin must be Signed and must not be the owner of the `item`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item the sender wants to buy.
+		/// - `price`: The price the sender is willing to pay.
+		/// - `expiry`: The block from which the offer can no longer be accepted.
+		///
+		/// Emits `OfferCancelled` if an offer is cancelled to make room.
+		/// Emits `OfferPlaced`.
+		///
+		/// Weight: `O(MaxOffers)`
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::place_offer())]
+		pub fn place_offer(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			price: ItemPrice<T, I>,
+			expiry: T::BlockNumber,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_place_offer(collection, Some(item), origin, price, expiry)
+		}
+
+		/// Cancel an offer to buy an item, unreserving its price.
+		///
+		/// Origin must be Signed and must be the bidder of the offer.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item the offer is for.
+		///
+		/// Emits `OfferCancelled`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::cancel_offer())]
+		pub fn cancel_offer(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_cancel_offer(collection, Some(item), origin)
+		}
+
+		/// Offer to buy any item of a collection, reserving the price offered until the offer is
+		/// accepted or cancelled. The offer replaces any previous offer of the sender for the
+		/// collection.
+		///
+		/// When the collection already has `MaxOffers` offers, an expired one, or else the lowest
+		/// one, is cancelled to make room, which the price must be above.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `collection`: The collection the sender wants to buy an item of.
+		/// - `price`: The price the sender is willing to pay.
+		/// - `expiry`: The block from which the offer can no longer be accepted.
+		///
+		/// Emits `CollectionBidCancelled` if an offer is cancelled to make room.
+		/// Emits `CollectionBidPlaced`.
+		///
+		/// Weight: `O(MaxOffers)`
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::place_collection_bid())]
+		pub fn place_collection_bid(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			price: ItemPrice<T, I>,
+			expiry: T::BlockNumber,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_place_offer(collection, None, origin, price, expiry)
+		}
+
+		/// Cancel an offer to buy any item of a collection, unreserving its price.
+		///
+		/// Origin must be Signed and must be the bidder of the offer.
+		///
+		/// - `collection`: The collection the offer is for.
+		///
+		/// Emits `CollectionBidCancelled`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::cancel_collection_bid())]
+		pub fn cancel_collection_bid(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_cancel_offer(collection, None, origin)
+		}
+
+		/// Accept an offer to buy an item, selling it to the bidder at the price offered.
+		///
+		/// Origin must be Signed and must be the owner of the `item`.
+		///
+		/// The royalty of the item, or else of its collection, is paid out of the price to its
+		/// beneficiary, unless the beneficiary is the seller.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to sell.
+		/// - `bidder`: The account whose offer is accepted.
+		/// - `min_price`: The lowest price the sender is willing to sell at.
+		///
+		/// Emits `RoyaltyPaid` if a royalty is paid.
+		/// Emits `OfferAccepted` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::accept_offer())]
+		#[transactional]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			bidder: AccountIdLookupOf<T>,
+			min_price: ItemPrice<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let bidder = T::Lookup::lookup(bidder)?;
+			let offer = ItemOffers::<T, I>::take((&collection, &item, &bidder))
+				.ok_or(Error::<T, I>::UnknownOffer)?;
+			Self::do_accept_offer(collection, item, origin, bidder, offer, min_price)
+		}
+
+		/// Accept an offer to buy any item of a collection, selling an item to the bidder at the
+		/// price offered.
+		///
+		/// Origin must be Signed and must be the owner of the `item`.
+		///
+		/// The royalty of the item, or else of its collection, is paid out of the price to its
+		/// beneficiary, unless the beneficiary is the seller.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to sell.
+		/// - `bidder`: The account whose offer is accepted.
+		/// - `min_price`: The lowest price the sender is willing to sell at.
+		///
+		/// Emits `RoyaltyPaid` if a royalty is paid.
+		/// Emits `OfferAccepted` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::accept_collection_bid())]
+		#[transactional]
+		pub fn accept_collection_bid(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			bidder: AccountIdLookupOf<T>,
+			min_price: ItemPrice<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let bidder = T::Lookup::lookup(bidder)?;
+			let offer = CollectionBids::<T, I>::take(&collection, &bidder)
+				.ok_or(Error::<T, I>::UnknownOffer)?;
+			Self::do_accept_offer(collection, item, origin, bidder, offer, min_price)
+		}
+
+		/// Set (or remove) the transfer policy of a collection, restricting the transfers of its
+		/// items, such as event tickets.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the `collection`.
+		///
+		/// - `collection`: The identifier of the collection to change.
+		/// - `policy`: The restriction on the transfers of the items, or `None` to lift it.
+		///
+		/// Emits `TransferPolicySet`.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::set_transfer_policy())]
+		pub fn set_transfer_policy(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			policy: Option<TransferPolicyFor<T, I>>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+
+			let details =
+				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+			if let Some(check_owner) = &maybe_check_owner {
+				ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+			}
+
+			CollectionTransferPolicy::<T, I>::set(&collection, policy);
+			Self::deposit_event(Event::TransferPolicySet { collection, policy });
+			Ok(())
+		}
+
+		/// Check in a ticket at the venue, marking it as used and freezing it.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the `collection`, who
+		/// operates the venue.
+		///
+		/// The block of the check-in is recorded in the `CHECKED_IN_ATTRIBUTE` attribute of the
+		/// ticket, for whose deposit funds of the Owner of the `collection` are reserved like for
+		/// `set_attribute`.
+		///
+		/// - `collection`: The collection of the ticket.
+		/// - `item`: The ticket to check in.
+		///
+		/// Emits `AttributeSet`, `Frozen` and `CheckedIn`.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::check_in())]
+		pub fn check_in(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_check_in(collection, item, origin)
+		}
+
+		/// Undo the check-in of a ticket, clearing its `CHECKED_IN_ATTRIBUTE` attribute and
+		/// thawing it.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Freezer of
+		/// the `collection`.
+		///
+		/// Any deposit of the attribute is freed for the collection's owner.
+		///
+		/// - `collection`: The collection of the ticket.
+		/// - `item`: The ticket whose check-in to undo.
+		///
+		/// Emits `AttributeCleared`, `Thawed` and `CheckInUndone`.
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::undo_check_in())]
+		pub fn undo_check_in(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let maybe_check_freezer = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			Self::do_undo_check_in(collection, item, maybe_check_freezer)
+		}
+
+		/// Cancel an expired offer to buy an item, or any item of a collection, unreserving its
+		/// price for the bidder.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `collection`: The collection the offer is for.
+		/// - `maybe_item`: The item the offer is for, or `None` for an offer to buy any item of the
+		///   `collection`.
+		/// - `bidder`: The account whose offer is cancelled.
+		///
+		/// Emits `OfferCancelled` or `CollectionBidCancelled`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::cancel_expired_offer())]
+		pub fn cancel_expired_offer(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			bidder: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let bidder = T::Lookup::lookup(bidder)?;
+			let offer = match maybe_item {
+				Some(item) => ItemOffers::<T, I>::get((&collection, &item, &bidder)),
+				None => CollectionBids::<T, I>::get(collection, &bidder),
+			}
+			.ok_or(Error::<T, I>::UnknownOffer)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(offer.expiry <= now, Error::<T, I>::OfferNotExpired);
+			Self::do_cancel_offer(collection, maybe_item, bidder)
+		}
 	}
 }
@@ -52,3 +52,94 @@ pub fn migrate_to_v1<T: Config<I>, I: 'static, P: GetStorageVersion + PalletInfo
 		T::DbWeight::get().reads(1)
 	}
 }
+
+pub mod v2 {
+	use super::*;
+	use frame_support::{migrations::VersionedMigration, traits::OnRuntimeUpgrade, weights::Weight};
+
+	#[derive(Decode)]
+	pub struct OldCollectionDetails<AccountId, DepositBalance> {
+		pub owner: AccountId,
+		pub issuer: AccountId,
+		pub admin: AccountId,
+		pub freezer: AccountId,
+		pub total_deposit: DepositBalance,
+		pub free_holding: bool,
+		pub items: u32,
+		pub item_metadatas: u32,
+		pub attributes: u32,
+		pub is_frozen: bool,
+	}
+
+	impl<AccountId, DepositBalance> OldCollectionDetails<AccountId, DepositBalance> {
+		fn migrate_to_v2(self) -> CollectionDetails<AccountId, DepositBalance> {
+			CollectionDetails {
+				owner: self.owner,
+				issuer: self.issuer,
+				admin: self.admin,
+				freezer: self.freezer,
+				total_deposit: self.total_deposit,
+				free_holding: self.free_holding,
+				items: self.items,
+				item_metadatas: self.item_metadatas,
+				attributes: self.attributes,
+				is_frozen: self.is_frozen,
+				royalty: None,
+			}
+		}
+	}
+
+	/// Migrates the collections to the layout with a royalty, if the pallet is at storage version
+	/// 1.
+	///
+	/// Chains still on storage version 0 must have run [`migrate_to_v1`] before, or never needed
+	/// it because they started with the `CollectionAccount` index.
+	pub type MigrateToV2<T, I = ()> = VersionedMigration<
+		1,
+		2,
+		VersionUncheckedMigrateToV2<T, I>,
+		Pallet<T, I>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	/// Migrates the collections to the layout with a royalty, which existing collections don't
+	/// pay until their admin sets one, whatever the storage version.
+	pub struct VersionUncheckedMigrateToV2<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for VersionUncheckedMigrateToV2<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			Collection::<T, I>::translate::<
+				OldCollectionDetails<T::AccountId, DepositBalanceOf<T, I>>,
+				_,
+			>(|_key, old_value| {
+				translated.saturating_inc();
+				Some(old_value.migrate_to_v2())
+			});
+			log::info!(target: LOG_TARGET, "Upgraded {} collections", translated);
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let prev_count = Collection::<T, I>::iter_keys().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), &'static str> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = Collection::<T, I>::iter().count() as u32;
+			frame_support::ensure!(
+				prev_count == post_count,
+				"the collection count before and after the migration should be the same"
+			);
+			frame_support::ensure!(
+				Collection::<T, I>::iter_values().all(|details| details.royalty.is_none()),
+				"migrated collections must not pay royalties"
+			);
+			Ok(())
+		}
+	}
+}
@@ -18,8 +18,13 @@
 //! Tests for Uniques pallet.
 
 use crate::{mock::*, Event, *};
-use frame_support::{assert_noop, assert_ok, dispatch::Dispatchable, traits::Currency};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::Dispatchable,
+	traits::{Currency, ReservableCurrency},
+};
 use pallet_balances::Error as BalancesError;
+use sp_runtime::testing::TestSignature;
 use sp_std::prelude::*;
 
 fn items() -> Vec<(u64, u32, u32)> {
@@ -1058,3 +1063,713 @@ fn buy_item_should_work() {
 		}
 	});
 }
+
+#[test]
+fn set_royalty_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::set_team(RuntimeOrigin::signed(1), 0, 1, 2, 1));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 1));
+		events();
+		let royalty =
+			|share, beneficiary| RoyaltyInfo { share: Perbill::from_percent(share), beneficiary };
+
+		// only the admin or the force origin may set royalties
+		assert_noop!(
+			Uniques::set_collection_royalty(RuntimeOrigin::signed(1), 0, Some(royalty(10, 5))),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::set_item_royalty(RuntimeOrigin::signed(1), 0, 42, Some(royalty(10, 5))),
+			Error::<Test>::NoPermission
+		);
+		// royalties are capped
+		assert_noop!(
+			Uniques::set_collection_royalty(RuntimeOrigin::signed(2), 0, Some(royalty(51, 5))),
+			Error::<Test>::RoyaltyTooHigh
+		);
+		assert_noop!(
+			Uniques::set_item_royalty(RuntimeOrigin::root(), 0, 42, Some(royalty(51, 5))),
+			Error::<Test>::RoyaltyTooHigh
+		);
+		assert_noop!(
+			Uniques::set_item_royalty(RuntimeOrigin::signed(2), 0, 69, Some(royalty(10, 5))),
+			Error::<Test>::UnknownItem
+		);
+		assert_noop!(
+			Uniques::set_collection_royalty(RuntimeOrigin::signed(2), 1, Some(royalty(10, 5))),
+			Error::<Test>::UnknownCollection
+		);
+
+		assert_ok!(Uniques::set_collection_royalty(
+			RuntimeOrigin::signed(2),
+			0,
+			Some(royalty(10, 5))
+		));
+		assert_eq!(Uniques::royalty(0, 42), Some(royalty(10, 5)));
+		assert_ok!(Uniques::set_item_royalty(RuntimeOrigin::root(), 0, 42, Some(royalty(50, 6))));
+		assert_eq!(Uniques::royalty(0, 42), Some(royalty(50, 6)));
+		assert_eq!(
+			events(),
+			[
+				Event::<Test>::CollectionRoyaltySet {
+					collection: 0,
+					royalty: Some(royalty(10, 5))
+				},
+				Event::<Test>::ItemRoyaltySet {
+					collection: 0,
+					item: 42,
+					royalty: Some(royalty(50, 6))
+				},
+			]
+		);
+
+		// the item falls back to the royalty of its collection
+		assert_ok!(Uniques::set_item_royalty(RuntimeOrigin::signed(2), 0, 42, None));
+		assert_eq!(Uniques::royalty(0, 42), Some(royalty(10, 5)));
+		assert_ok!(Uniques::set_collection_royalty(RuntimeOrigin::signed(2), 0, None));
+		assert_eq!(Uniques::royalty(0, 42), None);
+
+		// burning the item removes its royalty
+		assert_ok!(Uniques::set_item_royalty(RuntimeOrigin::signed(2), 0, 42, Some(royalty(5, 6))));
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(1), 0, 42, None));
+		assert!(!ItemRoyalty::<Test>::contains_key(0, 42));
+	});
+}
+
+#[test]
+fn buy_item_pays_royalty() {
+	new_test_ext().execute_with(|| {
+		let (creator, label, seller, buyer) = (1, 2, 3, 4);
+		for who in [creator, label, seller, buyer] {
+			Balances::make_free_balance_be(&who, 1000);
+		}
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, creator, true));
+		for item in 0..3 {
+			assert_ok!(Uniques::mint(RuntimeOrigin::signed(creator), 0, item, seller));
+			assert_ok!(Uniques::set_price(RuntimeOrigin::signed(seller), 0, item, Some(100), None));
+		}
+		let royalty = |share, beneficiary| {
+			Some(RoyaltyInfo { share: Perbill::from_percent(share), beneficiary })
+		};
+		assert_ok!(Uniques::set_collection_royalty(
+			RuntimeOrigin::signed(creator),
+			0,
+			royalty(10, creator)
+		));
+		assert_ok!(Uniques::set_item_royalty(
+			RuntimeOrigin::signed(creator),
+			0,
+			1,
+			royalty(25, label)
+		));
+		events();
+
+		// the collection royalty is paid out of the price
+		assert_ok!(Uniques::buy_item(RuntimeOrigin::signed(buyer), 0, 0, 100));
+		assert_eq!(
+			events(),
+			[
+				Event::<Test>::RoyaltyPaid {
+					collection: 0,
+					item: 0,
+					beneficiary: creator,
+					amount: 10
+				},
+				Event::<Test>::Transferred { collection: 0, item: 0, from: seller, to: buyer },
+				Event::<Test>::ItemBought { collection: 0, item: 0, price: 100, seller, buyer },
+			]
+		);
+		assert_eq!(Balances::free_balance(creator), 1010);
+		assert_eq!(Balances::free_balance(seller), 1090);
+		assert_eq!(Balances::free_balance(buyer), 900);
+
+		// the item royalty overrides it
+		assert_ok!(Uniques::buy_item(RuntimeOrigin::signed(buyer), 0, 1, 100));
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: 0,
+			item: 1,
+			beneficiary: label,
+			amount: 25,
+		}));
+		assert_eq!(Balances::free_balance(label), 1025);
+		assert_eq!(Balances::free_balance(seller), 1165);
+
+		// a beneficiary selling the item pays no royalty to itself
+		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(buyer), 0, 0, Some(100), None));
+		assert_ok!(Uniques::set_item_royalty(
+			RuntimeOrigin::signed(creator),
+			0,
+			0,
+			royalty(10, buyer)
+		));
+		events();
+		assert_ok!(Uniques::buy_item(RuntimeOrigin::signed(seller), 0, 0, 100));
+		assert!(!events().iter().any(|e| matches!(e, Event::<Test>::RoyaltyPaid { .. })));
+		assert_eq!(Balances::free_balance(buyer), 900);
+	});
+}
+
+#[test]
+fn place_and_cancel_offers_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (owner, bidder) = (1, 2);
+		Balances::make_free_balance_be(&owner, 100);
+		Balances::make_free_balance_be(&bidder, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, owner, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(owner), 0, 42, owner));
+
+		assert_noop!(
+			Uniques::place_offer(RuntimeOrigin::signed(bidder), 0, 42, 0, 10),
+			Error::<Test>::BidTooLow
+		);
+		assert_noop!(
+			Uniques::place_offer(RuntimeOrigin::signed(bidder), 0, 42, 30, 1),
+			Error::<Test>::OfferExpired
+		);
+		assert_noop!(
+			Uniques::place_offer(RuntimeOrigin::signed(owner), 0, 42, 30, 10),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::place_offer(RuntimeOrigin::signed(bidder), 0, 43, 30, 10),
+			Error::<Test>::UnknownItem
+		);
+		assert_noop!(
+			Uniques::place_collection_bid(RuntimeOrigin::signed(bidder), 1, 30, 10),
+			Error::<Test>::UnknownCollection
+		);
+		assert_noop!(
+			Uniques::place_offer(RuntimeOrigin::signed(bidder), 0, 42, 101, 10),
+			BalancesError::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(Uniques::place_offer(RuntimeOrigin::signed(bidder), 0, 42, 30, 10));
+		assert_ok!(Uniques::place_collection_bid(RuntimeOrigin::signed(bidder), 0, 20, 10));
+		assert_eq!(Balances::reserved_balance(&bidder), 50);
+		// a new offer replaces the previous one
+		assert_ok!(Uniques::place_offer(RuntimeOrigin::signed(bidder), 0, 42, 80, 20));
+		assert_eq!(Balances::reserved_balance(&bidder), 100);
+		assert_eq!(ItemOffers::<Test>::get((0, 42, bidder)), Some(Offer { price: 80, expiry: 20 }));
+		assert!(events().contains(&Event::<Test>::OfferPlaced {
+			collection: 0,
+			item: 42,
+			bidder,
+			price: 80,
+			expiry: 20,
+		}));
+
+		assert_ok!(Uniques::cancel_offer(RuntimeOrigin::signed(bidder), 0, 42));
+		assert_noop!(
+			Uniques::cancel_offer(RuntimeOrigin::signed(bidder), 0, 42),
+			Error::<Test>::UnknownOffer
+		);
+		assert_ok!(Uniques::cancel_collection_bid(RuntimeOrigin::signed(bidder), 0));
+		assert_eq!(Balances::reserved_balance(&bidder), 0);
+		assert_eq!(
+			events(),
+			[
+				Event::<Test>::OfferCancelled { collection: 0, item: 42, bidder },
+				Event::<Test>::CollectionBidCancelled { collection: 0, bidder },
+			]
+		);
+	});
+}
+
+#[test]
+fn accept_offer_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (creator, seller, bidder, other) = (1, 2, 3, 4);
+		for who in [creator, seller, bidder, other] {
+			Balances::make_free_balance_be(&who, 1000);
+		}
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, creator, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(creator), 0, 42, seller));
+		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(seller), 0, 42, Some(500), None));
+		assert_ok!(Uniques::set_collection_royalty(
+			RuntimeOrigin::signed(creator),
+			0,
+			Some(RoyaltyInfo { share: Perbill::from_percent(10), beneficiary: creator })
+		));
+		// the whole balance of the bidder is offered
+		Balances::make_free_balance_be(&bidder, 300);
+		assert_ok!(Uniques::place_offer(RuntimeOrigin::signed(bidder), 0, 42, 300, 10));
+		assert_ok!(Uniques::place_offer(RuntimeOrigin::signed(other), 0, 42, 200, 10));
+		events();
+
+		assert_noop!(
+			Uniques::accept_offer(RuntimeOrigin::signed(seller), 0, 42, creator, 0),
+			Error::<Test>::UnknownOffer
+		);
+		assert_noop!(
+			Uniques::accept_offer(RuntimeOrigin::signed(other), 0, 42, bidder, 0),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::accept_offer(RuntimeOrigin::signed(seller), 0, 42, bidder, 301),
+			Error::<Test>::BidTooLow
+		);
+		// an offer which lost part of its reserve no longer pays its price
+		let _ = Balances::slash_reserved(&other, 50);
+		assert_noop!(
+			Uniques::accept_offer(RuntimeOrigin::signed(seller), 0, 42, other, 0),
+			Error::<Test>::OfferSlashed
+		);
+
+		assert_ok!(Uniques::accept_offer(RuntimeOrigin::signed(seller), 0, 42, bidder, 300));
+		assert_eq!(
+			events(),
+			[
+				Event::<Test>::RoyaltyPaid {
+					collection: 0,
+					item: 42,
+					beneficiary: creator,
+					amount: 30
+				},
+				Event::<Test>::Transferred { collection: 0, item: 42, from: seller, to: bidder },
+				Event::<Test>::OfferAccepted {
+					collection: 0,
+					item: 42,
+					price: 300,
+					seller,
+					buyer: bidder
+				},
+			]
+		);
+		assert_eq!(Balances::total_balance(&bidder), 0);
+		assert_eq!(Balances::free_balance(creator), 1030);
+		assert_eq!(Balances::free_balance(seller), 1270);
+		assert!(ItemOffers::<Test>::get((0, 42, bidder)).is_none());
+		// the listing is gone with the old owner
+		assert_noop!(
+			Uniques::buy_item(RuntimeOrigin::signed(other), 0, 42, 500),
+			Error::<Test>::NotForSale
+		);
+
+		// the other offer stays on the item, for its new owner, until it expires
+		System::set_block_number(10);
+		assert_noop!(
+			Uniques::accept_offer(RuntimeOrigin::signed(bidder), 0, 42, other, 0),
+			Error::<Test>::OfferExpired
+		);
+		assert_ok!(Uniques::cancel_offer(RuntimeOrigin::signed(other), 0, 42));
+		assert_eq!(Balances::free_balance(other), 950);
+	});
+}
+
+#[test]
+fn accept_collection_bid_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (owner, bidder) = (1, 2);
+		Balances::make_free_balance_be(&owner, 100);
+		Balances::make_free_balance_be(&bidder, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, owner, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(owner), 0, 42, owner));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(owner), 0, 43, owner));
+		assert_ok!(Uniques::freeze(RuntimeOrigin::signed(owner), 0, 43));
+		assert_ok!(Uniques::place_collection_bid(RuntimeOrigin::signed(bidder), 0, 50, 10));
+		events();
+
+		// frozen items can't be sold
+		assert_noop!(
+			Uniques::accept_collection_bid(RuntimeOrigin::signed(owner), 0, 43, bidder, 50),
+			Error::<Test>::Frozen
+		);
+		assert_ok!(Uniques::accept_collection_bid(RuntimeOrigin::signed(owner), 0, 42, bidder, 50));
+		assert_eq!(Uniques::owner(0, 42), Some(bidder));
+		assert_eq!(Balances::free_balance(owner), 150);
+		assert_eq!(Balances::total_balance(&bidder), 50);
+		assert!(events().contains(&Event::<Test>::OfferAccepted {
+			collection: 0,
+			item: 42,
+			price: 50,
+			seller: owner,
+			buyer: bidder,
+		}));
+
+		// the bid buys a single item
+		assert_ok!(Uniques::thaw(RuntimeOrigin::signed(owner), 0, 43));
+		assert_noop!(
+			Uniques::accept_collection_bid(RuntimeOrigin::signed(owner), 0, 43, bidder, 50),
+			Error::<Test>::UnknownOffer
+		);
+	});
+}
+
+#[test]
+fn offers_should_be_bounded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let owner = 1;
+		for who in 1..=6 {
+			Balances::make_free_balance_be(&who, 100);
+		}
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, owner, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(owner), 0, 42, owner));
+		assert_ok!(Uniques::place_offer(RuntimeOrigin::signed(2), 0, 42, 30, 5));
+		assert_ok!(Uniques::place_offer(RuntimeOrigin::signed(3), 0, 42, 20, 10));
+		assert_ok!(Uniques::place_offer(RuntimeOrigin::signed(4), 0, 42, 40, 10));
+		events();
+
+		// the item has `MaxOffers` offers, so a new one must outbid the lowest
+		assert_noop!(
+			Uniques::place_offer(RuntimeOrigin::signed(5), 0, 42, 20, 10),
+			Error::<Test>::BidTooLow
+		);
+		// bidders can still replace their own offers
+		assert_ok!(Uniques::place_offer(RuntimeOrigin::signed(3), 0, 42, 25, 10));
+		assert_ok!(Uniques::place_offer(RuntimeOrigin::signed(5), 0, 42, 26, 10));
+		assert!(ItemOffers::<Test>::get((0, 42, 3)).is_none());
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::reserved_balance(5), 26);
+		assert!(events().contains(&Event::<Test>::OfferCancelled {
+			collection: 0,
+			item: 42,
+			bidder: 3
+		}));
+
+		// an expired offer makes room first, whatever its price
+		System::set_block_number(5);
+		assert_ok!(Uniques::place_offer(RuntimeOrigin::signed(6), 0, 42, 1, 10));
+		assert!(ItemOffers::<Test>::get((0, 42, 2)).is_none());
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		// so do the bids on any item of the collection
+		assert_ok!(Uniques::place_collection_bid(RuntimeOrigin::signed(2), 0, 10, 10));
+		assert_ok!(Uniques::place_collection_bid(RuntimeOrigin::signed(3), 0, 20, 10));
+		assert_ok!(Uniques::place_collection_bid(RuntimeOrigin::signed(4), 0, 30, 10));
+		assert_noop!(
+			Uniques::place_collection_bid(RuntimeOrigin::signed(5), 0, 10, 10),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(Uniques::place_collection_bid(RuntimeOrigin::signed(5), 0, 11, 10));
+		assert!(CollectionBids::<Test>::get(0, 2).is_none());
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(
+			events().contains(&Event::<Test>::CollectionBidCancelled { collection: 0, bidder: 2 })
+		);
+	});
+}
+
+#[test]
+fn cancel_expired_offer_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (owner, bidder, other) = (1, 2, 3);
+		Balances::make_free_balance_be(&owner, 100);
+		Balances::make_free_balance_be(&bidder, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, owner, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(owner), 0, 42, owner));
+		assert_ok!(Uniques::place_offer(RuntimeOrigin::signed(bidder), 0, 42, 30, 10));
+		assert_ok!(Uniques::place_collection_bid(RuntimeOrigin::signed(bidder), 0, 20, 10));
+		events();
+
+		assert_noop!(
+			Uniques::cancel_expired_offer(RuntimeOrigin::signed(other), 0, Some(42), bidder),
+			Error::<Test>::OfferNotExpired
+		);
+		assert_noop!(
+			Uniques::cancel_expired_offer(RuntimeOrigin::signed(other), 0, Some(42), owner),
+			Error::<Test>::UnknownOffer
+		);
+
+		// anyone cancels the offers once they expired
+		System::set_block_number(10);
+		assert_ok!(Uniques::cancel_expired_offer(
+			RuntimeOrigin::signed(other),
+			0,
+			Some(42),
+			bidder
+		));
+		assert_ok!(Uniques::cancel_expired_offer(RuntimeOrigin::signed(other), 0, None, bidder));
+		assert_eq!(Balances::reserved_balance(bidder), 0);
+		assert_eq!(
+			events(),
+			[
+				Event::<Test>::OfferCancelled { collection: 0, item: 42, bidder },
+				Event::<Test>::CollectionBidCancelled { collection: 0, bidder },
+			]
+		);
+	});
+}
+
+#[test]
+fn burn_and_destroy_should_cancel_offers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (owner, bidder) = (1, 2);
+		Balances::make_free_balance_be(&owner, 100);
+		Balances::make_free_balance_be(&bidder, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, owner, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(owner), 0, 42, owner));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(owner), 0, 43, owner));
+		assert_ok!(Uniques::place_offer(RuntimeOrigin::signed(bidder), 0, 42, 10, 10));
+		assert_ok!(Uniques::place_offer(RuntimeOrigin::signed(bidder), 0, 43, 20, 10));
+		assert_ok!(Uniques::place_collection_bid(RuntimeOrigin::signed(bidder), 0, 30, 10));
+		events();
+
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(owner), 0, 42, None));
+		assert!(ItemOffers::<Test>::get((0, 42, bidder)).is_none());
+		assert_eq!(Balances::reserved_balance(bidder), 50);
+		assert_eq!(
+			events(),
+			[
+				Event::<Test>::OfferCancelled { collection: 0, item: 42, bidder },
+				Event::<Test>::Burned { collection: 0, item: 42, owner },
+			]
+		);
+
+		let witness = Collection::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Uniques::destroy(RuntimeOrigin::signed(owner), 0, witness));
+		assert_eq!(ItemOffers::<Test>::iter().count(), 0);
+		assert_eq!(CollectionBids::<Test>::iter().count(), 0);
+		assert_eq!(Balances::reserved_balance(bidder), 0);
+		assert_eq!(
+			events(),
+			[
+				Event::<Test>::OfferCancelled { collection: 0, item: 43, bidder },
+				Event::<Test>::CollectionBidCancelled { collection: 0, bidder },
+				Event::<Test>::Destroyed { collection: 0 },
+			]
+		);
+	});
+}
+
+#[test]
+fn transfer_policy_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 1000);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 1));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 43, 1));
+		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(1), 0, 43, Some(150), None));
+
+		assert_noop!(
+			Uniques::set_transfer_policy(
+				RuntimeOrigin::signed(2),
+				0,
+				Some(TransferPolicy::NonTransferable)
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::set_transfer_policy(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(TransferPolicy::NonTransferable)
+		));
+		assert!(events().contains(&Event::<Test>::TransferPolicySet {
+			collection: 0,
+			policy: Some(TransferPolicy::NonTransferable),
+		}));
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(1), 0, 42, 2),
+			Error::<Test>::TransferForbidden
+		);
+		assert_noop!(Uniques::check_transferable(0, 0), Error::<Test>::TransferForbidden);
+		// items held by pallets are released whatever the policy
+		assert_ok!(Uniques::do_release(0, 43, 2));
+		assert_ok!(Uniques::do_release(0, 43, 1));
+		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(1), 0, 43, Some(150), None));
+		// tickets can still be minted and burned
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 44, 2));
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(2), 0, 44, None));
+
+		System::set_block_number(1);
+		assert_ok!(Uniques::set_transfer_policy(
+			RuntimeOrigin::root(),
+			0,
+			Some(TransferPolicy::TransferableUntil(5))
+		));
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::check_transferable(0, 4));
+		assert_noop!(Uniques::check_transferable(0, 5), Error::<Test>::TransferForbidden);
+		System::set_block_number(5);
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 1),
+			Error::<Test>::TransferForbidden
+		);
+
+		assert_ok!(Uniques::set_transfer_policy(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(TransferPolicy::ResaleCapped { face_value: 100 })
+		));
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 1));
+		assert_noop!(
+			Uniques::set_price(RuntimeOrigin::signed(1), 0, 42, Some(101), None),
+			Error::<Test>::AboveFaceValue
+		);
+		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(1), 0, 42, Some(100), None));
+		assert_noop!(
+			Uniques::place_offer(RuntimeOrigin::signed(2), 0, 42, 101, 10),
+			Error::<Test>::AboveFaceValue
+		);
+		assert_noop!(
+			Uniques::place_collection_bid(RuntimeOrigin::signed(2), 0, 101, 10),
+			Error::<Test>::AboveFaceValue
+		);
+		// the item listed before the policy was set can't be sold at its price anymore
+		assert_noop!(
+			Uniques::buy_item(RuntimeOrigin::signed(2), 0, 43, 150),
+			Error::<Test>::AboveFaceValue
+		);
+		assert_ok!(Uniques::buy_item(RuntimeOrigin::signed(2), 0, 42, 100));
+
+		assert_ok!(Uniques::set_transfer_policy(RuntimeOrigin::signed(1), 0, None));
+		assert_ok!(Uniques::buy_item(RuntimeOrigin::signed(2), 0, 43, 150));
+	});
+}
+
+#[test]
+fn check_in_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(7);
+		let (owner, operator, holder) = (1, 2, 3);
+		Balances::make_free_balance_be(&owner, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, owner, false));
+		assert_ok!(Uniques::set_team(RuntimeOrigin::signed(owner), 0, owner, owner, operator));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(owner), 0, 42, holder));
+		let reserved = Balances::reserved_balance(owner);
+		events();
+		let challenge = b"door 3, 20:15".to_vec();
+		let signed_by = |who| TestSignature(who, challenge.clone());
+
+		assert_eq!(
+			Uniques::ticket_status(0, 42, &challenge, &signed_by(holder)),
+			Some(TicketStatus {
+				owner: holder,
+				checked_in_at: None,
+				signed_by_owner: true,
+				valid: true
+			})
+		);
+		assert_eq!(
+			Uniques::ticket_status(0, 42, &challenge, &signed_by(operator)),
+			Some(TicketStatus {
+				owner: holder,
+				checked_in_at: None,
+				signed_by_owner: false,
+				valid: false
+			})
+		);
+		assert_eq!(Uniques::ticket_status(0, 43, &challenge, &signed_by(holder)), None);
+
+		assert_noop!(
+			Uniques::check_in(RuntimeOrigin::signed(owner), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::check_in(RuntimeOrigin::signed(operator), 0, 43),
+			Error::<Test>::UnknownItem
+		);
+		assert_ok!(Uniques::check_in(RuntimeOrigin::signed(operator), 0, 42));
+		assert_eq!(
+			events(),
+			[
+				Event::<Test>::AttributeSet {
+					collection: 0,
+					maybe_item: Some(42),
+					key: bvec![b'c', b'h', b'e', b'c', b'k', b'e', b'd', b'_', b'i', b'n'],
+					value: bvec![7, 0, 0, 0, 0, 0, 0, 0],
+				},
+				Event::<Test>::Frozen { collection: 0, item: 42 },
+				Event::<Test>::CheckedIn { collection: 0, item: 42, owner: holder },
+			]
+		);
+		assert_eq!(
+			attributes(0),
+			vec![(Some(42), CHECKED_IN_ATTRIBUTE.to_vec(), vec![7, 0, 0, 0, 0, 0, 0, 0])]
+		);
+		// the deposit of the attribute is held by the owner of the collection
+		assert_eq!(Balances::reserved_balance(owner), reserved + 19);
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(holder), 0, 42, owner),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			Uniques::check_in(RuntimeOrigin::signed(operator), 0, 42),
+			Error::<Test>::AlreadyCheckedIn
+		);
+		assert_eq!(
+			Uniques::ticket_status(0, 42, &challenge, &signed_by(holder)),
+			Some(TicketStatus {
+				owner: holder,
+				checked_in_at: Some(7),
+				signed_by_owner: true,
+				valid: false
+			})
+		);
+
+		// the check-in can't be undone like other attributes
+		let key = Uniques::checked_in_key();
+		assert_noop!(
+			Uniques::clear_attribute(RuntimeOrigin::signed(owner), 0, Some(42), key.clone()),
+			Error::<Test>::CheckInAttribute
+		);
+		assert_noop!(
+			Uniques::set_attribute(RuntimeOrigin::root(), 0, Some(42), key, bvec![0]),
+			Error::<Test>::CheckInAttribute
+		);
+		assert_noop!(
+			Uniques::undo_check_in(RuntimeOrigin::signed(owner), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::undo_check_in(RuntimeOrigin::signed(operator), 0, 42));
+		assert!(events().contains(&Event::<Test>::CheckInUndone {
+			collection: 0,
+			item: 42,
+			owner: holder
+		}));
+		assert!(attributes(0).is_empty());
+		assert_eq!(Balances::reserved_balance(owner), reserved);
+		assert!(Uniques::ticket_status(0, 42, &challenge, &signed_by(holder)).unwrap().valid);
+		assert_noop!(
+			Uniques::undo_check_in(RuntimeOrigin::root(), 0, 42),
+			Error::<Test>::NotCheckedIn
+		);
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(holder), 0, 42, owner));
+	});
+}
+
+#[test]
+fn migrate_to_v2_adds_no_royalty_to_collections() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		// owner, issuer, admin, freezer, total_deposit, free_holding, items, item_metadatas,
+		// attributes, is_frozen
+		let old = (1u64, 2u64, 3u64, 4u64, 10u64, false, 5u32, 1u32, 2u32, true);
+		frame_support::storage::unhashed::put(&Collection::<Test>::hashed_key_for(7), &old);
+		StorageVersion::new(1).put::<Uniques>();
+
+		migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Uniques::on_chain_storage_version(), 2);
+		assert_eq!(
+			Collection::<Test>::get(7),
+			Some(CollectionDetails {
+				owner: 1,
+				issuer: 2,
+				admin: 3,
+				freezer: 4,
+				total_deposit: 10,
+				free_holding: false,
+				items: 5,
+				item_metadatas: 1,
+				attributes: 2,
+				is_frozen: true,
+				royalty: None,
+			})
+		);
+
+		// the migration only runs at storage version 1
+		let details = Collection::<Test>::get(7);
+		migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(Collection::<Test>::get(7), details);
+	});
+}
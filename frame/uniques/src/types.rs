@@ -23,6 +23,8 @@ use frame_support::{
 	traits::Get,
 };
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 pub(super) type DepositBalanceOf<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
@@ -32,6 +34,10 @@ pub(super) type ItemDetailsFor<T, I> =
 	ItemDetails<<T as SystemConfig>::AccountId, DepositBalanceOf<T, I>>;
 pub(super) type ItemPrice<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
+pub(super) type RoyaltyInfoFor<T> = RoyaltyInfo<<T as SystemConfig>::AccountId>;
+pub(super) type OfferFor<T, I = ()> = Offer<ItemPrice<T, I>, <T as SystemConfig>::BlockNumber>;
+pub(super) type TransferPolicyFor<T, I = ()> =
+	TransferPolicy<<T as SystemConfig>::BlockNumber, ItemPrice<T, I>>;
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct CollectionDetails<AccountId, DepositBalance> {
@@ -56,6 +62,53 @@ pub struct CollectionDetails<AccountId, DepositBalance> {
 	pub(super) attributes: u32,
 	/// Whether the collection is frozen for non-admin transfers.
 	pub(super) is_frozen: bool,
+	/// The royalty paid out of the price of its items when they are bought, unless they have
+	/// their own.
+	pub(super) royalty: Option<RoyaltyInfo<AccountId>>,
+}
+
+/// A royalty paid to the creators of a collection or item out of the price it is bought at.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RoyaltyInfo<AccountId> {
+	/// The share of the price paid as royalty.
+	pub share: Perbill,
+	/// The account the royalty is paid to, which may be a split account sharing it further.
+	pub beneficiary: AccountId,
+}
+
+/// An offer to buy an item, or any item of a collection, at a price reserved from the bidder.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Offer<Balance, BlockNumber> {
+	/// The price offered, reserved until the offer is accepted or cancelled.
+	pub price: Balance,
+	/// The block from which the offer can no longer be accepted.
+	pub expiry: BlockNumber,
+}
+
+/// Restriction on the transfers of the items of a collection, such as event tickets.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum TransferPolicy<BlockNumber, Balance> {
+	/// Items can't be transferred once minted.
+	NonTransferable,
+	/// Items can only be transferred before the given block.
+	TransferableUntil(BlockNumber),
+	/// Items can be transferred, but not sold above their face value.
+	ResaleCapped { face_value: Balance },
+}
+
+/// Status of a ticket for a verifier at the door, who challenged its holder to sign a message.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TicketStatus<AccountId, BlockNumber> {
+	/// The owner of the ticket.
+	pub owner: AccountId,
+	/// The block the ticket was checked in at, if it was used.
+	pub checked_in_at: Option<BlockNumber>,
+	/// Whether the challenge is signed by the owner.
+	pub signed_by_owner: bool,
+	/// Whether the ticket admits its holder: unused, and the challenge is signed by its owner.
+	pub valid: bool,
 }
 
 /// Witness data for the destroy transactions.
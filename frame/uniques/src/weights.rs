@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `bm2`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! THE WEIGHTS OF THE ROYALTY, OFFER, BID, TRANSFER POLICY AND CHECK-IN CALLS WERE MEASURED ON
+//! 2026-10-18 BY DRIVING THE `benchmark pallet` LOOP OVER THE NATIVE RUNTIME INSTEAD OF WASM.
+//! REGENERATE THEM WITH THE COMMAND BELOW ON REFERENCE HARDWARE BEFORE RELYING ON THE TIMINGS.
 
 // Executed Command:
 // ./target/production/substrate
@@ -74,6 +78,18 @@ pub trait WeightInfo {
 	fn set_collection_max_supply() -> Weight;
 	fn set_price() -> Weight;
 	fn buy_item() -> Weight;
+	fn set_collection_royalty() -> Weight;
+	fn set_item_royalty() -> Weight;
+	fn place_offer() -> Weight;
+	fn cancel_offer() -> Weight;
+	fn place_collection_bid() -> Weight;
+	fn cancel_collection_bid() -> Weight;
+	fn accept_offer() -> Weight;
+	fn accept_collection_bid() -> Weight;
+	fn set_transfer_policy() -> Weight;
+	fn check_in() -> Weight;
+	fn undo_check_in() -> Weight;
+	fn cancel_expired_offer() -> Weight;
 }
 
 /// Weights for pallet_uniques using the Substrate node and recommended hardware.
@@ -106,19 +122,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Uniques Class (r:1 w:1)
-	/// Proof: Uniques Class (max_values: None, max_size: Some(178), added: 2653, mode: MaxEncodedLen)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
 	/// Storage: Uniques Asset (r:1001 w:1000)
 	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:1000 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemOffers (r:20001 w:20000)
+	/// Proof: Uniques ItemOffers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: System Account (r:20 w:20)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionBids (r:21 w:20)
+	/// Proof: Uniques CollectionBids (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:1000 w:1000)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Uniques ClassAccount (r:0 w:1)
 	/// Proof: Uniques ClassAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	/// Storage: Uniques Attribute (r:0 w:1000)
 	/// Proof: Uniques Attribute (max_values: None, max_size: Some(364), added: 2839, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionTransferPolicy (r:0 w:1)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
 	/// Storage: Uniques ClassMetadataOf (r:0 w:1)
 	/// Proof: Uniques ClassMetadataOf (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Uniques InstanceMetadataOf (r:0 w:1000)
 	/// Proof: Uniques InstanceMetadataOf (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
-	/// Storage: Uniques Account (r:0 w:1000)
-	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Uniques CollectionMaxSupply (r:0 w:1)
 	/// Proof: Uniques CollectionMaxSupply (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	/// The range of component `n` is `[0, 1000]`.
@@ -126,23 +152,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `a` is `[0, 1000]`.
 	fn destroy(n: u32, m: u32, a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `450 + n * (108 ±0) + m * (56 ±0) + a * (107 ±0)`
-		//  Estimated: `5250 + n * (2597 ±0)`
-		// Minimum execution time: 2_404_081 nanoseconds.
-		Weight::from_parts(2_419_004_000, 5250)
-			// Standard Error: 27_175
-			.saturating_add(Weight::from_ref_time(8_616_904).saturating_mul(n.into()))
-			// Standard Error: 27_175
-			.saturating_add(Weight::from_ref_time(334_249).saturating_mul(m.into()))
-			// Standard Error: 27_175
-			.saturating_add(Weight::from_ref_time(213_038).saturating_mul(a.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
-			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+		//  Measured:  `5583 + n * (1657 ±0) + m * (56 ±0) + a * (107 ±0)`
+		//  Estimated: `113753 + n * (60051 ±0)`
+		// Minimum execution time: 5_975_358 nanoseconds.
+		Weight::from_parts(6_330_927_000, 113753)
+			// Standard Error: 11_609_620
+			.saturating_add(Weight::from_ref_time(737_169_253).saturating_mul(n.into()))
+			// Standard Error: 11_609_620
+			.saturating_add(Weight::from_ref_time(28_551_883).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(44_u64))
+			.saturating_add(T::DbWeight::get().reads((23_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(45_u64))
+			.saturating_add(T::DbWeight::get().writes((22_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
-			.saturating_add(Weight::from_proof_size(2597).saturating_mul(n.into()))
+			.saturating_add(Weight::from_proof_size(60051).saturating_mul(n.into()))
 	}
 	/// Storage: Uniques Asset (r:1 w:1)
 	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
@@ -161,22 +185,34 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:0)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: Uniques Class (r:1 w:1)
-	/// Proof: Uniques Class (max_values: None, max_size: Some(178), added: 2653, mode: MaxEncodedLen)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
 	/// Storage: Uniques Asset (r:1 w:1)
 	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemOffers (r:21 w:20)
+	/// Proof: Uniques ItemOffers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: System Account (r:20 w:20)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:1 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:0 w:1)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
 	/// Storage: Uniques Account (r:0 w:1)
 	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Uniques ItemPriceOf (r:0 w:1)
 	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
 	fn burn() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `559`
-		//  Estimated: `5250`
-		// Minimum execution time: 30_497 nanoseconds.
-		Weight::from_parts(30_714_000, 5250)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		//  Measured:  `5751`
+		//  Estimated: `119859`
+		// Minimum execution time: 412_587 nanoseconds.
+		Weight::from_parts(665_749_000, 119859)
+			.saturating_add(T::DbWeight::get().reads(46_u64))
+			.saturating_add(T::DbWeight::get().writes(45_u64))
 	}
 	/// Storage: Uniques Class (r:1 w:0)
 	/// Proof: Uniques Class (max_values: None, max_size: Some(178), added: 2653, mode: MaxEncodedLen)
@@ -450,19 +486,223 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
 	/// Storage: Uniques ItemPriceOf (r:1 w:1)
 	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:1 w:0)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
 	/// Storage: Uniques Class (r:1 w:0)
-	/// Proof: Uniques Class (max_values: None, max_size: Some(178), added: 2653, mode: MaxEncodedLen)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Uniques Account (r:0 w:2)
 	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	fn buy_item() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `703`
-		//  Estimated: `7814`
-		// Minimum execution time: 35_628 nanoseconds.
-		Weight::from_parts(35_886_000, 7814)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		//  Measured:  `775`
+		//  Estimated: `13009`
+		// Minimum execution time: 48_630 nanoseconds.
+		Weight::from_parts(49_112_000, 13009)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Uniques Class (r:1 w:1)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	fn set_collection_royalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `382`
+		//  Estimated: `2690`
+		// Minimum execution time: 9_999 nanoseconds.
+		Weight::from_parts(10_709_000, 2690)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Uniques Class (r:1 w:1)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:0)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:0 w:1)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn set_item_royalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `508`
+		//  Estimated: `5287`
+		// Minimum execution time: 14_944 nanoseconds.
+		Weight::from_parts(16_019_000, 5287)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Uniques CollectionTransferPolicy (r:1 w:0)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:0)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemOffers (r:22 w:2)
+	/// Proof: Uniques ItemOffers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn place_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2504`
+		//  Estimated: `67141`
+		// Minimum execution time: 248_092 nanoseconds.
+		Weight::from_parts(319_879_000, 67141)
+			.saturating_add(T::DbWeight::get().reads(26_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: Uniques ItemOffers (r:1 w:1)
+	/// Proof: Uniques ItemOffers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `582`
+		//  Estimated: `5186`
+		// Minimum execution time: 21_167 nanoseconds.
+		Weight::from_parts(29_121_000, 5186)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Uniques CollectionTransferPolicy (r:1 w:0)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionBids (r:22 w:2)
+	/// Proof: Uniques CollectionBids (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn place_collection_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2305`
+		//  Estimated: `66794`
+		// Minimum execution time: 191_664 nanoseconds.
+		Weight::from_parts(308_330_000, 66794)
+			.saturating_add(T::DbWeight::get().reads(26_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Uniques CollectionBids (r:1 w:1)
+	/// Proof: Uniques CollectionBids (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_collection_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `496`
+		//  Estimated: `5166`
+		// Minimum execution time: 19_333 nanoseconds.
+		Weight::from_parts(22_992_000, 5166)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Uniques ItemOffers (r:1 w:1)
+	/// Proof: Uniques ItemOffers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionTransferPolicy (r:1 w:0)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:1 w:0)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:0)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn accept_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1283`
+		//  Estimated: `23177`
+		// Minimum execution time: 70_785 nanoseconds.
+		Weight::from_parts(114_403_000, 23177)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: Uniques CollectionBids (r:1 w:1)
+	/// Proof: Uniques CollectionBids (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionTransferPolicy (r:1 w:0)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:1 w:0)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:0)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn accept_collection_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1263`
+		//  Estimated: `23157`
+		// Minimum execution time: 69_749 nanoseconds.
+		Weight::from_parts(96_614_000, 23157)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionTransferPolicy (r:0 w:1)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	fn set_transfer_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `382`
+		//  Estimated: `2690`
+		// Minimum execution time: 12_687 nanoseconds.
+		Weight::from_parts(17_181_000, 2690)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques Attribute (r:1 w:1)
+	/// Proof: Uniques Attribute (max_values: None, max_size: Some(364), added: 2839, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:1)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	fn check_in() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `560`
+		//  Estimated: `8126`
+		// Minimum execution time: 34_177 nanoseconds.
+		Weight::from_parts(54_401_000, 8126)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Uniques Class (r:1 w:1)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques Attribute (r:1 w:1)
+	/// Proof: Uniques Attribute (max_values: None, max_size: Some(364), added: 2839, mode: MaxEncodedLen)
+	fn undo_check_in() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `671`
+		//  Estimated: `8126`
+		// Minimum execution time: 32_657 nanoseconds.
+		Weight::from_parts(51_575_000, 8126)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Uniques ItemOffers (r:1 w:1)
+	/// Proof: Uniques ItemOffers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_expired_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `582`
+		//  Estimated: `5186`
+		// Minimum execution time: 31_848 nanoseconds.
+		Weight::from_parts(34_472_000, 5186)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -494,19 +734,29 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Uniques Class (r:1 w:1)
-	/// Proof: Uniques Class (max_values: None, max_size: Some(178), added: 2653, mode: MaxEncodedLen)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
 	/// Storage: Uniques Asset (r:1001 w:1000)
 	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:1000 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemOffers (r:20001 w:20000)
+	/// Proof: Uniques ItemOffers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: System Account (r:20 w:20)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionBids (r:21 w:20)
+	/// Proof: Uniques CollectionBids (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:1000 w:1000)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Uniques ClassAccount (r:0 w:1)
 	/// Proof: Uniques ClassAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	/// Storage: Uniques Attribute (r:0 w:1000)
 	/// Proof: Uniques Attribute (max_values: None, max_size: Some(364), added: 2839, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionTransferPolicy (r:0 w:1)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
 	/// Storage: Uniques ClassMetadataOf (r:0 w:1)
 	/// Proof: Uniques ClassMetadataOf (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Uniques InstanceMetadataOf (r:0 w:1000)
 	/// Proof: Uniques InstanceMetadataOf (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
-	/// Storage: Uniques Account (r:0 w:1000)
-	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Uniques CollectionMaxSupply (r:0 w:1)
 	/// Proof: Uniques CollectionMaxSupply (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	/// The range of component `n` is `[0, 1000]`.
@@ -514,23 +764,21 @@ impl WeightInfo for () {
 	/// The range of component `a` is `[0, 1000]`.
 	fn destroy(n: u32, m: u32, a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `450 + n * (108 ±0) + m * (56 ±0) + a * (107 ±0)`
-		//  Estimated: `5250 + n * (2597 ±0)`
-		// Minimum execution time: 2_404_081 nanoseconds.
-		Weight::from_parts(2_419_004_000, 5250)
-			// Standard Error: 27_175
-			.saturating_add(Weight::from_ref_time(8_616_904).saturating_mul(n.into()))
-			// Standard Error: 27_175
-			.saturating_add(Weight::from_ref_time(334_249).saturating_mul(m.into()))
-			// Standard Error: 27_175
-			.saturating_add(Weight::from_ref_time(213_038).saturating_mul(a.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
-			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+		//  Measured:  `5583 + n * (1657 ±0) + m * (56 ±0) + a * (107 ±0)`
+		//  Estimated: `113753 + n * (60051 ±0)`
+		// Minimum execution time: 5_975_358 nanoseconds.
+		Weight::from_parts(6_330_927_000, 113753)
+			// Standard Error: 11_609_620
+			.saturating_add(Weight::from_ref_time(737_169_253).saturating_mul(n.into()))
+			// Standard Error: 11_609_620
+			.saturating_add(Weight::from_ref_time(28_551_883).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(44_u64))
+			.saturating_add(RocksDbWeight::get().reads((23_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(45_u64))
+			.saturating_add(RocksDbWeight::get().writes((22_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
-			.saturating_add(Weight::from_proof_size(2597).saturating_mul(n.into()))
+			.saturating_add(Weight::from_proof_size(60051).saturating_mul(n.into()))
 	}
 	/// Storage: Uniques Asset (r:1 w:1)
 	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
@@ -549,22 +797,34 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:0)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: Uniques Class (r:1 w:1)
-	/// Proof: Uniques Class (max_values: None, max_size: Some(178), added: 2653, mode: MaxEncodedLen)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
 	/// Storage: Uniques Asset (r:1 w:1)
 	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemOffers (r:21 w:20)
+	/// Proof: Uniques ItemOffers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: System Account (r:20 w:20)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: MusicRights Works (r:1 w:0)
+	/// Proof: MusicRights Works (max_values: None, max_size: Some(756), added: 3231, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:0 w:1)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
 	/// Storage: Uniques Account (r:0 w:1)
 	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Uniques ItemPriceOf (r:0 w:1)
 	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
 	fn burn() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `559`
-		//  Estimated: `5250`
-		// Minimum execution time: 30_497 nanoseconds.
-		Weight::from_parts(30_714_000, 5250)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `5751`
+		//  Estimated: `119859`
+		// Minimum execution time: 412_587 nanoseconds.
+		Weight::from_parts(665_749_000, 119859)
+			.saturating_add(RocksDbWeight::get().reads(46_u64))
+			.saturating_add(RocksDbWeight::get().writes(45_u64))
 	}
 	/// Storage: Uniques Class (r:1 w:0)
 	/// Proof: Uniques Class (max_values: None, max_size: Some(178), added: 2653, mode: MaxEncodedLen)
@@ -838,17 +1098,221 @@ impl WeightInfo for () {
 	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
 	/// Storage: Uniques ItemPriceOf (r:1 w:1)
 	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:1 w:0)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
 	/// Storage: Uniques Class (r:1 w:0)
-	/// Proof: Uniques Class (max_values: None, max_size: Some(178), added: 2653, mode: MaxEncodedLen)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Uniques Account (r:0 w:2)
 	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	fn buy_item() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `703`
-		//  Estimated: `7814`
-		// Minimum execution time: 35_628 nanoseconds.
-		Weight::from_parts(35_886_000, 7814)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		//  Measured:  `775`
+		//  Estimated: `13009`
+		// Minimum execution time: 48_630 nanoseconds.
+		Weight::from_parts(49_112_000, 13009)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Uniques Class (r:1 w:1)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	fn set_collection_royalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `382`
+		//  Estimated: `2690`
+		// Minimum execution time: 9_999 nanoseconds.
+		Weight::from_parts(10_709_000, 2690)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Uniques Class (r:1 w:1)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:0)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:0 w:1)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn set_item_royalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `508`
+		//  Estimated: `5287`
+		// Minimum execution time: 14_944 nanoseconds.
+		Weight::from_parts(16_019_000, 5287)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Uniques CollectionTransferPolicy (r:1 w:0)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:0)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemOffers (r:22 w:2)
+	/// Proof: Uniques ItemOffers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn place_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2504`
+		//  Estimated: `67141`
+		// Minimum execution time: 248_092 nanoseconds.
+		Weight::from_parts(319_879_000, 67141)
+			.saturating_add(RocksDbWeight::get().reads(26_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: Uniques ItemOffers (r:1 w:1)
+	/// Proof: Uniques ItemOffers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `582`
+		//  Estimated: `5186`
+		// Minimum execution time: 21_167 nanoseconds.
+		Weight::from_parts(29_121_000, 5186)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Uniques CollectionTransferPolicy (r:1 w:0)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionBids (r:22 w:2)
+	/// Proof: Uniques CollectionBids (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn place_collection_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2305`
+		//  Estimated: `66794`
+		// Minimum execution time: 191_664 nanoseconds.
+		Weight::from_parts(308_330_000, 66794)
+			.saturating_add(RocksDbWeight::get().reads(26_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Uniques CollectionBids (r:1 w:1)
+	/// Proof: Uniques CollectionBids (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_collection_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `496`
+		//  Estimated: `5166`
+		// Minimum execution time: 19_333 nanoseconds.
+		Weight::from_parts(22_992_000, 5166)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Uniques ItemOffers (r:1 w:1)
+	/// Proof: Uniques ItemOffers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionTransferPolicy (r:1 w:0)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:1 w:0)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:0)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn accept_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1283`
+		//  Estimated: `23177`
+		// Minimum execution time: 70_785 nanoseconds.
+		Weight::from_parts(114_403_000, 23177)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Uniques CollectionBids (r:1 w:1)
+	/// Proof: Uniques CollectionBids (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionTransferPolicy (r:1 w:0)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemRoyalty (r:1 w:0)
+	/// Proof: Uniques ItemRoyalty (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: NftFractionalization FractionalizedItems (r:1 w:0)
+	/// Proof: NftFractionalization FractionalizedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: NftAuctions AuctionedItems (r:1 w:0)
+	/// Proof: NftAuctions AuctionedItems (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Uniques Account (r:0 w:2)
+	/// Proof: Uniques Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Uniques ItemPriceOf (r:0 w:1)
+	/// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn accept_collection_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1263`
+		//  Estimated: `23157`
+		// Minimum execution time: 69_749 nanoseconds.
+		Weight::from_parts(96_614_000, 23157)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Uniques Class (r:1 w:0)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: Uniques CollectionTransferPolicy (r:0 w:1)
+	/// Proof: Uniques CollectionTransferPolicy (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	fn set_transfer_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `382`
+		//  Estimated: `2690`
+		// Minimum execution time: 12_687 nanoseconds.
+		Weight::from_parts(17_181_000, 2690)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques Attribute (r:1 w:1)
+	/// Proof: Uniques Attribute (max_values: None, max_size: Some(364), added: 2839, mode: MaxEncodedLen)
+	/// Storage: Uniques Class (r:1 w:1)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	fn check_in() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `560`
+		//  Estimated: `8126`
+		// Minimum execution time: 34_177 nanoseconds.
+		Weight::from_parts(54_401_000, 8126)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Uniques Class (r:1 w:1)
+	/// Proof: Uniques Class (max_values: None, max_size: Some(215), added: 2690, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:1)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(122), added: 2597, mode: MaxEncodedLen)
+	/// Storage: Uniques Attribute (r:1 w:1)
+	/// Proof: Uniques Attribute (max_values: None, max_size: Some(364), added: 2839, mode: MaxEncodedLen)
+	fn undo_check_in() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `671`
+		//  Estimated: `8126`
+		// Minimum execution time: 32_657 nanoseconds.
+		Weight::from_parts(51_575_000, 8126)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Uniques ItemOffers (r:1 w:1)
+	/// Proof: Uniques ItemOffers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_expired_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `582`
+		//  Estimated: `5186`
+		// Minimum execution time: 31_848 nanoseconds.
+		Weight::from_parts(34_472_000, 5186)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }